@@ -1,11 +1,7 @@
-use std::env;
+use std::{env, sync::Arc};
 
-use birdie::{
-    rest_api::Endpoint,
-    spot::user_data_stream::{StartUserDataStreamParams, UserDataStreamPayload},
-    web_socket::ConnectionStatus,
-    web_socket_stream::connect_raw_stream,
-};
+use birdie::{user_data_stream::UserDataStream, web_socket_stream::StreamEvent};
+use futures_util::StreamExt;
 
 #[tokio::main]
 async fn main() {
@@ -18,45 +14,23 @@ async fn main() {
     let rest_api = birdie::rest_api(&rest_base_url, &rest_api_key, &rest_secret_key)
         .expect("Failed to initialize Rest API Client");
 
-    let params = StartUserDataStreamParams::new();
-    let resp = rest_api
-        .user_data_stream()
-        .start_user_data_stream()
-        .request(params)
-        .await
-        .expect("Failed to start user data stream");
-    let listen_key = resp.listen_key;
-    println!("listen_key={listen_key}");
-
     let stream_endpoint = env::var("BINANCE_WEB_SOCKET_STREAM_ENDPOINT")
         .expect("BINANCE_WEB_SOCKET_STREAM_ENDPOINT is not set");
 
-    let (tx, mut rx) = tokio::sync::mpsc::channel(10);
-    let (data_writer, mut data_reader) = tokio::sync::mpsc::channel(1024);
-    connect_raw_stream::<UserDataStreamPayload>(&stream_endpoint, &listen_key, data_writer, tx)
+    // The listen key is kept alive, and renewed once expired.
+    let mut stream = UserDataStream::spot(Arc::new(rest_api), &stream_endpoint);
+    stream
+        .start()
         .await
-        .unwrap();
-
-    loop {
-        tokio::select! {
-            Some(status) = rx.recv() => {
-                match status {
-                    ConnectionStatus::Connected => {
-                        println!("connected");
-                    }
-                    ConnectionStatus::PingReceived => {
-                        println!("ping");
-                    }
-                    ConnectionStatus::PoingSent => {
-                        println!("pong");
-                    }
-                    ConnectionStatus::Disconnected => {
-                        println!("disconnected");
-                    }
-                }
-            }
-            Some(data) = data_reader.recv() => {
-                println!("{:?}", data);
+        .expect("Failed to start user data stream");
+    println!("listen_key={}", stream.listen_key().unwrap_or_default());
+
+    while let Some(item) = stream.next().await {
+        match item {
+            Ok(data) => println!("{data:?}"),
+            Err(StreamEvent::Status(status)) => println!("{status:?}"),
+            Err(StreamEvent::Unparsed(unparsed)) => {
+                println!("unparsed message: {}: {}", unparsed.error, unparsed.message);
             }
         }
     }
@@ -0,0 +1,104 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    decimal::Decimal,
+    enums::{futures::PositionSide, SecurityType},
+    rest_api::endpoint,
+};
+
+endpoint!(
+    "/dapi/v1/account",
+    Method::GET,
+    SecurityType::UserData,
+    AccountInformationEndpoint,
+    AccountInformationParams,
+    AccountInformationResponse,
+    weight = 5
+);
+
+/// Get current account information.
+///
+/// - Weight: 5
+pub struct AccountInformationEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> AccountInformationEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformationParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl Default for AccountInformationParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AccountInformationParams {
+    pub fn new() -> Self {
+        Self { recv_window: None }
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformationResponse {
+    pub assets: Vec<Asset>,
+    pub positions: Vec<Position>,
+    pub can_deposit: bool,
+    pub can_trade: bool,
+    pub can_withdraw: bool,
+    pub fee_tier: i64,
+    pub update_time: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Asset {
+    pub asset: String,
+    pub wallet_balance: Decimal,
+    pub unrealized_profit: Decimal,
+    pub margin_balance: Decimal,
+    pub maint_margin: Decimal,
+    pub initial_margin: Decimal,
+    pub position_initial_margin: Decimal,
+    pub open_order_initial_margin: Decimal,
+    pub max_withdraw_amount: Decimal,
+    pub cross_wallet_balance: Decimal,
+    pub cross_un_pnl: Decimal,
+    pub available_balance: Decimal,
+    pub update_time: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    pub symbol: String,
+    pub position_amt: Decimal,
+    pub initial_margin: Decimal,
+    pub maint_margin: Decimal,
+    pub unrealized_profit: Decimal,
+    pub position_initial_margin: Decimal,
+    pub open_order_initial_margin: Decimal,
+    pub leverage: Decimal,
+    pub isolated: bool,
+    pub position_side: PositionSide,
+    pub entry_price: Decimal,
+    pub break_even_price: Decimal,
+    pub max_qty: Decimal,
+    pub update_time: i64,
+}
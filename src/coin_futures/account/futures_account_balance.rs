@@ -0,0 +1,66 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/dapi/v1/balance",
+    Method::GET,
+    SecurityType::UserData,
+    FuturesAccountBalanceEndpoint,
+    FuturesAccountBalanceParams,
+    FuturesAccountBalanceResponse,
+    weight = 1
+);
+
+/// Query account balance info.
+///
+/// - Weight: 1
+pub struct FuturesAccountBalanceEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> FuturesAccountBalanceEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesAccountBalanceParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl Default for FuturesAccountBalanceParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FuturesAccountBalanceParams {
+    pub fn new() -> Self {
+        Self { recv_window: None }
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+pub type FuturesAccountBalanceResponse = Vec<Balance>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Balance {
+    pub account_alias: String,
+    pub asset: String,
+    pub balance: Decimal,
+    pub withdraw_available: Decimal,
+    pub cross_wallet_balance: Decimal,
+    pub cross_un_pnl: Decimal,
+    pub available_balance: Decimal,
+    pub update_time: i64,
+}
@@ -0,0 +1,123 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    decimal::Decimal,
+    enums::{futures::IncomeType, SecurityType},
+    rest_api::endpoint,
+};
+
+endpoint!(
+    "/dapi/v1/income",
+    Method::GET,
+    SecurityType::UserData,
+    GetIncomeHistoryEndpoint,
+    GetIncomeHistoryParams,
+    GetIncomeHistoryResponse,
+    weight = 20
+);
+
+/// Query income history.
+///
+/// - Weight: 20
+pub struct GetIncomeHistoryEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> GetIncomeHistoryEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetIncomeHistoryParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    income_type: Option<IncomeType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl Default for GetIncomeHistoryParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GetIncomeHistoryParams {
+    pub fn new() -> Self {
+        Self {
+            symbol: None,
+            income_type: None,
+            start_time: None,
+            end_time: None,
+            page: None,
+            limit: None,
+            recv_window: None,
+        }
+    }
+
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_owned());
+        self
+    }
+
+    pub fn income_type(mut self, income_type: IncomeType) -> Self {
+        self.income_type = Some(income_type);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Default 100, max 1000.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+pub type GetIncomeHistoryResponse = Vec<Income>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Income {
+    /// Empty when the income is not related to a symbol.
+    pub symbol: String,
+    pub income_type: IncomeType,
+    pub income: Decimal,
+    pub asset: String,
+    pub info: String,
+    pub time: i64,
+    pub tran_id: String,
+    /// Empty when the income is not related to a trade.
+    pub trade_id: String,
+}
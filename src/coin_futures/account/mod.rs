@@ -0,0 +1,32 @@
+mod account_information;
+mod futures_account_balance;
+mod get_current_position_mode;
+mod get_income_history;
+mod notional_bracket_for_pair;
+mod user_commission_rate;
+
+pub use account_information::*;
+pub use futures_account_balance::*;
+pub use get_current_position_mode::*;
+pub use get_income_history::*;
+pub use notional_bracket_for_pair::*;
+pub use user_commission_rate::*;
+
+use crate::rest_api::{route, RestApiClient};
+
+pub struct RestApiHandler<'r> {
+    client: &'r RestApiClient,
+}
+
+impl<'r> RestApiHandler<'r> {
+    pub fn new(client: &'r RestApiClient) -> Self {
+        RestApiHandler { client }
+    }
+
+    route!(futures_account_balance, FuturesAccountBalanceEndpoint);
+    route!(account_information, AccountInformationEndpoint);
+    route!(user_commission_rate, UserCommissionRateEndpoint);
+    route!(get_current_position_mode, GetCurrentPositionModeEndpoint);
+    route!(get_income_history, GetIncomeHistoryEndpoint);
+    route!(notional_bracket_for_pair, NotionalBracketForPairEndpoint);
+}
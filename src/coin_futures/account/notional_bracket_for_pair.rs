@@ -0,0 +1,88 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/dapi/v2/leverageBracket",
+    Method::GET,
+    SecurityType::UserData,
+    NotionalBracketForPairEndpoint,
+    NotionalBracketForPairParams,
+    NotionalBracketForPairResponse,
+    weight = 1
+);
+
+/// Get the pair's default notional bracket list, or the user's list when
+/// overwritten.
+///
+/// - Weight: 1
+pub struct NotionalBracketForPairEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> NotionalBracketForPairEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotionalBracketForPairParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl Default for NotionalBracketForPairParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NotionalBracketForPairParams {
+    pub fn new() -> Self {
+        Self {
+            symbol: None,
+            recv_window: None,
+        }
+    }
+
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_owned());
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+pub type NotionalBracketForPairResponse = Vec<SymbolBrackets>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolBrackets {
+    pub symbol: String,
+    /// User symbol bracket multiplier, only sent when it is not 1.
+    pub notional_coef: Option<Decimal>,
+    pub brackets: Vec<Bracket>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bracket {
+    pub bracket: i64,
+    /// Max initial leverage of the bracket.
+    pub initial_leverage: i64,
+    /// Cap quantity of the bracket, in the base asset.
+    pub qty_cap: Decimal,
+    /// Quantity threshold of the bracket, in the base asset.
+    pub qty_floor: Decimal,
+    pub maint_margin_ratio: Decimal,
+    /// Auxiliary number for the quick calculation of the maintenance margin.
+    pub cum: Decimal,
+}
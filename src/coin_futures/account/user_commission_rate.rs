@@ -0,0 +1,57 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/dapi/v1/commissionRate",
+    Method::GET,
+    SecurityType::UserData,
+    UserCommissionRateEndpoint,
+    UserCommissionRateParams,
+    UserCommissionRateResponse,
+    weight = 20
+);
+
+/// Get User Commission Rate.
+///
+/// - Weight: 20
+pub struct UserCommissionRateEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> UserCommissionRateEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserCommissionRateParams {
+    symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl UserCommissionRateParams {
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            recv_window: None,
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserCommissionRateResponse {
+    pub symbol: String,
+    pub maker_commission_rate: Decimal,
+    pub taker_commission_rate: Decimal,
+}
@@ -0,0 +1,97 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, rest_api::endpoint};
+
+endpoint!(
+    "/dapi/v1/aggTrades",
+    Method::GET,
+    AggregateTradesListEndpoint,
+    AggregateTradesListParams,
+    AggregateTradesListResponse,
+    weight = 20
+);
+
+/// Get compressed, aggregate market trades. Market trades that fill in 100ms with the
+/// same price and the same taking side will have the quantity aggregated.
+///
+/// - Weight: 20
+pub struct AggregateTradesListEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> AggregateTradesListEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AggregateTradesListParams {
+    symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+}
+
+impl AggregateTradesListParams {
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            from_id: None,
+            start_time: None,
+            end_time: None,
+            limit: None,
+        }
+    }
+
+    /// Id to get aggregate trades from, inclusive.
+    pub fn from_id(mut self, from_id: i64) -> Self {
+        self.from_id = Some(from_id);
+        self
+    }
+
+    /// The interval between `start_time` and `end_time` must be
+    /// less than 1 hour.
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Default 500; max 1000.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+pub type AggregateTradesListResponse = Vec<AggregateTrade>;
+
+#[derive(Debug, Deserialize)]
+pub struct AggregateTrade {
+    #[serde(rename = "a")]
+    pub aggregate_trade_id: i64,
+    #[serde(rename = "p")]
+    pub price: Decimal,
+    #[serde(rename = "q")]
+    pub quantity: Decimal,
+    #[serde(rename = "f")]
+    pub first_trade_id: i64,
+    #[serde(rename = "l")]
+    pub last_trade_id: i64,
+    #[serde(rename = "T")]
+    pub timestamp: i64,
+    #[serde(rename = "m")]
+    pub is_buyer_maker: bool,
+}
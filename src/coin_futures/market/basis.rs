@@ -0,0 +1,88 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, enums::futures::ContractType, rest_api::endpoint};
+
+endpoint!(
+    "/futures/data/basis",
+    Method::GET,
+    BasisEndpoint,
+    BasisParams,
+    BasisResponse
+);
+
+/// The basis of a contract type of a pair, i.e. the difference between the
+/// futures price and the index price.
+///
+/// - Weight: 1
+pub struct BasisEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> BasisEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BasisParams {
+    pair: String,
+    contract_type: ContractType,
+    period: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_time: Option<i64>,
+}
+
+impl BasisParams {
+    /// `period` is one of `5m`, `15m`, `30m`, `1h`, `2h`, `4h`, `6h`, `12h` and `1d`.
+    pub fn new(pair: &str, contract_type: ContractType, period: &str) -> Self {
+        Self {
+            pair: pair.to_owned(),
+            contract_type,
+            period: period.to_owned(),
+            limit: None,
+            start_time: None,
+            end_time: None,
+        }
+    }
+
+    /// Default 30; max 500.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Only the data of the latest 30 days is available.
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+}
+
+pub type BasisResponse = Vec<Basis>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Basis {
+    pub pair: String,
+    pub contract_type: ContractType,
+    pub index_price: Decimal,
+    pub futures_price: Decimal,
+    pub basis: Decimal,
+    pub basis_rate: Decimal,
+    /// Empty for the perpetual contracts.
+    #[serde(deserialize_with = "crate::decimal::empty_as_none")]
+    pub annualized_basis_rate: Option<Decimal>,
+    pub timestamp: i64,
+}
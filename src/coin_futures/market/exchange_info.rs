@@ -0,0 +1,89 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    decimal::Decimal,
+    enums::futures::{ContractStats, ContractType, OrderType, TimeInForce},
+    enums::RateLimit,
+    filters::{futures::SymbolFilter, ExchangeFilter},
+    rest_api::endpoint,
+};
+
+endpoint!(
+    "/dapi/v1/exchangeInfo",
+    Method::GET,
+    ExchangeInfoEndpoint,
+    ExchangeInfoParams,
+    ExchangeInfoResponse
+);
+
+/// Current exchange trading rules and symbol information.
+///
+/// - Weight: 1
+pub struct ExchangeInfoEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> ExchangeInfoEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInfoParams {}
+
+impl Default for ExchangeInfoParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExchangeInfoParams {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInfoResponse {
+    pub exchange_filters: Vec<ExchangeFilter>,
+    pub rate_limits: Vec<RateLimit>,
+    pub server_time: i64,
+    pub symbols: Vec<Symbol>,
+    pub timezone: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Symbol {
+    pub symbol: String,
+    pub pair: String,
+    pub contract_type: ContractType,
+    pub delivery_date: i64,
+    pub onboard_date: i64,
+    pub contract_status: ContractStats,
+    /// Value of a contract, in the quote asset.
+    pub contract_size: i64,
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub margin_asset: String,
+    pub price_precision: i64,
+    pub quantity_precision: i64,
+    pub base_asset_precision: i64,
+    pub quote_precision: i64,
+    pub equal_qty_precision: i64,
+    pub maint_margin_percent: Decimal,
+    pub required_margin_percent: Decimal,
+    pub underlying_type: String,
+    pub underlying_sub_type: Vec<String>,
+    pub trigger_protect: Decimal,
+    pub liquidation_fee: Decimal,
+    pub market_take_bound: Decimal,
+    pub max_move_order_limit: Option<i64>,
+    pub filters: Vec<SymbolFilter>,
+    pub order_types: Vec<OrderType>,
+    pub time_in_force: Vec<TimeInForce>,
+}
@@ -0,0 +1,54 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, rest_api::endpoint};
+
+endpoint!(
+    "/dapi/v1/fundingInfo",
+    Method::GET,
+    GetFundingInfoEndpoint,
+    GetFundingInfoParams,
+    GetFundingInfoResponse
+);
+
+/// Query the funding rate info of the symbols that had an adjustment of the
+/// funding rate cap, floor or interval.
+///
+/// - Weight: 1
+pub struct GetFundingInfoEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> GetFundingInfoEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFundingInfoParams {}
+
+impl Default for GetFundingInfoParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GetFundingInfoParams {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+pub type GetFundingInfoResponse = Vec<FundingInfo>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingInfo {
+    pub symbol: String,
+    pub adjusted_funding_rate_cap: Decimal,
+    pub adjusted_funding_rate_floor: Decimal,
+    pub funding_interval_hours: i64,
+    pub disclaimer: bool,
+}
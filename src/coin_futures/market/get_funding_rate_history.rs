@@ -0,0 +1,76 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, rest_api::endpoint};
+
+endpoint!(
+    "/dapi/v1/fundingRate",
+    Method::GET,
+    GetFundingRateHistoryEndpoint,
+    GetFundingRateHistoryParams,
+    GetFundingRateHistoryResponse
+);
+
+/// Get the funding rate history of a perpetual symbol.
+///
+/// - Weight: 1
+pub struct GetFundingRateHistoryEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> GetFundingRateHistoryEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFundingRateHistoryParams {
+    symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+}
+
+impl GetFundingRateHistoryParams {
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            start_time: None,
+            end_time: None,
+            limit: None,
+        }
+    }
+
+    /// Timestamp in ms to get funding rate from, inclusive.
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    /// Timestamp in ms to get funding rate until, inclusive.
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Default 100; max 1000.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+pub type GetFundingRateHistoryResponse = Vec<FundingRate>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRate {
+    pub symbol: String,
+    pub funding_time: i64,
+    pub funding_rate: Decimal,
+}
@@ -0,0 +1,82 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, rest_api::endpoint};
+
+endpoint!(
+    "/dapi/v1/premiumIndex",
+    Method::GET,
+    IndexPriceAndMarkPriceEndpoint,
+    IndexPriceAndMarkPriceParams,
+    IndexPriceAndMarkPriceResponse,
+    weight = 10
+);
+
+/// Query index price and mark price.
+///
+/// - Weight: 10
+pub struct IndexPriceAndMarkPriceEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> IndexPriceAndMarkPriceEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexPriceAndMarkPriceParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pair: Option<String>,
+}
+
+impl Default for IndexPriceAndMarkPriceParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IndexPriceAndMarkPriceParams {
+    pub fn new() -> Self {
+        Self {
+            symbol: None,
+            pair: None,
+        }
+    }
+
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_owned());
+        self
+    }
+
+    pub fn pair(mut self, pair: &str) -> Self {
+        self.pair = Some(pair.to_owned());
+        self
+    }
+}
+
+pub type IndexPriceAndMarkPriceResponse = Vec<PremiumIndex>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PremiumIndex {
+    pub symbol: String,
+    pub pair: String,
+    pub mark_price: Decimal,
+    pub index_price: Decimal,
+    /// Only useful in the last hour before the settlement starts.
+    pub estimated_settle_price: Decimal,
+    /// Empty for the delivery contracts.
+    #[serde(deserialize_with = "crate::decimal::empty_as_none")]
+    pub last_funding_rate: Option<Decimal>,
+    /// Empty for the delivery contracts.
+    #[serde(deserialize_with = "crate::decimal::empty_as_none")]
+    pub interest_rate: Option<Decimal>,
+    /// 0 for the delivery contracts.
+    pub next_funding_time: i64,
+    pub time: i64,
+}
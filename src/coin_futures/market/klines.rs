@@ -0,0 +1,96 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, enums::KlineInterval, rest_api::endpoint};
+
+endpoint!(
+    "/dapi/v1/klines",
+    Method::GET,
+    KlinesEndpoint,
+    KlinesParams,
+    KlinesResponse,
+    weight = |params| match params.limit.unwrap_or(500) {
+        ..=99 => 1,
+        100..=499 => 2,
+        500..=1000 => 5,
+        _ => 10,
+    }
+);
+
+/// Kline/candlestick bars for a symbol. Klines are uniquely identified by their
+/// open time.
+///
+/// - Weight:
+///   - limit [1,100): 1
+///   - limit [100,500): 2
+///   - limit \[500,1000\]: 5
+///   - limit (1000,): 10
+pub struct KlinesEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> KlinesEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KlinesParams {
+    symbol: String,
+    interval: KlineInterval,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+}
+
+impl KlinesParams {
+    /// The interval between `start_time` and `end_time` can be at most 200 days.
+    pub fn new(symbol: &str, interval: KlineInterval) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            interval,
+            start_time: None,
+            end_time: None,
+            limit: None,
+        }
+    }
+
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Default 500; max 1500.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+pub type KlinesResponse = Vec<Kline>;
+
+#[derive(Debug, Deserialize)]
+pub struct Kline(
+    pub i64,     // Open time
+    pub Decimal, // Open price
+    pub Decimal, // High price
+    pub Decimal, // Low price
+    pub Decimal, // Close price
+    pub Decimal, // Volume, in contracts
+    pub i64,     // Close time
+    pub Decimal, // Base asset volume
+    pub i64,     // Number of trades
+    pub Decimal, // Taker buy volume
+    pub Decimal, // Taker buy base asset volume
+    pub String,  // Unused field, ignore.
+);
@@ -0,0 +1,80 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, rest_api::endpoint};
+
+endpoint!(
+    "/futures/data/globalLongShortAccountRatio",
+    Method::GET,
+    LongShortRatioEndpoint,
+    LongShortRatioParams,
+    LongShortRatioResponse
+);
+
+/// The long/short ratio of all the accounts with a position on the pair.
+///
+/// - Weight: 1
+pub struct LongShortRatioEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> LongShortRatioEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LongShortRatioParams {
+    pair: String,
+    period: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_time: Option<i64>,
+}
+
+impl LongShortRatioParams {
+    /// `period` is one of `5m`, `15m`, `30m`, `1h`, `2h`, `4h`, `6h`, `12h` and `1d`.
+    pub fn new(pair: &str, period: &str) -> Self {
+        Self {
+            pair: pair.to_owned(),
+            period: period.to_owned(),
+            limit: None,
+            start_time: None,
+            end_time: None,
+        }
+    }
+
+    /// Default 30; max 500.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Only the data of the latest 30 days is available.
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+}
+
+pub type LongShortRatioResponse = Vec<LongShortRatio>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LongShortRatio {
+    pub pair: String,
+    pub long_short_ratio: Decimal,
+    pub long_account: Decimal,
+    pub short_account: Decimal,
+    pub timestamp: i64,
+}
@@ -0,0 +1,101 @@
+mod aggregate_trades_list;
+mod basis;
+mod check_server_time;
+mod continuous_contract_klines;
+mod exchange_info;
+mod get_funding_info;
+mod get_funding_rate_history;
+mod index_price_and_mark_price;
+mod index_price_klines;
+mod klines;
+mod long_short_ratio;
+mod mark_price_klines;
+mod old_trade_lookup;
+mod open_interest;
+mod open_interest_statistics;
+mod order_book;
+mod premium_index_klines;
+mod query_index_price_constituents;
+mod recent_trades_list;
+mod symbol_order_book_ticker;
+mod symbol_price_ticker;
+mod taker_buy_sell_volume;
+mod test_connectivity;
+mod ticker_24hr;
+mod top_trader_long_short_account_ratio;
+mod top_trader_long_short_position_ratio;
+
+pub use aggregate_trades_list::*;
+pub use basis::*;
+pub use check_server_time::*;
+pub use continuous_contract_klines::*;
+pub use exchange_info::*;
+pub use get_funding_info::*;
+pub use get_funding_rate_history::*;
+pub use index_price_and_mark_price::*;
+pub use index_price_klines::*;
+pub use klines::*;
+pub use long_short_ratio::*;
+pub use mark_price_klines::*;
+pub use old_trade_lookup::*;
+pub use open_interest::*;
+pub use open_interest_statistics::*;
+pub use order_book::*;
+pub use premium_index_klines::*;
+pub use query_index_price_constituents::*;
+pub use recent_trades_list::*;
+pub use symbol_order_book_ticker::*;
+pub use symbol_price_ticker::*;
+pub use taker_buy_sell_volume::*;
+pub use test_connectivity::*;
+pub use ticker_24hr::*;
+pub use top_trader_long_short_account_ratio::*;
+pub use top_trader_long_short_position_ratio::*;
+
+use crate::rest_api::{route, RestApiClient};
+
+pub struct RestApiHandler<'r> {
+    client: &'r RestApiClient,
+}
+
+impl<'r> RestApiHandler<'r> {
+    pub fn new(client: &'r RestApiClient) -> Self {
+        RestApiHandler { client }
+    }
+
+    route!(test_connectivity, TestConnectivityEndpoint);
+    route!(check_server_time, CheckServerTimeEndpoint);
+    route!(exchange_info, ExchangeInfoEndpoint);
+    route!(order_book, OrderBookEndpoint);
+    route!(recent_trades_list, RecentTradesListEndpoint);
+    route!(old_trade_lookup, OldTradeLookupEndpoint);
+    route!(aggregate_trades_list, AggregateTradesListEndpoint);
+    route!(index_price_and_mark_price, IndexPriceAndMarkPriceEndpoint);
+    route!(get_funding_rate_history, GetFundingRateHistoryEndpoint);
+    route!(get_funding_info, GetFundingInfoEndpoint);
+    route!(klines, KlinesEndpoint);
+    route!(continuous_contract_klines, ContinuousContractKlinesEndpoint);
+    route!(index_price_klines, IndexPriceKlinesEndpoint);
+    route!(mark_price_klines, MarkPriceKlinesEndpoint);
+    route!(premium_index_klines, PremiumIndexKlinesEndpoint);
+    route!(ticker_24hr, Ticker24hrEndpoint);
+    route!(symbol_price_ticker, SymbolPriceTickerEndpoint);
+    route!(symbol_order_book_ticker, SymbolOrderBookTickerEndpoint);
+    route!(open_interest, OpenInterestEndpoint);
+    route!(
+        query_index_price_constituents,
+        QueryIndexPriceConstituentsEndpoint
+    );
+    route!(open_interest_statistics, OpenInterestStatisticsEndpoint);
+    route!(
+        top_trader_long_short_account_ratio,
+        TopTraderLongShortAccountRatioEndpoint
+    );
+    route!(
+        top_trader_long_short_position_ratio,
+        TopTraderLongShortPositionRatioEndpoint
+    );
+    route!(long_short_ratio, LongShortRatioEndpoint);
+    route!(taker_buy_sell_volume, TakerBuySellVolumeEndpoint);
+    route!(basis, BasisEndpoint);
+}
@@ -0,0 +1,81 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/dapi/v1/historicalTrades",
+    Method::GET,
+    SecurityType::MarketData,
+    OldTradeLookupEndpoint,
+    OldTradeLookupParams,
+    OldTradeLookupResponse,
+    weight = 20
+);
+
+/// Get older market historical trades.
+///
+/// - Weight: 20
+pub struct OldTradeLookupEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> OldTradeLookupEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OldTradeLookupParams {
+    symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl OldTradeLookupParams {
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            limit: None,
+            from_id: None,
+            recv_window: None,
+        }
+    }
+
+    /// Default 100; max 500.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Trade id to fetch from. Default gets most recent trades.
+    pub fn from_id(mut self, from_id: i64) -> Self {
+        self.from_id = Some(from_id);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+pub type OldTradeLookupResponse = Vec<OldTrade>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OldTrade {
+    pub id: i64,
+    pub price: Decimal,
+    /// Quantity in contracts.
+    pub qty: Decimal,
+    pub base_qty: Decimal,
+    pub time: i64,
+    pub is_buyer_maker: bool,
+}
@@ -0,0 +1,50 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, enums::futures::ContractType, rest_api::endpoint};
+
+endpoint!(
+    "/dapi/v1/openInterest",
+    Method::GET,
+    OpenInterestEndpoint,
+    OpenInterestParams,
+    OpenInterestResponse
+);
+
+/// Get present open interest of a specific symbol.
+///
+/// - Weight: 1
+pub struct OpenInterestEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> OpenInterestEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestParams {
+    symbol: String,
+}
+
+impl OpenInterestParams {
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestResponse {
+    pub symbol: String,
+    pub pair: String,
+    /// Open interest in contracts.
+    pub open_interest: Decimal,
+    pub contract_type: ContractType,
+    pub time: i64,
+}
@@ -0,0 +1,84 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, enums::futures::ContractType, rest_api::endpoint};
+
+endpoint!(
+    "/futures/data/openInterestHist",
+    Method::GET,
+    OpenInterestStatisticsEndpoint,
+    OpenInterestStatisticsParams,
+    OpenInterestStatisticsResponse
+);
+
+/// Open Interest Statistics.
+///
+/// - Weight: 1
+pub struct OpenInterestStatisticsEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> OpenInterestStatisticsEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestStatisticsParams {
+    pair: String,
+    contract_type: ContractType,
+    period: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_time: Option<i64>,
+}
+
+impl OpenInterestStatisticsParams {
+    /// `period` is one of `5m`, `15m`, `30m`, `1h`, `2h`, `4h`, `6h`, `12h` and `1d`.
+    pub fn new(pair: &str, contract_type: ContractType, period: &str) -> Self {
+        Self {
+            pair: pair.to_owned(),
+            contract_type,
+            period: period.to_owned(),
+            limit: None,
+            start_time: None,
+            end_time: None,
+        }
+    }
+
+    /// Default 30; max 500.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Only the data of the latest 30 days is available.
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+}
+
+pub type OpenInterestStatisticsResponse = Vec<OpenInterestStatistics>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestStatistics {
+    pub pair: String,
+    pub contract_type: ContractType,
+    /// Open interest in contracts.
+    pub sum_open_interest: Decimal,
+    /// Open interest in the base asset.
+    pub sum_open_interest_value: Decimal,
+    pub timestamp: i64,
+}
@@ -0,0 +1,72 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, rest_api::endpoint};
+
+endpoint!(
+    "/dapi/v1/depth",
+    Method::GET,
+    OrderBookEndpoint,
+    OrderBookParams,
+    OrderBookResponse,
+    weight = |params| match params.limit.unwrap_or(500) {
+        ..=50 => 2,
+        51..=100 => 5,
+        101..=500 => 10,
+        _ => 20,
+    }
+);
+
+/// Query symbol orderbook.
+///
+/// - Weight:
+///   - Limit 5, 10, 20, 50: 2
+///   - Limit 100: 5
+///   - Limit 500: 10
+///   - Limit 1000: 20
+pub struct OrderBookEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> OrderBookEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderBookParams {
+    symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+}
+
+impl OrderBookParams {
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            limit: None,
+        }
+    }
+
+    /// Default 500; valid limits: 5, 10, 20, 50, 100, 500, 1000.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OrderBookResponse {
+    #[serde(rename = "lastUpdateId")]
+    pub last_update_id: i64,
+    pub symbol: String,
+    pub pair: String,
+    #[serde(rename = "E")]
+    pub message_output_time: i64,
+    #[serde(rename = "T")]
+    pub transaction_time: i64,
+    pub bids: Vec<(Decimal, Decimal)>,
+    pub asks: Vec<(Decimal, Decimal)>,
+}
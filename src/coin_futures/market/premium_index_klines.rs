@@ -0,0 +1,104 @@
+use reqwest::Method;
+use serde::Serialize;
+
+use crate::{
+    enums::{KlineInterval, SecurityType},
+    rest_api::{Endpoint, RestApiClient},
+    Params,
+};
+
+use super::Kline;
+
+impl Params for PremiumIndexKlinesParams {}
+
+impl Endpoint for PremiumIndexKlinesEndpoint<'_> {
+    type Params = PremiumIndexKlinesParams;
+    type Response = PremiumIndexKlinesResponse;
+
+    fn client(&self) -> &RestApiClient {
+        self.client
+    }
+
+    fn path(&self) -> &str {
+        "/dapi/v1/premiumIndexKlines"
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn security_type(&self) -> SecurityType {
+        SecurityType::None
+    }
+
+    fn weight(&self, params: &Self::Params) -> u32 {
+        match params.limit.unwrap_or(500) {
+            ..=99 => 1,
+            100..=499 => 2,
+            500..=1000 => 5,
+            _ => 10,
+        }
+    }
+}
+
+/// Premium index kline bars of a symbol. The volumes are always 0, and the
+/// number of trades is the number of basic data.
+///
+/// - Weight:
+///   - limit [1,100): 1
+///   - limit [100,500): 2
+///   - limit \[500,1000\]: 5
+///   - limit (1000,): 10
+pub struct PremiumIndexKlinesEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> PremiumIndexKlinesEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PremiumIndexKlinesParams {
+    symbol: String,
+    interval: KlineInterval,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+}
+
+impl PremiumIndexKlinesParams {
+    /// The interval between `start_time` and `end_time` can be at most 200 days.
+    pub fn new(symbol: &str, interval: KlineInterval) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            interval,
+            start_time: None,
+            end_time: None,
+            limit: None,
+        }
+    }
+
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Default 500; max 1500.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+pub type PremiumIndexKlinesResponse = Vec<Kline>;
@@ -0,0 +1,57 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, rest_api::endpoint};
+
+endpoint!(
+    "/dapi/v1/constituents",
+    Method::GET,
+    QueryIndexPriceConstituentsEndpoint,
+    QueryIndexPriceConstituentsParams,
+    QueryIndexPriceConstituentsResponse,
+    weight = 2
+);
+
+/// Query the index price constituents of a pair.
+///
+/// - Weight: 2
+pub struct QueryIndexPriceConstituentsEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> QueryIndexPriceConstituentsEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryIndexPriceConstituentsParams {
+    symbol: String,
+}
+
+impl QueryIndexPriceConstituentsParams {
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryIndexPriceConstituentsResponse {
+    pub symbol: String,
+    pub time: i64,
+    pub constituents: Vec<Constituent>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Constituent {
+    pub exchange: String,
+    pub symbol: String,
+    pub price: Decimal,
+    pub weight: Decimal,
+}
@@ -0,0 +1,63 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, rest_api::endpoint};
+
+endpoint!(
+    "/dapi/v1/trades",
+    Method::GET,
+    RecentTradesListEndpoint,
+    RecentTradesListParams,
+    RecentTradesListResponse,
+    weight = 5
+);
+
+/// Get recent market trades.
+///
+/// - Weight: 5
+pub struct RecentTradesListEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> RecentTradesListEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentTradesListParams {
+    symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+}
+
+impl RecentTradesListParams {
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            limit: None,
+        }
+    }
+
+    /// Default 500; max 1000.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+pub type RecentTradesListResponse = Vec<Trade>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    pub id: i64,
+    pub price: Decimal,
+    /// Quantity in contracts.
+    pub qty: Decimal,
+    pub base_qty: Decimal,
+    pub time: i64,
+    pub is_buyer_maker: bool,
+}
@@ -0,0 +1,79 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, rest_api::endpoint};
+
+endpoint!(
+    "/dapi/v1/ticker/bookTicker",
+    Method::GET,
+    SymbolOrderBookTickerEndpoint,
+    SymbolOrderBookTickerParams,
+    SymbolOrderBookTickerResponse,
+    weight = |params| if params.symbol.is_some() { 2 } else { 5 }
+);
+
+/// Best price/qty on the order book for a symbol or symbols.
+///
+/// - Weight:
+///   - with symbol: 2
+///   - without symbol: 5
+pub struct SymbolOrderBookTickerEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> SymbolOrderBookTickerEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolOrderBookTickerParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pair: Option<String>,
+}
+
+impl Default for SymbolOrderBookTickerParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SymbolOrderBookTickerParams {
+    pub fn new() -> Self {
+        Self {
+            symbol: None,
+            pair: None,
+        }
+    }
+
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_owned());
+        self
+    }
+
+    /// All the symbols of the pair are returned when `symbol` is not
+    /// sent.
+    pub fn pair(mut self, pair: &str) -> Self {
+        self.pair = Some(pair.to_owned());
+        self
+    }
+}
+
+pub type SymbolOrderBookTickerResponse = Vec<BookTicker>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BookTicker {
+    pub last_update_id: i64,
+    pub symbol: String,
+    pub pair: String,
+    pub bid_price: Decimal,
+    pub bid_qty: Decimal,
+    pub ask_price: Decimal,
+    pub ask_qty: Decimal,
+    pub time: i64,
+}
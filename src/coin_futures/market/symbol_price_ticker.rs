@@ -0,0 +1,75 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, rest_api::endpoint};
+
+endpoint!(
+    "/dapi/v1/ticker/price",
+    Method::GET,
+    SymbolPriceTickerEndpoint,
+    SymbolPriceTickerParams,
+    SymbolPriceTickerResponse,
+    weight = |params| if params.symbol.is_some() { 1 } else { 2 }
+);
+
+/// Latest price for a symbol or symbols.
+///
+/// - Weight:
+///   - with symbol: 1
+///   - without symbol: 2
+pub struct SymbolPriceTickerEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> SymbolPriceTickerEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolPriceTickerParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pair: Option<String>,
+}
+
+impl Default for SymbolPriceTickerParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SymbolPriceTickerParams {
+    pub fn new() -> Self {
+        Self {
+            symbol: None,
+            pair: None,
+        }
+    }
+
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_owned());
+        self
+    }
+
+    /// All the symbols of the pair are returned when `symbol` is not
+    /// sent.
+    pub fn pair(mut self, pair: &str) -> Self {
+        self.pair = Some(pair.to_owned());
+        self
+    }
+}
+
+pub type SymbolPriceTickerResponse = Vec<PriceTicker>;
+
+#[derive(Debug, Deserialize)]
+pub struct PriceTicker {
+    pub symbol: String,
+    #[serde(rename = "ps")]
+    pub pair: String,
+    pub price: Decimal,
+    pub time: i64,
+}
@@ -0,0 +1,86 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, enums::futures::ContractType, rest_api::endpoint};
+
+endpoint!(
+    "/futures/data/takerBuySellVol",
+    Method::GET,
+    TakerBuySellVolumeEndpoint,
+    TakerBuySellVolumeParams,
+    TakerBuySellVolumeResponse
+);
+
+/// Taker buy/sell volumes of a contract type of a pair.
+///
+/// - Weight: 1
+pub struct TakerBuySellVolumeEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> TakerBuySellVolumeEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TakerBuySellVolumeParams {
+    pair: String,
+    contract_type: ContractType,
+    period: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_time: Option<i64>,
+}
+
+impl TakerBuySellVolumeParams {
+    /// `period` is one of `5m`, `15m`, `30m`, `1h`, `2h`, `4h`, `6h`, `12h` and `1d`.
+    pub fn new(pair: &str, contract_type: ContractType, period: &str) -> Self {
+        Self {
+            pair: pair.to_owned(),
+            contract_type,
+            period: period.to_owned(),
+            limit: None,
+            start_time: None,
+            end_time: None,
+        }
+    }
+
+    /// Default 30; max 500.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Only the data of the latest 30 days is available.
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+}
+
+pub type TakerBuySellVolumeResponse = Vec<TakerBuySellVolume>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TakerBuySellVolume {
+    pub pair: String,
+    pub contract_type: ContractType,
+    /// Volume in contracts.
+    pub taker_buy_vol: Decimal,
+    pub taker_sell_vol: Decimal,
+    /// Volume in the base asset.
+    pub taker_buy_vol_value: Decimal,
+    pub taker_sell_vol_value: Decimal,
+    pub timestamp: i64,
+}
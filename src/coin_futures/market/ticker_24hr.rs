@@ -0,0 +1,90 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, rest_api::endpoint};
+
+endpoint!(
+    "/dapi/v1/ticker/24hr",
+    Method::GET,
+    Ticker24hrEndpoint,
+    Ticker24hrParams,
+    Ticker24hrResponse,
+    weight = |params| if params.symbol.is_some() { 1 } else { 40 }
+);
+
+/// 24 hour rolling window price change statistics. Careful when accessing this
+/// with no symbol.
+///
+/// - Weight:
+///   - with symbol: 1
+///   - without symbol: 40
+pub struct Ticker24hrEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> Ticker24hrEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ticker24hrParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pair: Option<String>,
+}
+
+impl Default for Ticker24hrParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Ticker24hrParams {
+    pub fn new() -> Self {
+        Self {
+            symbol: None,
+            pair: None,
+        }
+    }
+
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_owned());
+        self
+    }
+
+    /// All the symbols of the pair are returned when `symbol` is not
+    /// sent.
+    pub fn pair(mut self, pair: &str) -> Self {
+        self.pair = Some(pair.to_owned());
+        self
+    }
+}
+
+pub type Ticker24hrResponse = Vec<Ticker>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ticker {
+    pub symbol: String,
+    pub pair: String,
+    pub price_change: Decimal,
+    pub price_change_percent: Decimal,
+    pub weighted_avg_price: Decimal,
+    pub last_price: Decimal,
+    pub last_qty: Decimal,
+    pub open_price: Decimal,
+    pub high_price: Decimal,
+    pub low_price: Decimal,
+    /// Volume in contracts.
+    pub volume: Decimal,
+    pub base_volume: Decimal,
+    pub open_time: i64,
+    pub close_time: i64,
+    pub first_id: i64,
+    pub last_id: i64,
+    pub count: i64,
+}
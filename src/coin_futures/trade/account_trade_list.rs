@@ -0,0 +1,145 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    decimal::Decimal,
+    enums::{
+        futures::{OrderSide, PositionSide},
+        SecurityType,
+    },
+    rest_api::endpoint,
+};
+
+endpoint!(
+    "/dapi/v1/userTrades",
+    Method::GET,
+    SecurityType::UserData,
+    AccountTradeListEndpoint,
+    AccountTradeListParams,
+    AccountTradeListResponse,
+    weight = |params| if params.symbol.is_some() { 20 } else { 40 }
+);
+
+/// Get trades for a specific account and symbol or pair.
+///
+/// - Weight:
+///   - 20 with symbol
+///   - 40 with pair
+pub struct AccountTradeListEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> AccountTradeListEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountTradeListParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pair: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl Default for AccountTradeListParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AccountTradeListParams {
+    /// Either `symbol` or `pair` must be sent.
+    pub fn new() -> Self {
+        Self {
+            symbol: None,
+            pair: None,
+            order_id: None,
+            start_time: None,
+            end_time: None,
+            from_id: None,
+            limit: None,
+            recv_window: None,
+        }
+    }
+
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_owned());
+        self
+    }
+
+    pub fn pair(mut self, pair: &str) -> Self {
+        self.pair = Some(pair.to_owned());
+        self
+    }
+
+    /// Can only be used with `symbol`.
+    pub fn order_id(mut self, order_id: i64) -> Self {
+        self.order_id = Some(order_id);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Trade id to fetch from, cannot be sent with `pair`.
+    pub fn from_id(mut self, from_id: i64) -> Self {
+        self.from_id = Some(from_id);
+        self
+    }
+
+    /// Default 50, max 1000.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+pub type AccountTradeListResponse = Vec<AccountTrade>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountTrade {
+    pub symbol: String,
+    pub id: i64,
+    pub order_id: i64,
+    pub pair: String,
+    pub side: OrderSide,
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub realized_pnl: Decimal,
+    pub margin_asset: String,
+    pub base_qty: Decimal,
+    pub commission: Decimal,
+    pub commission_asset: String,
+    pub time: i64,
+    pub position_side: PositionSide,
+    pub buyer: bool,
+    pub maker: bool,
+}
@@ -0,0 +1,60 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/dapi/v1/countdownCancelAll",
+    Method::POST,
+    SecurityType::Trade,
+    AutoCancelAllOpenOrdersEndpoint,
+    AutoCancelAllOpenOrdersParams,
+    AutoCancelAllOpenOrdersResponse,
+    weight = 10
+);
+
+/// Cancel all open orders of the specified symbol at the end of the specified
+/// countdown. The endpoint should be called repeatedly as heartbeats.
+///
+/// - Weight: 10
+pub struct AutoCancelAllOpenOrdersEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> AutoCancelAllOpenOrdersEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoCancelAllOpenOrdersParams {
+    symbol: String,
+    countdown_time: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl AutoCancelAllOpenOrdersParams {
+    /// `countdown_time` is in milliseconds, 0 cancels the timer.
+    pub fn new(symbol: &str, countdown_time: i64) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            countdown_time,
+            recv_window: None,
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoCancelAllOpenOrdersResponse {
+    pub symbol: String,
+    pub countdown_time: String,
+}
@@ -0,0 +1,55 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/dapi/v1/allOpenOrders",
+    Method::DELETE,
+    SecurityType::Trade,
+    CancelAllOpenOrdersEndpoint,
+    CancelAllOpenOrdersParams,
+    CancelAllOpenOrdersResponse,
+    weight = 1
+);
+
+/// Cancel all open orders on a symbol.
+///
+/// - Weight: 1
+pub struct CancelAllOpenOrdersEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> CancelAllOpenOrdersEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAllOpenOrdersParams {
+    symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl CancelAllOpenOrdersParams {
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            recv_window: None,
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CancelAllOpenOrdersResponse {
+    pub code: i64,
+    pub msg: String,
+}
@@ -0,0 +1,80 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{enums::SecurityType, errors::BinanceError, rest_api::endpoint};
+
+use super::CancelOrderDetail;
+
+endpoint!(
+    "/dapi/v1/batchOrders",
+    Method::DELETE,
+    SecurityType::Trade,
+    CancelMultipleOrdersEndpoint,
+    CancelMultipleOrdersParams,
+    CancelMultipleOrdersResponse,
+    weight = 1
+);
+
+/// Cancel multiple orders, at most 10 orders per request.
+///
+/// - Weight: 1
+pub struct CancelMultipleOrdersEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> CancelMultipleOrdersEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelMultipleOrdersParams {
+    symbol: String,
+    #[serde(serialize_with = "crate::rest_api::serialize_json")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order_id_list: Option<Vec<i64>>,
+    #[serde(serialize_with = "crate::rest_api::serialize_json")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    orig_client_order_id_list: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl CancelMultipleOrdersParams {
+    /// Either `order_id_list` or `orig_client_order_id_list` must be sent.
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            order_id_list: None,
+            orig_client_order_id_list: None,
+            recv_window: None,
+        }
+    }
+
+    pub fn order_id_list(mut self, order_id_list: Vec<i64>) -> Self {
+        self.order_id_list = Some(order_id_list);
+        self
+    }
+
+    pub fn orig_client_order_id_list(mut self, orig_client_order_id_list: Vec<String>) -> Self {
+        self.orig_client_order_id_list = Some(orig_client_order_id_list);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+pub type CancelMultipleOrdersResponse = Vec<CancelMultipleOrdersResult>;
+
+/// The result of each order, in the order of the request.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum CancelMultipleOrdersResult {
+    Success(Box<CancelOrderDetail>),
+    Failure(BinanceError),
+}
@@ -0,0 +1,61 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/dapi/v1/leverage",
+    Method::POST,
+    SecurityType::Trade,
+    ChangeInitialLeverageEndpoint,
+    ChangeInitialLeverageParams,
+    ChangeInitialLeverageResponse,
+    weight = 1
+);
+
+/// Change user's initial leverage in the specific symbol market.
+///
+/// - Weight: 1
+pub struct ChangeInitialLeverageEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> ChangeInitialLeverageEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeInitialLeverageParams {
+    symbol: String,
+    leverage: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl ChangeInitialLeverageParams {
+    /// `leverage` ranges from 1 to 125.
+    pub fn new(symbol: &str, leverage: i64) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            leverage,
+            recv_window: None,
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeInitialLeverageResponse {
+    pub leverage: i64,
+    /// Maximum quantity of base asset.
+    pub max_qty: Decimal,
+    pub symbol: String,
+}
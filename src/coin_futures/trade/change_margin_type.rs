@@ -0,0 +1,58 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/dapi/v1/marginType",
+    Method::POST,
+    SecurityType::Trade,
+    ChangeMarginTypeEndpoint,
+    ChangeMarginTypeParams,
+    ChangeMarginTypeResponse,
+    weight = 1
+);
+
+/// Change user's margin type in the specific symbol market.
+///
+/// - Weight: 1
+pub struct ChangeMarginTypeEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> ChangeMarginTypeEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeMarginTypeParams {
+    symbol: String,
+    margin_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl ChangeMarginTypeParams {
+    /// `margin_type` is either `ISOLATED` or `CROSSED`.
+    pub fn new(symbol: &str, margin_type: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            margin_type: margin_type.to_owned(),
+            recv_window: None,
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ChangeMarginTypeResponse {
+    pub code: i64,
+    pub msg: String,
+}
@@ -0,0 +1,55 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/dapi/v1/positionSide/dual",
+    Method::POST,
+    SecurityType::Trade,
+    ChangePositionModeEndpoint,
+    ChangePositionModeParams,
+    ChangePositionModeResponse,
+    weight = 1
+);
+
+/// Change user's position mode (Hedge Mode or One-way Mode) on EVERY symbol.
+///
+/// - Weight: 1
+pub struct ChangePositionModeEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> ChangePositionModeEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangePositionModeParams {
+    dual_side_position: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl ChangePositionModeParams {
+    pub fn new(dual_side_position: bool) -> Self {
+        Self {
+            dual_side_position: dual_side_position.to_string(),
+            recv_window: None,
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ChangePositionModeResponse {
+    pub code: i64,
+    pub msg: String,
+}
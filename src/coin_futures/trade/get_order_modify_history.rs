@@ -0,0 +1,119 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/dapi/v1/orderAmendment",
+    Method::GET,
+    SecurityType::UserData,
+    GetOrderModifyHistoryEndpoint,
+    GetOrderModifyHistoryParams,
+    GetOrderModifyHistoryResponse,
+    weight = 1
+);
+
+/// Get order modification history.
+///
+/// - Weight: 1
+pub struct GetOrderModifyHistoryEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> GetOrderModifyHistoryEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetOrderModifyHistoryParams {
+    symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    orig_client_order_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl GetOrderModifyHistoryParams {
+    /// Either `order_id` or `orig_client_order_id` must be sent.
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            order_id: None,
+            orig_client_order_id: None,
+            start_time: None,
+            end_time: None,
+            limit: None,
+            recv_window: None,
+        }
+    }
+
+    pub fn order_id(mut self, order_id: i64) -> Self {
+        self.order_id = Some(order_id);
+        self
+    }
+
+    pub fn orig_client_order_id(mut self, orig_client_order_id: &str) -> Self {
+        self.orig_client_order_id = Some(orig_client_order_id.to_owned());
+        self
+    }
+
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Default 50, max 100.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+pub type GetOrderModifyHistoryResponse = Vec<OrderModifyRecord>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderModifyRecord {
+    pub amendment_id: i64,
+    pub symbol: String,
+    pub pair: String,
+    pub order_id: i64,
+    pub client_order_id: String,
+    pub time: i64,
+    pub amendment: OrderAmendment,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderAmendment {
+    pub price: Amendment,
+    pub orig_qty: Amendment,
+    pub count: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Amendment {
+    pub before: Decimal,
+    pub after: Decimal,
+}
@@ -0,0 +1,100 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    decimal::Decimal,
+    enums::{futures::PositionSide, SecurityType},
+    rest_api::endpoint,
+};
+
+endpoint!(
+    "/dapi/v1/positionMargin/history",
+    Method::GET,
+    SecurityType::UserData,
+    GetPositionMarginChangeHistoryEndpoint,
+    GetPositionMarginChangeHistoryParams,
+    GetPositionMarginChangeHistoryResponse,
+    weight = 1
+);
+
+/// Get position margin change history.
+///
+/// - Weight: 1
+pub struct GetPositionMarginChangeHistoryEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> GetPositionMarginChangeHistoryEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPositionMarginChangeHistoryParams {
+    symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    r#type: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl GetPositionMarginChangeHistoryParams {
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            r#type: None,
+            start_time: None,
+            end_time: None,
+            limit: None,
+            recv_window: None,
+        }
+    }
+
+    /// 1 for adding position margin, 2 for reducing position margin.
+    pub fn r#type(mut self, r#type: i64) -> Self {
+        self.r#type = Some(r#type);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Default 50.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+pub type GetPositionMarginChangeHistoryResponse = Vec<PositionMarginChangeHistory>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMarginChangeHistory {
+    pub amount: Decimal,
+    pub asset: String,
+    pub symbol: String,
+    pub time: i64,
+    pub r#type: i64,
+    pub position_side: PositionSide,
+}
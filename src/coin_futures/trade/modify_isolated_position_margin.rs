@@ -0,0 +1,77 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    decimal::Decimal,
+    enums::{futures::PositionSide, SecurityType},
+    rest_api::endpoint,
+};
+
+endpoint!(
+    "/dapi/v1/positionMargin",
+    Method::POST,
+    SecurityType::Trade,
+    ModifyIsolatedPositionMarginEndpoint,
+    ModifyIsolatedPositionMarginParams,
+    ModifyIsolatedPositionMarginResponse,
+    weight = 1
+);
+
+/// Modify isolated position margin.
+///
+/// - Weight: 1
+pub struct ModifyIsolatedPositionMarginEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> ModifyIsolatedPositionMarginEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModifyIsolatedPositionMarginParams {
+    symbol: String,
+    amount: Decimal,
+    r#type: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    position_side: Option<PositionSide>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl ModifyIsolatedPositionMarginParams {
+    /// `type` is 1 to add position margin, 2 to reduce position margin.
+    pub fn new(symbol: &str, amount: impl Into<Decimal>, r#type: i64) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            amount: amount.into(),
+            r#type,
+            position_side: None,
+            recv_window: None,
+        }
+    }
+
+    /// Default `BOTH` for One-way Mode; `LONG` or `SHORT` must be sent in Hedge
+    /// Mode.
+    pub fn position_side(mut self, position_side: PositionSide) -> Self {
+        self.position_side = Some(position_side);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModifyIsolatedPositionMarginResponse {
+    pub amount: Decimal,
+    pub code: i64,
+    pub msg: String,
+    pub r#type: i64,
+}
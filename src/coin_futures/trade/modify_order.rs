@@ -0,0 +1,152 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    decimal::Decimal,
+    enums::{
+        futures::{
+            OrderSide, OrderStatus, OrderType, PositionSide, PriceMatch, StpMode, TimeInForce,
+            WorkingType,
+        },
+        SecurityType,
+    },
+    rest_api::endpoint,
+};
+
+endpoint!(
+    "/dapi/v1/order",
+    Method::PUT,
+    SecurityType::Trade,
+    ModifyOrderEndpoint,
+    ModifyOrderParams,
+    ModifyOrderResponse,
+    weight = 1
+);
+
+/// Order modify function, currently only LIMIT order modification is supported,
+/// modified orders will be reordered in the match queue.
+///
+/// - Weight:
+///   - 1 on 1min order rate limit (`X-MBX-ORDER-COUNT-1M`)
+///   - 1 on IP rate limit (`X-MBX-USED-WEIGHT-1M`)
+pub struct ModifyOrderEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> ModifyOrderEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModifyOrderInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    orig_client_order_id: Option<String>,
+    symbol: String,
+    side: OrderSide,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    quantity: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    price: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    price_match: Option<PriceMatch>,
+}
+
+impl ModifyOrderInput {
+    /// Either `quantity` or `price` must be sent.
+    pub fn new(symbol: &str, side: OrderSide) -> Self {
+        Self {
+            order_id: None,
+            orig_client_order_id: None,
+            symbol: symbol.to_owned(),
+            side,
+            quantity: None,
+            price: None,
+            price_match: None,
+        }
+    }
+
+    pub fn order_id(mut self, order_id: i64) -> Self {
+        self.order_id = Some(order_id);
+        self
+    }
+
+    pub fn orig_client_order_id(mut self, orig_client_order_id: &str) -> Self {
+        self.orig_client_order_id = Some(orig_client_order_id.to_owned());
+        self
+    }
+
+    pub fn quantity(mut self, quantity: impl Into<Decimal>) -> Self {
+        self.quantity = Some(quantity.into());
+        self
+    }
+
+    pub fn price(mut self, price: impl Into<Decimal>) -> Self {
+        self.price = Some(price.into());
+        self
+    }
+
+    /// Cannot be sent with `price`.
+    pub fn price_match(mut self, price_match: PriceMatch) -> Self {
+        self.price_match = Some(price_match);
+        self
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModifyOrderParams {
+    #[serde(flatten)]
+    input: ModifyOrderInput,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl ModifyOrderParams {
+    pub fn new(input: ModifyOrderInput) -> Self {
+        Self {
+            input,
+            recv_window: None,
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+pub type ModifyOrderResponse = ModifyOrderDetail;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModifyOrderDetail {
+    pub order_id: i64,
+    pub symbol: String,
+    pub pair: String,
+    pub status: OrderStatus,
+    pub client_order_id: String,
+    pub price: Decimal,
+    pub avg_price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub cum_qty: Decimal,
+    pub cum_base: Decimal,
+    pub time_in_force: TimeInForce,
+    pub r#type: OrderType,
+    pub reduce_only: bool,
+    pub close_position: bool,
+    pub side: OrderSide,
+    pub position_side: PositionSide,
+    pub stop_price: Decimal,
+    pub working_type: WorkingType,
+    pub price_protect: bool,
+    pub orig_type: OrderType,
+    pub price_match: PriceMatch,
+    pub self_trade_prevention_mode: StpMode,
+    pub update_time: i64,
+}
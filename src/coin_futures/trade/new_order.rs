@@ -0,0 +1,244 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    decimal::Decimal,
+    enums::{
+        futures::{
+            OrderSide, OrderStatus, OrderType, PositionSide, PriceMatch, ResponseType, StpMode,
+            TimeInForce, WorkingType,
+        },
+        SecurityType,
+    },
+    rest_api::endpoint,
+};
+
+endpoint!(
+    "/dapi/v1/order",
+    Method::POST,
+    SecurityType::Trade,
+    NewOrderEndpoint,
+    NewOrderParams,
+    NewOrderResponse,
+    weight = 0,
+    retry = |params| params.input.new_client_order_id.is_some()
+);
+
+/// Send in a new order.
+///
+/// - Weight:
+///   - 1 on 1min order rate limit (`X-MBX-ORDER-COUNT-1M`)
+///   - 0 on IP rate limit (`X-MBX-USED-WEIGHT-1M`)
+pub struct NewOrderEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> NewOrderEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOrderInput {
+    symbol: String,
+    side: OrderSide,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    position_side: Option<PositionSide>,
+    r#type: OrderType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    quantity: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reduce_only: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    price: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new_client_order_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop_price: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    close_position: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    activation_price: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    callback_rate: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    working_type: Option<WorkingType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    price_protect: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new_order_resp_type: Option<ResponseType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    price_match: Option<PriceMatch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    self_trade_prevention_mode: Option<StpMode>,
+}
+
+impl NewOrderInput {
+    pub fn new(symbol: &str, side: OrderSide, r#type: OrderType) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            side,
+            position_side: None,
+            r#type,
+            time_in_force: None,
+            quantity: None,
+            reduce_only: None,
+            price: None,
+            new_client_order_id: None,
+            stop_price: None,
+            close_position: None,
+            activation_price: None,
+            callback_rate: None,
+            working_type: None,
+            price_protect: None,
+            new_order_resp_type: None,
+            price_match: None,
+            self_trade_prevention_mode: None,
+        }
+    }
+
+    /// Default `BOTH` in One-way Mode; `LONG` or `SHORT` must be sent in Hedge
+    /// Mode.
+    pub fn position_side(mut self, position_side: PositionSide) -> Self {
+        self.position_side = Some(position_side);
+        self
+    }
+
+    pub fn time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    /// Quantity in contracts.
+    pub fn quantity(mut self, quantity: impl Into<Decimal>) -> Self {
+        self.quantity = Some(quantity.into());
+        self
+    }
+
+    pub fn reduce_only(mut self, reduce_only: bool) -> Self {
+        self.reduce_only = Some(reduce_only.to_string());
+        self
+    }
+
+    pub fn price(mut self, price: impl Into<Decimal>) -> Self {
+        self.price = Some(price.into());
+        self
+    }
+
+    /// Setting it allows the request to be retried when it is rejected before
+    /// being executed. When its execution status is unknown, the order has to
+    /// be queried by `origClientOrderId` before it is sent again, see
+    /// [`RetryPolicy`](crate::rest_api::RetryPolicy).
+    pub fn new_client_order_id(mut self, new_client_order_id: &str) -> Self {
+        self.new_client_order_id = Some(new_client_order_id.to_owned());
+        self
+    }
+
+    pub fn stop_price(mut self, stop_price: impl Into<Decimal>) -> Self {
+        self.stop_price = Some(stop_price.into());
+        self
+    }
+
+    pub fn close_position(mut self, close_position: bool) -> Self {
+        self.close_position = Some(close_position.to_string());
+        self
+    }
+
+    pub fn activation_price(mut self, activation_price: impl Into<Decimal>) -> Self {
+        self.activation_price = Some(activation_price.into());
+        self
+    }
+
+    pub fn callback_rate(mut self, callback_rate: impl Into<Decimal>) -> Self {
+        self.callback_rate = Some(callback_rate.into());
+        self
+    }
+
+    pub fn working_type(mut self, working_type: WorkingType) -> Self {
+        self.working_type = Some(working_type);
+        self
+    }
+
+    pub fn price_protect(mut self, price_protect: bool) -> Self {
+        self.price_protect = Some(price_protect.to_string());
+        self
+    }
+
+    pub fn new_order_resp_type(mut self, new_order_resp_type: ResponseType) -> Self {
+        self.new_order_resp_type = Some(new_order_resp_type);
+        self
+    }
+
+    /// Cannot be sent with `price`.
+    pub fn price_match(mut self, price_match: PriceMatch) -> Self {
+        self.price_match = Some(price_match);
+        self
+    }
+
+    pub fn self_trade_prevention_mode(mut self, self_trade_prevention_mode: StpMode) -> Self {
+        self.self_trade_prevention_mode = Some(self_trade_prevention_mode);
+        self
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOrderParams {
+    #[serde(flatten)]
+    input: NewOrderInput,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl NewOrderParams {
+    pub fn new(input: NewOrderInput) -> Self {
+        Self {
+            input,
+            recv_window: None,
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+pub type NewOrderResponse = NewOrderDetail;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOrderDetail {
+    pub client_order_id: String,
+    pub cum_qty: Decimal,
+    /// Filled quantity in the base asset.
+    pub cum_base: Decimal,
+    pub executed_qty: Decimal,
+    pub order_id: i64,
+    pub avg_price: Decimal,
+    pub orig_qty: Decimal,
+    pub price: Decimal,
+    pub reduce_only: bool,
+    pub side: OrderSide,
+    pub position_side: PositionSide,
+    pub status: OrderStatus,
+    pub stop_price: Decimal,
+    pub close_position: bool,
+    pub symbol: String,
+    pub pair: String,
+    pub time_in_force: TimeInForce,
+    pub r#type: OrderType,
+    pub orig_type: OrderType,
+    /// Only sent with trailing stop orders.
+    pub activate_price: Option<Decimal>,
+    pub price_rate: Option<Decimal>,
+    pub update_time: i64,
+    pub working_type: WorkingType,
+    pub price_protect: bool,
+    pub price_match: PriceMatch,
+    pub self_trade_prevention_mode: StpMode,
+}
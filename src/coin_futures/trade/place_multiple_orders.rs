@@ -0,0 +1,64 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{enums::SecurityType, errors::BinanceError, rest_api::endpoint};
+
+use super::{NewOrderDetail, NewOrderInput};
+
+endpoint!(
+    "/dapi/v1/batchOrders",
+    Method::POST,
+    SecurityType::Trade,
+    PlaceMultipleOrdersEndpoint,
+    PlaceMultipleOrdersParams,
+    PlaceMultipleOrdersResponse,
+    weight = 5
+);
+
+/// Place multiple orders, at most 5 orders per request.
+///
+/// - Weight:
+///   - 5 on 1min order rate limit (`X-MBX-ORDER-COUNT-1M`)
+///   - 5 on IP rate limit (`X-MBX-USED-WEIGHT-1M`)
+pub struct PlaceMultipleOrdersEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> PlaceMultipleOrdersEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaceMultipleOrdersParams {
+    #[serde(serialize_with = "crate::rest_api::serialize_json")]
+    batch_orders: Vec<NewOrderInput>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl PlaceMultipleOrdersParams {
+    pub fn new(batch_orders: Vec<NewOrderInput>) -> Self {
+        Self {
+            batch_orders,
+            recv_window: None,
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+pub type PlaceMultipleOrdersResponse = Vec<PlaceMultipleOrdersResult>;
+
+/// The result of each order, in the order of the request.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum PlaceMultipleOrdersResult {
+    Success(Box<NewOrderDetail>),
+    Failure(BinanceError),
+}
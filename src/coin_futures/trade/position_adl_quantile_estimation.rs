@@ -0,0 +1,79 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/dapi/v1/adlQuantile",
+    Method::GET,
+    SecurityType::UserData,
+    PositionAdlQuantileEstimationEndpoint,
+    PositionAdlQuantileEstimationParams,
+    PositionAdlQuantileEstimationResponse,
+    weight = 5
+);
+
+/// Query position ADL quantile estimation.
+///
+/// - Weight: 5
+pub struct PositionAdlQuantileEstimationEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> PositionAdlQuantileEstimationEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionAdlQuantileEstimationParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl Default for PositionAdlQuantileEstimationParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PositionAdlQuantileEstimationParams {
+    pub fn new() -> Self {
+        Self {
+            symbol: None,
+            recv_window: None,
+        }
+    }
+
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_owned());
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+pub type PositionAdlQuantileEstimationResponse = Vec<PositionAdlQuantileEstimation>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionAdlQuantileEstimation {
+    pub symbol: String,
+    pub adl_quantile: AdlQuantile,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub struct AdlQuantile {
+    pub long: i64,
+    pub short: i64,
+    pub both: Option<i64>,
+    pub hedge: Option<i64>,
+}
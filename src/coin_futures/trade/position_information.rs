@@ -0,0 +1,96 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    decimal::Decimal,
+    enums::{futures::PositionSide, SecurityType},
+    rest_api::endpoint,
+};
+
+endpoint!(
+    "/dapi/v1/positionRisk",
+    Method::GET,
+    SecurityType::UserData,
+    PositionInformationEndpoint,
+    PositionInformationParams,
+    PositionInformationResponse,
+    weight = 1
+);
+
+/// Get current position information.
+///
+/// - Weight: 1
+pub struct PositionInformationEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> PositionInformationEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionInformationParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    margin_asset: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pair: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl Default for PositionInformationParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PositionInformationParams {
+    pub fn new() -> Self {
+        Self {
+            margin_asset: None,
+            pair: None,
+            recv_window: None,
+        }
+    }
+
+    pub fn margin_asset(mut self, margin_asset: &str) -> Self {
+        self.margin_asset = Some(margin_asset.to_owned());
+        self
+    }
+
+    pub fn pair(mut self, pair: &str) -> Self {
+        self.pair = Some(pair.to_owned());
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+pub type PositionInformationResponse = Vec<PositionInformation>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionInformation {
+    pub symbol: String,
+    pub position_amt: Decimal,
+    pub entry_price: Decimal,
+    pub break_even_price: Decimal,
+    pub mark_price: Decimal,
+    pub un_realized_profit: Decimal,
+    pub liquidation_price: Decimal,
+    pub leverage: Decimal,
+    pub max_qty: Decimal,
+    pub margin_type: String,
+    pub isolated_margin: Decimal,
+    pub is_auto_add_margin: String,
+    pub position_side: PositionSide,
+    pub notional_value: Decimal,
+    pub isolated_wallet: Decimal,
+    pub update_time: i64,
+}
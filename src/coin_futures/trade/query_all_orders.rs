@@ -0,0 +1,109 @@
+use reqwest::Method;
+use serde::Serialize;
+
+use crate::{enums::SecurityType, rest_api::endpoint};
+
+use super::OrderDetail;
+
+endpoint!(
+    "/dapi/v1/allOrders",
+    Method::GET,
+    SecurityType::UserData,
+    QueryAllOrdersEndpoint,
+    QueryAllOrdersParams,
+    QueryAllOrdersResponse,
+    weight = |params| if params.symbol.is_some() { 20 } else { 40 }
+);
+
+/// Get all account orders; active, canceled, or filled.
+///
+/// - Weight:
+///   - 20 with symbol
+///   - 40 with pair
+pub struct QueryAllOrdersEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> QueryAllOrdersEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryAllOrdersParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pair: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl Default for QueryAllOrdersParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl QueryAllOrdersParams {
+    /// Either `symbol` or `pair` must be sent.
+    pub fn new() -> Self {
+        Self {
+            symbol: None,
+            pair: None,
+            order_id: None,
+            start_time: None,
+            end_time: None,
+            limit: None,
+            recv_window: None,
+        }
+    }
+
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_owned());
+        self
+    }
+
+    pub fn pair(mut self, pair: &str) -> Self {
+        self.pair = Some(pair.to_owned());
+        self
+    }
+
+    pub fn order_id(mut self, order_id: i64) -> Self {
+        self.order_id = Some(order_id);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Default 50, max 100.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+pub type QueryAllOrdersResponse = Vec<OrderDetail>;
@@ -0,0 +1,100 @@
+use reqwest::Method;
+use serde::Serialize;
+
+use crate::{
+    enums::SecurityType,
+    rest_api::{Endpoint, RestApiClient},
+    Params,
+};
+
+use super::OrderDetail;
+
+impl Params for QueryCurrentAllOpenOrdersParams {}
+
+impl Endpoint for QueryCurrentAllOpenOrdersEndpoint<'_> {
+    type Params = QueryCurrentAllOpenOrdersParams;
+    type Response = QueryCurrentAllOpenOrdersResponse;
+
+    fn client(&self) -> &RestApiClient {
+        self.client
+    }
+
+    fn path(&self) -> &str {
+        "/dapi/v1/openOrders"
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn security_type(&self) -> SecurityType {
+        SecurityType::UserData
+    }
+
+    fn weight(&self, params: &Self::Params) -> u32 {
+        if params.symbol.is_some() {
+            1
+        } else {
+            40
+        }
+    }
+}
+
+/// Get all open orders on a symbol or a pair.
+///
+/// - Weight:
+///   - 1 for a single symbol
+///   - 40 when the symbol parameter is omitted
+pub struct QueryCurrentAllOpenOrdersEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> QueryCurrentAllOpenOrdersEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryCurrentAllOpenOrdersParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pair: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl Default for QueryCurrentAllOpenOrdersParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl QueryCurrentAllOpenOrdersParams {
+    pub fn new() -> Self {
+        Self {
+            symbol: None,
+            pair: None,
+            recv_window: None,
+        }
+    }
+
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_owned());
+        self
+    }
+
+    pub fn pair(mut self, pair: &str) -> Self {
+        self.pair = Some(pair.to_owned());
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+pub type QueryCurrentAllOpenOrdersResponse = Vec<OrderDetail>;
@@ -0,0 +1,87 @@
+use reqwest::Method;
+use serde::Serialize;
+
+use crate::{
+    enums::SecurityType,
+    rest_api::{Endpoint, RestApiClient},
+    Params,
+};
+
+use super::OrderDetail;
+
+impl Params for QueryCurrentOpenOrderParams {}
+
+impl Endpoint for QueryCurrentOpenOrderEndpoint<'_> {
+    type Params = QueryCurrentOpenOrderParams;
+    type Response = QueryCurrentOpenOrderResponse;
+
+    fn client(&self) -> &RestApiClient {
+        self.client
+    }
+
+    fn path(&self) -> &str {
+        "/dapi/v1/openOrder"
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn security_type(&self) -> SecurityType {
+        SecurityType::UserData
+    }
+}
+
+/// Query a current open order.
+///
+/// - Weight: 1
+pub struct QueryCurrentOpenOrderEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> QueryCurrentOpenOrderEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryCurrentOpenOrderParams {
+    symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    orig_client_order_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl QueryCurrentOpenOrderParams {
+    /// Either `order_id` or `orig_client_order_id` must be sent.
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            order_id: None,
+            orig_client_order_id: None,
+            recv_window: None,
+        }
+    }
+
+    pub fn order_id(mut self, order_id: i64) -> Self {
+        self.order_id = Some(order_id);
+        self
+    }
+
+    pub fn orig_client_order_id(mut self, orig_client_order_id: &str) -> Self {
+        self.orig_client_order_id = Some(orig_client_order_id.to_owned());
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+pub type QueryCurrentOpenOrderResponse = OrderDetail;
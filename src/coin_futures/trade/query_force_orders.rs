@@ -0,0 +1,133 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    decimal::Decimal,
+    enums::{
+        futures::{OrderSide, OrderStatus, OrderType, PositionSide, TimeInForce, WorkingType},
+        SecurityType,
+    },
+    rest_api::endpoint,
+};
+
+endpoint!(
+    "/dapi/v1/forceOrders",
+    Method::GET,
+    SecurityType::UserData,
+    QueryForceOrdersEndpoint,
+    QueryForceOrdersParams,
+    QueryForceOrdersResponse,
+    weight = |params| if params.symbol.is_some() { 20 } else { 50 }
+);
+
+/// Query user's force orders.
+///
+/// - Weight:
+///   - 20 with symbol
+///   - 50 without symbol
+pub struct QueryForceOrdersEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> QueryForceOrdersEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryForceOrdersParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_close_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl Default for QueryForceOrdersParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl QueryForceOrdersParams {
+    pub fn new() -> Self {
+        Self {
+            symbol: None,
+            auto_close_type: None,
+            start_time: None,
+            end_time: None,
+            limit: None,
+            recv_window: None,
+        }
+    }
+
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_owned());
+        self
+    }
+
+    /// `LIQUIDATION` or `ADL`, all kinds of orders are returned if not sent.
+    pub fn auto_close_type(mut self, auto_close_type: &str) -> Self {
+        self.auto_close_type = Some(auto_close_type.to_owned());
+        self
+    }
+
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Default 50, max 100.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+pub type QueryForceOrdersResponse = Vec<ForceOrder>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ForceOrder {
+    pub order_id: i64,
+    pub symbol: String,
+    pub pair: String,
+    pub status: OrderStatus,
+    pub client_order_id: String,
+    pub price: Decimal,
+    pub avg_price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub cum_base: Decimal,
+    pub time_in_force: TimeInForce,
+    pub r#type: OrderType,
+    pub reduce_only: bool,
+    pub close_position: bool,
+    pub side: OrderSide,
+    pub position_side: PositionSide,
+    pub stop_price: Decimal,
+    pub working_type: WorkingType,
+    pub price_protect: bool,
+    pub orig_type: OrderType,
+    pub time: i64,
+    pub update_time: i64,
+}
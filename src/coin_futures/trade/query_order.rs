@@ -0,0 +1,109 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    decimal::Decimal,
+    enums::{
+        futures::{
+            OrderSide, OrderStatus, OrderType, PositionSide, PriceMatch, StpMode, TimeInForce,
+            WorkingType,
+        },
+        SecurityType,
+    },
+    rest_api::endpoint,
+};
+
+endpoint!(
+    "/dapi/v1/order",
+    Method::GET,
+    SecurityType::UserData,
+    QueryOrderEndpoint,
+    QueryOrderParams,
+    QueryOrderResponse,
+    weight = 1
+);
+
+/// Check an order's status.
+///
+/// - Weight: 1
+pub struct QueryOrderEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> QueryOrderEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryOrderParams {
+    symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    orig_client_order_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl QueryOrderParams {
+    /// Either `order_id` or `orig_client_order_id` must be sent.
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            order_id: None,
+            orig_client_order_id: None,
+            recv_window: None,
+        }
+    }
+
+    pub fn order_id(mut self, order_id: i64) -> Self {
+        self.order_id = Some(order_id);
+        self
+    }
+
+    pub fn orig_client_order_id(mut self, orig_client_order_id: &str) -> Self {
+        self.orig_client_order_id = Some(orig_client_order_id.to_owned());
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+pub type QueryOrderResponse = OrderDetail;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderDetail {
+    pub avg_price: Decimal,
+    pub client_order_id: String,
+    pub cum_base: Decimal,
+    pub executed_qty: Decimal,
+    pub order_id: i64,
+    pub orig_qty: Decimal,
+    pub orig_type: OrderType,
+    pub price: Decimal,
+    pub reduce_only: bool,
+    pub side: OrderSide,
+    pub position_side: PositionSide,
+    pub status: OrderStatus,
+    pub stop_price: Decimal,
+    pub close_position: bool,
+    pub symbol: String,
+    pub pair: String,
+    pub time: i64,
+    pub time_in_force: TimeInForce,
+    pub r#type: OrderType,
+    pub activate_price: Option<Decimal>,
+    pub price_rate: Option<Decimal>,
+    pub update_time: i64,
+    pub working_type: WorkingType,
+    pub price_protect: bool,
+    pub price_match: PriceMatch,
+    pub self_trade_prevention_mode: StpMode,
+}
@@ -0,0 +1,258 @@
+mod close_user_data_stream;
+mod keepalive_user_data_stream;
+mod start_user_data_stream;
+
+pub use close_user_data_stream::*;
+pub use keepalive_user_data_stream::*;
+pub use start_user_data_stream::*;
+
+use crate::{
+    decimal::Decimal,
+    enums::futures::{
+        AccountUpdateReason, ExecutionType, OrderSide, OrderStatus, OrderType, PositionSide,
+        PriceMatch, StpMode, TimeInForce, WorkingType,
+    },
+    rest_api::{route, RestApiClient},
+    web_socket_stream::Payload,
+};
+use serde::Deserialize;
+
+pub struct RestApiHandler<'r> {
+    client: &'r RestApiClient,
+}
+
+impl<'r> RestApiHandler<'r> {
+    pub fn new(client: &'r RestApiClient) -> Self {
+        RestApiHandler { client }
+    }
+
+    route!(start_user_data_stream, StartUserDataStreamEndpoint);
+    route!(keepalive_user_data_stream, KeepaliveUserDataStreamEndpoint);
+    route!(close_user_data_stream, CloseUserDataStreamEndpoint);
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "e")]
+pub enum UserDataStreamPayload {
+    #[serde(rename = "ACCOUNT_UPDATE")]
+    AccountUpdate(AccountUpdate),
+    #[serde(rename = "ORDER_TRADE_UPDATE")]
+    OrderTradeUpdate(Box<OrderTradeUpdate>),
+    #[serde(rename = "ACCOUNT_CONFIG_UPDATE")]
+    AccountConfigUpdate(AccountConfigUpdate),
+    #[serde(rename = "MARGIN_CALL")]
+    MarginCall(MarginCall),
+    #[serde(rename = "listenKeyExpired")]
+    ListenKeyExpired(ListenKeyExpired),
+}
+
+impl Payload for UserDataStreamPayload {}
+
+/// A change of the balances or the positions. Only the balances and the
+/// positions that changed are sent.
+#[derive(Clone, Debug, Deserialize)]
+pub struct AccountUpdate {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "T")]
+    pub transaction_time: i64,
+    #[serde(rename = "i")]
+    pub account_alias: String,
+    #[serde(rename = "a")]
+    pub data: AccountUpdateData,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AccountUpdateData {
+    #[serde(rename = "m")]
+    pub reason: AccountUpdateReason,
+    #[serde(rename = "B")]
+    pub balances: Vec<BalanceData>,
+    #[serde(rename = "P")]
+    pub positions: Vec<PositionData>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct BalanceData {
+    #[serde(rename = "a")]
+    pub asset: String,
+    #[serde(rename = "wb")]
+    pub wallet_balance: Decimal,
+    #[serde(rename = "cw")]
+    pub cross_wallet_balance: Decimal,
+    /// Balance change except PnL and commission.
+    #[serde(rename = "bc")]
+    pub balance_change: Decimal,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct PositionData {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "pa")]
+    pub position_amount: Decimal,
+    #[serde(rename = "ep")]
+    pub entry_price: Decimal,
+    #[serde(rename = "bep")]
+    pub breakeven_price: Decimal,
+    /// Pre-fee accumulated realized.
+    #[serde(rename = "cr")]
+    pub accumulated_realized: Decimal,
+    #[serde(rename = "up")]
+    pub unrealized_pnl: Decimal,
+    /// `isolated` or `cross`.
+    #[serde(rename = "mt")]
+    pub margin_type: String,
+    #[serde(rename = "iw")]
+    pub isolated_wallet: Decimal,
+    #[serde(rename = "ps")]
+    pub position_side: PositionSide,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct OrderTradeUpdate {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "T")]
+    pub transaction_time: i64,
+    #[serde(rename = "i")]
+    pub account_alias: String,
+    #[serde(rename = "o")]
+    pub order: OrderTradeUpdateData,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct OrderTradeUpdateData {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+    #[serde(rename = "S")]
+    pub side: OrderSide,
+    #[serde(rename = "o")]
+    pub order_type: OrderType,
+    #[serde(rename = "f")]
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "q")]
+    pub original_quantity: Decimal,
+    #[serde(rename = "p")]
+    pub original_price: Decimal,
+    #[serde(rename = "ap")]
+    pub average_price: Decimal,
+    #[serde(rename = "sp")]
+    pub stop_price: Decimal,
+    #[serde(rename = "x")]
+    pub execution_type: ExecutionType,
+    #[serde(rename = "X")]
+    pub order_status: OrderStatus,
+    #[serde(rename = "i")]
+    pub order_id: i64,
+    #[serde(rename = "l")]
+    pub last_filled_quantity: Decimal,
+    #[serde(rename = "z")]
+    pub filled_accumulated_quantity: Decimal,
+    #[serde(rename = "L")]
+    pub last_filled_price: Decimal,
+    #[serde(rename = "ma")]
+    pub margin_asset: String,
+    /// Sent with the commission only.
+    #[serde(rename = "N")]
+    pub commission_asset: Option<String>,
+    #[serde(rename = "n")]
+    pub commission: Option<Decimal>,
+    #[serde(rename = "T")]
+    pub trade_time: i64,
+    #[serde(rename = "t")]
+    pub trade_id: i64,
+    #[serde(rename = "b")]
+    pub bids_notional: Decimal,
+    #[serde(rename = "a")]
+    pub ask_notional: Decimal,
+    #[serde(rename = "m")]
+    pub is_maker: bool,
+    #[serde(rename = "R")]
+    pub is_reduce_only: bool,
+    #[serde(rename = "wt")]
+    pub working_type: WorkingType,
+    #[serde(rename = "ot")]
+    pub original_order_type: OrderType,
+    #[serde(rename = "ps")]
+    pub position_side: PositionSide,
+    /// Whether the order closes the whole position, for conditional orders.
+    #[serde(rename = "cp")]
+    pub close_position: bool,
+    /// Only sent with trailing stop orders.
+    #[serde(rename = "AP")]
+    pub activation_price: Option<Decimal>,
+    #[serde(rename = "cr")]
+    pub callback_rate: Option<Decimal>,
+    #[serde(rename = "pP")]
+    pub price_protect: bool,
+    #[serde(rename = "rp")]
+    pub realized_profit: Decimal,
+    #[serde(rename = "V")]
+    pub self_trade_prevention_mode: StpMode,
+    #[serde(rename = "pm")]
+    pub price_match: PriceMatch,
+}
+
+/// A change of the leverage of a symbol.
+#[derive(Clone, Debug, Deserialize)]
+pub struct AccountConfigUpdate {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "T")]
+    pub transaction_time: i64,
+    #[serde(rename = "ac")]
+    pub leverage: LeverageConfig,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct LeverageConfig {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "l")]
+    pub leverage: i64,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MarginCall {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "i")]
+    pub account_alias: String,
+    /// Only sent with the crossed positions.
+    #[serde(rename = "cw")]
+    pub cross_wallet_balance: Option<Decimal>,
+    #[serde(rename = "p")]
+    pub positions: Vec<MarginCallPosition>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MarginCallPosition {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "ps")]
+    pub position_side: PositionSide,
+    #[serde(rename = "pa")]
+    pub position_amount: Decimal,
+    /// `CROSSED` or `ISOLATED`.
+    #[serde(rename = "mt")]
+    pub margin_type: String,
+    #[serde(rename = "iw")]
+    pub isolated_wallet: Decimal,
+    #[serde(rename = "mp")]
+    pub mark_price: Decimal,
+    #[serde(rename = "up")]
+    pub unrealized_pnl: Decimal,
+    #[serde(rename = "mm")]
+    pub maintenance_margin_required: Decimal,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListenKeyExpired {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "listenKey")]
+    pub listen_key: String,
+}
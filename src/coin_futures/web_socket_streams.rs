@@ -0,0 +1,391 @@
+use serde::Deserialize;
+
+use crate::{
+    decimal::Decimal,
+    enums::futures::{ContractType, OrderSide, OrderStatus, OrderType, TimeInForce},
+    web_socket_stream::{CombinedMessage, Payload},
+};
+
+/// The aggregate trade and contract info streams send the same payloads as
+/// their USD-M futures counterparts.
+pub use crate::usd_futures::web_socket_streams::{AggregatedTrade, ContractInfo, NotionalBracket};
+
+/// Payloads of combined COIN-M futures streams, routed on the name of their
+/// stream.
+#[derive(Clone, Debug)]
+pub enum CoinFuturesStreamPayload {
+    AggregatedTrade(AggregatedTrade),
+    IndexPrice(IndexPrice),
+    /// The `<symbol>@markPrice` streams.
+    MarkPrice(MarkPrice),
+    /// The `<pair>@markPrice` streams, with the mark price of every symbol of
+    /// the pair.
+    MarkPrices(Vec<MarkPrice>),
+    Kline(Kline),
+    ContinuousKline(ContinuousKline),
+    IndexPriceKline(IndexPriceKline),
+    MarkPriceKline(MarkPriceKline),
+    MiniTicker(MiniTicker),
+    /// The `!miniTicker@arr` stream.
+    MiniTickers(Vec<MiniTicker>),
+    Ticker(Ticker),
+    /// The `!ticker@arr` stream.
+    Tickers(Vec<Ticker>),
+    /// The `<symbol>@bookTicker` and `!bookTicker` streams.
+    BookTicker(BookTicker),
+    /// The `<symbol>@forceOrder` and `!forceOrder@arr` streams.
+    LiquidationOrder(LiquidationOrder),
+    /// The partial depth streams have the fields of the diff depth stream.
+    PartialBookDepth(Depth),
+    Depth(Depth),
+    ContractInfo(ContractInfo),
+}
+
+impl Payload for CoinFuturesStreamPayload {}
+
+impl<'de> Deserialize<'de> for CoinFuturesStreamPayload {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let message = CombinedMessage::deserialize(deserializer)?;
+        let stream = message.stream.as_str();
+
+        // All market streams: `!<stream>[@arr][@<speed>]`.
+        if let Some(stream) = stream.strip_prefix('!') {
+            return match stream.split('@').next().unwrap_or_default() {
+                "miniTicker" => message.data().map(Self::MiniTickers),
+                "ticker" => message.data().map(Self::Tickers),
+                "forceOrder" => message.data().map(Self::LiquidationOrder),
+                "bookTicker" => message.data().map(Self::BookTicker),
+                "contractInfo" => message.data().map(Self::ContractInfo),
+                _ => Err(message.unknown_stream()),
+            };
+        }
+
+        // Symbol and pair streams: `<symbol>@<stream>[_<param>][@<speed>]`.
+        let (target, name) = stream.split_once('@').unwrap_or_default();
+        match name.split(['_', '@']).next().unwrap_or_default() {
+            "aggTrade" => message.data().map(Self::AggregatedTrade),
+            "indexPrice" => message.data().map(Self::IndexPrice),
+            // Symbols are `<pair>_<delivery>`, e.g. `btcusd_perp`.
+            "markPrice" if target.contains('_') => message.data().map(Self::MarkPrice),
+            "markPrice" => message.data().map(Self::MarkPrices),
+            "kline" => message.data().map(Self::Kline),
+            "continuousKline" => message.data().map(Self::ContinuousKline),
+            "indexPriceKline" => message.data().map(Self::IndexPriceKline),
+            "markPriceKline" => message.data().map(Self::MarkPriceKline),
+            "miniTicker" => message.data().map(Self::MiniTicker),
+            "ticker" => message.data().map(Self::Ticker),
+            "bookTicker" => message.data().map(Self::BookTicker),
+            "forceOrder" => message.data().map(Self::LiquidationOrder),
+            "depth" => message.data().map(Self::Depth),
+            "depth5" | "depth10" | "depth20" => message.data().map(Self::PartialBookDepth),
+            _ => Err(message.unknown_stream()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct IndexPrice {
+    #[serde(rename = "e")]
+    pub event_type: String,
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "i")]
+    pub pair: String,
+    #[serde(rename = "p")]
+    pub index_price: Decimal,
+}
+
+impl Payload for IndexPrice {}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MarkPrice {
+    #[serde(rename = "e")]
+    pub event_type: String,
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "p")]
+    pub mark_price: Decimal,
+    #[serde(rename = "i")]
+    pub index_price: Decimal,
+    /// Only useful in the last hour before the settlement starts.
+    #[serde(rename = "P")]
+    pub estimated_settle_price: Decimal,
+    /// Empty for the delivery contracts.
+    #[serde(rename = "r", deserialize_with = "crate::decimal::empty_as_none")]
+    pub funding_rate: Option<Decimal>,
+    /// 0 for the delivery contracts.
+    #[serde(rename = "T")]
+    pub next_funding_time: i64,
+}
+
+impl Payload for MarkPrice {}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Kline {
+    #[serde(rename = "e")]
+    pub event_type: String,
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "k")]
+    pub kline: KlineData,
+}
+
+impl Payload for Kline {}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ContinuousKline {
+    #[serde(rename = "e")]
+    pub event_type: String,
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "ps")]
+    pub pair: String,
+    #[serde(rename = "ct")]
+    pub contract_type: ContractType,
+    #[serde(rename = "k")]
+    pub kline: KlineData,
+}
+
+impl Payload for ContinuousKline {}
+
+/// The volumes of the kline are always 0.
+#[derive(Clone, Debug, Deserialize)]
+pub struct IndexPriceKline {
+    #[serde(rename = "e")]
+    pub event_type: String,
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "ps")]
+    pub pair: String,
+    #[serde(rename = "k")]
+    pub kline: KlineData,
+}
+
+impl Payload for IndexPriceKline {}
+
+/// The volumes of the kline are always 0.
+#[derive(Clone, Debug, Deserialize)]
+pub struct MarkPriceKline {
+    #[serde(rename = "e")]
+    pub event_type: String,
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "ps")]
+    pub symbol: String,
+    #[serde(rename = "k")]
+    pub kline: KlineData,
+}
+
+impl Payload for MarkPriceKline {}
+
+/// The volumes are in contracts, the base asset volumes in the base asset.
+#[derive(Clone, Debug, Deserialize)]
+pub struct KlineData {
+    #[serde(rename = "t")]
+    pub start_time: i64,
+    #[serde(rename = "T")]
+    pub close_time: i64,
+    /// `0` with the index price klines, not sent with the continuous contract
+    /// klines.
+    #[serde(rename = "s")]
+    pub symbol: Option<String>,
+    #[serde(rename = "i")]
+    pub interval: String,
+    #[serde(rename = "f")]
+    pub first_update_id: i64,
+    #[serde(rename = "L")]
+    pub last_update_id: i64,
+    #[serde(rename = "o")]
+    pub open_price: Decimal,
+    #[serde(rename = "c")]
+    pub close_price: Decimal,
+    #[serde(rename = "h")]
+    pub high_price: Decimal,
+    #[serde(rename = "l")]
+    pub low_price: Decimal,
+    #[serde(rename = "v")]
+    pub volume: Decimal,
+    #[serde(rename = "n")]
+    pub number_of_trades: i64,
+    #[serde(rename = "x")]
+    pub is_closed: bool,
+    #[serde(rename = "q")]
+    pub base_asset_volume: Decimal,
+    #[serde(rename = "V")]
+    pub taker_buy_volume: Decimal,
+    #[serde(rename = "Q")]
+    pub taker_buy_base_asset_volume: Decimal,
+    #[serde(rename = "B")]
+    pub ignore: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MiniTicker {
+    #[serde(rename = "e")]
+    pub event_type: String,
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "ps")]
+    pub pair: String,
+    #[serde(rename = "c")]
+    pub close_price: Decimal,
+    #[serde(rename = "o")]
+    pub open_price: Decimal,
+    #[serde(rename = "h")]
+    pub high_price: Decimal,
+    #[serde(rename = "l")]
+    pub low_price: Decimal,
+    #[serde(rename = "v")]
+    pub volume: Decimal,
+    #[serde(rename = "q")]
+    pub base_asset_volume: Decimal,
+}
+
+impl Payload for MiniTicker {}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Ticker {
+    #[serde(rename = "e")]
+    pub event_type: String,
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "ps")]
+    pub pair: String,
+    #[serde(rename = "p")]
+    pub price_change: Decimal,
+    #[serde(rename = "P")]
+    pub price_change_percent: Decimal,
+    #[serde(rename = "w")]
+    pub weighted_avg_price: Decimal,
+    #[serde(rename = "c")]
+    pub last_price: Decimal,
+    #[serde(rename = "Q")]
+    pub last_quantity: Decimal,
+    #[serde(rename = "o")]
+    pub open_price: Decimal,
+    #[serde(rename = "h")]
+    pub high_price: Decimal,
+    #[serde(rename = "l")]
+    pub low_price: Decimal,
+    #[serde(rename = "v")]
+    pub volume: Decimal,
+    #[serde(rename = "q")]
+    pub base_asset_volume: Decimal,
+    #[serde(rename = "O")]
+    pub open_time: i64,
+    #[serde(rename = "C")]
+    pub close_time: i64,
+    #[serde(rename = "F")]
+    pub first_trade_id: i64,
+    #[serde(rename = "L")]
+    pub last_trade_id: i64,
+    #[serde(rename = "n")]
+    pub number_of_trades: i64,
+}
+
+impl Payload for Ticker {}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct BookTicker {
+    #[serde(rename = "e")]
+    pub event_type: String,
+    #[serde(rename = "u")]
+    pub order_book_update_id: i64,
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "T")]
+    pub transaction_time: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "ps")]
+    pub pair: String,
+    #[serde(rename = "b")]
+    pub best_bid_price: Decimal,
+    #[serde(rename = "B")]
+    pub best_bid_quantity: Decimal,
+    #[serde(rename = "a")]
+    pub best_ask_price: Decimal,
+    #[serde(rename = "A")]
+    pub best_ask_quantity: Decimal,
+}
+
+impl Payload for BookTicker {}
+
+/// The latest liquidation order of a symbol, at most one per second.
+#[derive(Clone, Debug, Deserialize)]
+pub struct LiquidationOrder {
+    #[serde(rename = "e")]
+    pub event_type: String,
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "o")]
+    pub order: LiquidationOrderData,
+}
+
+impl Payload for LiquidationOrder {}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct LiquidationOrderData {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "ps")]
+    pub pair: String,
+    #[serde(rename = "S")]
+    pub side: OrderSide,
+    #[serde(rename = "o")]
+    pub order_type: OrderType,
+    #[serde(rename = "f")]
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "q")]
+    pub original_quantity: Decimal,
+    #[serde(rename = "p")]
+    pub price: Decimal,
+    #[serde(rename = "ap")]
+    pub average_price: Decimal,
+    #[serde(rename = "X")]
+    pub order_status: OrderStatus,
+    #[serde(rename = "l")]
+    pub last_filled_quantity: Decimal,
+    #[serde(rename = "z")]
+    pub filled_accumulated_quantity: Decimal,
+    #[serde(rename = "T")]
+    pub trade_time: i64,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Depth {
+    #[serde(rename = "e")]
+    pub event_type: String,
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "T")]
+    pub transaction_time: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "ps")]
+    pub pair: String,
+    #[serde(rename = "U")]
+    pub first_update_id: i64,
+    #[serde(rename = "u")]
+    pub final_update_id: i64,
+    /// Final update id of the previous event of the stream.
+    #[serde(rename = "pu")]
+    pub prev_final_update_id: i64,
+    #[serde(rename = "b")]
+    pub bids: Vec<(Decimal, Decimal)>,
+    #[serde(rename = "a")]
+    pub asks: Vec<(Decimal, Decimal)>,
+}
+
+impl Payload for Depth {}
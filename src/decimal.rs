@@ -0,0 +1,561 @@
+//! Exact decimal numbers for prices, quantities and amounts.
+//!
+//! Binance sends decimals as strings, and rejects parameters with more
+//! precision than the symbol allows (`-1111 BadPrecision`). [`Decimal`] keeps
+//! the exact digits it was built from, and always serializes them in plain
+//! notation:
+//!
+//! ```
+//! use birdie::decimal::Decimal;
+//!
+//! let price: Decimal = "0.00001000".parse().unwrap();
+//! assert_eq!(price.to_string(), "0.00001000");
+//! assert_eq!(Decimal::from(1e-7).to_string(), "0.0000001");
+//! assert_eq!(Decimal::new(15, 1), "1.50".parse().unwrap());
+//! ```
+//!
+//! With the `rust_decimal` feature, [`Decimal`] converts from and to
+//! `rust_decimal::Decimal` for arithmetic.
+
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::{Add, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use thiserror::Error;
+
+/// The largest number of digits after the decimal point.
+pub const MAX_SCALE: u32 = 38;
+
+/// A decimal number, `mantissa * 10^-scale`.
+///
+/// Decimals that differ only by trailing zeros, like `1.5` and `1.50`, are
+/// equal, but each keeps its own scale when displayed.
+#[derive(Clone, Copy, Default)]
+pub struct Decimal {
+    mantissa: i128,
+    scale: u32,
+}
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum DecimalError {
+    #[error("invalid decimal: {0:?}")]
+    Invalid(String),
+    #[error("decimal out of range: {0:?}")]
+    OutOfRange(String),
+}
+
+impl Decimal {
+    pub const ZERO: Decimal = Decimal {
+        mantissa: 0,
+        scale: 0,
+    };
+
+    /// Create `mantissa * 10^-scale`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is larger than [`MAX_SCALE`].
+    pub const fn new(mantissa: i128, scale: u32) -> Self {
+        assert!(scale <= MAX_SCALE, "decimal scale out of range");
+        Self { mantissa, scale }
+    }
+
+    /// Convert a float through its shortest representation, `None` if it is
+    /// not finite or out of range.
+    pub fn from_f64(value: f64) -> Option<Self> {
+        if !value.is_finite() {
+            return None;
+        }
+        // `Display` of floats never uses the exponent notation.
+        value.to_string().parse().ok()
+    }
+
+    pub const fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    pub const fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    pub const fn is_zero(&self) -> bool {
+        self.mantissa == 0
+    }
+
+    pub const fn is_sign_negative(&self) -> bool {
+        self.mantissa < 0
+    }
+
+    pub const fn abs(&self) -> Self {
+        Self {
+            mantissa: self.mantissa.abs(),
+            scale: self.scale,
+        }
+    }
+
+    /// The same number without trailing zeros.
+    pub fn normalize(&self) -> Self {
+        let mut d = *self;
+        while d.scale > 0 && d.mantissa % 10 == 0 {
+            d.mantissa /= 10;
+            d.scale -= 1;
+        }
+        if d.mantissa == 0 {
+            d.scale = 0;
+        }
+        d
+    }
+
+    /// The number with `scale` digits after the decimal point, `None` if it
+    /// has more significant digits or doesn't fit.
+    pub fn rescale(&self, scale: u32) -> Option<Self> {
+        if scale > MAX_SCALE {
+            return None;
+        }
+        let mantissa = if scale >= self.scale {
+            self.mantissa.checked_mul(pow10(scale - self.scale)?)?
+        } else {
+            let factor = pow10(self.scale - scale)?;
+            if self.mantissa % factor != 0 {
+                return None;
+            }
+            self.mantissa / factor
+        };
+        Some(Self { mantissa, scale })
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let scale = self.scale.max(other.scale);
+        let mantissa = self
+            .rescale(scale)?
+            .mantissa
+            .checked_add(other.rescale(scale)?.mantissa)?;
+        Some(Self { mantissa, scale })
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_add(-other)
+    }
+
+    /// The exact product, `None` if it doesn't fit.
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let product = Self {
+            mantissa: self.mantissa.checked_mul(other.mantissa)?,
+            scale: self.scale + other.scale,
+        };
+        if product.scale <= MAX_SCALE {
+            return Some(product);
+        }
+        let product = product.normalize();
+        (product.scale <= MAX_SCALE).then_some(product)
+    }
+
+    /// The quotient with `scale` digits after the decimal point, rounded
+    /// toward zero. `None` if `other` is zero or the quotient doesn't fit.
+    pub fn checked_div(self, other: Self, scale: u32) -> Option<Self> {
+        if other.is_zero() || scale > MAX_SCALE {
+            return None;
+        }
+        // self / other = m1 * 10^(scale + s2 - s1) / m2 * 10^-scale
+        let exp = i64::from(scale) + i64::from(other.scale) - i64::from(self.scale);
+        let dividend = if exp >= 0 {
+            self.mantissa.checked_mul(pow10(exp as u32)?)?
+        } else {
+            self.mantissa / pow10((-exp) as u32)?
+        };
+        Some(Self {
+            mantissa: dividend / other.mantissa,
+            scale,
+        })
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        // Parsing the exact digits rounds correctly, unlike dividing.
+        self.to_string().parse().unwrap_or(f64::NAN)
+    }
+}
+
+fn pow10(exp: u32) -> Option<i128> {
+    10i128.checked_pow(exp)
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let scale = self.scale.max(other.scale);
+        match (self.rescale(scale), other.rescale(scale)) {
+            (Some(a), Some(b)) => a.mantissa.cmp(&b.mantissa),
+            // The number that doesn't fit is the larger one in magnitude.
+            (None, _) if self.is_sign_negative() => Ordering::Less,
+            (None, _) => Ordering::Greater,
+            (_, None) if other.is_sign_negative() => Ordering::Greater,
+            (_, None) => Ordering::Less,
+        }
+    }
+}
+
+impl Hash for Decimal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let d = self.normalize();
+        d.mantissa.hash(state);
+        d.scale.hash(state);
+    }
+}
+
+impl Neg for Decimal {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            mantissa: -self.mantissa,
+            scale: self.scale,
+        }
+    }
+}
+
+impl Add for Decimal {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.checked_add(other).expect("decimal overflow")
+    }
+}
+
+impl Sub for Decimal {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.checked_sub(other).expect("decimal overflow")
+    }
+}
+
+impl Mul for Decimal {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.checked_mul(other).expect("decimal overflow")
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = DecimalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || DecimalError::Invalid(s.to_owned());
+        let (negative, digits) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let (int, frac) = digits.split_once('.').unwrap_or((digits, ""));
+        let all_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+        if (int.is_empty() && frac.is_empty()) || !all_digits(int) || !all_digits(frac) {
+            return Err(invalid());
+        }
+
+        let scale = frac.len() as u32;
+        if scale > MAX_SCALE {
+            return Err(DecimalError::OutOfRange(s.to_owned()));
+        }
+        let mut mantissa: i128 = 0;
+        for b in int.bytes().chain(frac.bytes()) {
+            mantissa = mantissa
+                .checked_mul(10)
+                .and_then(|m| m.checked_add((b - b'0') as i128))
+                .ok_or_else(|| DecimalError::OutOfRange(s.to_owned()))?;
+        }
+        if negative {
+            mantissa = -mantissa;
+        }
+        Ok(Self { mantissa, scale })
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = self.mantissa.unsigned_abs().to_string();
+        let scale = self.scale as usize;
+        let sign = if self.is_sign_negative() { "-" } else { "" };
+        let s = if scale == 0 {
+            format!("{sign}{digits}")
+        } else if digits.len() > scale {
+            let (int, frac) = digits.split_at(digits.len() - scale);
+            format!("{sign}{int}.{frac}")
+        } else {
+            format!("{sign}0.{digits:0>scale$}")
+        };
+        f.pad(&s)
+    }
+}
+
+impl fmt::Debug for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+macro_rules! from_int {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Decimal {
+                fn from(value: $ty) -> Self {
+                    Self::new(value as i128, 0)
+                }
+            }
+        )*
+    };
+}
+
+from_int!(i8, i16, i32, i64, u8, u16, u32, u64, usize);
+
+impl From<f64> for Decimal {
+    /// Convert a float through its shortest representation, so `0.1` becomes
+    /// `0.1`, not the nearest binary value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the float is not finite or out of range, see
+    /// [`Decimal::from_f64`].
+    fn from(value: f64) -> Self {
+        Self::from_f64(value).expect("float is not a finite decimal")
+    }
+}
+
+impl Serialize for Decimal {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Decimal {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(DecimalVisitor)
+    }
+}
+
+struct DecimalVisitor;
+
+impl de::Visitor<'_> for DecimalVisitor {
+    type Value = Decimal;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a decimal number or string")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Decimal, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Decimal, E> {
+        Ok(v.into())
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Decimal, E> {
+        Ok(v.into())
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Decimal, E> {
+        Decimal::from_f64(v).ok_or_else(|| E::custom(format!("invalid decimal: {v}")))
+    }
+}
+
+/// Deserialize a decimal that Binance leaves empty when it does not apply,
+/// e.g. the funding rate of delivery contracts, as `None`.
+///
+/// ```
+/// use birdie::decimal::Decimal;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Premium {
+///     #[serde(deserialize_with = "birdie::decimal::empty_as_none")]
+///     rate: Option<Decimal>,
+/// }
+///
+/// let premium: Premium = serde_json::from_str(r#"{"rate":""}"#).unwrap();
+/// assert!(premium.rate.is_none());
+/// ```
+pub fn empty_as_none<'de, D>(deserializer: D) -> Result<Option<Decimal>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(OptionalDecimalVisitor)
+}
+
+struct OptionalDecimalVisitor;
+
+impl de::Visitor<'_> for OptionalDecimalVisitor {
+    type Value = Option<Decimal>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a decimal number, string or an empty string")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Option<Decimal>, E> {
+        if v.is_empty() {
+            return Ok(None);
+        }
+        DecimalVisitor.visit_str(v).map(Some)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Option<Decimal>, E> {
+        DecimalVisitor.visit_i64(v).map(Some)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Option<Decimal>, E> {
+        DecimalVisitor.visit_u64(v).map(Some)
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Option<Decimal>, E> {
+        DecimalVisitor.visit_f64(v).map(Some)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Option<Decimal>, E> {
+        Ok(None)
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl From<rust_decimal::Decimal> for Decimal {
+    fn from(value: rust_decimal::Decimal) -> Self {
+        Self::new(value.mantissa(), value.scale())
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl TryFrom<Decimal> for rust_decimal::Decimal {
+    type Error = DecimalError;
+
+    fn try_from(value: Decimal) -> Result<Self, Self::Error> {
+        rust_decimal::Decimal::try_from_i128_with_scale(value.mantissa, value.scale)
+            .map_err(|_| DecimalError::OutOfRange(value.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dec(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        for s in [
+            "0",
+            "1",
+            "-1",
+            "0.1",
+            "0.00000100",
+            "-0.05",
+            "123.450",
+            "0.0",
+        ] {
+            assert_eq!(dec(s).to_string(), s);
+        }
+        assert_eq!(dec(".5").to_string(), "0.5");
+        assert_eq!(dec("+5.").to_string(), "5");
+        for s in ["", ".", "-", "1e-7", "1.2.3", "abc", " 1"] {
+            assert!(s.parse::<Decimal>().is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn from_float() {
+        assert_eq!(Decimal::from(1e-7).to_string(), "0.0000001");
+        assert_eq!(Decimal::from(0.1).to_string(), "0.1");
+        assert_eq!(Decimal::from(100.0).to_string(), "100");
+        assert_eq!(Decimal::from_f64(f64::NAN), None);
+        assert_eq!(Decimal::from_f64(1e300), None);
+    }
+
+    #[test]
+    fn compare() {
+        assert_eq!(dec("1.5"), dec("1.50"));
+        assert!(dec("1.5") < dec("1.51"));
+        assert!(dec("-2") < dec("-1.99"));
+        assert!(dec("100000000000000000000000000000000000000") > dec("0.1"));
+        assert_eq!(dec("1.10").normalize().to_string(), "1.1");
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!((dec("0.1") + dec("0.2")).to_string(), "0.3");
+        assert_eq!((dec("1") - dec("1.25")).to_string(), "-0.25");
+        assert_eq!(dec("1.20").rescale(1), Some(dec("1.2")));
+        assert_eq!(dec("1.25").rescale(1), None);
+        assert_eq!((dec("1.5") * dec("-0.20")).to_string(), "-0.300");
+        assert_eq!(dec("1").checked_div(dec("3"), 4), Some(dec("0.3333")));
+        assert_eq!(dec("-2.5").checked_div(dec("0.5"), 0), Some(dec("-5")));
+        assert_eq!(dec("1").checked_div(Decimal::ZERO, 2), None);
+    }
+
+    #[test]
+    fn serde() {
+        let d: Decimal = serde_json::from_str(r#""0.00100000""#).unwrap();
+        assert_eq!(d.to_string(), "0.00100000");
+        let d: Decimal = serde_json::from_str("0.25").unwrap();
+        assert_eq!(d, dec("0.25"));
+        assert_eq!(serde_json::to_string(&d).unwrap(), r#""0.25""#);
+
+        #[derive(Deserialize)]
+        struct Rate {
+            #[serde(deserialize_with = "empty_as_none")]
+            rate: Option<Decimal>,
+        }
+        let r: Rate = serde_json::from_str(r#"{"rate":""}"#).unwrap();
+        assert!(r.rate.is_none());
+        let r: Rate = serde_json::from_str(r#"{"rate":"0.0001"}"#).unwrap();
+        assert_eq!(r.rate, Some(dec("0.0001")));
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn rust_decimal() {
+        let d = rust_decimal::Decimal::try_from(dec("-1.050")).unwrap();
+        assert_eq!(d.to_string(), "-1.050");
+        assert_eq!(Decimal::from(d), dec("-1.05"));
+        assert!(rust_decimal::Decimal::try_from(dec("0.00000000000000000000000000001")).is_err());
+    }
+
+    #[test]
+    fn params_query() {
+        use crate::{
+            enums::{OrderSide, OrderType},
+            spot::trade::NewOrderParams,
+            Params,
+        };
+
+        let params = NewOrderParams::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+            .quantity(1e-7)
+            .price(dec("65000.10"));
+        let query = params.as_query().unwrap();
+        assert!(query.contains("quantity=0.0000001"), "{query}");
+        assert!(query.contains("price=65000.10"), "{query}");
+    }
+}
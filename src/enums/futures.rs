@@ -6,7 +6,7 @@ pub enum SymbolType {
     Future,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ContractType {
     Perpetual,
@@ -17,7 +17,7 @@ pub enum ContractType {
     PerpetualDelivering,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ContractStats {
     PendingTrading,
@@ -30,7 +30,7 @@ pub enum ContractStats {
     Close,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OrderStatus {
     New,
@@ -39,9 +39,10 @@ pub enum OrderStatus {
     Canceled,
     Rejected,
     Expired,
+    ExpiredInMatch,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OrderType {
     Limit,
@@ -51,16 +52,18 @@ pub enum OrderType {
     TakeProfit,
     TakeProfitMarket,
     TrailingStopMarket,
+    /// Only reported by the user data stream.
+    Liquidation,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OrderSide {
     Buy,
     Sell,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum PositionSide {
     Both,
@@ -68,7 +71,7 @@ pub enum PositionSide {
     Short,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum TimeInForce {
     Gtc, // Good till cancel
@@ -78,7 +81,7 @@ pub enum TimeInForce {
     Gtd, // Good till date
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum WorkingType {
     MarkPrice,
@@ -94,7 +97,7 @@ pub enum ResponseType {
 
 pub type KlineInterval = super::KlineInterval;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum StpMode {
     None,
@@ -103,7 +106,7 @@ pub enum StpMode {
     ExpireMaker,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum PriceMatch {
     #[serde(rename = "NONE")]
     None,
@@ -125,17 +128,80 @@ pub enum PriceMatch {
     Queue20,
 }
 
+/// Execution type of an `ORDER_TRADE_UPDATE` event.
 #[derive(Clone, Debug, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct RateLimit {
-    pub rate_limit_type: String,
-    pub interval: RateLimitIntervals,
-    pub interval_num: i64,
-    pub limit: i64,
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ExecutionType {
+    New,
+    Canceled,
+    Calculated,
+    Expired,
+    Trade,
+    Amendment,
 }
 
+/// Reason of an `ACCOUNT_UPDATE` event.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum RateLimitIntervals {
-    Minute,
+pub enum AccountUpdateReason {
+    Deposit,
+    Withdraw,
+    Order,
+    FundingFee,
+    WithdrawReject,
+    Adjustment,
+    InsuranceClear,
+    AdminDeposit,
+    AdminWithdraw,
+    MarginTransfer,
+    MarginTypeChange,
+    AssetTransfer,
+    OptionsPremiumFee,
+    OptionsSettleProfit,
+    AutoExchange,
+    CoinSwapDeposit,
+    CoinSwapWithdraw,
 }
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IncomeType {
+    Transfer,
+    WelcomeBonus,
+    RealizedPnl,
+    FundingFee,
+    Commission,
+    InsuranceClear,
+    ReferralKickback,
+    CommissionRebate,
+    ApiRebate,
+    ContestReward,
+    CrossCollateralTransfer,
+    OptionsPremiumFee,
+    OptionsSettleProfit,
+    InternalTransfer,
+    AutoExchange,
+    /// Spelled as sent by the API.
+    #[serde(rename = "DELIVERED_SETTELMENT")]
+    DeliveredSettlement,
+    CoinSwapDeposit,
+    CoinSwapWithdraw,
+    PositionLimitIncreaseFee,
+    StrategyUmfuturesTransfer,
+    FeeReturn,
+    BfusdReward,
+    /// An income type that this crate does not know yet. It cannot be used
+    /// to filter the income history.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Update speed of the mark price streams.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MarkPriceSpeed {
+    Ms1000,
+    Ms3000,
+}
+
+// Futures share the rate limit definitions with spot.
+pub use super::{RateLimit, RateLimitIntervals};
@@ -177,13 +177,17 @@ pub struct RateLimit {
     pub interval: RateLimitIntervals,
     pub interval_num: i64,
     pub limit: i64,
+    /// The usage in the current window, only reported by the Web Socket API.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum RateLimitIntervals {
     Second,
     Minute,
+    Hour,
     Day,
 }
 
@@ -203,7 +207,7 @@ pub enum CancelRestriction {
     OnlyPartiallyFilled,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum CancelReplaceMode {
     StopOnFailure,
@@ -217,7 +221,7 @@ pub enum TickerType {
     Mini,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum KlineInterval {
     OneSecond,
     OneMinute,
@@ -237,12 +241,9 @@ pub enum KlineInterval {
     OneMonth,
 }
 
-impl Serialize for KlineInterval {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::ser::Serializer,
-    {
-        let interval = match self {
+impl KlineInterval {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
             KlineInterval::OneSecond => "1s",
             KlineInterval::OneMinute => "1m",
             KlineInterval::ThreeMinutes => "3m",
@@ -259,7 +260,38 @@ impl Serialize for KlineInterval {
             KlineInterval::ThreeDays => "3d",
             KlineInterval::OneWeek => "1w",
             KlineInterval::OneMonth => "1M",
-        };
-        serializer.serialize_str(interval)
+        }
+    }
+}
+
+impl Serialize for KlineInterval {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
     }
 }
+
+/// Update speed of the depth streams.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DepthSpeed {
+    Ms100,
+    Ms1000,
+}
+
+/// Number of levels of the partial depth streams.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Levels {
+    L5,
+    L10,
+    L20,
+}
+
+/// Window of the rolling window ticker streams.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Window {
+    H1,
+    H4,
+    D1,
+}
@@ -3,19 +3,56 @@
 //! See [docs](https://developers.binance.com/docs/binance-spot-api-docs/errors)
 //! for more information.
 
-use serde::Deserialize;
-use serde_repr::Deserialize_repr;
+use serde::{Deserialize, Deserializer};
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize)]
 pub struct BinanceError {
     pub code: BinanceErrorCode,
     pub msg: String,
 }
 
-#[derive(Debug, Deserialize_repr)]
-#[serde(untagged)]
-#[repr(i32)]
-pub enum BinanceErrorCode {
+/// Define [`BinanceErrorCode`] from a list of `Name = code` variants, plus a
+/// [`BinanceErrorCode::Other`] variant for the codes that are not listed.
+macro_rules! error_codes {
+    ($($(#[$meta:meta])* $name:ident = $code:literal,)*) => {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum BinanceErrorCode {
+            $($(#[$meta])* $name,)*
+            /// A code that is not known (yet) to this crate.
+            Other(i32),
+        }
+
+        impl BinanceErrorCode {
+            /// The numeric error code.
+            pub fn code(&self) -> i32 {
+                match self {
+                    $(Self::$name => $code,)*
+                    Self::Other(code) => *code,
+                }
+            }
+        }
+
+        impl From<i32> for BinanceErrorCode {
+            fn from(code: i32) -> Self {
+                match code {
+                    $($code => Self::$name,)*
+                    _ => Self::Other(code),
+                }
+            }
+        }
+    };
+}
+
+impl<'de> Deserialize<'de> for BinanceErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        i32::deserialize(deserializer).map(Self::from)
+    }
+}
+
+error_codes! {
     /// An unknown error occurred while processing the request.
     Unknown = -1000,
     /// Internal error; unable to process your request. Please try again.
@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
 
+use crate::decimal::Decimal;
+
 #[derive(Debug, Deserialize, Serialize)]
-#[serde(untagged)]
+#[serde(tag = "filterType", rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum SymbolFilter {
     PriceFilter(PriceFilter),
     PercentPrice(PercentPrice),
@@ -10,56 +12,57 @@ pub enum SymbolFilter {
     MarketLotSize(MarketLotSize),
     MaxNumOrders(MaxNumOrders),
     MaxNumAlgoOrders(MaxNumAlgoOrders),
+    /// A filter type that this crate does not know yet.
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PriceFilter {
-    filter_type: String,
-    min_price: String,
-    max_price: String,
-    tick_size: String,
+    pub min_price: Decimal,
+    pub max_price: Decimal,
+    pub tick_size: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PercentPrice {
-    filter_type: String,
-    multiplier_up: String,
-    multiplier_down: String,
-    avg_price_mins: i64,
+    pub multiplier_up: Decimal,
+    pub multiplier_down: Decimal,
+    pub multiplier_decimal: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct LotSize {
-    filter_type: String,
-    min_notional: String,
-    apply_to_market: bool,
-    avg_price_mins: i64,
+    pub min_qty: Decimal,
+    pub max_qty: Decimal,
+    pub step_size: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MinNotional {
-    filter_type: String,
-    min_notional: String,
-    apply_to_market: bool,
-    avg_price_mins: i64,
+    pub notional: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MarketLotSize {
-    filter_type: String,
-    min_qty: String,
-    max_qty: String,
-    step_size: String,
+    pub min_qty: Decimal,
+    pub max_qty: Decimal,
+    pub step_size: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MaxNumOrders {
-    filter_type: String,
-    max_num_orders: i64,
+    pub limit: i64,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MaxNumAlgoOrders {
-    filter_type: String,
-    max_num_algo_orders: i64,
+    pub limit: i64,
 }
@@ -8,8 +8,10 @@ pub mod futures;
 
 use serde::{Deserialize, Serialize};
 
+use crate::decimal::Decimal;
+
 #[derive(Debug, Deserialize, Serialize)]
-#[serde(untagged)]
+#[serde(tag = "filterType", rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum SymbolFilter {
     PriceFilter(PriceFilter),
     PercentPrice(PercentPrice),
@@ -24,145 +26,151 @@ pub enum SymbolFilter {
     MaxNumIcebergOrders(MaxNumIcebergOrders),
     MaxPosition(MaxPosition),
     TrailingDelta(TrailingDelta),
+    /// A filter type that this crate does not know yet.
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
-#[serde(untagged)]
+#[serde(tag = "filterType", rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ExchangeFilter {
     ExchangeMaxNumOrders(ExchangeMaxNumOrders),
     ExchangeMaxNumAlgoOrders(ExchangeMaxNumAlgoOrders),
     ExchangeMaxNumIcebergOrders(ExchangeMaxNumIcebergOrders),
+    /// A filter type that this crate does not know yet.
+    #[serde(other)]
+    Unknown,
 }
 
 /// [Price Filter](https://developers.binance.com/docs/binance-spot-api-docs/filters#price_filter)
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PriceFilter {
-    filter_type: String,
-    min_price: String,
-    max_price: String,
-    tick_size: String,
+    pub min_price: Decimal,
+    pub max_price: Decimal,
+    pub tick_size: Decimal,
 }
 
 /// [Percent Price](https://developers.binance.com/docs/binance-spot-api-docs/filters#percent_price)
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PercentPrice {
-    filter_type: String,
-    multiplier_up: String,
-    multiplier_down: String,
-    avg_price_mins: i64,
+    pub multiplier_up: Decimal,
+    pub multiplier_down: Decimal,
+    pub avg_price_mins: i64,
 }
 
 /// [Percent Price By Side](https://developers.binance.com/docs/binance-spot-api-docs/filters#percent_price)
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PercentPriceBySide {
-    filter_type: String,
-    bid_multiplier_up: String,
-    bid_multiplier_down: String,
-    ask_multiplier_up: String,
-    ask_multiplier_down: String,
-    avg_price_mins: i64,
+    pub bid_multiplier_up: Decimal,
+    pub bid_multiplier_down: Decimal,
+    pub ask_multiplier_up: Decimal,
+    pub ask_multiplier_down: Decimal,
+    pub avg_price_mins: i64,
 }
 
 /// [Lot Size](https://developers.binance.com/docs/binance-spot-api-docs/filters#lot_size)
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct LotSize {
-    filter_type: String,
-    min_notional: String,
-    apply_to_market: bool,
-    avg_price_mins: i64,
+    pub min_qty: Decimal,
+    pub max_qty: Decimal,
+    pub step_size: Decimal,
 }
 
 // [Min Notional](https://developers.binance.com/docs/binance-spot-api-docs/filters#min_notional)
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MinNotional {
-    filter_type: String,
-    min_notional: String,
-    apply_to_market: bool,
-    avg_price_mins: i64,
+    pub min_notional: Decimal,
+    pub apply_to_market: bool,
+    pub avg_price_mins: i64,
 }
 
 /// [Notional](https://developers.binance.com/docs/binance-spot-api-docs/filters#notional)
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Notional {
-    filter_type: String,
-    min_notional: String,
-    apply_min_to_market: bool,
-    max_notional: String,
-    apply_max_to_market: bool,
-    avg_price_mins: i64,
+    pub min_notional: Decimal,
+    pub apply_min_to_market: bool,
+    pub max_notional: Decimal,
+    pub apply_max_to_market: bool,
+    pub avg_price_mins: i64,
 }
 
 /// [Iceberg Parts](https://developers.binance.com/docs/binance-spot-api-docs/filters#iceberg_parts)
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct IcebergParts {
-    filter_type: String,
-    limit: i64,
+    pub limit: i64,
 }
 
 /// [Market Lot Size](https://developers.binance.com/docs/binance-spot-api-docs/filters#market_lot_size)
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MarketLotSize {
-    filter_type: String,
-    min_qty: String,
-    max_qty: String,
-    step_size: String,
+    pub min_qty: Decimal,
+    pub max_qty: Decimal,
+    pub step_size: Decimal,
 }
 
 /// [Max Num Orders](https://developers.binance.com/docs/binance-spot-api-docs/filters#max_num_orders)
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MaxNumOrders {
-    filter_type: String,
-    max_num_orders: i64,
+    pub max_num_orders: i64,
 }
 
 /// [Max Num Algo Orders](https://developers.binance.com/docs/binance-spot-api-docs/filters#max_num_algo_orders)
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MaxNumAlgoOrders {
-    filter_type: String,
-    max_num_algo_orders: i64,
+    pub max_num_algo_orders: i64,
 }
 
 /// [Max Num Iceberg Orders](https://developers.binance.com/docs/binance-spot-api-docs/filters#max_num_iceberg_orders)
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MaxNumIcebergOrders {
-    filter_type: String,
-    max_num_iceberg_orders: i64,
+    pub max_num_iceberg_orders: i64,
 }
 
 /// [Max Position](https://developers.binance.com/docs/binance-spot-api-docs/filters#max_position)
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MaxPosition {
-    filter_type: String,
-    max_position: String,
+    pub max_position: Decimal,
 }
 
 /// [Trailing Delta](https://developers.binance.com/docs/binance-spot-api-docs/filters#trailing_delta)
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct TrailingDelta {
-    filter_type: String,
-    min_trailing_above_delta: i64,
-    max_trailing_above_delta: i64,
-    min_trailing_below_delta: i64,
-    max_trailing_below_delta: i64,
+    pub min_trailing_above_delta: i64,
+    pub max_trailing_above_delta: i64,
+    pub min_trailing_below_delta: i64,
+    pub max_trailing_below_delta: i64,
 }
 
 /// [Exchange Max Num Orders](https://developers.binance.com/docs/binance-spot-api-docs/filters#exchange_max_num_orders)
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ExchangeMaxNumAlgoOrders {
-    filter_type: String,
-    max_num_algo_orders: i64,
+    pub max_num_algo_orders: i64,
 }
 
 /// [Exchange Max Num Algo Orders](https://developers.binance.com/docs/binance-spot-api-docs/filters#exchange_max_num_algo_orders)
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ExchangeMaxNumOrders {
-    filter_type: String,
-    max_num_orders: i64,
+    pub max_num_orders: i64,
 }
 
 /// [Exchange Max Num Iceberg Orders](https://developers.binance.com/docs/binance-spot-api-docs/filters#exchange_max_num_iceberg_orders)
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ExchangeMaxNumIcebergOrders {
-    filter_type: String,
-    max_num_iceberg_orders: i64,
+    pub max_num_iceberg_orders: i64,
 }
@@ -1,9 +0,0 @@
-//! Binance's FIX API - stub
-
-pub struct FixApiClient {}
-
-impl FixApiClient {
-    pub fn new() -> Self {
-        Self {}
-    }
-}
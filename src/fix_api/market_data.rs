@@ -0,0 +1,84 @@
+//! Messages of the market data sessions.
+
+use super::message::{msg_type, tag, FixMessage};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubscriptionRequestType {
+    Subscribe,
+    Unsubscribe,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MdEntryType {
+    Bid,
+    Offer,
+    Trade,
+}
+
+/// Subscribe to, or unsubscribe from, the trades or the order book of a
+/// symbol. Updates are sent as `MarketDataSnapshot` (`W`) and
+/// `MarketDataIncrementalRefresh` (`X`) messages.
+#[derive(Clone, Debug)]
+pub struct MarketDataRequest {
+    md_req_id: String,
+    subscription_request_type: SubscriptionRequestType,
+    market_depth: Option<u32>,
+    entry_types: Vec<MdEntryType>,
+    symbols: Vec<String>,
+}
+
+impl MarketDataRequest {
+    pub fn new(md_req_id: &str, subscription_request_type: SubscriptionRequestType) -> Self {
+        Self {
+            md_req_id: md_req_id.to_owned(),
+            subscription_request_type,
+            market_depth: None,
+            entry_types: Vec::new(),
+            symbols: Vec::new(),
+        }
+    }
+
+    /// The depth of the order book, required for `Bid` and `Offer` entries.
+    pub fn market_depth(mut self, market_depth: u32) -> Self {
+        self.market_depth = Some(market_depth);
+        self
+    }
+
+    pub fn entry_type(mut self, entry_type: MdEntryType) -> Self {
+        self.entry_types.push(entry_type);
+        self
+    }
+
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbols.push(symbol.to_owned());
+        self
+    }
+}
+
+impl From<MarketDataRequest> for FixMessage {
+    fn from(req: MarketDataRequest) -> Self {
+        let subscription_request_type = match req.subscription_request_type {
+            SubscriptionRequestType::Subscribe => 1,
+            SubscriptionRequestType::Unsubscribe => 2,
+        };
+        let mut msg = FixMessage::new(msg_type::MARKET_DATA_REQUEST)
+            .field(tag::MD_REQ_ID, req.md_req_id)
+            .field(tag::SUBSCRIPTION_REQUEST_TYPE, subscription_request_type)
+            .field_opt(tag::MARKET_DEPTH, req.market_depth);
+
+        msg.push(tag::NO_RELATED_SYM, req.symbols.len());
+        for symbol in req.symbols {
+            msg.push(tag::SYMBOL, symbol);
+        }
+        msg.push(tag::NO_MD_ENTRY_TYPES, req.entry_types.len());
+        for entry_type in req.entry_types {
+            let entry_type = match entry_type {
+                MdEntryType::Bid => 0,
+                MdEntryType::Offer => 1,
+                MdEntryType::Trade => 2,
+            };
+            msg.push(tag::MD_ENTRY_TYPE, entry_type);
+        }
+        msg
+    }
+}
@@ -0,0 +1,274 @@
+use std::fmt;
+
+use super::FixApiError;
+
+/// The field delimiter of FIX messages.
+pub const SOH: char = '\x01';
+
+const BEGIN_STRING: &str = "FIX.4.4";
+
+/// Maximum length of a message, well above the largest messages of Binance,
+/// so that a corrupt body length cannot make the reader wait forever.
+const MAX_FRAME_LEN: usize = 1 << 20;
+
+/// Tags of the fields used by this crate.
+pub mod tag {
+    pub const CL_ORD_ID: u32 = 11;
+    pub const CUM_QTY: u32 = 14;
+    pub const EXEC_ID: u32 = 17;
+    pub const EXEC_INST: u32 = 18;
+    pub const LAST_PX: u32 = 31;
+    pub const LAST_QTY: u32 = 32;
+    pub const MSG_SEQ_NUM: u32 = 34;
+    pub const MSG_TYPE: u32 = 35;
+    pub const ORDER_ID: u32 = 37;
+    pub const ORDER_QTY: u32 = 38;
+    pub const ORD_STATUS: u32 = 39;
+    pub const ORD_TYPE: u32 = 40;
+    pub const ORIG_CL_ORD_ID: u32 = 41;
+    pub const PRICE: u32 = 44;
+    pub const REF_SEQ_NUM: u32 = 45;
+    pub const SENDER_COMP_ID: u32 = 49;
+    pub const SENDING_TIME: u32 = 52;
+    pub const SIDE: u32 = 54;
+    pub const SYMBOL: u32 = 55;
+    pub const TARGET_COMP_ID: u32 = 56;
+    pub const TEXT: u32 = 58;
+    pub const TIME_IN_FORCE: u32 = 59;
+    pub const TRANSACT_TIME: u32 = 60;
+    pub const RAW_DATA_LENGTH: u32 = 95;
+    pub const RAW_DATA: u32 = 96;
+    pub const ENCRYPT_METHOD: u32 = 98;
+    pub const HEART_BT_INT: u32 = 108;
+    pub const MAX_FLOOR: u32 = 111;
+    pub const TEST_REQ_ID: u32 = 112;
+    pub const RESET_SEQ_NUM_FLAG: u32 = 141;
+    pub const NO_RELATED_SYM: u32 = 146;
+    pub const EXEC_TYPE: u32 = 150;
+    pub const LEAVES_QTY: u32 = 151;
+    pub const CASH_ORDER_QTY: u32 = 152;
+    pub const MD_REQ_ID: u32 = 262;
+    pub const SUBSCRIPTION_REQUEST_TYPE: u32 = 263;
+    pub const MARKET_DEPTH: u32 = 264;
+    pub const NO_MD_ENTRY_TYPES: u32 = 267;
+    pub const NO_MD_ENTRIES: u32 = 268;
+    pub const MD_ENTRY_TYPE: u32 = 269;
+    pub const USERNAME: u32 = 553;
+    pub const TRADE_ID: u32 = 1003;
+    pub const TRIGGER_TYPE: u32 = 1100;
+    pub const TRIGGER_ACTION: u32 = 1101;
+    pub const TRIGGER_PRICE: u32 = 1102;
+    pub const TRIGGER_PRICE_TYPE: u32 = 1107;
+    pub const TRIGGER_PRICE_DIRECTION: u32 = 1109;
+    pub const SELF_TRADE_PREVENTION_MODE: u32 = 25001;
+    pub const TRIGGER_TRAILING_DELTA_BIPS: u32 = 25009;
+    pub const ERROR_CODE: u32 = 25016;
+    pub const CUM_QUOTE_QTY: u32 = 25017;
+    pub const ORDER_CANCEL_REQUEST_AND_NEW_ORDER_SINGLE_MODE: u32 = 25033;
+    pub const CANCEL_CL_ORD_ID: u32 = 25034;
+    pub const MESSAGE_HANDLING: u32 = 25035;
+    pub const DROP_COPY_FLAG: u32 = 9406;
+}
+
+/// Message types of the FIX API.
+pub mod msg_type {
+    pub const HEARTBEAT: &str = "0";
+    pub const TEST_REQUEST: &str = "1";
+    pub const REJECT: &str = "3";
+    pub const LOGOUT: &str = "5";
+    pub const EXECUTION_REPORT: &str = "8";
+    pub const ORDER_CANCEL_REJECT: &str = "9";
+    pub const LOGON: &str = "A";
+    pub const NEW_ORDER_SINGLE: &str = "D";
+    pub const ORDER_CANCEL_REQUEST: &str = "F";
+    pub const MARKET_DATA_REQUEST: &str = "V";
+    pub const MARKET_DATA_SNAPSHOT: &str = "W";
+    pub const MARKET_DATA_INCREMENTAL_REFRESH: &str = "X";
+    pub const MARKET_DATA_REQUEST_REJECT: &str = "Y";
+    pub const ORDER_CANCEL_REQUEST_AND_NEW_ORDER_SINGLE: &str = "XCN";
+}
+
+/// A FIX message: its type and its fields in order, without the
+/// `BeginString`, `BodyLength` and `CheckSum` fields which are handled when
+/// encoding. Repeating groups are kept in order, as they are on the wire.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixMessage {
+    msg_type: String,
+    fields: Vec<(u32, String)>,
+}
+
+impl FixMessage {
+    pub fn new(msg_type: &str) -> Self {
+        Self {
+            msg_type: msg_type.to_owned(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Append a field.
+    pub fn field(mut self, tag: u32, value: impl ToString) -> Self {
+        self.push(tag, value);
+        self
+    }
+
+    /// Append a field if the value is set.
+    pub fn field_opt<T: ToString>(mut self, tag: u32, value: Option<T>) -> Self {
+        if let Some(value) = value {
+            self.push(tag, value);
+        }
+        self
+    }
+
+    pub fn push(&mut self, tag: u32, value: impl ToString) {
+        self.fields.push((tag, value.to_string()));
+    }
+
+    pub fn msg_type(&self) -> &str {
+        &self.msg_type
+    }
+
+    pub fn fields(&self) -> &[(u32, String)] {
+        &self.fields
+    }
+
+    /// The value of the first field with the given tag.
+    pub fn get(&self, tag: u32) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(t, _)| *t == tag)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Parse the value of the first field with the given tag.
+    pub fn parse<T: std::str::FromStr>(&self, tag: u32) -> Result<Option<T>, FixApiError> {
+        self.get(tag)
+            .map(|v| {
+                v.parse()
+                    .map_err(|_| FixApiError::Message(format!("invalid value of tag {tag}: {v:?}")))
+            })
+            .transpose()
+    }
+
+    /// The value of a field that must be present.
+    pub fn require(&self, tag: u32) -> Result<&str, FixApiError> {
+        self.get(tag)
+            .ok_or_else(|| FixApiError::Message(format!("missing tag {tag}")))
+    }
+
+    pub fn seq_num(&self) -> Option<u64> {
+        self.get(tag::MSG_SEQ_NUM).and_then(|v| v.parse().ok())
+    }
+
+    /// Encode the message, computing its body length and checksum.
+    pub fn encode(&self) -> String {
+        let mut body = format!("{}={}{SOH}", tag::MSG_TYPE, self.msg_type);
+        for (tag, value) in &self.fields {
+            body.push_str(&format!("{tag}={value}{SOH}"));
+        }
+        let mut msg = format!("8={BEGIN_STRING}{SOH}9={}{SOH}{body}", body.len());
+        let checksum = checksum(msg.as_bytes());
+        msg.push_str(&format!("10={checksum:03}{SOH}"));
+        msg
+    }
+
+    /// Decode a complete message, checking its body length and checksum.
+    pub fn decode(msg: &[u8]) -> Result<Self, FixApiError> {
+        let len = frame_len(msg)?.filter(|len| *len == msg.len());
+        let invalid = || FixApiError::Message(format!("invalid message: {}", display(msg)));
+        let len = len.ok_or_else(invalid)?;
+
+        let (frame, trailer) = msg.split_at(len - 7);
+        let checksum = std::str::from_utf8(&trailer[3..6]).map_err(|_| invalid())?;
+        if checksum.parse::<u8>().ok() != Some(self::checksum(frame)) {
+            return Err(FixApiError::Message(format!(
+                "invalid checksum: {}",
+                display(msg)
+            )));
+        }
+
+        let frame = std::str::from_utf8(frame).map_err(|_| invalid())?;
+        let mut fields = frame.split_terminator(SOH).skip(2).map(|field| {
+            let (tag, value) = field.split_once('=').ok_or_else(invalid)?;
+            let tag = tag.parse().map_err(|_| invalid())?;
+            Ok((tag, value.to_owned()))
+        });
+        let msg_type = match fields.next() {
+            Some(Ok((tag::MSG_TYPE, msg_type))) => msg_type,
+            _ => return Err(invalid()),
+        };
+        Ok(Self {
+            msg_type,
+            fields: fields.collect::<Result<_, FixApiError>>()?,
+        })
+    }
+}
+
+impl fmt::Display for FixMessage {
+    /// The encoded message, with `|` as the delimiter.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.encode().replace(SOH, "|"))
+    }
+}
+
+fn checksum(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0u8, |sum, b| sum.wrapping_add(*b))
+}
+
+fn display(msg: &[u8]) -> String {
+    String::from_utf8_lossy(msg).replace(SOH, "|")
+}
+
+/// The length of the message at the start of `buf`, `None` if it is not
+/// complete yet.
+pub(crate) fn frame_len(buf: &[u8]) -> Result<Option<usize>, FixApiError> {
+    let prefix = format!("8={BEGIN_STRING}{SOH}9=");
+    let n = prefix.len().min(buf.len());
+    if buf[..n] != prefix.as_bytes()[..n] {
+        return Err(FixApiError::Message(format!(
+            "invalid message start: {}",
+            display(buf)
+        )));
+    }
+    let Some(end) = buf[n..].iter().position(|b| *b == SOH as u8) else {
+        return Ok(None);
+    };
+    let body_len = std::str::from_utf8(&buf[n..n + end])
+        .ok()
+        .and_then(|v| v.parse::<usize>().ok())
+        .ok_or_else(|| FixApiError::Message(format!("invalid body length: {}", display(buf))))?;
+    // The checksum field is always `10=nnn<SOH>`.
+    let len = (n + end + 1 + 7)
+        .checked_add(body_len)
+        .filter(|len| *len <= MAX_FRAME_LEN)
+        .ok_or_else(|| FixApiError::Message(format!("body length too large: {body_len}")))?;
+    Ok((buf.len() >= len).then_some(len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_decode() {
+        let msg = FixMessage::new(msg_type::TEST_REQUEST)
+            .field(tag::SENDER_COMP_ID, "EXAMPLE")
+            .field(tag::TARGET_COMP_ID, "SPOT")
+            .field(tag::MSG_SEQ_NUM, 2)
+            .field(tag::TEST_REQ_ID, "ping");
+        let encoded = msg.encode();
+        assert_eq!(
+            encoded.replace(SOH, "|"),
+            "8=FIX.4.4|9=38|35=1|49=EXAMPLE|56=SPOT|34=2|112=ping|10=215|"
+        );
+        assert_eq!(frame_len(encoded.as_bytes()).unwrap(), Some(encoded.len()));
+        assert_eq!(frame_len(&encoded.as_bytes()[..20]).unwrap(), None);
+        assert_eq!(FixMessage::decode(encoded.as_bytes()).unwrap(), msg);
+        assert_eq!(msg.seq_num(), Some(2));
+
+        let corrupted = encoded.replace("ping", "pong");
+        assert!(FixMessage::decode(corrupted.as_bytes()).is_err());
+        assert!(frame_len(b"8=FIX.4.2\x01").is_err());
+        assert!(frame_len(b"8=FIX.4.4\x019=18446744073709551615\x01").is_err());
+        assert!(frame_len(b"8=FIX.4.4\x019=2000000\x01").is_err());
+    }
+}
@@ -0,0 +1,493 @@
+//! Binance's FIX API
+//!
+//! [`FixApiClient`] manages a FIX 4.4 session with one of the spot FIX
+//! endpoints:
+//!
+//! - order entry: `tcp+tls://fix-oe.binance.com:9000`
+//! - drop copy: `tcp+tls://fix-dc.binance.com:9000`
+//! - market data: `tcp+tls://fix-md.binance.com:9000`
+//!
+//! The session logs on with an Ed25519 key when connecting, then keeps the
+//! connection alive with heartbeats and test requests. Application messages
+//! received from Binance, such as execution reports or market data, are sent
+//! to the channel given to [`FixApiClient::connect`]; the channel is closed
+//! when the session ends.
+//!
+//! ```no_run
+//! use birdie::{
+//!     enums::{OrderSide, OrderType, TimeInForce},
+//!     fix_api::{ExecutionReport, FixApiClient, NewOrderSingle},
+//! };
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let endpoint = "tcp+tls://fix-oe.binance.com:9000";
+//! let mut client = FixApiClient::new(endpoint, "api_key", "ed25519_private_key")?
+//!     .sender_comp_id("BIRDIE1");
+//! let (tx, mut rx) = tokio::sync::mpsc::channel(100);
+//! client.connect(tx).await?;
+//!
+//! let order = NewOrderSingle::new("order-1", "BTCUSDT", OrderSide::Buy, OrderType::Limit)
+//!     .order_qty(0.001)
+//!     .price(50000)
+//!     .time_in_force(TimeInForce::Gtc);
+//! client.send(order).await?;
+//!
+//! while let Some(msg) = rx.recv().await {
+//!     if let Ok(report) = ExecutionReport::try_from(&msg) {
+//!         println!("{:?}: {:?}", report.cl_ord_id, report.ord_status);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+mod market_data;
+mod message;
+mod order_entry;
+
+use std::{sync::Arc, time::Duration};
+
+use jiff::Timestamp;
+use thiserror::Error;
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, ReadHalf, WriteHalf},
+    net::TcpStream,
+    sync::{mpsc, oneshot},
+    time::{self, Instant},
+};
+use tokio_rustls::{
+    rustls::{self, pki_types::ServerName},
+    TlsConnector,
+};
+use tracing::{debug, error, warn};
+use url::Url;
+
+use crate::signer::{Ed25519Signer, Signer, SignerError};
+
+pub use market_data::*;
+#[cfg(feature = "test-support")]
+pub(crate) use message::frame_len;
+pub use message::{msg_type, tag, FixMessage, SOH};
+pub use order_entry::*;
+
+const CHANNEL_BUFFER: usize = 2048;
+const TARGET_COMP_ID: &str = "SPOT";
+const LOGON_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Error)]
+pub enum FixApiError {
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("signer error: {0}")]
+    Signer(#[from] SignerError),
+    #[error("message error: {0}")]
+    Message(String),
+    #[error("session error: {0}")]
+    Session(String),
+    #[error("client error: {0}")]
+    Client(String),
+}
+
+/// The kind of session, which determines the endpoint to connect to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FixSession {
+    #[default]
+    OrderEntry,
+    /// Receives the execution reports of all the sessions of the account.
+    DropCopy,
+    MarketData,
+}
+
+/// How Binance processes the messages of an order entry session.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MessageHandling {
+    /// Messages may be processed in parallel, and out of order.
+    Unordered,
+    /// Messages are processed in the order of their sequence numbers.
+    #[default]
+    Sequential,
+}
+
+/// A message to send, and the channel to send its sequence number back.
+type Command = (FixMessage, oneshot::Sender<Result<u64, FixApiError>>);
+
+pub struct FixApiClient {
+    endpoint: String,
+    api_key: String,
+    signer: Arc<dyn Signer>,
+    sender_comp_id: String,
+    session: FixSession,
+    message_handling: MessageHandling,
+    heartbeat_interval: Duration,
+    command_sender: Option<mpsc::Sender<Command>>,
+}
+
+impl FixApiClient {
+    /// Create a client that logs on with an Ed25519 private key (PKCS#8 PEM).
+    pub fn new(endpoint: &str, api_key: &str, secret_key: &str) -> Result<Self, SignerError> {
+        let signer = Ed25519Signer::new(secret_key)?;
+        Ok(Self::with_signer(endpoint, api_key, signer))
+    }
+
+    /// Create a client that logs on with the given signer. Binance only
+    /// accepts Ed25519 keys for the FIX API.
+    pub fn with_signer<S>(endpoint: &str, api_key: &str, signer: S) -> Self
+    where
+        S: Signer + 'static,
+    {
+        Self {
+            endpoint: endpoint.to_owned(),
+            api_key: api_key.to_owned(),
+            signer: Arc::new(signer),
+            sender_comp_id: "BIRDIE".to_owned(),
+            session: FixSession::default(),
+            message_handling: MessageHandling::default(),
+            heartbeat_interval: Duration::from_secs(30),
+            command_sender: None,
+        }
+    }
+
+    /// The `SenderCompID` of the session, which must be unique among the
+    /// concurrent sessions of the account.
+    pub fn sender_comp_id(mut self, sender_comp_id: &str) -> Self {
+        self.sender_comp_id = sender_comp_id.to_owned();
+        self
+    }
+
+    pub fn session(mut self, session: FixSession) -> Self {
+        self.session = session;
+        self
+    }
+
+    pub fn message_handling(mut self, message_handling: MessageHandling) -> Self {
+        self.message_handling = message_handling;
+        self
+    }
+
+    /// The heartbeat interval of the session, between 5 and 60 seconds.
+    /// Defaults to 30 seconds.
+    pub fn heartbeat_interval(mut self, heartbeat_interval: Duration) -> Self {
+        self.heartbeat_interval = heartbeat_interval;
+        self
+    }
+
+    /// Connect and log on. Application messages received during the session
+    /// are sent to `message_sender`.
+    pub async fn connect(
+        &mut self,
+        message_sender: mpsc::Sender<FixMessage>,
+    ) -> Result<(), FixApiError> {
+        let stream = connect_stream(&self.endpoint).await?;
+        let (reader, writer) = tokio::io::split(stream);
+        let mut session = Session {
+            reader: Reader {
+                reader,
+                buf: Vec::new(),
+            },
+            writer: Writer {
+                writer,
+                sender_comp_id: self.sender_comp_id.clone(),
+                next_seq_num: 1,
+                last_sent: Instant::now(),
+            },
+            expected_seq_num: 1,
+            last_received: Instant::now(),
+            test_request_sent: false,
+            heartbeat_interval: self.heartbeat_interval,
+        };
+
+        self.logon(&mut session).await?;
+
+        let (command_sender, command_receiver) = mpsc::channel(CHANNEL_BUFFER);
+        self.command_sender = Some(command_sender);
+        tokio::spawn(session.run(command_receiver, message_sender));
+        Ok(())
+    }
+
+    async fn logon<S>(&self, session: &mut Session<S>) -> Result<(), FixApiError>
+    where
+        S: AsyncRead + AsyncWrite,
+    {
+        let (seq_num, sending_time) = session.writer.next_header();
+        let payload = [
+            msg_type::LOGON,
+            &self.sender_comp_id,
+            TARGET_COMP_ID,
+            &seq_num.to_string(),
+            &sending_time,
+        ]
+        .join(&SOH.to_string());
+        let signature = self.signer.sign(&payload)?;
+
+        let message_handling = match self.message_handling {
+            MessageHandling::Unordered => 1,
+            MessageHandling::Sequential => 2,
+        };
+        let logon = FixMessage::new(msg_type::LOGON)
+            .field(tag::ENCRYPT_METHOD, 0)
+            .field(tag::HEART_BT_INT, self.heartbeat_interval.as_secs())
+            .field(tag::RAW_DATA_LENGTH, signature.len())
+            .field(tag::RAW_DATA, signature)
+            .field(tag::RESET_SEQ_NUM_FLAG, "Y")
+            .field(tag::USERNAME, &self.api_key)
+            .field(tag::MESSAGE_HANDLING, message_handling)
+            .field_opt(
+                tag::DROP_COPY_FLAG,
+                (self.session == FixSession::DropCopy).then_some("Y"),
+            );
+        session.writer.write(logon, seq_num, &sending_time).await?;
+
+        let resp = time::timeout(LOGON_TIMEOUT, session.receive())
+            .await
+            .map_err(|_| FixApiError::Session("logon timed out".to_owned()))??;
+        match resp {
+            Some(msg) if msg.msg_type() == msg_type::LOGON => Ok(()),
+            Some(msg) => Err(FixApiError::Session(format!(
+                "logon rejected: {}",
+                msg.get(tag::TEXT).unwrap_or(msg.msg_type())
+            ))),
+            None => Err(FixApiError::Session(
+                "connection closed during logon".to_owned(),
+            )),
+        }
+    }
+
+    /// Send a message, returning its sequence number.
+    pub async fn send(&self, msg: impl Into<FixMessage>) -> Result<u64, FixApiError> {
+        let (tx, rx) = oneshot::channel();
+        self.command_sender
+            .as_ref()
+            .ok_or_else(|| FixApiError::Client("not connected".to_owned()))?
+            .send((msg.into(), tx))
+            .await
+            .map_err(|_| FixApiError::Client("session closed".to_owned()))?;
+        rx.await
+            .map_err(|_| FixApiError::Client("session closed".to_owned()))?
+    }
+
+    /// Log out. Binance answers with a `Logout` message and closes the
+    /// connection.
+    pub async fn logout(&self) -> Result<(), FixApiError> {
+        self.send(FixMessage::new(msg_type::LOGOUT)).await?;
+        Ok(())
+    }
+}
+
+/// A stream to a FIX endpoint, with or without TLS.
+trait FixStream: AsyncRead + AsyncWrite + Send + Unpin {}
+
+impl<T: AsyncRead + AsyncWrite + Send + Unpin> FixStream for T {}
+
+/// Connect to a `tcp+tls://` endpoint, or to a plain `tcp://` one such as a
+/// local acceptor.
+async fn connect_stream(endpoint: &str) -> Result<Box<dyn FixStream>, FixApiError> {
+    let url = Url::parse(endpoint)
+        .map_err(|err| FixApiError::Client(format!("invalid endpoint {endpoint}: {err}")))?;
+    let (Some(host), Some(port)) = (url.host_str(), url.port()) else {
+        return Err(FixApiError::Client(format!(
+            "invalid endpoint {endpoint}: missing host or port"
+        )));
+    };
+    let stream = TcpStream::connect((host, port)).await?;
+    stream.set_nodelay(true)?;
+
+    match url.scheme() {
+        "tcp" => Ok(Box::new(stream)),
+        "tcp+tls" => {
+            let mut roots = rustls::RootCertStore::empty();
+            roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+            let provider = Arc::new(rustls::crypto::ring::default_provider());
+            let config = rustls::ClientConfig::builder_with_provider(provider)
+                .with_safe_default_protocol_versions()
+                .map_err(|err| FixApiError::Client(format!("tls error: {err}")))?
+                .with_root_certificates(roots)
+                .with_no_client_auth();
+            let server_name = ServerName::try_from(host.to_owned())
+                .map_err(|err| FixApiError::Client(format!("invalid host {host}: {err}")))?;
+            let stream = TlsConnector::from(Arc::new(config))
+                .connect(server_name, stream)
+                .await?;
+            Ok(Box::new(stream))
+        }
+        scheme => Err(FixApiError::Client(format!(
+            "unsupported scheme {scheme}, expected tcp+tls or tcp"
+        ))),
+    }
+}
+
+struct Reader<S> {
+    reader: ReadHalf<S>,
+    buf: Vec<u8>,
+}
+
+impl<S: AsyncRead> Reader<S> {
+    /// Read the next message, `None` if the connection was closed.
+    async fn next(&mut self) -> Result<Option<FixMessage>, FixApiError> {
+        loop {
+            if let Some(len) = message::frame_len(&self.buf)? {
+                let msg = FixMessage::decode(&self.buf[..len]);
+                self.buf.drain(..len);
+                return msg.map(Some);
+            }
+            let mut chunk = [0u8; 4096];
+            let n = self.reader.read(&mut chunk).await?;
+            if n == 0 {
+                return Ok(None);
+            }
+            self.buf.extend_from_slice(&chunk[..n]);
+        }
+    }
+}
+
+struct Writer<S> {
+    writer: WriteHalf<S>,
+    sender_comp_id: String,
+    next_seq_num: u64,
+    last_sent: Instant,
+}
+
+impl<S: AsyncWrite> Writer<S> {
+    /// Take the next sequence number, and the sending time.
+    fn next_header(&mut self) -> (u64, String) {
+        let seq_num = self.next_seq_num;
+        self.next_seq_num += 1;
+        let sending_time = Timestamp::now().strftime("%Y%m%d-%H:%M:%S%.3f");
+        (seq_num, sending_time.to_string())
+    }
+
+    async fn send(&mut self, msg: FixMessage) -> Result<u64, FixApiError> {
+        let (seq_num, sending_time) = self.next_header();
+        self.write(msg, seq_num, &sending_time).await?;
+        Ok(seq_num)
+    }
+
+    async fn write(
+        &mut self,
+        msg: FixMessage,
+        seq_num: u64,
+        sending_time: &str,
+    ) -> Result<(), FixApiError> {
+        let mut full = FixMessage::new(msg.msg_type())
+            .field(tag::SENDER_COMP_ID, &self.sender_comp_id)
+            .field(tag::TARGET_COMP_ID, TARGET_COMP_ID)
+            .field(tag::MSG_SEQ_NUM, seq_num)
+            .field(tag::SENDING_TIME, sending_time);
+        for (tag, value) in msg.fields() {
+            full.push(*tag, value);
+        }
+        debug!("sending FIX message: {full}");
+        self.writer.write_all(full.encode().as_bytes()).await?;
+        self.writer.flush().await?;
+        self.last_sent = Instant::now();
+        Ok(())
+    }
+}
+
+struct Session<S> {
+    reader: Reader<S>,
+    writer: Writer<S>,
+    expected_seq_num: u64,
+    last_received: Instant,
+    test_request_sent: bool,
+    heartbeat_interval: Duration,
+}
+
+impl<S: AsyncRead + AsyncWrite> Session<S> {
+    /// Receive the next message, checking its sequence number.
+    async fn receive(&mut self) -> Result<Option<FixMessage>, FixApiError> {
+        let Some(msg) = self.reader.next().await? else {
+            return Ok(None);
+        };
+        debug!("received FIX message: {msg}");
+        self.last_received = Instant::now();
+        self.test_request_sent = false;
+
+        match msg.seq_num() {
+            Some(seq_num) if seq_num == self.expected_seq_num => {}
+            // Binance doesn't support resend requests, so gaps can't be filled.
+            Some(seq_num) if seq_num > self.expected_seq_num => {
+                warn!(
+                    "FIX sequence gap: expected {}, received {seq_num}",
+                    self.expected_seq_num
+                );
+            }
+            seq_num => {
+                return Err(FixApiError::Session(format!(
+                    "unexpected sequence number {seq_num:?}, expected {}",
+                    self.expected_seq_num
+                )));
+            }
+        }
+        self.expected_seq_num = msg.seq_num().unwrap_or_default() + 1;
+        Ok(Some(msg))
+    }
+
+    async fn run(
+        mut self,
+        mut commands: mpsc::Receiver<Command>,
+        messages: mpsc::Sender<FixMessage>,
+    ) {
+        let mut ticker = time::interval(Duration::from_secs(1));
+        loop {
+            tokio::select! {
+                Some((msg, tx)) = commands.recv() => {
+                    let _ = tx.send(self.writer.send(msg).await);
+                }
+                msg = self.receive() => {
+                    let msg = match msg {
+                        Ok(Some(msg)) => msg,
+                        Ok(None) => break,
+                        Err(err) => {
+                            error!("FIX session error: {err}");
+                            break;
+                        }
+                    };
+                    match msg.msg_type() {
+                        msg_type::HEARTBEAT => {}
+                        msg_type::TEST_REQUEST => {
+                            let heartbeat = FixMessage::new(msg_type::HEARTBEAT)
+                                .field_opt(tag::TEST_REQ_ID, msg.get(tag::TEST_REQ_ID));
+                            if let Err(err) = self.writer.send(heartbeat).await {
+                                error!("FIX write error: {err}");
+                                break;
+                            }
+                        }
+                        _ => {
+                            let logout = msg.msg_type() == msg_type::LOGOUT;
+                            if messages.send(msg).await.is_err() || logout {
+                                break;
+                            }
+                        }
+                    }
+                }
+                _ = ticker.tick() => {
+                    if let Err(err) = self.keep_alive().await {
+                        error!("FIX session error: {err}");
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Send a heartbeat when nothing was sent for a heartbeat interval, and a
+    /// test request when nothing was received. The session is considered lost
+    /// if the test request is not answered within another interval.
+    async fn keep_alive(&mut self) -> Result<(), FixApiError> {
+        let interval = self.heartbeat_interval;
+        if self.last_received.elapsed() >= interval * 2 && self.test_request_sent {
+            return Err(FixApiError::Session("heartbeat timed out".to_owned()));
+        }
+        if self.last_received.elapsed() >= interval && !self.test_request_sent {
+            let id = Timestamp::now().as_millisecond();
+            let test_request = FixMessage::new(msg_type::TEST_REQUEST).field(tag::TEST_REQ_ID, id);
+            self.writer.send(test_request).await?;
+            self.test_request_sent = true;
+        }
+        if self.writer.last_sent.elapsed() >= interval {
+            self.writer
+                .send(FixMessage::new(msg_type::HEARTBEAT))
+                .await?;
+        }
+        Ok(())
+    }
+}
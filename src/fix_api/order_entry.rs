@@ -0,0 +1,377 @@
+//! Messages of the order entry and drop copy sessions.
+
+use super::{
+    message::{msg_type, tag, FixMessage},
+    FixApiError,
+};
+use crate::{
+    decimal::Decimal,
+    enums::{
+        CancelReplaceMode, OrderSide, OrderStatus, OrderType, SelfTradePreventionMode, TimeInForce,
+    },
+};
+
+/// The direction in which the price must move to trigger a stop or take
+/// profit order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TriggerPriceDirection {
+    Up,
+    Down,
+}
+
+/// Place a new order.
+#[derive(Clone, Debug)]
+pub struct NewOrderSingle {
+    cl_ord_id: String,
+    symbol: String,
+    side: OrderSide,
+    ord_type: OrderType,
+    order_qty: Option<Decimal>,
+    cash_order_qty: Option<Decimal>,
+    price: Option<Decimal>,
+    time_in_force: Option<TimeInForce>,
+    max_floor: Option<Decimal>,
+    trigger_price: Option<Decimal>,
+    trigger_price_direction: Option<TriggerPriceDirection>,
+    trigger_trailing_delta_bips: Option<i64>,
+    self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+}
+
+impl NewOrderSingle {
+    pub fn new(cl_ord_id: &str, symbol: &str, side: OrderSide, ord_type: OrderType) -> Self {
+        Self {
+            cl_ord_id: cl_ord_id.to_owned(),
+            symbol: symbol.to_owned(),
+            side,
+            ord_type,
+            order_qty: None,
+            cash_order_qty: None,
+            price: None,
+            time_in_force: None,
+            max_floor: None,
+            trigger_price: None,
+            trigger_price_direction: None,
+            trigger_trailing_delta_bips: None,
+            self_trade_prevention_mode: None,
+        }
+    }
+
+    pub fn order_qty(mut self, order_qty: impl Into<Decimal>) -> Self {
+        self.order_qty = Some(order_qty.into());
+        self
+    }
+
+    /// The quantity in the quote asset, for market orders.
+    pub fn cash_order_qty(mut self, cash_order_qty: impl Into<Decimal>) -> Self {
+        self.cash_order_qty = Some(cash_order_qty.into());
+        self
+    }
+
+    pub fn price(mut self, price: impl Into<Decimal>) -> Self {
+        self.price = Some(price.into());
+        self
+    }
+
+    pub fn time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    /// The visible quantity of an iceberg order.
+    pub fn max_floor(mut self, max_floor: impl Into<Decimal>) -> Self {
+        self.max_floor = Some(max_floor.into());
+        self
+    }
+
+    /// The price that triggers a stop loss or take profit order.
+    pub fn trigger_price(mut self, trigger_price: impl Into<Decimal>) -> Self {
+        self.trigger_price = Some(trigger_price.into());
+        self
+    }
+
+    pub fn trigger_price_direction(mut self, direction: TriggerPriceDirection) -> Self {
+        self.trigger_price_direction = Some(direction);
+        self
+    }
+
+    pub fn trigger_trailing_delta_bips(mut self, bips: i64) -> Self {
+        self.trigger_trailing_delta_bips = Some(bips);
+        self
+    }
+
+    pub fn self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.self_trade_prevention_mode = Some(mode);
+        self
+    }
+
+    fn write(&self, msg: FixMessage) -> FixMessage {
+        let (ord_type, post_only) = match self.ord_type {
+            OrderType::Market => ("1", false),
+            OrderType::Limit => ("2", false),
+            OrderType::LimitMaker => ("2", true),
+            OrderType::StopLoss | OrderType::TakeProfit => ("3", false),
+            OrderType::StopLossLimit | OrderType::TakeProfitLimit => ("4", false),
+        };
+        let triggered = self.trigger_price.is_some() || self.trigger_trailing_delta_bips.is_some();
+
+        msg.field(tag::CL_ORD_ID, &self.cl_ord_id)
+            .field(tag::SYMBOL, &self.symbol)
+            .field(tag::SIDE, side_code(&self.side))
+            .field(tag::ORD_TYPE, ord_type)
+            .field_opt(tag::EXEC_INST, post_only.then_some("6"))
+            .field_opt(tag::ORDER_QTY, self.order_qty)
+            .field_opt(tag::CASH_ORDER_QTY, self.cash_order_qty)
+            .field_opt(tag::PRICE, self.price)
+            .field_opt(
+                tag::TIME_IN_FORCE,
+                self.time_in_force.as_ref().map(tif_code),
+            )
+            .field_opt(tag::MAX_FLOOR, self.max_floor)
+            // Price movement, activate, last trade price.
+            .field_opt(tag::TRIGGER_TYPE, triggered.then_some(4))
+            .field_opt(tag::TRIGGER_ACTION, triggered.then_some(1))
+            .field_opt(tag::TRIGGER_PRICE, self.trigger_price)
+            .field_opt(tag::TRIGGER_PRICE_TYPE, triggered.then_some(2))
+            .field_opt(
+                tag::TRIGGER_PRICE_DIRECTION,
+                self.trigger_price_direction.map(|d| match d {
+                    TriggerPriceDirection::Up => "U",
+                    TriggerPriceDirection::Down => "D",
+                }),
+            )
+            .field_opt(
+                tag::TRIGGER_TRAILING_DELTA_BIPS,
+                self.trigger_trailing_delta_bips,
+            )
+            .field_opt(
+                tag::SELF_TRADE_PREVENTION_MODE,
+                self.self_trade_prevention_mode.as_ref().map(stp_code),
+            )
+    }
+}
+
+impl From<NewOrderSingle> for FixMessage {
+    fn from(order: NewOrderSingle) -> Self {
+        order.write(FixMessage::new(msg_type::NEW_ORDER_SINGLE))
+    }
+}
+
+/// Cancel an order, identified by its `OrderID` or its `ClOrdID`.
+#[derive(Clone, Debug)]
+pub struct OrderCancelRequest {
+    cl_ord_id: String,
+    symbol: String,
+    orig_cl_ord_id: Option<String>,
+    order_id: Option<i64>,
+}
+
+impl OrderCancelRequest {
+    pub fn new(cl_ord_id: &str, symbol: &str) -> Self {
+        Self {
+            cl_ord_id: cl_ord_id.to_owned(),
+            symbol: symbol.to_owned(),
+            orig_cl_ord_id: None,
+            order_id: None,
+        }
+    }
+
+    pub fn orig_cl_ord_id(mut self, orig_cl_ord_id: &str) -> Self {
+        self.orig_cl_ord_id = Some(orig_cl_ord_id.to_owned());
+        self
+    }
+
+    pub fn order_id(mut self, order_id: i64) -> Self {
+        self.order_id = Some(order_id);
+        self
+    }
+}
+
+impl From<OrderCancelRequest> for FixMessage {
+    fn from(req: OrderCancelRequest) -> Self {
+        FixMessage::new(msg_type::ORDER_CANCEL_REQUEST)
+            .field(tag::CL_ORD_ID, req.cl_ord_id)
+            .field_opt(tag::ORIG_CL_ORD_ID, req.orig_cl_ord_id)
+            .field_opt(tag::ORDER_ID, req.order_id)
+            .field(tag::SYMBOL, req.symbol)
+    }
+}
+
+/// Cancel an order and place a new one, see [`CancelReplaceMode`] for what
+/// happens when the cancellation fails.
+#[derive(Clone, Debug)]
+pub struct OrderCancelRequestAndNewOrderSingle {
+    mode: CancelReplaceMode,
+    cancel_cl_ord_id: Option<String>,
+    orig_cl_ord_id: Option<String>,
+    order_id: Option<i64>,
+    order: NewOrderSingle,
+}
+
+impl OrderCancelRequestAndNewOrderSingle {
+    /// Cancel the order of the same symbol identified with
+    /// [`Self::orig_cl_ord_id`] or [`Self::order_id`], and place `order`.
+    pub fn new(mode: CancelReplaceMode, order: NewOrderSingle) -> Self {
+        Self {
+            mode,
+            cancel_cl_ord_id: None,
+            orig_cl_ord_id: None,
+            order_id: None,
+            order,
+        }
+    }
+
+    /// The `ClOrdID` of the cancellation.
+    pub fn cancel_cl_ord_id(mut self, cancel_cl_ord_id: &str) -> Self {
+        self.cancel_cl_ord_id = Some(cancel_cl_ord_id.to_owned());
+        self
+    }
+
+    pub fn orig_cl_ord_id(mut self, orig_cl_ord_id: &str) -> Self {
+        self.orig_cl_ord_id = Some(orig_cl_ord_id.to_owned());
+        self
+    }
+
+    pub fn order_id(mut self, order_id: i64) -> Self {
+        self.order_id = Some(order_id);
+        self
+    }
+}
+
+impl From<OrderCancelRequestAndNewOrderSingle> for FixMessage {
+    fn from(req: OrderCancelRequestAndNewOrderSingle) -> Self {
+        let mode = match req.mode {
+            CancelReplaceMode::StopOnFailure => 1,
+            CancelReplaceMode::AllowFailure => 2,
+        };
+        let msg = FixMessage::new(msg_type::ORDER_CANCEL_REQUEST_AND_NEW_ORDER_SINGLE)
+            .field(tag::ORDER_CANCEL_REQUEST_AND_NEW_ORDER_SINGLE_MODE, mode)
+            .field_opt(tag::CANCEL_CL_ORD_ID, req.cancel_cl_ord_id)
+            .field_opt(tag::ORIG_CL_ORD_ID, req.orig_cl_ord_id)
+            .field_opt(tag::ORDER_ID, req.order_id);
+        req.order.write(msg)
+    }
+}
+
+/// What an [`ExecutionReport`] reports.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExecType {
+    New,
+    Canceled,
+    Replaced,
+    Rejected,
+    Trade,
+    Expired,
+    Other(String),
+}
+
+/// The state of an order, after it was placed, canceled or filled.
+#[derive(Clone, Debug)]
+pub struct ExecutionReport {
+    pub cl_ord_id: Option<String>,
+    pub orig_cl_ord_id: Option<String>,
+    pub order_id: Option<i64>,
+    pub exec_id: Option<String>,
+    pub exec_type: ExecType,
+    pub ord_status: OrderStatus,
+    pub symbol: String,
+    pub side: Option<OrderSide>,
+    pub order_qty: Option<Decimal>,
+    pub price: Option<Decimal>,
+    pub cum_qty: Option<Decimal>,
+    pub leaves_qty: Option<Decimal>,
+    pub cum_quote_qty: Option<Decimal>,
+    pub last_px: Option<Decimal>,
+    pub last_qty: Option<Decimal>,
+    pub trade_id: Option<i64>,
+    pub transact_time: Option<String>,
+    pub error_code: Option<i64>,
+    pub text: Option<String>,
+}
+
+impl TryFrom<&FixMessage> for ExecutionReport {
+    type Error = FixApiError;
+
+    fn try_from(msg: &FixMessage) -> Result<Self, Self::Error> {
+        if msg.msg_type() != msg_type::EXECUTION_REPORT {
+            return Err(FixApiError::Message(format!(
+                "not an execution report: {msg}"
+            )));
+        }
+        let exec_type = match msg.require(tag::EXEC_TYPE)? {
+            "0" => ExecType::New,
+            "4" => ExecType::Canceled,
+            "5" => ExecType::Replaced,
+            "8" => ExecType::Rejected,
+            "F" => ExecType::Trade,
+            "C" => ExecType::Expired,
+            other => ExecType::Other(other.to_owned()),
+        };
+        let ord_status = match msg.require(tag::ORD_STATUS)? {
+            "0" => OrderStatus::New,
+            "1" => OrderStatus::PartiallyFilled,
+            "2" => OrderStatus::Filled,
+            "4" => OrderStatus::Canceled,
+            "6" => OrderStatus::PendingCancel,
+            "8" => OrderStatus::Rejected,
+            "A" => OrderStatus::PendingNew,
+            "C" => OrderStatus::Expired,
+            other => {
+                return Err(FixApiError::Message(format!(
+                    "unknown order status: {other:?}"
+                )))
+            }
+        };
+        let side = match msg.get(tag::SIDE) {
+            Some("1") => Some(OrderSide::Buy),
+            Some("2") => Some(OrderSide::Sell),
+            _ => None,
+        };
+        let string = |tag| msg.get(tag).map(str::to_owned);
+
+        Ok(Self {
+            cl_ord_id: string(tag::CL_ORD_ID),
+            orig_cl_ord_id: string(tag::ORIG_CL_ORD_ID),
+            order_id: msg.parse(tag::ORDER_ID)?,
+            exec_id: string(tag::EXEC_ID),
+            exec_type,
+            ord_status,
+            symbol: msg.require(tag::SYMBOL)?.to_owned(),
+            side,
+            order_qty: msg.parse(tag::ORDER_QTY)?,
+            price: msg.parse(tag::PRICE)?,
+            cum_qty: msg.parse(tag::CUM_QTY)?,
+            leaves_qty: msg.parse(tag::LEAVES_QTY)?,
+            cum_quote_qty: msg.parse(tag::CUM_QUOTE_QTY)?,
+            last_px: msg.parse(tag::LAST_PX)?,
+            last_qty: msg.parse(tag::LAST_QTY)?,
+            trade_id: msg.parse(tag::TRADE_ID)?,
+            transact_time: string(tag::TRANSACT_TIME),
+            error_code: msg.parse(tag::ERROR_CODE)?,
+            text: string(tag::TEXT),
+        })
+    }
+}
+
+fn side_code(side: &OrderSide) -> &'static str {
+    match side {
+        OrderSide::Buy => "1",
+        OrderSide::Sell => "2",
+    }
+}
+
+fn tif_code(tif: &TimeInForce) -> &'static str {
+    match tif {
+        TimeInForce::Gtc => "1",
+        TimeInForce::Ioc => "3",
+        TimeInForce::Fok => "4",
+    }
+}
+
+fn stp_code(mode: &SelfTradePreventionMode) -> &'static str {
+    match mode {
+        SelfTradePreventionMode::None => "1",
+        SelfTradePreventionMode::ExpireTaker => "2",
+        SelfTradePreventionMode::ExpireMaker => "3",
+        SelfTradePreventionMode::ExpireBoth => "4",
+    }
+}
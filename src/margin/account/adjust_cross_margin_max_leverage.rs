@@ -9,7 +9,8 @@ endpoint!(
     SecurityType::UserData,
     AdjustCrossMarginMaxLeverageEndpoint,
     AdjustCrossMarginMaxLeverageParams,
-    AdjustCrossMarginMaxLeverageResponse
+    AdjustCrossMarginMaxLeverageResponse,
+    weight = 3000
 );
 
 /// Adjust cross margin max leverage.
@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
@@ -10,7 +9,8 @@ endpoint!(
     SecurityType::Trade,
     DisableIsolatedMarginAccountEndpoint,
     DisableIsolatedMarginAccountParams,
-    DisableIsolatedMarginAccountResponse
+    DisableIsolatedMarginAccountResponse,
+    weight = 300
 );
 
 /// Disable isolated margin account for a specific symbol. Each trading pair can
@@ -32,7 +32,6 @@ impl<'r> DisableIsolatedMarginAccountEndpoint<'r> {
 pub struct DisableIsolatedMarginAccountParams {
     symbol: String,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl DisableIsolatedMarginAccountParams {
@@ -40,7 +39,6 @@ impl DisableIsolatedMarginAccountParams {
         Self {
             symbol: symbol.to_owned(),
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
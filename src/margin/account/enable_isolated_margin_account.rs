@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
@@ -10,7 +9,8 @@ endpoint!(
     SecurityType::Trade,
     EnableIsolatedMarginAccountEndpoint,
     EnableIsolatedMarginAccountParams,
-    EnableIsolatedMarginAccountResponse
+    EnableIsolatedMarginAccountResponse,
+    weight = 300
 );
 
 /// Enable isolated margin account for a specific symbol(Only supports
@@ -32,7 +32,6 @@ impl<'r> EnableIsolatedMarginAccountEndpoint<'r> {
 pub struct EnableIsolatedMarginAccountParams {
     symbol: String,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl EnableIsolatedMarginAccountParams {
@@ -40,7 +39,6 @@ impl EnableIsolatedMarginAccountParams {
         Self {
             symbol: symbol.to_owned(),
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/sapi/v1/margin/tradeCoeff",
@@ -10,7 +9,8 @@ endpoint!(
     SecurityType::UserData,
     GetSummaryOfMarginAccountEndpoint,
     GetSummaryOfMarginAccountParams,
-    GetSummaryOfMarginAccountResponse
+    GetSummaryOfMarginAccountResponse,
+    weight = 10
 );
 
 /// Get personal margin level information.
@@ -30,15 +30,11 @@ impl<'r> GetSummaryOfMarginAccountEndpoint<'r> {
 #[serde(rename_all = "camelCase")]
 pub struct GetSummaryOfMarginAccountParams {
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl GetSummaryOfMarginAccountParams {
     pub fn new() -> Self {
-        Self {
-            recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
-        }
+        Self { recv_window: None }
     }
 
     pub fn recv_window(mut self, recv_window: i64) -> Self {
@@ -50,7 +46,7 @@ impl GetSummaryOfMarginAccountParams {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetSummaryOfMarginAccountResponse {
-    pub normal_bar: String,
-    pub margin_call_bar: String,
-    pub force_liquidation_bar: String,
+    pub normal_bar: Decimal,
+    pub margin_call_bar: Decimal,
+    pub force_liquidation_bar: Decimal,
 }
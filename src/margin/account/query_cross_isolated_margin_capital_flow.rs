@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/sapi/v1/margin/capital-flow",
@@ -10,7 +9,8 @@ endpoint!(
     SecurityType::UserData,
     QueryCrossIsolatedMarginCapitalFlowEndpoint,
     QueryCrossIsolatedMarginCapitalFlowParams,
-    QueryCrossIsolatedMarginCapitalFlowResponse
+    QueryCrossIsolatedMarginCapitalFlowResponse,
+    weight = 100
 );
 
 /// Query Cross Isolated Margin Capital Flow.
@@ -37,7 +37,6 @@ pub struct QueryCrossIsolatedMarginCapitalFlowParams {
     from_id: Option<i64>,
     limit: Option<i64>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryCrossIsolatedMarginCapitalFlowParams {
@@ -51,7 +50,6 @@ impl QueryCrossIsolatedMarginCapitalFlowParams {
             from_id: None,
             limit: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -107,5 +105,5 @@ pub struct CrossIsolatedMarginCapitalFlow {
     pub asset: String,
     pub symbol: Option<String>,
     pub r#type: String,
-    pub amount: String,
+    pub amount: Decimal,
 }
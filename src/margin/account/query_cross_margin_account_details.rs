@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/sapi/v1/margin/account",
@@ -10,7 +9,8 @@ endpoint!(
     SecurityType::UserData,
     QueryCrossMarginAccountDetailsEndpoint,
     QueryCrossMarginAccountDetailsParams,
-    QueryCrossMarginAccountDetailsResponse
+    QueryCrossMarginAccountDetailsResponse,
+    weight = 10
 );
 
 /// Query Cross Margin Account Details.
@@ -30,15 +30,11 @@ impl<'r> QueryCrossMarginAccountDetailsEndpoint<'r> {
 #[serde(rename_all = "camelCase")]
 pub struct QueryCrossMarginAccountDetailsParams {
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryCrossMarginAccountDetailsParams {
     pub fn new() -> Self {
-        Self {
-            recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
-        }
+        Self { recv_window: None }
     }
 
     pub fn recv_window(mut self, recv_window: i64) -> Self {
@@ -52,12 +48,13 @@ impl QueryCrossMarginAccountDetailsParams {
 pub struct QueryCrossMarginAccountDetailsResponse {
     pub created: bool,
     pub borrow_enabled: bool,
-    pub margin_level: String,
-    pub collateral_margin_level: String,
-    pub total_asset_of_btc: String,
-    pub total_liability_of_btc: String,
-    pub total_net_asset_of_btc: String,
-    pub total_collateral_value_in_usdt: Option<String>,
+    pub margin_level: Decimal,
+    pub collateral_margin_level: Decimal,
+    pub total_asset_of_btc: Decimal,
+    pub total_liability_of_btc: Decimal,
+    pub total_net_asset_of_btc: Decimal,
+    #[serde(rename = "TotalCollateralValueInUSDT")]
+    pub total_collateral_value_in_usdt: Option<Decimal>,
     pub trade_enabled: bool,
     pub transfer_enabled: bool,
     pub account_type: String,
@@ -68,9 +65,9 @@ pub struct QueryCrossMarginAccountDetailsResponse {
 #[serde(rename_all = "camelCase")]
 pub struct UserAsset {
     pub asset: String,
-    pub borrowed: String,
-    pub free: String,
-    pub interest: String,
-    pub locked: String,
-    pub net_asset: String,
+    pub borrowed: Decimal,
+    pub free: Decimal,
+    pub interest: Decimal,
+    pub locked: Decimal,
+    pub net_asset: Decimal,
 }
@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/sapi/v1/margin/crossMarginData",
@@ -10,7 +9,8 @@ endpoint!(
     SecurityType::UserData,
     QueryCrossMarginFeeDataEndpoint,
     QueryCrossMarginFeeDataParams,
-    QueryCrossMarginFeeDataResponse
+    QueryCrossMarginFeeDataResponse,
+    weight = |params| if params.coin.is_some() { 1 } else { 5 }
 );
 
 /// Get cross margin fee data collection with any vip level or user's current
@@ -35,7 +35,6 @@ pub struct QueryCrossMarginFeeDataParams {
     vip_level: Option<i64>,
     coin: Option<String>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryCrossMarginFeeDataParams {
@@ -44,7 +43,6 @@ impl QueryCrossMarginFeeDataParams {
             vip_level: None,
             coin: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -73,8 +71,8 @@ pub struct CrossMarginFeeData {
     pub coin: String,
     pub transfer_in: bool,
     pub borrowable: bool,
-    pub daily_interest: String,
-    pub yearly_interest: String,
-    pub borrow_limit: String,
+    pub daily_interest: Decimal,
+    pub yearly_interest: Decimal,
+    pub borrow_limit: Decimal,
     pub marginable_pairs: Vec<String>,
 }
@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
@@ -30,15 +29,11 @@ impl<'r> QueryEnabledIsolatedMarginAccountLimitEndpoint<'r> {
 #[serde(rename_all = "camelCase")]
 pub struct QueryEnabledIsolatedMarginAccountLimitParams {
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryEnabledIsolatedMarginAccountLimitParams {
     pub fn new() -> Self {
-        Self {
-            recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
-        }
+        Self { recv_window: None }
     }
 
     pub fn recv_window(mut self, recv_window: i64) -> Self {
@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/sapi/v1/margin/isolated/account",
@@ -10,7 +9,8 @@ endpoint!(
     SecurityType::UserData,
     QueryIsolatedMarginAccountInfoEndpoint,
     QueryIsolatedMarginAccountInfoParams,
-    QueryIsolatedMarginAccountInfoResponse
+    QueryIsolatedMarginAccountInfoResponse,
+    weight = 10
 );
 
 /// Query Isolated Margin Account Info.
@@ -31,7 +31,6 @@ impl<'r> QueryIsolatedMarginAccountInfoEndpoint<'r> {
 pub struct QueryIsolatedMarginAccountInfoParams {
     symbol: Option<String>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryIsolatedMarginAccountInfoParams {
@@ -39,7 +38,6 @@ impl QueryIsolatedMarginAccountInfoParams {
         Self {
             symbol: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -58,9 +56,9 @@ impl QueryIsolatedMarginAccountInfoParams {
 #[serde(rename_all = "camelCase")]
 pub struct QueryIsolatedMarginAccountInfoResponse {
     pub assets: Vec<Asset>,
-    pub total_asset_of_btc: Option<String>,
-    pub total_liability_of_btc: Option<String>,
-    pub total_net_asset_of_btc: Option<String>,
+    pub total_asset_of_btc: Option<Decimal>,
+    pub total_liability_of_btc: Option<Decimal>,
+    pub total_net_asset_of_btc: Option<Decimal>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -68,12 +66,12 @@ pub struct QueryIsolatedMarginAccountInfoResponse {
 pub struct Asset {
     pub asset: String,
     pub borrow_enabled: bool,
-    pub borrowed: String,
-    pub free: String,
-    pub interest: String,
-    pub locked: String,
-    pub net_asset: String,
-    pub net_asset_of_btc: String,
+    pub borrowed: Decimal,
+    pub free: Decimal,
+    pub interest: Decimal,
+    pub locked: Decimal,
+    pub net_asset: Decimal,
+    pub net_asset_of_btc: Decimal,
     pub repay_enabled: bool,
-    pub total_asset: String,
+    pub total_asset: Decimal,
 }
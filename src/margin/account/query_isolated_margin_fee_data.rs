@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/sapi/v1/margin/isolatedMarginData",
@@ -10,7 +9,8 @@ endpoint!(
     SecurityType::UserData,
     QueryIsolatedMarginFeeDataEndpoint,
     QueryIsolatedMarginFeeDataParams,
-    QueryIsolatedMarginFeeDataResponse
+    QueryIsolatedMarginFeeDataResponse,
+    weight = |params| if params.symbol.is_some() { 1 } else { 10 }
 );
 
 /// Get isolated margin fee data collection with any vip level or user's current
@@ -35,7 +35,6 @@ pub struct QueryIsolatedMarginFeeDataParams {
     vip_level: Option<i64>,
     symbol: Option<String>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryIsolatedMarginFeeDataParams {
@@ -44,7 +43,6 @@ impl QueryIsolatedMarginFeeDataParams {
             vip_level: None,
             symbol: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -71,7 +69,7 @@ pub type QueryIsolatedMarginFeeDataResponse = Vec<IsolatedMarginFee>;
 pub struct IsolatedMarginFee {
     pub vip_level: i64,
     pub symbol: String,
-    pub leverage: String,
+    pub leverage: Decimal,
     pub data: Vec<IsolatedMarginFeeData>,
 }
 
@@ -79,6 +77,6 @@ pub struct IsolatedMarginFee {
 #[serde(rename_all = "camelCase")]
 pub struct IsolatedMarginFeeData {
     pub coin: String,
-    pub daily_interest: String,
-    pub borrow_limit: String,
+    pub daily_interest: Decimal,
+    pub borrow_limit: Decimal,
 }
@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
@@ -34,7 +33,6 @@ pub struct ToggleBnbBurnOnSpotTradeAndMarginInterestParams {
     interest_bnb_burn: Option<String>,
     #[serde(rename = "recvWindow")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl ToggleBnbBurnOnSpotTradeAndMarginInterestParams {
@@ -43,7 +41,6 @@ impl ToggleBnbBurnOnSpotTradeAndMarginInterestParams {
             spot_bnb_burn: None,
             interest_bnb_burn: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/sapi/v1/margin/next-hourly-interest-rate",
@@ -10,7 +9,8 @@ endpoint!(
     SecurityType::UserData,
     GetFutureHourlyInterestRateEndpoint,
     GetFutureHourlyInterestRateParams,
-    GetFutureHourlyInterestRateResponse
+    GetFutureHourlyInterestRateResponse,
+    weight = 100
 );
 
 /// Get future hourly interest rate.
@@ -31,7 +31,6 @@ impl<'r> GetFutureHourlyInterestRateEndpoint<'r> {
 pub struct GetFutureHourlyInterestRateParams {
     assets: String,
     is_isolated: String,
-    timestamp: i64,
 }
 
 impl GetFutureHourlyInterestRateParams {
@@ -39,7 +38,6 @@ impl GetFutureHourlyInterestRateParams {
         Self {
             assets: assets.to_owned(),
             is_isolated: is_isolated.to_owned(),
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 }
@@ -50,5 +48,5 @@ pub type GetFutureHourlyInterestRateResponse = Vec<FutureHourlyInterestRate>;
 #[serde(rename_all = "camelCase")]
 pub struct FutureHourlyInterestRate {
     pub asset: String,
-    pub next_hourly_interest_rate: String,
+    pub next_hourly_interest_rate: Decimal,
 }
@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/sapi/v1/margin/interestHistory",
@@ -37,7 +36,6 @@ pub struct GetInterestHistoryParams {
     size: Option<i64>,
     archived: Option<bool>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl GetInterestHistoryParams {
@@ -51,7 +49,6 @@ impl GetInterestHistoryParams {
             size: None,
             archived: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -110,9 +107,9 @@ pub struct InterestHistory {
     pub interest_accured_time: i64,
     pub asset: String,
     pub raw_asset: String,
-    pub principal: String,
-    pub interest: String,
-    pub interest_rate: String,
+    pub principal: Decimal,
+    pub interest: Decimal,
+    pub interest_rate: Decimal,
     pub r#type: String,
     pub isolated_symbol: String,
 }
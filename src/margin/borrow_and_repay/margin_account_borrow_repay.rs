@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/sapi/v1/margin/borrow-repay",
@@ -10,7 +9,8 @@ endpoint!(
     SecurityType::Margin,
     MarginAccountBorrowRepayEndpoint,
     MarginAccountBorrowRepayParams,
-    MarginAccountBorrowRepayResponse
+    MarginAccountBorrowRepayResponse,
+    weight = 1500
 );
 
 /// Margin account borrow/repay(MARGIN).
@@ -32,22 +32,26 @@ pub struct MarginAccountBorrowRepayParams {
     asset: String,
     is_isolated: String,
     symbol: String,
-    amount: String,
+    amount: Decimal,
     r#type: String,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl MarginAccountBorrowRepayParams {
-    pub fn new(asset: &str, is_isolated: &str, symbol: &str, amount: &str, r#type: &str) -> Self {
+    pub fn new(
+        asset: &str,
+        is_isolated: &str,
+        symbol: &str,
+        amount: impl Into<Decimal>,
+        r#type: &str,
+    ) -> Self {
         Self {
             asset: asset.to_owned(),
             is_isolated: is_isolated.to_owned(),
             symbol: symbol.to_owned(),
-            amount: amount.to_owned(),
+            amount: amount.into(),
             r#type: r#type.to_owned(),
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/sapi/v1/margin/borrow-repay",
@@ -10,7 +9,8 @@ endpoint!(
     SecurityType::UserData,
     QueryBorrowRepayRecordsEndpoint,
     QueryBorrowRepayRecordsParams,
-    QueryBorrowRepayRecordsResponse
+    QueryBorrowRepayRecordsResponse,
+    weight = 10
 );
 
 /// Get Interest History.
@@ -38,7 +38,6 @@ pub struct QueryBorrowRepayRecordsParams {
     size: Option<i64>,
     r#type: String,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryBorrowRepayRecordsParams {
@@ -53,7 +52,6 @@ impl QueryBorrowRepayRecordsParams {
             size: None,
             r#type: r#type.to_owned(),
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -110,10 +108,10 @@ pub struct QueryBorrowRepayRecordsResponse {
 pub struct BorrowRepayRecord {
     pub r#type: String,
     pub isolated_symbol: String,
-    pub amount: String,
+    pub amount: Decimal,
     pub asset: String,
-    pub interest: String,
-    pub principal: String,
+    pub interest: Decimal,
+    pub principal: Decimal,
     pub status: String,
     pub timestamp: i64,
     pub tx_id: i64,
@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/sapi/v1/margin/interestRateHistory",
@@ -34,7 +33,6 @@ pub struct QueryMarginInterestRateHistoryParams {
     start_time: Option<i64>,
     end_time: Option<i64>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryMarginInterestRateHistoryParams {
@@ -45,7 +43,6 @@ impl QueryMarginInterestRateHistoryParams {
             start_time: None,
             end_time: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -76,7 +73,7 @@ pub type QueryMarginInterestRateHistoryResponse = Vec<InterestRateHistory>;
 #[serde(rename_all = "camelCase")]
 pub struct InterestRateHistory {
     pub asset: String,
-    pub daily_interest_rate: String,
+    pub daily_interest_rate: Decimal,
     pub timestamp: i64,
     pub vip_level: i64,
 }
@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/sapi/v1/margin/maxBorrowable",
@@ -10,7 +9,8 @@ endpoint!(
     SecurityType::UserData,
     QueryMaxBorrowEndpoint,
     QueryMaxBorrowParams,
-    QueryMaxBorrowResponse
+    QueryMaxBorrowResponse,
+    weight = 50
 );
 
 /// Query Max Borrow.
@@ -32,7 +32,6 @@ pub struct QueryMaxBorrowParams {
     asset: String,
     isolated_symbol: Option<String>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryMaxBorrowParams {
@@ -41,7 +40,6 @@ impl QueryMaxBorrowParams {
             asset: asset.to_owned(),
             isolated_symbol: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -59,6 +57,6 @@ impl QueryMaxBorrowParams {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryMaxBorrowResponse {
-    pub amount: String,
-    pub borrow_limit: String,
+    pub amount: Decimal,
+    pub borrow_limit: Decimal,
 }
@@ -1,7 +1,7 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/sapi/v1/margin/crossMarginCollateralRatio",
@@ -9,7 +9,8 @@ endpoint!(
     SecurityType::MarketData,
     CrossMarginCollateralRatioEndpoint,
     CrossMarginCollateralRatioParams,
-    CrossMarginCollateralRatioResponse
+    CrossMarginCollateralRatioResponse,
+    weight = 100
 );
 
 /// Cross margin collateral ratio.
@@ -48,9 +49,9 @@ pub struct CrossMarginCollateralRatio {
 #[serde(rename_all = "camelCase")]
 pub struct Collateral {
     #[serde(default)]
-    pub min_used_value: String,
+    pub min_usd_value: Decimal,
     #[serde(default)]
-    pub max_used_value: String,
+    pub max_usd_value: Decimal,
     #[serde(default)]
-    pub discount_rate: String,
+    pub discount_rate: Decimal,
 }
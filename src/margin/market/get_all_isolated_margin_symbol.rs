@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
@@ -10,7 +9,8 @@ endpoint!(
     SecurityType::MarketData,
     GetAllIsolatedMarginSymbolEndpoint,
     GetAllIsolatedMarginSymbolParams,
-    GetAllIsolatedMarginSymbolResponse
+    GetAllIsolatedMarginSymbolResponse,
+    weight = 10
 );
 
 /// Get All Isolated Margin Symbol.
@@ -33,7 +33,6 @@ pub struct GetAllIsolatedMarginSymbolParams {
     symbol: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl GetAllIsolatedMarginSymbolParams {
@@ -41,7 +40,6 @@ impl GetAllIsolatedMarginSymbolParams {
         Self {
             symbol: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -1,7 +1,7 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/sapi/v1/margin/allAssets",
@@ -52,7 +52,7 @@ pub struct MarginAsset {
     pub asset_name: String,
     pub is_borrowable: bool,
     pub is_mortgageable: bool,
-    pub user_min_borrow: String,
-    pub user_min_repay: String,
+    pub user_min_borrow: Decimal,
+    pub user_min_repay: Decimal,
     pub delist_time: Option<i64>,
 }
@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
@@ -10,7 +9,8 @@ endpoint!(
     SecurityType::MarketData,
     GetDelistScheduleEndpoint,
     GetDelistScheduleParams,
-    GetDelistScheduleResponse
+    GetDelistScheduleResponse,
+    weight = 100
 );
 
 /// Get tokens or symbols delist schedule for cross margin and isolated margin.
@@ -31,15 +31,11 @@ impl<'r> GetDelistScheduleEndpoint<'r> {
 pub struct GetDelistScheduleParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl GetDelistScheduleParams {
     pub fn new() -> Self {
-        Self {
-            recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
-        }
+        Self { recv_window: None }
     }
 
     pub fn recv_window(mut self, recv_window: i64) -> Self {
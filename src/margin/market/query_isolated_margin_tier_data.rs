@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/sapi/v1/margin/isolatedMarginTier",
@@ -35,7 +34,6 @@ pub struct QueryIsolatedMarginTierDataParams {
     tier: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryIsolatedMarginTierDataParams {
@@ -44,7 +42,6 @@ impl QueryIsolatedMarginTierDataParams {
             symbol: symbol.to_owned(),
             tier: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -66,9 +63,9 @@ pub type QueryIsolatedMarginTierDataResponse = Vec<IsolatedMarginTierData>;
 pub struct IsolatedMarginTierData {
     pub symbol: String,
     pub tier: i64,
-    pub effective_multiple: String,
-    pub initial_risk_ratio: String,
-    pub liquidation_risk_ratio: String,
-    pub base_asset_max_borrowable: String,
-    pub quote_asset_max_borrowable: String,
+    pub effective_multiple: Decimal,
+    pub initial_risk_ratio: Decimal,
+    pub liquidation_risk_ratio: Decimal,
+    pub base_asset_max_borrowable: Decimal,
+    pub quote_asset_max_borrowable: Decimal,
 }
@@ -1,7 +1,7 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/sapi/v1/margin/leverageBracket",
@@ -49,8 +49,8 @@ pub struct LiabilityCoinLeverageBracket {
 #[serde(rename_all = "camelCase")]
 pub struct Bracket {
     pub leverage: i64,
-    pub max_debt: String,
-    pub maintenance_margin_rate: String,
-    pub initial_margin_rate: String,
+    pub max_debt: Decimal,
+    pub maintenance_margin_rate: Decimal,
+    pub initial_margin_rate: Decimal,
     pub fast_num: String,
 }
@@ -1,6 +1,5 @@
 use std::collections::HashMap;
 
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
@@ -12,7 +11,8 @@ endpoint!(
     SecurityType::MarketData,
     QueryMarginAvailableInventoryEndpoint,
     QueryMarginAvailableInventoryParams,
-    QueryMarginAvailableInventoryResponse
+    QueryMarginAvailableInventoryResponse,
+    weight = 50
 );
 
 /// Margin available Inventory query.
@@ -32,14 +32,12 @@ impl<'r> QueryMarginAvailableInventoryEndpoint<'r> {
 #[serde(rename_all = "camelCase")]
 pub struct QueryMarginAvailableInventoryParams {
     r#type: String,
-    timestamp: i64,
 }
 
 impl QueryMarginAvailableInventoryParams {
     pub fn new(r#type: &str) -> Self {
         Self {
             r#type: r#type.to_owned(),
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 }
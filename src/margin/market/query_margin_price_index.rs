@@ -1,7 +1,7 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/sapi/v1/margin/priceIndex",
@@ -9,7 +9,8 @@ endpoint!(
     SecurityType::MarketData,
     QueryMarginPriceIndexEndpoint,
     QueryMarginPriceIndexParams,
-    QueryMarginPriceIndexResponse
+    QueryMarginPriceIndexResponse,
+    weight = 10
 );
 
 /// Query Margin PriceIndex.
@@ -43,6 +44,6 @@ impl QueryMarginPriceIndexParams {
 #[serde(rename_all = "camelCase")]
 pub struct QueryMarginPriceIndexResponse {
     pub calc_time: i64,
-    pub price: String,
+    pub price: Decimal,
     pub symbol: String,
 }
@@ -8,7 +8,9 @@ use serde::Deserialize;
 pub use start_user_data_stream::*;
 
 use crate::{
+    decimal::Decimal,
     rest_api::{route, RestApiClient},
+    spot::user_data_stream::ListenKeyExpired,
     web_socket_stream::Payload,
 };
 
@@ -27,11 +29,14 @@ impl<'r> RestApiHandler<'r> {
 }
 
 #[derive(Clone, Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
 #[serde(tag = "e")]
 pub enum RiskDataStreamPayload {
+    #[serde(rename = "MARGIN_LEVEL_STATUS_CHANGE")]
     MarginCall(MarginCall),
+    #[serde(rename = "USER_LIABILITY_CHANGE")]
     LiabilityUpdate(LiabilityUpdate),
+    #[serde(rename = "listenKeyExpired")]
+    ListenKeyExpired(ListenKeyExpired),
 }
 
 impl Payload for RiskDataStreamPayload {}
@@ -41,7 +46,7 @@ pub struct MarginCall {
     #[serde(rename = "E")]
     pub event_time: i64,
     #[serde(rename = "l")]
-    pub margin_level: String,
+    pub margin_level: Decimal,
     #[serde(rename = "s")]
     pub margin_call_status: String,
 }
@@ -55,7 +60,7 @@ pub struct LiabilityUpdate {
     #[serde(rename = "t")]
     pub liability_update_type: String,
     #[serde(rename = "p")]
-    pub principle_quantity: String,
+    pub principle_quantity: Decimal,
     #[serde(rename = "i")]
-    pub interest_quantity: String,
+    pub interest_quantity: Decimal,
 }
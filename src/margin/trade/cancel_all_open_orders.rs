@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
@@ -15,7 +14,8 @@ endpoint!(
     SecurityType::Trade,
     CancelAllOpenOrdersEndpoint,
     CancelAllOpenOrdersParams,
-    CancelAllOpenOrdersResponse
+    CancelAllOpenOrdersResponse,
+    weight = 100
 );
 
 /// Cancels all active orders on a symbol for margin account.
@@ -39,7 +39,6 @@ pub struct CancelAllOpenOrdersParams {
     symbol: String,
     isolated: Option<String>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl CancelAllOpenOrdersParams {
@@ -48,7 +47,6 @@ impl CancelAllOpenOrdersParams {
             symbol: symbol.to_owned(),
             isolated: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -82,6 +80,7 @@ pub struct CancelMarginOrderListResult {
     pub list_client_order_id: String,
     pub transaction_time: i64,
     pub symbol: String,
+    pub is_isolated: bool,
     pub orders: Vec<MarginOcoOrderListItem>,
     pub order_reports: Vec<MarginOcoOrderListReport>,
 }
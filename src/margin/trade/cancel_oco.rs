@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::Serialize;
 
@@ -55,7 +54,6 @@ pub struct CancelOcoParams {
     list_client_order_id: Option<String>,
     new_client_order_id: Option<String>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl CancelOcoParams {
@@ -67,7 +65,6 @@ impl CancelOcoParams {
             list_client_order_id: None,
             new_client_order_id: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
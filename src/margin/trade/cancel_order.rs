@@ -1,8 +1,8 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    decimal::Decimal,
     enums::{
         OrderSide, OrderStatus, OrderType, SecurityType, SelfTradePreventionMode, TimeInForce,
     },
@@ -15,7 +15,8 @@ endpoint!(
     SecurityType::Trade,
     CancelOrderEndpoint,
     CancelOrderParams,
-    CancelOrderResponse
+    CancelOrderResponse,
+    weight = 10
 );
 
 /// Cancel an active order for margin account.
@@ -40,7 +41,6 @@ pub struct CancelOrderParams {
     list_client_order_id: Option<String>,
     new_client_order_id: Option<String>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl CancelOrderParams {
@@ -52,7 +52,6 @@ impl CancelOrderParams {
             list_client_order_id: None,
             new_client_order_id: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -92,10 +91,10 @@ pub struct CancelMarginOrderDetail {
     pub order_id: i64,
     pub orig_client_order_id: String,
     pub client_order_id: String,
-    pub price: String,
-    pub orig_qty: String,
-    pub executed_qty: String,
-    pub cummulative_quote_qty: String,
+    pub price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub cummulative_quote_qty: Decimal,
     pub status: OrderStatus,
     pub time_in_force: TimeInForce,
     pub r#type: OrderType,
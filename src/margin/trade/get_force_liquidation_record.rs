@@ -1,8 +1,8 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    decimal::Decimal,
     enums::{OrderSide, SecurityType, TimeInForce},
     rest_api::endpoint,
 };
@@ -38,7 +38,6 @@ pub struct GetForceLiquidationRecordParams {
     current: Option<i64>,
     size: Option<i64>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl GetForceLiquidationRecordParams {
@@ -50,7 +49,6 @@ impl GetForceLiquidationRecordParams {
             current: None,
             size: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -95,11 +93,11 @@ pub struct GetForceLiquidationRecordResponse {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ForceLiquidationRecord {
-    pub avg_price: String,
-    pub executed_qty: String,
+    pub avg_price: Decimal,
+    pub executed_qty: Decimal,
     pub order_id: i64,
-    pub price: String,
-    pub qty: String,
+    pub price: Decimal,
+    pub qty: Decimal,
     pub side: OrderSide,
     pub symbol: String,
     pub time_in_force: TimeInForce,
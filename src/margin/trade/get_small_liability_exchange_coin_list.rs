@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/sapi/v1/margin/exchange-small-liability",
@@ -10,7 +9,8 @@ endpoint!(
     SecurityType::UserData,
     GetSmallLiabilityExchangeCoinListEndpoint,
     GetSmallLiabilityExchangeCoinListParams,
-    GetSmallLiabilityExchangeCoinListResponse
+    GetSmallLiabilityExchangeCoinListResponse,
+    weight = 100
 );
 
 /// Query the coins which can be small liability exchange.
@@ -30,15 +30,11 @@ impl<'r> GetSmallLiabilityExchangeCoinListEndpoint<'r> {
 #[serde(rename_all = "camelCase")]
 pub struct GetSmallLiabilityExchangeCoinListParams {
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl GetSmallLiabilityExchangeCoinListParams {
     pub fn new() -> Self {
-        Self {
-            recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
-        }
+        Self { recv_window: None }
     }
 
     pub fn recv_window(mut self, recv_window: i64) -> Self {
@@ -53,8 +49,8 @@ pub type GetSmallLiabilityExchangeCoinListResponse = Vec<SmallLiabilityExchangeC
 #[serde(rename_all = "camelCase")]
 pub struct SmallLiabilityExchangeCoin {
     pub asset: String,
-    pub interest: String,
-    pub principal: String,
+    pub interest: Decimal,
+    pub principal: Decimal,
     pub liability_asset: String,
-    pub liability_qty: f64,
+    pub liability_qty: Decimal,
 }
@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/sapi/v1/margin/exchange-small-liability-history",
@@ -10,7 +9,8 @@ endpoint!(
     SecurityType::UserData,
     GetSmallLiabilityExchangeHistoryEndpoint,
     GetSmallLiabilityExchangeHistoryParams,
-    GetSmallLiabilityExchangeHistoryResponse
+    GetSmallLiabilityExchangeHistoryResponse,
+    weight = 100
 );
 
 /// Get Small liability Exchange History.
@@ -34,7 +34,6 @@ pub struct GetSmallLiabilityExchangeHistoryParams {
     start_time: Option<i64>,
     end_time: Option<i64>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl GetSmallLiabilityExchangeHistoryParams {
@@ -45,7 +44,6 @@ impl GetSmallLiabilityExchangeHistoryParams {
             start_time: None,
             end_time: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -86,9 +84,9 @@ pub struct GetSmallLiabilityExchangeHistoryResponse {
 #[serde(rename_all = "camelCase")]
 pub struct SmallLiabilityExchangeCoinHistory {
     pub asset: String,
-    pub amount: String,
+    pub amount: Decimal,
     pub target_asset: String,
-    pub target_amount: String,
+    pub target_amount: Decimal,
     pub biz_type: String,
     pub timestamp: i64,
 }
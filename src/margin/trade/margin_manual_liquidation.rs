@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/sapi/v1/margin/manual-liquidation",
@@ -10,7 +9,8 @@ endpoint!(
     SecurityType::Margin,
     MarginManualLiquidationEndpoint,
     MarginManualLiquidationParams,
-    MarginManualLiquidationResponse
+    MarginManualLiquidationResponse,
+    weight = 3000
 );
 
 /// Margin Manual Liquidation.
@@ -32,7 +32,6 @@ pub struct MarginManualLiquidationParams {
     r#type: String,
     symbol: Option<String>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl MarginManualLiquidationParams {
@@ -41,7 +40,6 @@ impl MarginManualLiquidationParams {
             r#type: r#type.to_owned(),
             symbol: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -62,8 +60,8 @@ pub type MarginManualLiquidationResponse = Vec<MarginManualLiquidation>;
 #[serde(rename_all = "camelCase")]
 pub struct MarginManualLiquidation {
     pub asset: String,
-    pub interest: String,
-    pub principal: String,
-    pub liquidation_asset: String,
-    pub liquidation_qty: String,
+    pub interest: Decimal,
+    pub principal: Decimal,
+    pub liability_asset: String,
+    pub liability_qty: Decimal,
 }
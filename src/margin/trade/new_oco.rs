@@ -1,8 +1,8 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    decimal::Decimal,
     enums::{
         ContingencyType, OrderSide, OrderStatus, OrderType, ResponseType, SecurityType,
         SelfTradePreventionMode, SideEffectType, TimeInForce,
@@ -16,7 +16,8 @@ endpoint!(
     SecurityType::Trade,
     NewOcoEndpoint,
     NewOcoParams,
-    NewOcoResponse
+    NewOcoResponse,
+    weight = 6
 );
 
 /// Send in a new OCO for a margin account.
@@ -39,36 +40,41 @@ pub struct NewOcoParams {
     isolated: Option<String>,
     list_client_order_id: Option<String>,
     side: OrderSide,
-    quantity: f64,
+    quantity: Decimal,
     limit_client_order_id: Option<String>,
-    price: f64,
-    limit_iceberg_qty: Option<f64>,
+    price: Decimal,
+    limit_iceberg_qty: Option<Decimal>,
     stop_client_order_id: Option<String>,
-    stop_price: f64,
-    stop_limit_price: Option<f64>,
-    stop_iceberg_qty: Option<f64>,
+    stop_price: Decimal,
+    stop_limit_price: Option<Decimal>,
+    stop_iceberg_qty: Option<Decimal>,
     stop_limit_time_in_force: Option<TimeInForce>,
     new_order_resp_type: Option<ResponseType>,
     side_effect_type: Option<SideEffectType>,
     self_trade_prevention_mode: Option<SelfTradePreventionMode>,
     auto_repay_at_cancel: Option<bool>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl NewOcoParams {
-    pub fn new(symbol: &str, side: OrderSide, quantity: f64, price: f64, stop_price: f64) -> Self {
+    pub fn new(
+        symbol: &str,
+        side: OrderSide,
+        quantity: impl Into<Decimal>,
+        price: impl Into<Decimal>,
+        stop_price: impl Into<Decimal>,
+    ) -> Self {
         Self {
             symbol: symbol.to_owned(),
             isolated: None,
             list_client_order_id: None,
             side,
-            quantity,
+            quantity: quantity.into(),
             limit_client_order_id: None,
-            price,
+            price: price.into(),
             limit_iceberg_qty: None,
             stop_client_order_id: None,
-            stop_price,
+            stop_price: stop_price.into(),
             stop_limit_price: None,
             stop_iceberg_qty: None,
             stop_limit_time_in_force: None,
@@ -77,7 +83,6 @@ impl NewOcoParams {
             self_trade_prevention_mode: None,
             auto_repay_at_cancel: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -96,8 +101,8 @@ impl NewOcoParams {
         self
     }
 
-    pub fn limit_iceberg_qty(mut self, limit_iceberg_qty: f64) -> Self {
-        self.limit_iceberg_qty = Some(limit_iceberg_qty);
+    pub fn limit_iceberg_qty(mut self, limit_iceberg_qty: impl Into<Decimal>) -> Self {
+        self.limit_iceberg_qty = Some(limit_iceberg_qty.into());
         self
     }
 
@@ -106,13 +111,13 @@ impl NewOcoParams {
         self
     }
 
-    pub fn stop_limit_price(mut self, stop_limit_price: f64) -> Self {
-        self.stop_limit_price = Some(stop_limit_price);
+    pub fn stop_limit_price(mut self, stop_limit_price: impl Into<Decimal>) -> Self {
+        self.stop_limit_price = Some(stop_limit_price.into());
         self
     }
 
-    pub fn stop_iceberg_qty(mut self, stop_iceberg_qty: f64) -> Self {
-        self.stop_iceberg_qty = Some(stop_iceberg_qty);
+    pub fn stop_iceberg_qty(mut self, stop_iceberg_qty: impl Into<Decimal>) -> Self {
+        self.stop_iceberg_qty = Some(stop_iceberg_qty.into());
         self
     }
 
@@ -162,6 +167,8 @@ pub struct MarginOcoOrder {
     pub list_client_order_id: String,
     pub transaction_time: i64,
     pub symbol: String,
+    pub margin_buy_borrow_amount: Option<Decimal>,
+    pub margin_buy_borrow_asset: Option<String>,
     pub is_isolated: bool,
     #[serde(default)]
     pub orders: Vec<MarginOcoOrderListItem>,
@@ -181,19 +188,20 @@ pub struct MarginOcoOrderListItem {
 #[serde(rename_all = "camelCase")]
 pub struct MarginOcoOrderListReport {
     pub symbol: String,
-    pub orig_client_order_id: String,
+    pub orig_client_order_id: Option<String>,
     pub order_id: i64,
     pub order_list_id: i64,
     pub client_order_id: String,
-    pub price: String,
-    pub orig_qty: String,
-    pub executed_qty: String,
-    pub cummulative_quote_qty: String,
+    pub transact_time: Option<i64>,
+    pub price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub cummulative_quote_qty: Decimal,
     pub status: OrderStatus,
     pub time_in_force: TimeInForce,
     pub r#type: OrderType,
     pub side: OrderSide,
-    pub iceberg_qty: Option<String>,
-    pub stop_price: Option<String>,
+    pub iceberg_qty: Option<Decimal>,
+    pub stop_price: Option<Decimal>,
     pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
 }
@@ -1,8 +1,8 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    decimal::Decimal,
     enums::{
         OrderSide, OrderStatus, OrderType, ResponseType, SecurityType, SelfTradePreventionMode,
         SideEffectType, TimeInForce,
@@ -16,7 +16,9 @@ endpoint!(
     SecurityType::Trade,
     NewOrderEndpoint,
     NewOrderParams,
-    NewOrderResponse
+    NewOrderResponse,
+    weight = 6,
+    retry = |params| params.new_client_order_id.is_some()
 );
 
 /// Post a new order for margin account.
@@ -39,19 +41,18 @@ pub struct NewOrderParams {
     is_isolated: Option<bool>,
     side: OrderSide,
     r#type: OrderType,
-    quantity: Option<f64>,
-    quote_order_qty: Option<f64>,
-    price: Option<f64>,
-    stop_price: Option<f64>,
+    quantity: Option<Decimal>,
+    quote_order_qty: Option<Decimal>,
+    price: Option<Decimal>,
+    stop_price: Option<Decimal>,
     new_client_order_id: Option<String>,
-    iceberg_qty: Option<f64>,
+    iceberg_qty: Option<Decimal>,
     new_order_resp_type: Option<ResponseType>,
     side_effect_type: Option<SideEffectType>,
     time_in_force: Option<TimeInForce>,
     self_trade_prevention_mode: Option<SelfTradePreventionMode>,
     auto_repay_at_cancel: Option<bool>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl NewOrderParams {
@@ -73,7 +74,6 @@ impl NewOrderParams {
             self_trade_prevention_mode: None,
             auto_repay_at_cancel: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -82,33 +82,37 @@ impl NewOrderParams {
         self
     }
 
-    pub fn quantity(mut self, quantity: f64) -> Self {
-        self.quantity = Some(quantity);
+    pub fn quantity(mut self, quantity: impl Into<Decimal>) -> Self {
+        self.quantity = Some(quantity.into());
         self
     }
 
-    pub fn quote_order_qty(mut self, quote_order_qty: f64) -> Self {
-        self.quote_order_qty = Some(quote_order_qty);
+    pub fn quote_order_qty(mut self, quote_order_qty: impl Into<Decimal>) -> Self {
+        self.quote_order_qty = Some(quote_order_qty.into());
         self
     }
 
-    pub fn price(mut self, price: f64) -> Self {
-        self.price = Some(price);
+    pub fn price(mut self, price: impl Into<Decimal>) -> Self {
+        self.price = Some(price.into());
         self
     }
 
-    pub fn stop_price(mut self, stop_price: f64) -> Self {
-        self.stop_price = Some(stop_price);
+    pub fn stop_price(mut self, stop_price: impl Into<Decimal>) -> Self {
+        self.stop_price = Some(stop_price.into());
         self
     }
 
+    /// Setting it allows the request to be retried when it is rejected before
+    /// being executed. When its execution status is unknown, the order has to
+    /// be queried by `origClientOrderId` before it is sent again, see
+    /// [`RetryPolicy`](crate::rest_api::RetryPolicy).
     pub fn new_client_order_id(mut self, new_client_order_id: &str) -> Self {
         self.new_client_order_id = Some(new_client_order_id.to_owned());
         self
     }
 
-    pub fn iceberg_qty(mut self, iceberg_qty: f64) -> Self {
-        self.iceberg_qty = Some(iceberg_qty);
+    pub fn iceberg_qty(mut self, iceberg_qty: impl Into<Decimal>) -> Self {
+        self.iceberg_qty = Some(iceberg_qty.into());
         self
     }
 
@@ -146,12 +150,13 @@ impl NewOrderParams {
     }
 }
 
+/// The variants are tried in order, from the most detailed one.
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 pub enum NewOrderResponse {
-    Ack(Box<NewMarginOrderAck>),
-    Result(Box<NewMarginOrderResult>),
     Full(Box<NewMarginOrderFull>),
+    Result(Box<NewMarginOrderResult>),
+    Ack(Box<NewMarginOrderAck>),
 }
 
 #[derive(Debug, Deserialize)]
@@ -171,10 +176,10 @@ pub struct NewMarginOrderResult {
     pub order_id: i64,
     pub client_order_id: String,
     pub transact_time: i64,
-    pub price: String,
-    pub orig_qty: String,
-    pub executed_qty: String,
-    pub cummulative_quote_qty: String,
+    pub price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub cummulative_quote_qty: Decimal,
     pub status: OrderStatus,
     pub time_in_force: TimeInForce,
     pub r#type: OrderType,
@@ -188,19 +193,19 @@ pub struct NewMarginOrderResult {
 pub struct NewMarginOrderFull {
     pub symbol: String,
     pub order_id: i64,
-    pub order_list_id: i64,
     pub client_order_id: String,
     pub transact_time: i64,
-    pub price: String,
-    pub orig_qty: String,
-    pub executed_qty: String,
-    pub cummulative_quote_qty: String,
+    pub price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub cummulative_quote_qty: Decimal,
     pub status: OrderStatus,
     pub time_in_force: TimeInForce,
     pub r#type: OrderType,
-    pub side: String,
+    pub side: OrderSide,
     pub margin_buy_borrow_amount: Option<i64>,
     pub margin_buy_borrow_asset: Option<String>,
+    pub is_isolated: bool,
     pub self_trade_prevention_mode: SelfTradePreventionMode,
     pub fills: Vec<MarginOrderFill>,
 }
@@ -208,9 +213,9 @@ pub struct NewMarginOrderFull {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MarginOrderFill {
-    pub price: String,
-    pub qty: String,
-    pub commission: String,
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub commission: Decimal,
     pub commission_asset: String,
     pub trade_id: i64,
 }
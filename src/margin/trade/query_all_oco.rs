@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::Serialize;
 
@@ -12,7 +11,8 @@ endpoint!(
     SecurityType::UserData,
     QueryAllOcoEndpoint,
     QueryAllOcoParams,
-    QueryAllOcoResponse
+    QueryAllOcoResponse,
+    weight = 200
 );
 
 /// Retrieves all OCO for a specific margin account based on provided optional
@@ -39,7 +39,6 @@ pub struct QueryAllOcoParams {
     end_time: Option<i64>,
     limit: Option<i64>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryAllOcoParams {
@@ -52,7 +51,6 @@ impl QueryAllOcoParams {
             end_time: None,
             limit: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -1,8 +1,8 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    decimal::Decimal,
     enums::{
         OrderSide, OrderStatus, OrderType, SecurityType, SelfTradePreventionMode, TimeInForce,
     },
@@ -15,7 +15,8 @@ endpoint!(
     SecurityType::UserData,
     QueryAllOrdersEndpoint,
     QueryAllOrdersParams,
-    QueryAllOrdersResponse
+    QueryAllOrdersResponse,
+    weight = 200
 );
 
 /// Query Margin Account's All Orders.
@@ -40,7 +41,6 @@ pub struct QueryAllOrdersParams {
     end_time: Option<i64>,
     limit: Option<i64>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryAllOrdersParams {
@@ -52,7 +52,6 @@ impl QueryAllOrdersParams {
             end_time: None,
             limit: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -88,16 +87,16 @@ pub type QueryAllOrdersResponse = Vec<MarginOrderDetail>;
 #[serde(rename_all = "camelCase")]
 pub struct MarginOrderDetail {
     pub client_order_id: String,
-    pub cummulative_quote_qty: String,
-    pub executed_qty: String,
-    pub iceberg_qty: String,
+    pub cummulative_quote_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub iceberg_qty: Decimal,
     pub is_working: bool,
     pub order_id: i64,
-    pub orig_qty: String,
-    pub price: String,
+    pub orig_qty: Decimal,
+    pub price: Decimal,
     pub side: OrderSide,
     pub status: OrderStatus,
-    pub stop_price: String,
+    pub stop_price: Decimal,
     pub symbol: String,
     pub is_isolated: bool,
     pub time: i64,
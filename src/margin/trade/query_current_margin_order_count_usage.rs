@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
@@ -13,7 +12,8 @@ endpoint!(
     SecurityType::Trade,
     QueryCurrentMarginOrderCountUsageEndpoint,
     QueryCurrentMarginOrderCountUsageParams,
-    QueryCurrentMarginOrderCountUsageResponse
+    QueryCurrentMarginOrderCountUsageResponse,
+    weight = 20
 );
 
 /// Displays the user's current margin order count usage for all intervals.
@@ -35,7 +35,6 @@ pub struct QueryCurrentMarginOrderCountUsageParams {
     symbol: Option<String>,
     is_isolated: Option<String>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryCurrentMarginOrderCountUsageParams {
@@ -44,7 +43,6 @@ impl QueryCurrentMarginOrderCountUsageParams {
             symbol: None,
             is_isolated: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
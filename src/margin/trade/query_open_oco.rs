@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::Serialize;
 
@@ -29,6 +28,10 @@ impl Endpoint for QueryOpenOcoEndpoint<'_> {
     fn security_type(&self) -> SecurityType {
         SecurityType::UserData
     }
+
+    fn weight(&self, _params: &Self::Params) -> u32 {
+        10
+    }
 }
 
 impl Params for QueryOpenOcoParams {}
@@ -52,7 +55,6 @@ pub struct QueryOpenOcoParams {
     symbol: Option<String>,
     is_isolated: Option<String>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryOpenOcoParams {
@@ -61,7 +63,6 @@ impl QueryOpenOcoParams {
             symbol: None,
             is_isolated: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
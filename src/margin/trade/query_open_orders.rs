@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::Serialize;
 
@@ -29,6 +28,10 @@ impl Endpoint for QueryOpenOrdersEndpoint<'_> {
     fn security_type(&self) -> SecurityType {
         SecurityType::UserData
     }
+
+    fn weight(&self, _params: &Self::Params) -> u32 {
+        10
+    }
 }
 
 impl Params for QueryOpenOrdersParams {}
@@ -52,7 +55,6 @@ pub struct QueryOpenOrdersParams {
     symbol: Option<String>,
     is_isolated: Option<String>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryOpenOrdersParams {
@@ -61,7 +63,6 @@ impl QueryOpenOrdersParams {
             symbol: None,
             is_isolated: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
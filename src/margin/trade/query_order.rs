@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::Serialize;
 
@@ -29,6 +28,10 @@ impl Endpoint for QueryOrderEndpoint<'_> {
     fn security_type(&self) -> SecurityType {
         SecurityType::UserData
     }
+
+    fn weight(&self, _params: &Self::Params) -> u32 {
+        10
+    }
 }
 
 impl Params for QueryOrderParams {}
@@ -54,7 +57,6 @@ pub struct QueryOrderParams {
     order_id: Option<i64>,
     orig_client_order_id: Option<String>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryOrderParams {
@@ -65,7 +67,6 @@ impl QueryOrderParams {
             order_id: None,
             orig_client_order_id: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
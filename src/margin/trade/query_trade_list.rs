@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/sapi/v1/margin/myTrades",
@@ -10,7 +9,8 @@ endpoint!(
     SecurityType::UserData,
     QueryTradeListEndpoint,
     QueryTradeListParams,
-    QueryTradeListResponse
+    QueryTradeListResponse,
+    weight = 10
 );
 
 /// Query Margin Account's Trade List.
@@ -37,7 +37,6 @@ pub struct QueryTradeListParams {
     from_id: Option<i64>,
     limit: Option<i64>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryTradeListParams {
@@ -51,7 +50,6 @@ impl QueryTradeListParams {
             from_id: None,
             limit: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -96,15 +94,15 @@ pub type QueryTradeListResponse = Vec<MarginTrade>;
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MarginTrade {
-    pub commission: String,
+    pub commission: Decimal,
     pub commission_asset: String,
     pub id: i64,
     pub is_best_match: bool,
     pub is_buyer: bool,
     pub is_maker: bool,
     pub order_id: i64,
-    pub price: String,
-    pub qty: String,
+    pub price: Decimal,
+    pub qty: Decimal,
     pub symbol: String,
     pub is_isolated: bool,
     pub time: i64,
@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
@@ -10,7 +9,8 @@ endpoint!(
     SecurityType::Margin,
     SmallLiabilityExchangeEndpoint,
     SmallLiabilityExchangeParams,
-    SmallLiabilityExchangeResponse
+    SmallLiabilityExchangeResponse,
+    weight = 3000
 );
 
 /// Small Liability Exchange
@@ -31,7 +31,6 @@ impl<'r> SmallLiabilityExchangeEndpoint<'r> {
 pub struct SmallLiabilityExchangeParams {
     asset_names: String,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl SmallLiabilityExchangeParams {
@@ -39,7 +38,6 @@ impl SmallLiabilityExchangeParams {
         Self {
             asset_names: asset_names.to_owned(),
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -28,12 +28,16 @@ impl<'r> CloseIsolatedMarginUserDataStreamEndpoint<'r> {
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CloseIsolatedMarginUserDataStreamParams {
+    pub symbol: String,
     pub listen_key: String,
 }
 
 impl CloseIsolatedMarginUserDataStreamParams {
-    pub fn new(listen_key: String) -> Self {
-        Self { listen_key }
+    pub fn new(symbol: &str, listen_key: String) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            listen_key,
+        }
     }
 }
 
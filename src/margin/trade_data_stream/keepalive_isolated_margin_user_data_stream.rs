@@ -32,12 +32,16 @@ impl<'r> KeepaliveIsolatedMarginUserDataStreamEndpoint<'r> {
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct KeepaliveIsolatedMarginUserDataStreamParams {
+    pub symbol: String,
     pub listen_key: String,
 }
 
 impl KeepaliveIsolatedMarginUserDataStreamParams {
-    pub fn new(listen_key: String) -> Self {
-        Self { listen_key }
+    pub fn new(symbol: &str, listen_key: String) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            listen_key,
+        }
     }
 }
 
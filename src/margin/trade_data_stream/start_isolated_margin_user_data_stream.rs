@@ -29,17 +29,15 @@ impl<'r> StartIsolatedMarginUserDataStreamEndpoint<'r> {
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct StartIsolatedMarginUserDataStreamParams {}
-
-impl Default for StartIsolatedMarginUserDataStreamParams {
-    fn default() -> Self {
-        Self::new()
-    }
+pub struct StartIsolatedMarginUserDataStreamParams {
+    pub symbol: String,
 }
 
 impl StartIsolatedMarginUserDataStreamParams {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+        }
     }
 }
 
@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/sapi/v1/margin/transfer",
@@ -38,7 +37,6 @@ pub struct GetCrossMarginTransferHistoryParams {
     archived: Option<String>,
     isolated_symbol: Option<String>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl GetCrossMarginTransferHistoryParams {
@@ -53,7 +51,6 @@ impl GetCrossMarginTransferHistoryParams {
             archived: None,
             isolated_symbol: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -113,7 +110,7 @@ pub struct GetCrossMarginTransferHistoryResponse {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MarginTransferRow {
-    pub amount: String,
+    pub amount: Decimal,
     pub asset: String,
     pub status: String,
     pub timestamp: i64,
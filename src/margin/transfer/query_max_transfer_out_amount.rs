@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/sapi/v1/margin/maxTransferable",
@@ -10,7 +9,8 @@ endpoint!(
     SecurityType::UserData,
     QueryMaxTransferOutAmountEndpoint,
     QueryMaxTransferOutAmountParams,
-    QueryMaxTransferOutAmountResponse
+    QueryMaxTransferOutAmountResponse,
+    weight = 50
 );
 
 /// Query Max Transfer-Out Amount.
@@ -32,7 +32,6 @@ pub struct QueryMaxTransferOutAmountParams {
     asset: String,
     isolated_symbol: Option<String>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryMaxTransferOutAmountParams {
@@ -41,7 +40,6 @@ impl QueryMaxTransferOutAmountParams {
             asset: asset.to_owned(),
             isolated_symbol: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -59,5 +57,5 @@ impl QueryMaxTransferOutAmountParams {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryMaxTransferOutAmountResponse {
-    pub amount: String,
+    pub amount: Decimal,
 }
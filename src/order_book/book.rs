@@ -0,0 +1,148 @@
+use std::collections::BTreeMap;
+
+use crate::decimal::Decimal;
+
+/// Digits after the decimal point of [`OrderBook::vwap`].
+const VWAP_SCALE: u32 = 16;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BookSide {
+    Bid,
+    Ask,
+}
+
+/// The price levels of an order book, as of an update id.
+#[derive(Clone, Debug, Default)]
+pub struct OrderBook {
+    last_update_id: i64,
+    bids: BTreeMap<Decimal, Decimal>,
+    asks: BTreeMap<Decimal, Decimal>,
+}
+
+impl OrderBook {
+    pub(super) fn new(
+        last_update_id: i64,
+        bids: &[(Decimal, Decimal)],
+        asks: &[(Decimal, Decimal)],
+    ) -> Self {
+        let mut book = Self::default();
+        book.update(last_update_id, bids, asks);
+        book
+    }
+
+    /// Apply the levels of an update. Quantities are absolute, and a quantity
+    /// of zero removes the level.
+    pub(super) fn update(
+        &mut self,
+        final_update_id: i64,
+        bids: &[(Decimal, Decimal)],
+        asks: &[(Decimal, Decimal)],
+    ) {
+        for (levels, updates) in [(&mut self.bids, bids), (&mut self.asks, asks)] {
+            for &(price, quantity) in updates {
+                if quantity.is_zero() {
+                    levels.remove(&price);
+                } else {
+                    levels.insert(price, quantity);
+                }
+            }
+        }
+        self.last_update_id = final_update_id;
+    }
+
+    pub fn last_update_id(&self) -> i64 {
+        self.last_update_id
+    }
+
+    /// The highest bid, with its quantity.
+    pub fn best_bid(&self) -> Option<(Decimal, Decimal)> {
+        self.bids().next()
+    }
+
+    /// The lowest ask, with its quantity.
+    pub fn best_ask(&self) -> Option<(Decimal, Decimal)> {
+        self.asks().next()
+    }
+
+    /// The bids, from the highest.
+    pub fn bids(&self) -> impl Iterator<Item = (Decimal, Decimal)> + '_ {
+        self.bids
+            .iter()
+            .rev()
+            .map(|(&price, &quantity)| (price, quantity))
+    }
+
+    /// The asks, from the lowest.
+    pub fn asks(&self) -> impl Iterator<Item = (Decimal, Decimal)> + '_ {
+        self.asks
+            .iter()
+            .map(|(&price, &quantity)| (price, quantity))
+    }
+
+    /// The quantity at `price`, zero if there is no level at that price.
+    pub fn depth_at(&self, side: BookSide, price: Decimal) -> Decimal {
+        let levels = match side {
+            BookSide::Bid => &self.bids,
+            BookSide::Ask => &self.asks,
+        };
+        levels.get(&price).copied().unwrap_or(Decimal::ZERO)
+    }
+
+    /// The average price of filling `size` against `side` from its best
+    /// price: the asks to buy, the bids to sell. `None` if the side holds less
+    /// than `size`.
+    pub fn vwap(&self, side: BookSide, size: Decimal) -> Option<Decimal> {
+        if size <= Decimal::ZERO {
+            return None;
+        }
+        let levels: Box<dyn Iterator<Item = (Decimal, Decimal)>> = match side {
+            BookSide::Bid => Box::new(self.bids()),
+            BookSide::Ask => Box::new(self.asks()),
+        };
+
+        let mut remaining = size;
+        let mut notional = Decimal::ZERO;
+        for (price, quantity) in levels {
+            let filled = quantity.min(remaining);
+            notional = notional.checked_add(price.checked_mul(filled)?)?;
+            remaining = remaining.checked_sub(filled)?;
+            if remaining.is_zero() {
+                return notional
+                    .checked_div(size, VWAP_SCALE)
+                    .map(|vwap| vwap.normalize());
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dec(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn levels_and_vwap() {
+        let mut book = OrderBook::new(
+            1,
+            &[(dec("10"), dec("1")), (dec("9"), dec("2"))],
+            &[(dec("11"), dec("1")), (dec("12"), dec("3"))],
+        );
+        book.update(2, &[(dec("10.0"), dec("0"))], &[(dec("11"), dec("1.5"))]);
+
+        assert_eq!(book.last_update_id(), 2);
+        assert_eq!(book.best_bid(), Some((dec("9"), dec("2"))));
+        assert_eq!(book.best_ask(), Some((dec("11"), dec("1.5"))));
+        assert_eq!(book.depth_at(BookSide::Ask, dec("12.00")), dec("3"));
+        assert_eq!(book.depth_at(BookSide::Bid, dec("10")), Decimal::ZERO);
+
+        assert_eq!(book.vwap(BookSide::Ask, dec("2")), Some(dec("11.25")));
+        assert_eq!(book.vwap(BookSide::Ask, dec("1")), Some(dec("11")));
+        assert_eq!(book.vwap(BookSide::Bid, dec("2")), Some(dec("9")));
+        assert_eq!(book.vwap(BookSide::Bid, dec("2.5")), None);
+        assert_eq!(book.vwap(BookSide::Ask, Decimal::ZERO), None);
+    }
+}
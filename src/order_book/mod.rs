@@ -0,0 +1,360 @@
+//! Local order books, maintained from the diff depth streams.
+//!
+//! A [`LocalOrderBook`] follows Binance's procedure: it buffers the updates
+//! of the depth stream, fetches a snapshot from the REST API, discards the
+//! updates older than the snapshot, and applies the others in order. When an
+//! update is missing, or the connection is lost, the book is discarded and
+//! synced again from a new snapshot.
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//!
+//! use birdie::{decimal::Decimal, order_book::{BookSide, LocalOrderBook, OrderBookEvent}};
+//!
+//! # async fn example() {
+//! let client = birdie::rest_api("https://api.binance.com", "", "").unwrap();
+//! let mut book = LocalOrderBook::spot(
+//!     Arc::new(client),
+//!     "wss://stream.binance.com:9443",
+//!     "BTCUSDT",
+//! );
+//! book.start().await.unwrap();
+//!
+//! let mut events = book.subscribe();
+//! while let Ok(event) = events.recv().await {
+//!     if let OrderBookEvent::Updated(_) = event {
+//!         let size = Decimal::new(1, 0);
+//!         println!("{:?} {:?}", book.best_bid(), book.vwap(BookSide::Ask, size));
+//!     }
+//! }
+//! # }
+//! ```
+mod book;
+mod sync;
+
+use std::{
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+
+use futures_util::{future::BoxFuture, stream::BoxStream, FutureExt, StreamExt};
+use tokio::{sync::broadcast, task::JoinHandle};
+use tracing::error;
+
+use crate::{
+    decimal::Decimal,
+    enums::DepthSpeed,
+    rest_api::{Endpoint, RestApiClient, RestApiError},
+    spot, usd_futures,
+    web_socket::{ConnectionStatus, ReconnectPolicy},
+    web_socket_stream::{open_stream_with_policy, StreamEvent, StreamName, WebSocketStreamError},
+};
+pub use book::{BookSide, OrderBook};
+use sync::{BookSync, DepthUpdate, Outcome, Snapshot};
+
+const EVENT_BUFFER: usize = 1024;
+
+/// Delay before fetching a snapshot again, when it failed or was older than
+/// the updates. It doubles on every retry, up to [`MAX_SNAPSHOT_RETRY`].
+const SNAPSHOT_RETRY: Duration = Duration::from_secs(1);
+
+const MAX_SNAPSHOT_RETRY: Duration = Duration::from_secs(30);
+
+#[derive(Clone, Copy, Debug)]
+enum Market {
+    Spot,
+    UsdFutures,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum OrderBookEvent {
+    /// The book is in sync, as of the update id.
+    Synced(i64),
+    /// An update was applied, with its final update id.
+    Updated(i64),
+    /// An update is missing or the connection was lost, the book is
+    /// unavailable until synced again.
+    Resyncing,
+    Status(ConnectionStatus),
+}
+
+/// An order book of a symbol, kept in sync with the diff depth stream.
+///
+/// The queries return `None` until the book is in sync.
+pub struct LocalOrderBook {
+    market: Market,
+    rest_client: Arc<RestApiClient>,
+    stream_endpoint: String,
+    symbol: String,
+    speed: DepthSpeed,
+    snapshot_limit: i64,
+    reconnect_policy: ReconnectPolicy,
+    sync: Arc<RwLock<BookSync>>,
+    event_sender: broadcast::Sender<OrderBookEvent>,
+    task: Option<JoinHandle<()>>,
+}
+
+impl LocalOrderBook {
+    /// A spot order book. The snapshots are fetched with `rest_client`, which
+    /// needs no API key.
+    pub fn spot(rest_client: Arc<RestApiClient>, stream_endpoint: &str, symbol: &str) -> Self {
+        Self::new(Market::Spot, rest_client, stream_endpoint, symbol)
+    }
+
+    /// A USD-M futures order book, where the updates are chained by their
+    /// `pu`.
+    pub fn usd_futures(
+        rest_client: Arc<RestApiClient>,
+        stream_endpoint: &str,
+        symbol: &str,
+    ) -> Self {
+        Self::new(Market::UsdFutures, rest_client, stream_endpoint, symbol)
+    }
+
+    fn new(
+        market: Market,
+        rest_client: Arc<RestApiClient>,
+        stream_endpoint: &str,
+        symbol: &str,
+    ) -> Self {
+        Self {
+            market,
+            rest_client,
+            stream_endpoint: stream_endpoint.to_owned(),
+            symbol: symbol.to_owned(),
+            speed: DepthSpeed::Ms100,
+            snapshot_limit: 1000,
+            reconnect_policy: ReconnectPolicy::default(),
+            sync: Arc::new(RwLock::new(BookSync::new(market))),
+            event_sender: broadcast::channel(EVENT_BUFFER).0,
+            task: None,
+        }
+    }
+
+    /// Default: 100ms. The USD-M futures stream without a speed updates every
+    /// 250ms.
+    pub fn speed(mut self, speed: DepthSpeed) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Levels of the snapshots. The book may miss the levels beyond, until
+    /// they are updated. Default: 1000.
+    pub fn snapshot_limit(mut self, snapshot_limit: i64) -> Self {
+        self.snapshot_limit = snapshot_limit;
+        self
+    }
+
+    pub fn reconnect_policy(mut self, reconnect_policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = reconnect_policy;
+        self
+    }
+
+    /// Connect to the depth stream, and spawn the task that syncs the book.
+    /// The book is in sync once [`OrderBookEvent::Synced`] is sent.
+    pub async fn start(&mut self) -> Result<(), WebSocketStreamError> {
+        let stream = StreamName::depth(&self.symbol, self.speed);
+        let endpoint = format!("{}/ws/{stream}", self.stream_endpoint);
+        let policy = self.reconnect_policy.clone();
+        let updates = match self.market {
+            Market::Spot => {
+                open_stream_with_policy::<spot::web_socket_streams::Depth>(&endpoint, policy)
+                    .await?
+                    .map(|item| item.map(DepthUpdate::from))
+                    .boxed()
+            }
+            Market::UsdFutures => {
+                open_stream_with_policy::<usd_futures::web_socket_streams::Depth>(&endpoint, policy)
+                    .await?
+                    .map(|item| item.map(DepthUpdate::from))
+                    .boxed()
+            }
+        };
+
+        let snapshots = SnapshotSource {
+            market: self.market,
+            rest_client: self.rest_client.clone(),
+            symbol: self.symbol.clone(),
+            limit: self.snapshot_limit,
+        };
+        let task = maintain(
+            updates,
+            snapshots,
+            self.sync.clone(),
+            self.event_sender.clone(),
+        );
+        if let Some(previous) = self.task.take() {
+            previous.abort();
+            self.sync.write().unwrap().reset();
+        }
+        self.task = Some(tokio::spawn(task));
+        Ok(())
+    }
+
+    /// Receive the [`OrderBookEvent`]s. A receiver that lags behind misses
+    /// the oldest events.
+    pub fn subscribe(&self) -> broadcast::Receiver<OrderBookEvent> {
+        self.event_sender.subscribe()
+    }
+
+    /// A copy of the book.
+    pub fn book(&self) -> Option<OrderBook> {
+        self.read(OrderBook::clone)
+    }
+
+    pub fn last_update_id(&self) -> Option<i64> {
+        self.read(OrderBook::last_update_id)
+    }
+
+    pub fn best_bid(&self) -> Option<(Decimal, Decimal)> {
+        self.read(OrderBook::best_bid).flatten()
+    }
+
+    pub fn best_ask(&self) -> Option<(Decimal, Decimal)> {
+        self.read(OrderBook::best_ask).flatten()
+    }
+
+    /// See [`OrderBook::depth_at`].
+    pub fn depth_at(&self, side: BookSide, price: Decimal) -> Option<Decimal> {
+        self.read(|book| book.depth_at(side, price))
+    }
+
+    /// See [`OrderBook::vwap`].
+    pub fn vwap(&self, side: BookSide, size: Decimal) -> Option<Decimal> {
+        self.read(|book| book.vwap(side, size)).flatten()
+    }
+
+    fn read<T>(&self, f: impl FnOnce(&OrderBook) -> T) -> Option<T> {
+        self.sync.read().unwrap().book().map(f)
+    }
+}
+
+impl Drop for LocalOrderBook {
+    fn drop(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
+}
+
+struct SnapshotSource {
+    market: Market,
+    rest_client: Arc<RestApiClient>,
+    symbol: String,
+    limit: i64,
+}
+
+impl SnapshotSource {
+    async fn fetch(&self) -> Result<Snapshot, RestApiError> {
+        let client = self.rest_client.as_ref();
+        match self.market {
+            Market::Spot => {
+                let params = spot::market::OrderBookParams::new(&self.symbol).limit(self.limit);
+                let res = client.spot().market().order_book().request(params).await?;
+                Ok(Snapshot {
+                    last_update_id: res.last_update_id,
+                    bids: res.bids,
+                    asks: res.asks,
+                })
+            }
+            Market::UsdFutures => {
+                let params =
+                    usd_futures::market::OrderBookParams::new(&self.symbol).limit(self.limit);
+                let res = client
+                    .usd_futures()
+                    .market()
+                    .order_book()
+                    .request(params)
+                    .await?;
+                Ok(Snapshot {
+                    last_update_id: res.last_update_id,
+                    bids: res.bids,
+                    asks: res.asks,
+                })
+            }
+        }
+    }
+}
+
+/// Apply the updates, and fetch a snapshot whenever the book is out of sync,
+/// until the stream ends. The updates keep being buffered while the snapshot
+/// is fetched, so that the stream is not held up by a slow or failing REST
+/// API.
+async fn maintain(
+    mut updates: BoxStream<'static, Result<DepthUpdate, StreamEvent>>,
+    snapshots: SnapshotSource,
+    sync: Arc<RwLock<BookSync>>,
+    event_sender: broadcast::Sender<OrderBookEvent>,
+) {
+    let notify = |outcome: Outcome| {
+        let event = match outcome {
+            Outcome::Synced(id) => OrderBookEvent::Synced(id),
+            Outcome::Updated(id) => OrderBookEvent::Updated(id),
+            Outcome::Gap => OrderBookEvent::Resyncing,
+            Outcome::Pending | Outcome::Ignored => return,
+        };
+        // There may be no receivers.
+        let _ = event_sender.send(event);
+    };
+
+    let mut fetch: Option<BoxFuture<'_, Result<Snapshot, RestApiError>>> = None;
+    let mut retry_delay = Duration::ZERO;
+    loop {
+        if fetch.is_none() && sync.read().unwrap().needs_snapshot() {
+            let snapshots = &snapshots;
+            fetch = Some(
+                async move {
+                    tokio::time::sleep(retry_delay).await;
+                    snapshots.fetch().await
+                }
+                .boxed(),
+            );
+        }
+
+        tokio::select! {
+            item = updates.next() => {
+                let Some(item) = item else {
+                    break;
+                };
+                match item {
+                    Ok(update) => {
+                        let outcome = sync.write().unwrap().push(update);
+                        notify(outcome);
+                    }
+                    Err(StreamEvent::Status(status)) => {
+                        // The updates are lost with the connection, and the
+                        // snapshot being fetched is too old for the next ones.
+                        if matches!(status, ConnectionStatus::Disconnected) {
+                            fetch = None;
+                            if sync.write().unwrap().reset() {
+                                notify(Outcome::Gap);
+                            }
+                        }
+                        let _ = event_sender.send(OrderBookEvent::Status(status));
+                    }
+                    Err(StreamEvent::Unparsed(unparsed)) => {
+                        error!("order book {}: {}", unparsed.error, unparsed.message);
+                    }
+                }
+            }
+            res = async { fetch.as_mut().unwrap().await }, if fetch.is_some() => {
+                fetch = None;
+                let outcome = match res {
+                    Ok(snapshot) => sync.write().unwrap().apply_snapshot(snapshot),
+                    Err(err) => {
+                        error!("order book snapshot error: {err}");
+                        Outcome::Gap
+                    }
+                };
+                if outcome == Outcome::Gap {
+                    // The snapshot failed, or is older than the buffered
+                    // updates.
+                    retry_delay = (retry_delay * 2).clamp(SNAPSHOT_RETRY, MAX_SNAPSHOT_RETRY);
+                } else {
+                    retry_delay = Duration::ZERO;
+                    notify(outcome);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,254 @@
+use std::mem;
+
+use super::{Market, OrderBook};
+use crate::{decimal::Decimal, spot, usd_futures};
+
+/// An update of the depth stream of either market.
+#[derive(Clone, Debug)]
+pub(super) struct DepthUpdate {
+    first_update_id: i64,
+    final_update_id: i64,
+    /// `pu`, the final update id of the previous update. USD-M futures only.
+    prev_final_update_id: Option<i64>,
+    bids: Vec<(Decimal, Decimal)>,
+    asks: Vec<(Decimal, Decimal)>,
+}
+
+impl From<spot::web_socket_streams::Depth> for DepthUpdate {
+    fn from(depth: spot::web_socket_streams::Depth) -> Self {
+        Self {
+            first_update_id: depth.first_update_id,
+            final_update_id: depth.final_update_id,
+            prev_final_update_id: None,
+            bids: depth.bids,
+            asks: depth.asks,
+        }
+    }
+}
+
+impl From<usd_futures::web_socket_streams::Depth> for DepthUpdate {
+    fn from(depth: usd_futures::web_socket_streams::Depth) -> Self {
+        Self {
+            first_update_id: depth.first_update_id,
+            final_update_id: depth.final_update_id,
+            prev_final_update_id: Some(depth.prev_final_update_id),
+            bids: depth.bids,
+            asks: depth.asks,
+        }
+    }
+}
+
+/// A snapshot of the order book, from the REST API.
+#[derive(Debug)]
+pub(super) struct Snapshot {
+    pub(super) last_update_id: i64,
+    pub(super) bids: Vec<(Decimal, Decimal)>,
+    pub(super) asks: Vec<(Decimal, Decimal)>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(super) enum Outcome {
+    /// The update is buffered, or the book waits for the update following the
+    /// snapshot.
+    Pending,
+    /// The update is older than the book.
+    Ignored,
+    /// The book is in sync as of the update id.
+    Synced(i64),
+    Updated(i64),
+    /// Updates are missing, the book is discarded until a new snapshot.
+    Gap,
+}
+
+#[derive(Debug)]
+enum State {
+    /// Updates received while waiting for a snapshot.
+    Buffering(Vec<DepthUpdate>),
+    /// A snapshot is applied, waiting for the update that follows it.
+    Syncing(OrderBook),
+    Synced(OrderBook),
+}
+
+/// Binance's procedure to keep a local order book in sync with the depth
+/// stream of a market.
+#[derive(Debug)]
+pub(super) struct BookSync {
+    market: Market,
+    state: State,
+}
+
+impl BookSync {
+    pub(super) fn new(market: Market) -> Self {
+        Self {
+            market,
+            state: State::Buffering(Vec::new()),
+        }
+    }
+
+    /// The book, once in sync.
+    pub(super) fn book(&self) -> Option<&OrderBook> {
+        match &self.state {
+            State::Synced(book) => Some(book),
+            _ => None,
+        }
+    }
+
+    /// Whether updates are buffered and a snapshot should be fetched.
+    pub(super) fn needs_snapshot(&self) -> bool {
+        matches!(&self.state, State::Buffering(updates) if !updates.is_empty())
+    }
+
+    /// Discard the book and the buffered updates, e.g. when the connection is
+    /// lost. Returns whether the book was in sync.
+    pub(super) fn reset(&mut self) -> bool {
+        let state = mem::replace(&mut self.state, State::Buffering(Vec::new()));
+        matches!(state, State::Synced(_))
+    }
+
+    pub(super) fn push(&mut self, update: DepthUpdate) -> Outcome {
+        let last_update_id = match &mut self.state {
+            State::Buffering(updates) => {
+                updates.push(update);
+                return Outcome::Pending;
+            }
+            State::Syncing(book) | State::Synced(book) => book.last_update_id(),
+        };
+        let syncing = matches!(self.state, State::Syncing(_));
+
+        let follows = match (self.market, syncing) {
+            // The first update must contain `lastUpdateId + 1`, then every
+            // update must start right after the previous one.
+            (Market::Spot, _) => {
+                if update.final_update_id <= last_update_id {
+                    return Outcome::Ignored;
+                }
+                update.first_update_id <= last_update_id + 1
+            }
+            // The first update must contain `lastUpdateId`, then the `pu` of
+            // every update must be the `u` of the previous one.
+            (Market::UsdFutures, true) => {
+                if update.final_update_id < last_update_id {
+                    return Outcome::Ignored;
+                }
+                update.first_update_id <= last_update_id
+            }
+            (Market::UsdFutures, false) => {
+                if update.prev_final_update_id == Some(last_update_id) {
+                    true
+                } else if update.final_update_id <= last_update_id {
+                    return Outcome::Ignored;
+                } else {
+                    false
+                }
+            }
+        };
+        if !follows {
+            self.state = State::Buffering(vec![update]);
+            return Outcome::Gap;
+        }
+
+        let state = mem::replace(&mut self.state, State::Buffering(Vec::new()));
+        let (State::Syncing(mut book) | State::Synced(mut book)) = state else {
+            unreachable!("the book is syncing or synced");
+        };
+        book.update(update.final_update_id, &update.bids, &update.asks);
+        self.state = State::Synced(book);
+        if syncing {
+            Outcome::Synced(update.final_update_id)
+        } else {
+            Outcome::Updated(update.final_update_id)
+        }
+    }
+
+    /// Apply a snapshot, and the updates buffered since the first one. A gap
+    /// means that the snapshot is older than the buffered updates.
+    pub(super) fn apply_snapshot(&mut self, snapshot: Snapshot) -> Outcome {
+        let book = OrderBook::new(snapshot.last_update_id, &snapshot.bids, &snapshot.asks);
+        let state = mem::replace(&mut self.state, State::Syncing(book));
+        let State::Buffering(updates) = state else {
+            self.state = state;
+            return Outcome::Ignored;
+        };
+
+        for update in updates {
+            self.push(update);
+        }
+        match &self.state {
+            State::Buffering(_) => Outcome::Gap,
+            State::Syncing(_) => Outcome::Pending,
+            State::Synced(book) => Outcome::Synced(book.last_update_id()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn update(first: i64, last: i64, prev: Option<i64>) -> DepthUpdate {
+        DepthUpdate {
+            first_update_id: first,
+            final_update_id: last,
+            prev_final_update_id: prev,
+            bids: vec![(Decimal::new(last.into(), 0), Decimal::new(1, 0))],
+            asks: Vec::new(),
+        }
+    }
+
+    fn snapshot(last_update_id: i64) -> Snapshot {
+        Snapshot {
+            last_update_id,
+            bids: Vec::new(),
+            asks: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn spot_sync() {
+        let mut sync = BookSync::new(Market::Spot);
+        assert!(!sync.needs_snapshot());
+        assert_eq!(sync.push(update(95, 99, None)), Outcome::Pending);
+        assert_eq!(sync.push(update(100, 101, None)), Outcome::Pending);
+        assert_eq!(sync.push(update(102, 103, None)), Outcome::Pending);
+        assert!(sync.needs_snapshot());
+
+        assert_eq!(sync.apply_snapshot(snapshot(100)), Outcome::Synced(103));
+        assert_eq!(sync.book().unwrap().bids().count(), 2);
+        assert_eq!(sync.push(update(102, 103, None)), Outcome::Ignored);
+        assert_eq!(sync.push(update(104, 104, None)), Outcome::Updated(104));
+
+        assert_eq!(sync.push(update(106, 107, None)), Outcome::Gap);
+        assert!(sync.book().is_none());
+        assert!(sync.needs_snapshot());
+        // The snapshot is older than the buffered updates.
+        assert_eq!(sync.apply_snapshot(snapshot(104)), Outcome::Gap);
+        assert_eq!(sync.apply_snapshot(snapshot(105)), Outcome::Synced(107));
+    }
+
+    #[test]
+    fn spot_sync_waits_for_the_update_after_the_snapshot() {
+        let mut sync = BookSync::new(Market::Spot);
+        sync.push(update(95, 99, None));
+        assert_eq!(sync.apply_snapshot(snapshot(100)), Outcome::Pending);
+        assert!(!sync.needs_snapshot());
+        assert_eq!(sync.push(update(101, 102, None)), Outcome::Synced(102));
+        assert!(sync.reset());
+        assert!(sync.book().is_none());
+    }
+
+    #[test]
+    fn usd_futures_sync() {
+        let mut sync = BookSync::new(Market::UsdFutures);
+        sync.push(update(90, 95, Some(89)));
+        sync.push(update(96, 101, Some(95)));
+        assert_eq!(sync.apply_snapshot(snapshot(98)), Outcome::Synced(101));
+        assert_eq!(sync.push(update(96, 101, Some(95))), Outcome::Ignored);
+        // Futures update ids are not consecutive, only `pu` links them.
+        assert_eq!(
+            sync.push(update(110, 112, Some(101))),
+            Outcome::Updated(112)
+        );
+        assert_eq!(sync.push(update(120, 125, Some(115))), Outcome::Gap);
+        assert_eq!(sync.apply_snapshot(snapshot(121)), Outcome::Synced(125));
+    }
+}
@@ -0,0 +1,249 @@
+use std::{sync::Arc, time::Duration};
+
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Client, Proxy,
+};
+use url::Url;
+
+use crate::signer::{HmacSigner, Signer};
+
+use super::{RateLimitMode, RateLimiter, RestApiClient, RestApiError, RetryPolicy, ServerClock};
+
+/// Builder of [`RestApiClient`].
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use birdie::rest_api::RestApiClient;
+///
+/// let client = RestApiClient::builder("https://api.binance.com")
+///     .api_key("api_key")
+///     .secret_key("secret_key")
+///     .connect_timeout(Duration::from_secs(3))
+///     .timeout(Duration::from_secs(10))
+///     .proxy(reqwest::Proxy::all("http://127.0.0.1:8080").unwrap())
+///     .user_agent("my-bot/1.0")
+///     .recv_window(5000)
+///     .build()
+///     .unwrap();
+/// ```
+///
+/// Without a secret key or a signer, only endpoints that do not need a
+/// signature can be used. SOCKS proxies require the `socks` feature.
+pub struct RestApiClientBuilder {
+    endpoint: String,
+    api_key: String,
+    signer: Arc<dyn Signer>,
+    client: Option<Client>,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    proxy: Option<Proxy>,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    user_agent: Option<String>,
+    headers: HeaderMap,
+    recv_window: Option<i64>,
+    time_sync: bool,
+    clock: Option<Arc<ServerClock>>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    rate_limit_mode: RateLimitMode,
+    retry_policy: RetryPolicy,
+}
+
+impl RestApiClientBuilder {
+    pub fn new(endpoint: &str) -> Self {
+        Self {
+            endpoint: endpoint.to_owned(),
+            api_key: String::new(),
+            signer: Arc::new(HmacSigner::new("")),
+            client: None,
+            connect_timeout: None,
+            timeout: None,
+            read_timeout: None,
+            proxy: None,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            user_agent: None,
+            headers: HeaderMap::new(),
+            recv_window: None,
+            time_sync: true,
+            clock: None,
+            rate_limiter: None,
+            rate_limit_mode: RateLimitMode::default(),
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    pub fn api_key(mut self, api_key: &str) -> Self {
+        self.api_key = api_key.to_owned();
+        self
+    }
+
+    /// Sign requests with an HMAC secret key.
+    pub fn secret_key(self, secret_key: &str) -> Self {
+        self.signer(HmacSigner::new(secret_key))
+    }
+
+    /// Sign requests with the given signer. See [`crate::signer`] for the
+    /// supported key types.
+    pub fn signer<S>(mut self, signer: S) -> Self
+    where
+        S: Signer + 'static,
+    {
+        self.signer = Arc::new(signer);
+        self
+    }
+
+    /// Use an existing HTTP client, e.g. one that is shared with other parts
+    /// of the application. The timeout, proxy, pool and user agent options
+    /// are ignored in this case, as they are properties of the HTTP client.
+    pub fn client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Timeout for the connect phase of a request.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Total timeout of a request, from connecting until the response body
+    /// has been read.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Timeout for every read of the response.
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    /// HTTP(S) proxy, or SOCKS proxy with the `socks` feature.
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Maximum number of idle connections kept per host.
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// How long idle connections are kept in the pool.
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.to_owned());
+        self
+    }
+
+    /// Header sent with every request. Unlike the other HTTP options, default
+    /// headers also apply to a client set with
+    /// [`RestApiClientBuilder::client`].
+    pub fn default_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Default `recvWindow` in milliseconds for signed requests whose params
+    /// do not set one. The value cannot be greater than 60000.
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    /// Whether to calibrate the clock against the server time automatically.
+    /// Enabled by default. When disabled, the local clock is used as is.
+    pub fn time_sync(mut self, time_sync: bool) -> Self {
+        self.time_sync = time_sync;
+        self
+    }
+
+    /// Share a clock with other clients of the same server, e.g. a
+    /// [`WebSocketApiClient`](crate::web_socket_api::WebSocketApiClient), so
+    /// that they are calibrated together.
+    pub fn clock(mut self, clock: Arc<ServerClock>) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    /// Share a rate limiter with other clients that use the same IP or
+    /// account. Its own mode applies.
+    pub fn rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// What to do with requests that would go over a rate limit. Requests wait
+    /// by default. Ignored when a limiter is shared with
+    /// [`RestApiClientBuilder::rate_limiter`].
+    pub fn rate_limit_mode(mut self, mode: RateLimitMode) -> Self {
+        self.rate_limit_mode = mode;
+        self
+    }
+
+    /// Retry policy for failed requests of retryable endpoints, see
+    /// [`Endpoint::retryable`](super::Endpoint::retryable).
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn build(self) -> Result<RestApiClient, RestApiError> {
+        let endpoint = Url::parse(&self.endpoint)?;
+        let client = match self.client {
+            Some(client) => client,
+            None => {
+                let mut builder = Client::builder();
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(timeout) = self.read_timeout {
+                    builder = builder.read_timeout(timeout);
+                }
+                if let Some(proxy) = self.proxy {
+                    builder = builder.proxy(proxy);
+                }
+                if let Some(max) = self.pool_max_idle_per_host {
+                    builder = builder.pool_max_idle_per_host(max);
+                }
+                if let Some(timeout) = self.pool_idle_timeout {
+                    builder = builder.pool_idle_timeout(timeout);
+                }
+                if let Some(user_agent) = self.user_agent {
+                    builder = builder.user_agent(user_agent);
+                }
+                builder.build()?
+            }
+        };
+
+        let rate_limiter = self
+            .rate_limiter
+            .unwrap_or_else(|| Arc::new(RateLimiter::new(self.rate_limit_mode)));
+
+        Ok(RestApiClient {
+            client,
+            endpoint,
+            api_key: self.api_key,
+            headers: self.headers,
+            signer: self.signer,
+            clock: self.clock.unwrap_or_default(),
+            time_sync: self.time_sync,
+            recv_window: self.recv_window,
+            rate_limiter,
+            retry_policy: self.retry_policy,
+        })
+    }
+}
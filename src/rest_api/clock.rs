@@ -0,0 +1,51 @@
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+
+use jiff::Timestamp;
+
+/// Keeps track of the offset between the local clock and Binance's server
+/// clock, so that the `timestamp` of signed requests is not rejected with
+/// [`BinanceErrorCode::InvalidTimestamp`](crate::errors::BinanceErrorCode::InvalidTimestamp)
+/// on hosts with clock drift.
+#[derive(Debug, Default)]
+pub struct ServerClock {
+    offset: AtomicI64,
+    synced: AtomicBool,
+}
+
+impl ServerClock {
+    /// Current server time in milliseconds, estimated from the local clock.
+    pub fn now(&self) -> i64 {
+        Timestamp::now().as_millisecond() + self.offset()
+    }
+
+    /// Offset in milliseconds to add to the local clock to get the server time.
+    pub fn offset(&self) -> i64 {
+        self.offset.load(Ordering::Relaxed)
+    }
+
+    /// Whether the clock has been calibrated against the server at least once.
+    pub fn is_synced(&self) -> bool {
+        self.synced.load(Ordering::Relaxed)
+    }
+
+    /// Calibrate the clock with a server time that was returned by a request
+    /// sent at `sent_at` and answered at `received_at` (both local time). The
+    /// server time is assumed to be taken halfway through the round trip.
+    pub(crate) fn calibrate(&self, server_time: i64, sent_at: i64, received_at: i64) {
+        let local_time = sent_at + (received_at - sent_at) / 2;
+        self.offset
+            .store(server_time - local_time, Ordering::Relaxed);
+        self.synced.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Path of the check server time endpoint of the API that `path` belongs to.
+pub(crate) fn server_time_path(path: &str) -> &'static str {
+    if path.starts_with("/fapi/") {
+        "/fapi/v1/time"
+    } else if path.starts_with("/dapi/") {
+        "/dapi/v1/time"
+    } else {
+        "/api/v3/time"
+    }
+}
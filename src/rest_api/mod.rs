@@ -75,23 +75,79 @@
 //! ```
 //!
 //! Now, try it yourself!
+//!
+//! ## Signed Requests
+//!
+//! The `timestamp` parameter of signed requests is injected by the client at
+//! signing time, using a [`ServerClock`] that is calibrated against the server
+//! time on the first signed request, and re-calibrated whenever Binance
+//! rejects a request with
+//! [`BinanceErrorCode::InvalidTimestamp`](crate::errors::BinanceErrorCode::InvalidTimestamp).
+//! A default `recvWindow` can be set with
+//! [`RestApiClientBuilder::recv_window`], it
+//! is used when the request params do not set one.
+//!
+//! ## Rate Limits
+//!
+//! Every [`Endpoint`] knows its request weight, and the client keeps track of
+//! the used weight and order count with a [`RateLimiter`]. Once the limits are
+//! known, requests that would go over them are held until the window resets,
+//! or rejected with [`RestApiError::RateLimited`] in [`RateLimitMode::Reject`]:
+//!
+//! ```no_run
+//! use birdie::rest_api::{Endpoint, RateLimitMode, RestApiClient};
+//! use birdie::spot::general::ExchangeInfoParams;
+//!
+//! # async fn run() {
+//! let client = RestApiClient::builder("https://api.binance.com")
+//!     .api_key("api_key")
+//!     .secret_key("api_secret")
+//!     .rate_limit_mode(RateLimitMode::Reject)
+//!     .build()
+//!     .unwrap();
+//! let info = client.general().exchange_info().request(ExchangeInfoParams::new()).await.unwrap();
+//! client.rate_limiter().set_rate_limits(&info.rate_limits);
+//!
+//! for usage in client.rate_limit_usage() {
+//!     println!("{usage:?}");
+//! }
+//! # }
+//! ```
+mod builder;
+mod clock;
+mod rate_limit;
+mod retry;
+
+pub use builder::RestApiClientBuilder;
+pub use clock::ServerClock;
+pub use rate_limit::{RateLimitMode, RateLimitUsage, RateLimiter};
+pub use retry::RetryPolicy;
 
-use reqwest::{Client, Method, RequestBuilder};
-use serde::Serializer;
+use std::{future::Future, sync::Arc, time::Duration};
+
+use jiff::Timestamp;
+use reqwest::{
+    header::{HeaderMap, RETRY_AFTER},
+    Client, Method, RequestBuilder,
+};
+use serde::{Serialize, Serializer};
 use thiserror::Error;
 use tracing::debug;
 use tracing::error;
 use tracing::info;
-use url::Url;
+use url::{form_urlencoded, Url};
 
 use crate::enums::SecurityType;
-use crate::errors::BinanceError;
-use crate::hmac_signature;
+use crate::errors::{BinanceError, BinanceErrorCode};
+use crate::signer::{Signer, SignerError};
+pub(crate) use rate_limit::RequestCost;
 
+use crate::coin_futures;
 use crate::margin;
 use crate::spot;
 use crate::spot::account;
 use crate::spot::general;
+use crate::spot::general::{CheckServerTimeParams, CheckServerTimeResponse};
 use crate::spot::market;
 use crate::spot::trade;
 use crate::spot::user_data_stream;
@@ -109,28 +165,175 @@ pub enum RestApiError {
     Json(#[from] serde_json::Error),
     #[error("query string parse error: {0}")]
     QueryString(#[from] serde_qs::Error),
-    #[error("Hmac error: {0}")]
-    Hmac(#[from] hmac::digest::InvalidLength),
+    #[error("signer error: {0}")]
+    Signer(#[from] SignerError),
     #[error("binance error: {0}")]
-    Binance(String, Option<BinanceError>),
+    Binance(Box<ErrorResponse>),
+    #[error("rate limit exceeded, retry after {0:?}")]
+    RateLimited(Duration),
+}
+
+impl RestApiError {
+    /// The Binance error code, if the response body contains one.
+    pub fn error_code(&self) -> Option<BinanceErrorCode> {
+        match self {
+            RestApiError::Binance(res) => res.error.as_ref().map(|error| error.code),
+            _ => None,
+        }
+    }
+
+    /// Whether the request may succeed if it is sent again: a transport
+    /// error, an HTTP 5xx or 429 response, or one of the
+    /// [`BinanceErrorCode::Disconnected`] and [`BinanceErrorCode::ServerBusy`]
+    /// errors. Never holds when [`RestApiError::is_execution_unknown`] does,
+    /// as sending the request again may execute it twice.
+    pub fn is_retryable(&self) -> bool {
+        if self.is_execution_unknown() {
+            return false;
+        }
+        match self {
+            RestApiError::Reqwest(err) => err.is_timeout() || err.is_connect() || err.is_request(),
+            RestApiError::Binance(res) => {
+                res.status >= 500
+                    || res.status == 429
+                    || matches!(
+                        self.error_code(),
+                        Some(BinanceErrorCode::Disconnected | BinanceErrorCode::ServerBusy)
+                    )
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the request was rejected because of a rate limit, either by
+    /// the client-side rate limiter or by Binance (HTTP 429 and 418).
+    pub fn is_rate_limited(&self) -> bool {
+        match self {
+            RestApiError::RateLimited(_) => true,
+            RestApiError::Binance(res) => {
+                res.status == 429
+                    || res.status == 418
+                    || matches!(
+                        self.error_code(),
+                        Some(BinanceErrorCode::TooManyRequests | BinanceErrorCode::TooManyOrders)
+                    )
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the request may or may not have been executed, i.e.
+    /// [`BinanceErrorCode::UnexpectedResp`] (-1006) or
+    /// [`BinanceErrorCode::Timeout`] (-1007). The status of an order has to be
+    /// queried before sending it again.
+    pub fn is_execution_unknown(&self) -> bool {
+        matches!(
+            self.error_code(),
+            Some(BinanceErrorCode::UnexpectedResp | BinanceErrorCode::Timeout)
+        )
+    }
+}
+
+/// Error response of the REST API.
+#[derive(Clone, Debug)]
+pub struct ErrorResponse {
+    /// HTTP status code.
+    pub status: u16,
+    /// Raw response body.
+    pub body: String,
+    /// The response body, if it is a Binance error.
+    pub error: Option<BinanceError>,
+    /// Response headers, including the rate limit headers.
+    pub headers: HeaderMap,
+}
+
+impl ErrorResponse {
+    /// Delay from the `Retry-After` header, sent with HTTP 429 and 418.
+    pub fn retry_after(&self) -> Option<Duration> {
+        let secs = self.headers.get(RETRY_AFTER)?.to_str().ok()?.parse().ok()?;
+        Some(Duration::from_secs(secs))
+    }
+
+    /// Usage reported by the `X-MBX-USED-WEIGHT-*` and `X-MBX-ORDER-COUNT-*`
+    /// headers.
+    pub fn rate_limit_usage(&self) -> Vec<RateLimitUsage> {
+        self.headers
+            .iter()
+            .filter_map(|(name, value)| {
+                let (rate_limit_type, interval_num, interval) =
+                    rate_limit::parse_header_name(name.as_str())?;
+                Some(RateLimitUsage {
+                    rate_limit_type: rate_limit_type.to_owned(),
+                    interval,
+                    interval_num,
+                    used: value.to_str().ok()?.parse().ok()?,
+                    limit: None,
+                })
+            })
+            .collect()
+    }
+}
+
+impl std::fmt::Display for ErrorResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.error {
+            Some(error) => write!(f, "{} {} {}", self.status, error.code.code(), error.msg),
+            None => write!(f, "{} {}", self.status, self.body),
+        }
+    }
 }
 
 pub struct RestApiClient {
     pub(self) client: Client,
     pub(self) endpoint: Url,
     api_key: String,
-    secret_key: String,
+    headers: HeaderMap,
+    signer: Arc<dyn Signer>,
+    clock: Arc<ServerClock>,
+    time_sync: bool,
+    recv_window: Option<i64>,
+    rate_limiter: Arc<RateLimiter>,
+    retry_policy: RetryPolicy,
 }
 
 impl RestApiClient {
+    /// Create a client that signs requests with an HMAC secret key.
     pub fn new(endpoint: &str, api_key: &str, secret_key: &str) -> Result<Self, RestApiError> {
-        let base_url = Url::parse(endpoint)?;
-        Ok(Self {
-            client: Client::new(),
-            endpoint: base_url,
-            api_key: api_key.to_string(),
-            secret_key: secret_key.to_string(),
-        })
+        Self::builder(endpoint)
+            .api_key(api_key)
+            .secret_key(secret_key)
+            .build()
+    }
+
+    /// Create a client that signs requests with the given signer. See
+    /// [`crate::signer`] for the supported key types.
+    pub fn with_signer<S>(endpoint: &str, api_key: &str, signer: S) -> Result<Self, RestApiError>
+    where
+        S: Signer + 'static,
+    {
+        Self::builder(endpoint)
+            .api_key(api_key)
+            .signer(signer)
+            .build()
+    }
+
+    /// Create a builder to configure the HTTP client and the request options.
+    pub fn builder(endpoint: &str) -> RestApiClientBuilder {
+        RestApiClientBuilder::new(endpoint)
+    }
+
+    /// The clock used to timestamp signed requests.
+    pub fn clock(&self) -> &Arc<ServerClock> {
+        &self.clock
+    }
+
+    pub fn rate_limiter(&self) -> &Arc<RateLimiter> {
+        &self.rate_limiter
+    }
+
+    /// Snapshot of the current rate limit usage.
+    pub fn rate_limit_usage(&self) -> Vec<RateLimitUsage> {
+        self.rate_limiter.usage(self.clock.now())
     }
 
     pub fn account(&self) -> account::RestApiHandler {
@@ -165,22 +368,27 @@ impl RestApiClient {
         usd_futures::RestApiCategory::new(self)
     }
 
+    pub fn coin_futures(&self) -> coin_futures::RestApiCategory {
+        coin_futures::RestApiCategory::new(self)
+    }
+
     pub(self) async fn request<P, R>(
         &self,
         method: Method,
         endpoint: &str,
         params: P,
+        cost: RequestCost,
+        retryable: bool,
     ) -> Result<R, RestApiError>
     where
         P: Params,
         R: Response,
     {
-        let mut url = self.endpoint.join(endpoint)?;
-        url.set_query(Some(&params.as_query()?));
-        info!("send request to {url}");
-
-        let req = self.client.request(method, url);
-        Self::send_request(req).await
+        let query = params.as_query()?;
+        self.with_retry(retryable, &method, || {
+            self.send_unsigned(method.clone(), endpoint, &query, cost, false)
+        })
+        .await
     }
 
     pub(self) async fn auth_request<P, R>(
@@ -188,20 +396,18 @@ impl RestApiClient {
         method: Method,
         endpoint: &str,
         params: P,
+        cost: RequestCost,
+        retryable: bool,
     ) -> Result<R, RestApiError>
     where
         P: Params,
         R: Response,
     {
-        let mut url = self.endpoint.join(endpoint)?;
-        url.set_query(Some(&params.as_query()?));
-        info!("send auth request to {url}");
-
-        let req = self
-            .client
-            .request(method, url)
-            .header("X-MBX-APIKEY", &self.api_key);
-        Self::send_request(req).await
+        let query = params.as_query()?;
+        self.with_retry(retryable, &method, || {
+            self.send_unsigned(method.clone(), endpoint, &query, cost, true)
+        })
+        .await
     }
 
     pub(self) async fn signed_request<P, R>(
@@ -209,38 +415,210 @@ impl RestApiClient {
         method: Method,
         endpoint: &str,
         params: P,
+        cost: RequestCost,
+        retryable: bool,
     ) -> Result<R, RestApiError>
     where
         P: Params,
         R: Response,
     {
-        let mut url = self.endpoint.join(endpoint)?;
         let query = params.as_query()?;
-        let signature = hmac_signature(&self.secret_key, &query)?;
-        let query = format!("{query}&signature={signature}");
-        url.set_query(Some(&query));
+        self.with_retry(retryable, &method, || {
+            self.send_synced(method.clone(), endpoint, &query, cost)
+        })
+        .await
+    }
+
+    /// Send the request until it succeeds, fails with an error that is not
+    /// worth retrying, or the retry policy gives up.
+    async fn with_retry<R, F, Fut>(
+        &self,
+        retryable: bool,
+        method: &Method,
+        send: F,
+    ) -> Result<R, RestApiError>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<R, RestApiError>>,
+    {
+        let mut attempt = 0;
+        loop {
+            let backoff = match send().await {
+                Err(err) if retryable && self.retry_policy.should_retry(attempt, method, &err) => {
+                    // The rate limiter holds the next attempt until the
+                    // Retry-After delay expires.
+                    if err.is_rate_limited() {
+                        Duration::ZERO
+                    } else {
+                        self.retry_policy.backoff(attempt)
+                    }
+                }
+                res => return res,
+            };
+
+            attempt += 1;
+            info!("request failed, retry {attempt} in {backoff:?}");
+            tokio::time::sleep(backoff).await;
+        }
+    }
+
+    async fn send_unsigned<R>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        query: &str,
+        cost: RequestCost,
+        auth: bool,
+    ) -> Result<R, RestApiError>
+    where
+        R: Response,
+    {
+        let mut url = self.endpoint.join(endpoint)?;
+        url.set_query(Some(query));
+
+        let mut req = self
+            .client
+            .request(method, url.clone())
+            .headers(self.headers.clone());
+        if auth {
+            info!("send auth request to {url}");
+            req = req.header("X-MBX-APIKEY", &self.api_key);
+        } else {
+            info!("send request to {url}");
+        }
+        self.send_request(req, cost).await
+    }
+
+    /// Send a signed request, calibrating the clock first if needed, and again
+    /// if the timestamp is rejected.
+    async fn send_synced<R>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        query: &str,
+        cost: RequestCost,
+    ) -> Result<R, RestApiError>
+    where
+        R: Response,
+    {
+        if self.time_sync && !self.clock.is_synced() {
+            self.sync_time(endpoint).await?;
+        }
+
+        match self
+            .send_signed(method.clone(), endpoint, query, cost)
+            .await
+        {
+            Err(err)
+                if self.time_sync
+                    && err.error_code() == Some(BinanceErrorCode::InvalidTimestamp) => {}
+            res => return res,
+        }
+
+        info!("timestamp rejected, re-calibrating clock");
+        self.sync_time(endpoint).await?;
+        self.send_signed(method, endpoint, query, cost).await
+    }
+
+    async fn send_signed<R>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        query: &str,
+        cost: RequestCost,
+    ) -> Result<R, RestApiError>
+    where
+        R: Response,
+    {
+        let mut url = self.endpoint.join(endpoint)?;
+        url.set_query(Some(&self.sign_query(query)?));
         info!("send signed request to {url}");
 
         let req = self
             .client
             .request(method, url)
+            .headers(self.headers.clone())
             .header("X-MBX-APIKEY", &self.api_key);
-        Self::send_request(req).await
+        self.send_request(req, cost).await
+    }
+
+    /// Append `recvWindow` (if not set), `timestamp` and `signature` to the
+    /// query string.
+    fn sign_query(&self, query: &str) -> Result<String, RestApiError> {
+        let mut serializer = form_urlencoded::Serializer::new(query.to_owned());
+        if let Some(recv_window) = self.recv_window {
+            if !form_urlencoded::parse(query.as_bytes()).any(|(k, _)| k == "recvWindow") {
+                serializer.append_pair("recvWindow", &recv_window.to_string());
+            }
+        }
+        serializer.append_pair("timestamp", &self.clock.now().to_string());
+        let query = serializer.finish();
+
+        let signature = self.signer.sign(&query)?;
+        // Ed25519 and RSA signatures are base64 encoded, thus may contain
+        // characters that need to be escaped.
+        let signature: String = form_urlencoded::byte_serialize(signature.as_bytes()).collect();
+        Ok(format!("{query}&signature={signature}"))
+    }
+
+    /// Calibrate the clock against the check server time endpoint of the API
+    /// that `endpoint` belongs to.
+    async fn sync_time(&self, endpoint: &str) -> Result<(), RestApiError> {
+        let path = clock::server_time_path(endpoint);
+        let cost = RequestCost::new(path, &Method::GET, SecurityType::None, 1);
+        let sent_at = Timestamp::now().as_millisecond();
+        let res: CheckServerTimeResponse = self
+            .request(Method::GET, path, CheckServerTimeParams::new(), cost, false)
+            .await?;
+        let received_at = Timestamp::now().as_millisecond();
+        self.clock.calibrate(res.server_time, sent_at, received_at);
+        debug!("clock offset: {}ms", self.clock.offset());
+        Ok(())
     }
 
-    async fn send_request<R>(req: RequestBuilder) -> Result<R, RestApiError>
+    async fn send_request<R>(
+        &self,
+        req: RequestBuilder,
+        cost: RequestCost,
+    ) -> Result<R, RestApiError>
     where
         R: Response,
     {
+        self.acquire(cost).await?;
         let res = req.send().await?;
+        self.rate_limiter
+            .update(res.status(), res.headers(), self.clock.now());
         if res.status().is_success() {
             debug!("response: {res:?}");
             Ok(res.json().await?)
         } else {
-            let status = res.status().to_string();
-            let error = res.json::<BinanceError>().await.ok();
-            error!("response: {status}, {error:?}");
-            Err(RestApiError::Binance(status, error))
+            let status = res.status().as_u16();
+            let headers = res.headers().clone();
+            let body = res.text().await?;
+            let error = serde_json::from_str::<BinanceError>(&body).ok();
+            error!("response: {status}, {body}");
+            Err(RestApiError::Binance(Box::new(ErrorResponse {
+                status,
+                body,
+                error,
+                headers,
+            })))
+        }
+    }
+
+    /// Wait until the request fits in the rate limits, or fail in
+    /// [`RateLimitMode::Reject`].
+    async fn acquire(&self, cost: RequestCost) -> Result<(), RestApiError> {
+        loop {
+            let wait = match self.rate_limiter.try_acquire(cost, self.clock.now()) {
+                Ok(()) => return Ok(()),
+                Err(wait) => Duration::from_millis(wait as u64),
+            };
+            if self.rate_limiter.mode() == RateLimitMode::Reject {
+                return Err(RestApiError::RateLimited(wait));
+            }
+            info!("rate limit reached, waiting {wait:?}");
+            tokio::time::sleep(wait).await;
         }
     }
 }
@@ -260,6 +638,17 @@ where
     }
 }
 
+/// Serialize the value as a JSON string, for the params taking a list of
+/// objects, e.g. `batchOrders`.
+pub fn serialize_json<S, T>(v: &T, s: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    let json = serde_json::to_string(v).map_err(serde::ser::Error::custom)?;
+    s.serialize_str(&json)
+}
+
 #[async_trait::async_trait]
 pub trait Endpoint {
     type Params: Params;
@@ -270,29 +659,84 @@ pub trait Endpoint {
     fn method(&self) -> Method;
     fn security_type(&self) -> SecurityType;
 
+    /// Request weight of the endpoint for the given params.
+    fn weight(&self, _params: &Self::Params) -> u32 {
+        1
+    }
+
+    /// Whether a failed request can be sent again without side effects, see
+    /// [`RetryPolicy`]. Only `GET` requests are retried by default.
+    fn retryable(&self, _params: &Self::Params) -> bool {
+        self.method() == Method::GET
+    }
+
     async fn request(&self, params: Self::Params) -> Result<Self::Response, RestApiError> {
+        let cost = RequestCost::new(
+            self.path(),
+            &self.method(),
+            self.security_type(),
+            self.weight(&params),
+        );
+        let retryable = self.retryable(&params);
         match self.security_type() {
             SecurityType::None => {
                 self.client()
-                    .request(self.method(), self.path(), params)
+                    .request(self.method(), self.path(), params, cost, retryable)
                     .await
             }
             SecurityType::UserStream => {
                 self.client()
-                    .auth_request(self.method(), self.path(), params)
+                    .auth_request(self.method(), self.path(), params, cost, retryable)
                     .await
             }
             _ => {
                 self.client()
-                    .signed_request(self.method(), self.path(), params)
+                    .signed_request(self.method(), self.path(), params, cost, retryable)
                     .await
             }
         }
     }
 }
 
+/// Implement [`Endpoint`] for an endpoint type. The security type defaults to
+/// [`SecurityType::None`]. The optional `weight` and `retry` arguments
+/// override [`Endpoint::weight`] and [`Endpoint::retryable`], each is either a
+/// constant or a closure over the params:
+///
+/// ```ignore
+/// endpoint!(
+///     "/api/v3/depth",
+///     Method::GET,
+///     OrderBookEndpoint,
+///     OrderBookParams,
+///     OrderBookResponse,
+///     weight = |params| match params.limit { .. }
+/// );
+/// ```
 macro_rules! endpoint {
-    ($path:literal, $method:expr, $name:ident, $params:ty, $response:ty) => {
+    ($path:literal, $method:expr, $name:ident, $params:ty, $response:ty $(, $($opts:tt)+)?) => {
+        $crate::rest_api::endpoint!(
+            @impl $path,
+            $method,
+            $crate::enums::SecurityType::None,
+            $name,
+            $params,
+            $response,
+            [$($($opts)+)?]
+        );
+    };
+    ($path:literal, $method:expr, $security:expr, $name:ident, $params:ty, $response:ty $(, $($opts:tt)+)?) => {
+        $crate::rest_api::endpoint!(
+            @impl $path,
+            $method,
+            $security,
+            $name,
+            $params,
+            $response,
+            [$($($opts)+)?]
+        );
+    };
+    (@impl $path:literal, $method:expr, $security:expr, $name:ident, $params:ty, $response:ty, [$($opts:tt)*]) => {
         impl crate::Params for $params {}
         impl crate::Response for $response {}
 
@@ -314,35 +758,40 @@ macro_rules! endpoint {
             }
 
             fn security_type(&self) -> $crate::enums::SecurityType {
-                $crate::enums::SecurityType::None
+                $security
             }
+
+            $crate::rest_api::endpoint!(@opts $($opts)*);
         }
     };
-    ($path:literal, $method:expr, $security:expr, $name:ident, $params:ty, $response:ty) => {
-        impl crate::Params for $params {}
-        impl crate::Response for $response {}
-
-        #[async_trait::async_trait]
-        impl crate::rest_api::Endpoint for $name<'_> {
-            type Params = $params;
-            type Response = $response;
-
-            fn client(&self) -> &crate::rest_api::RestApiClient {
-                self.client
-            }
+    (@opts) => {};
+    (@opts weight = |$params:ident| $weight:expr $(, $($rest:tt)*)?) => {
+        fn weight(&self, $params: &Self::Params) -> u32 {
+            $weight
+        }
 
-            fn path(&self) -> &str {
-                $path
-            }
+        $crate::rest_api::endpoint!(@opts $($($rest)*)?);
+    };
+    (@opts weight = $weight:expr $(, $($rest:tt)*)?) => {
+        fn weight(&self, _params: &Self::Params) -> u32 {
+            $weight
+        }
 
-            fn method(&self) -> reqwest::Method {
-                $method
-            }
+        $crate::rest_api::endpoint!(@opts $($($rest)*)?);
+    };
+    (@opts retry = |$params:ident| $retry:expr $(, $($rest:tt)*)?) => {
+        fn retryable(&self, $params: &Self::Params) -> bool {
+            $retry
+        }
 
-            fn security_type(&self) -> $crate::enums::SecurityType {
-                $security
-            }
+        $crate::rest_api::endpoint!(@opts $($($rest)*)?);
+    };
+    (@opts retry = $retry:expr $(, $($rest:tt)*)?) => {
+        fn retryable(&self, _params: &Self::Params) -> bool {
+            $retry
         }
+
+        $crate::rest_api::endpoint!(@opts $($($rest)*)?);
     };
 }
 
@@ -380,4 +829,235 @@ mod tests {
             "v=%5B%22a%22%2C%22b%22%5D"
         );
     }
+
+    #[test]
+    fn json() {
+        #[derive(serde::Serialize)]
+        struct Test {
+            #[serde(serialize_with = "serialize_json")]
+            v: Vec<i64>,
+        }
+
+        let t = Test { v: vec![1, 2] };
+        assert_eq!(serde_qs::to_string(&t).unwrap(), "v=%5B1%2C2%5D");
+    }
+
+    #[test]
+    fn sign_query() {
+        let client = RestApiClient::builder("https://api.binance.com")
+            .api_key("key")
+            .secret_key("secret")
+            .recv_window(3000)
+            .build()
+            .unwrap();
+
+        let query = client.sign_query("symbol=BTCUSDT").unwrap();
+        let pairs: Vec<_> = form_urlencoded::parse(query.as_bytes()).collect();
+        let keys: Vec<_> = pairs.iter().map(|(k, _)| k.as_ref()).collect();
+        assert_eq!(keys, ["symbol", "recvWindow", "timestamp", "signature"]);
+        assert_eq!(pairs[1].1, "3000");
+
+        // recvWindow set by the params takes precedence.
+        let query = client.sign_query("recvWindow=1000").unwrap();
+        let pairs: Vec<_> = form_urlencoded::parse(query.as_bytes()).collect();
+        let keys: Vec<_> = pairs.iter().map(|(k, _)| k.as_ref()).collect();
+        assert_eq!(keys, ["recvWindow", "timestamp", "signature"]);
+        assert_eq!(pairs[0].1, "1000");
+    }
+
+    #[test]
+    fn rate_limiter() {
+        use crate::enums::{RateLimit, RateLimitIntervals};
+        use reqwest::{header::HeaderMap, StatusCode};
+
+        let limiter = RateLimiter::default();
+        limiter.set_rate_limits(&[RateLimit {
+            rate_limit_type: "REQUEST_WEIGHT".to_owned(),
+            interval: RateLimitIntervals::Minute,
+            interval_num: 1,
+            limit: 10,
+            count: None,
+        }]);
+        let cost = RequestCost::new("/api/v3/depth", &Method::GET, SecurityType::None, 5);
+        let now = 60_000;
+
+        assert_eq!(limiter.try_acquire(cost, now), Ok(()));
+        assert_eq!(limiter.try_acquire(cost, now), Ok(()));
+        assert_eq!(limiter.try_acquire(cost, now + 1_000), Err(59_000));
+        // The window resets on the next minute.
+        assert_eq!(limiter.try_acquire(cost, now + 60_000), Ok(()));
+
+        // The Web Socket API methods that place orders count as orders.
+        assert_eq!(RequestCost::web_socket("order.place", 1).orders, 1);
+        assert_eq!(RequestCost::web_socket("orderList.place.oco", 1).orders, 1);
+        assert_eq!(RequestCost::web_socket("order.modify", 1).orders, 1);
+        assert_eq!(RequestCost::web_socket("order.test", 1).orders, 0);
+        assert_eq!(RequestCost::web_socket("order.cancel", 1).orders, 0);
+
+        // The used weight reported by the server takes precedence.
+        let mut headers = HeaderMap::new();
+        headers.insert("x-mbx-used-weight-1m", "2".parse().unwrap());
+        headers.insert("x-mbx-order-count-10s", "1".parse().unwrap());
+        limiter.update(StatusCode::OK, &headers, now + 60_000);
+        let usage = limiter.usage(now + 60_000);
+        assert_eq!(usage.len(), 2);
+        assert_eq!(usage[0].used, 2);
+        assert_eq!(usage[0].limit, Some(10));
+        assert_eq!(usage[1].rate_limit_type, "ORDERS");
+        assert_eq!(usage[1].interval, RateLimitIntervals::Second);
+        assert_eq!(usage[1].interval_num, 10);
+        assert_eq!(usage[1].limit, None);
+
+        // Retry-After blocks every request.
+        let mut headers = HeaderMap::new();
+        headers.insert("retry-after", "30".parse().unwrap());
+        limiter.update(StatusCode::TOO_MANY_REQUESTS, &headers, now + 60_000);
+        let cost = RequestCost::new("/api/v3/ping", &Method::GET, SecurityType::None, 1);
+        assert_eq!(limiter.try_acquire(cost, now + 61_000), Err(29_000));
+    }
+
+    #[test]
+    fn retry_policy() {
+        let policy = RetryPolicy::new()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(300))
+            .jitter(false);
+        assert_eq!(policy.backoff(0), Duration::from_millis(100));
+        assert_eq!(policy.backoff(1), Duration::from_millis(200));
+        assert_eq!(policy.backoff(2), Duration::from_millis(300));
+
+        let policy = policy.jitter(true);
+        let backoff = policy.backoff(1);
+        assert!(backoff >= Duration::from_millis(100) && backoff <= Duration::from_millis(200));
+
+        let response = |status, code: Option<BinanceErrorCode>| {
+            RestApiError::Binance(Box::new(ErrorResponse {
+                status,
+                body: String::new(),
+                error: code.map(|code| BinanceError {
+                    code,
+                    msg: String::new(),
+                }),
+                headers: HeaderMap::new(),
+            }))
+        };
+        let busy = response(400, Some(BinanceErrorCode::ServerBusy));
+        let unavailable = response(503, None);
+        let rate_limited = response(429, None);
+        let bad_request = response(400, Some(BinanceErrorCode::Other(-1999)));
+        let unknown = response(400, Some(BinanceErrorCode::Timeout));
+        let get = Method::GET;
+        assert!(policy.should_retry(0, &get, &busy));
+        assert!(policy.should_retry(0, &get, &unavailable));
+        assert!(policy.should_retry(0, &get, &unknown));
+        assert!(rate_limited.is_rate_limited());
+        assert!(!policy.should_retry(0, &get, &rate_limited));
+        assert!(!policy.should_retry(0, &get, &bad_request));
+        assert!(!policy.should_retry(3, &get, &unavailable));
+        assert!(!RetryPolicy::disabled().should_retry(0, &get, &unavailable));
+
+        // Rate limit rejections are waited out only within the maximum
+        // backoff, and IP bans never are.
+        let retry_after = |status, secs: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert("retry-after", secs.parse().unwrap());
+            RestApiError::Binance(Box::new(ErrorResponse {
+                status,
+                body: String::new(),
+                error: None,
+                headers,
+            }))
+        };
+        let held = retry_after(429, "1");
+        let banned = retry_after(418, "1");
+        let policy = policy.max_backoff(Duration::from_secs(1));
+        assert!(policy.should_retry(0, &get, &held));
+        assert!(!policy.should_retry(0, &get, &retry_after(429, "2")));
+        assert!(!policy.should_retry(0, &get, &banned));
+
+        // Other requests are only retried when they were rejected before
+        // being executed.
+        let post = Method::POST;
+        assert!(policy.should_retry(0, &post, &busy));
+        assert!(policy.should_retry(0, &post, &held));
+        assert!(!policy.should_retry(0, &post, &banned));
+        assert!(!policy.should_retry(0, &post, &rate_limited));
+        assert!(!policy.should_retry(0, &post, &unavailable));
+        assert!(!policy.should_retry(0, &post, &unknown));
+    }
+
+    #[test]
+    fn builder() {
+        use reqwest::header::{HeaderValue, USER_AGENT};
+
+        let client = RestApiClient::builder("https://api.binance.com")
+            .api_key("key")
+            .client(Client::new())
+            .default_header(USER_AGENT, HeaderValue::from_static("birdie"))
+            .recv_window(3000)
+            .build()
+            .unwrap();
+        assert_eq!(client.api_key, "key");
+        assert_eq!(client.headers[USER_AGENT], "birdie");
+        assert_eq!(client.recv_window, Some(3000));
+        assert_eq!(client.rate_limiter.mode(), RateLimitMode::Wait);
+
+        // A shared limiter keeps its own mode.
+        let rate_limiter = Arc::new(RateLimiter::new(RateLimitMode::Reject));
+        let client = RestApiClient::builder("https://api.binance.com")
+            .rate_limit_mode(RateLimitMode::Wait)
+            .rate_limiter(rate_limiter.clone())
+            .build()
+            .unwrap();
+        assert!(Arc::ptr_eq(&client.rate_limiter, &rate_limiter));
+        assert_eq!(rate_limiter.mode(), RateLimitMode::Reject);
+
+        assert!(matches!(
+            RestApiClient::builder("not a url").build(),
+            Err(RestApiError::Url(_))
+        ));
+    }
+
+    #[test]
+    fn error_response() {
+        let error: BinanceError =
+            serde_json::from_str(r#"{"code":-1121,"msg":"Invalid symbol."}"#).unwrap();
+        assert_eq!(error.code, BinanceErrorCode::BadSymbol);
+        let error: BinanceError = serde_json::from_str(r#"{"code":-9999,"msg":""}"#).unwrap();
+        assert_eq!(error.code, BinanceErrorCode::Other(-9999));
+        assert_eq!(error.code.code(), -9999);
+
+        let mut headers = HeaderMap::new();
+        headers.insert("retry-after", "12".parse().unwrap());
+        headers.insert("x-mbx-used-weight-1m", "6000".parse().unwrap());
+        let err = RestApiError::Binance(Box::new(ErrorResponse {
+            status: 418,
+            body: "banned".to_owned(),
+            error: None,
+            headers,
+        }));
+        assert!(err.is_rate_limited());
+        assert!(!err.is_retryable());
+        assert!(!err.is_execution_unknown());
+
+        // A timeout of the backend may have executed the request.
+        let unknown = RestApiError::Binance(Box::new(ErrorResponse {
+            status: 503,
+            body: String::new(),
+            error: Some(BinanceError {
+                code: BinanceErrorCode::Timeout,
+                msg: String::new(),
+            }),
+            headers: HeaderMap::new(),
+        }));
+        assert!(unknown.is_execution_unknown());
+        assert!(!unknown.is_retryable());
+
+        let RestApiError::Binance(res) = err else {
+            panic!()
+        };
+        assert_eq!(res.retry_after(), Some(Duration::from_secs(12)));
+        assert_eq!(res.rate_limit_usage()[0].used, 6000);
+        assert_eq!(res.to_string(), "418 banned");
+    }
 }
@@ -0,0 +1,353 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use reqwest::{header::HeaderMap, Method, StatusCode};
+
+use crate::enums::{RateLimit, RateLimitIntervals, SecurityType};
+
+/// What to do with a request that would go over a rate limit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RateLimitMode {
+    /// Hold the request until the rate limit window resets.
+    #[default]
+    Wait,
+    /// Fail the request with
+    /// [`RestApiError::RateLimited`](super::RestApiError::RateLimited).
+    Reject,
+}
+
+/// Usage of a rate limit in its current window.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RateLimitUsage {
+    pub rate_limit_type: String,
+    pub interval: RateLimitIntervals,
+    pub interval_num: i64,
+    pub used: i64,
+    /// The limit, if it has been set with [`RateLimiter::set_rate_limits`].
+    pub limit: Option<i64>,
+}
+
+/// What a request counts against the rate limits.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct RequestCost {
+    pub(crate) requests: u32,
+    pub(crate) weight: u32,
+    pub(crate) orders: u32,
+}
+
+impl RequestCost {
+    pub(crate) fn new(
+        path: &str,
+        method: &Method,
+        security_type: SecurityType,
+        weight: u32,
+    ) -> Self {
+        // SAPI endpoints have their own limits that are not listed by the
+        // exchange info endpoints.
+        if path.starts_with("/sapi/") {
+            return Self::default();
+        }
+        let places_order = matches!(security_type, SecurityType::Trade)
+            && matches!(*method, Method::POST | Method::PUT)
+            && !path.ends_with("/test");
+        Self {
+            requests: 1,
+            weight,
+            orders: u32::from(places_order),
+        }
+    }
+
+    /// Cost of a Web Socket API request. The methods that place or modify
+    /// orders count against the order limits, the test ones aside.
+    pub(crate) fn web_socket(method: &str, weight: u32) -> Self {
+        let places_order = !method.ends_with(".test")
+            && (method.contains(".place")
+                || method.ends_with(".modify")
+                || method.ends_with(".cancelReplace"));
+        Self {
+            requests: 1,
+            weight,
+            orders: u32::from(places_order),
+        }
+    }
+
+    fn amount(&self, rate_limit_type: &str) -> i64 {
+        match rate_limit_type {
+            "RAW_REQUESTS" => self.requests.into(),
+            "REQUEST_WEIGHT" => self.weight.into(),
+            "ORDERS" => self.orders.into(),
+            _ => 0,
+        }
+    }
+}
+
+type CounterKey = (String, RateLimitIntervals, i64);
+
+#[derive(Debug)]
+struct Counter {
+    /// Start of the window the counter belongs to, in milliseconds.
+    window: i64,
+    used: i64,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    mode: RateLimitMode,
+    limits: Vec<RateLimit>,
+    counters: HashMap<CounterKey, Counter>,
+    banned_until: Option<i64>,
+}
+
+/// Client-side rate limiter.
+///
+/// Requests are counted locally against the limits set with
+/// [`RateLimiter::set_rate_limits`], usually the `rate_limits` of the exchange
+/// info response, and the counters are overwritten with the
+/// `X-MBX-USED-WEIGHT-*` and `X-MBX-ORDER-COUNT-*` headers of every response,
+/// so the usage of other clients sharing the same IP or account is taken into
+/// account as well. A `Retry-After` header on a 429 or 418 response blocks all
+/// requests until it expires.
+///
+/// The `rateLimits` of the Web Socket API responses update the counters and
+/// the limits too, so a limiter shared with a
+/// [`WebSocketApiClient`](crate::web_socket_api::WebSocketApiClient) accounts
+/// for the orders placed over both APIs.
+///
+/// Windows are aligned to the server clock, all times are in milliseconds.
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    state: Mutex<State>,
+}
+
+impl RateLimiter {
+    pub fn new(mode: RateLimitMode) -> Self {
+        let limiter = Self::default();
+        limiter.set_mode(mode);
+        limiter
+    }
+
+    pub fn mode(&self) -> RateLimitMode {
+        self.state.lock().unwrap().mode
+    }
+
+    pub fn set_mode(&self, mode: RateLimitMode) {
+        self.state.lock().unwrap().mode = mode;
+    }
+
+    /// Set the limits to enforce. Limits of unknown types are ignored.
+    pub fn set_rate_limits(&self, rate_limits: &[RateLimit]) {
+        self.state.lock().unwrap().limits = rate_limits.to_vec();
+    }
+
+    /// Snapshot of the usage of every enforced limit and every counter
+    /// reported by the server, at time `now`.
+    pub fn usage(&self, now: i64) -> Vec<RateLimitUsage> {
+        let state = self.state.lock().unwrap();
+        let used = |key: &CounterKey| {
+            let window = window_start(now, key.1, key.2);
+            state
+                .counters
+                .get(key)
+                .filter(|counter| counter.window == window)
+                .map_or(0, |counter| counter.used)
+        };
+
+        let mut usage: Vec<_> = state
+            .limits
+            .iter()
+            .map(|limit| {
+                let key = counter_key(&limit.rate_limit_type, limit.interval, limit.interval_num);
+                RateLimitUsage {
+                    used: used(&key),
+                    limit: Some(limit.limit),
+                    rate_limit_type: key.0,
+                    interval: key.1,
+                    interval_num: key.2,
+                }
+            })
+            .collect();
+        for key in state.counters.keys() {
+            let listed = usage.iter().any(|u| {
+                u.rate_limit_type == key.0 && u.interval == key.1 && u.interval_num == key.2
+            });
+            if !listed {
+                usage.push(RateLimitUsage {
+                    rate_limit_type: key.0.clone(),
+                    interval: key.1,
+                    interval_num: key.2,
+                    used: used(key),
+                    limit: None,
+                });
+            }
+        }
+        usage
+    }
+
+    /// Ban all requests until `until`.
+    pub fn ban_until(&self, until: i64) {
+        let mut state = self.state.lock().unwrap();
+        state.banned_until = state.banned_until.max(Some(until));
+    }
+
+    /// Count the request if it fits in every limit, otherwise return how long
+    /// to wait before trying again.
+    pub(crate) fn try_acquire(&self, cost: RequestCost, now: i64) -> Result<(), i64> {
+        let mut state = self.state.lock().unwrap();
+        let State {
+            limits,
+            counters,
+            banned_until,
+            ..
+        } = &mut *state;
+
+        if let Some(until) = *banned_until {
+            if until > now {
+                return Err(until - now);
+            }
+            *banned_until = None;
+        }
+
+        let mut wait = 0;
+        for limit in limits.iter() {
+            let amount = cost.amount(&limit.rate_limit_type);
+            if amount == 0 {
+                continue;
+            }
+            let key = counter_key(&limit.rate_limit_type, limit.interval, limit.interval_num);
+            let window = window_start(now, limit.interval, limit.interval_num);
+            let used = counters
+                .get(&key)
+                .filter(|counter| counter.window == window)
+                .map_or(0, |counter| counter.used);
+            // A request that exceeds a limit on its own is let through in an
+            // empty window, otherwise it would never be sent.
+            if used > 0 && used + amount > limit.limit {
+                let end = window + interval_millis(limit.interval, limit.interval_num);
+                wait = wait.max(end - now);
+            }
+        }
+        if wait > 0 {
+            return Err(wait);
+        }
+
+        for limit in limits.iter() {
+            let amount = cost.amount(&limit.rate_limit_type);
+            if amount == 0 {
+                continue;
+            }
+            let key = counter_key(&limit.rate_limit_type, limit.interval, limit.interval_num);
+            let window = window_start(now, limit.interval, limit.interval_num);
+            let counter = counters.entry(key).or_insert(Counter { window, used: 0 });
+            if counter.window != window {
+                *counter = Counter { window, used: 0 };
+            }
+            counter.used += amount;
+        }
+        Ok(())
+    }
+
+    /// Update the counters and the limits with the `rateLimits` of a Web
+    /// Socket API response.
+    pub(crate) fn update_rate_limits(&self, rate_limits: &[RateLimit], now: i64) {
+        let mut state = self.state.lock().unwrap();
+        for rate_limit in rate_limits {
+            let key = counter_key(
+                &rate_limit.rate_limit_type,
+                rate_limit.interval,
+                rate_limit.interval_num,
+            );
+            let same_limit = |limit: &RateLimit| {
+                limit.rate_limit_type == key.0
+                    && limit.interval == key.1
+                    && limit.interval_num == key.2
+            };
+            match state.limits.iter_mut().find(|limit| same_limit(limit)) {
+                Some(limit) => limit.limit = rate_limit.limit,
+                None => state.limits.push(RateLimit {
+                    count: None,
+                    ..rate_limit.clone()
+                }),
+            }
+            if let Some(used) = rate_limit.count {
+                let window = window_start(now, key.1, key.2);
+                state.counters.insert(key, Counter { window, used });
+            }
+        }
+    }
+
+    /// Update the counters with the usage reported in the response headers.
+    pub(crate) fn update(&self, status: StatusCode, headers: &HeaderMap, now: i64) {
+        let mut state = self.state.lock().unwrap();
+        for (name, value) in headers {
+            let Some((rate_limit_type, interval_num, interval)) = parse_header_name(name.as_str())
+            else {
+                continue;
+            };
+            let Some(used) = value.to_str().ok().and_then(|v| v.parse().ok()) else {
+                continue;
+            };
+            let window = window_start(now, interval, interval_num);
+            state.counters.insert(
+                counter_key(rate_limit_type, interval, interval_num),
+                Counter { window, used },
+            );
+        }
+
+        if matches!(
+            status,
+            StatusCode::TOO_MANY_REQUESTS | StatusCode::IM_A_TEAPOT
+        ) {
+            let retry_after = headers
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse::<i64>().ok())
+                .unwrap_or(60);
+            let until = now + retry_after * 1000;
+            state.banned_until = state.banned_until.max(Some(until));
+        }
+    }
+}
+
+fn counter_key(
+    rate_limit_type: &str,
+    interval: RateLimitIntervals,
+    interval_num: i64,
+) -> CounterKey {
+    (rate_limit_type.to_owned(), interval, interval_num)
+}
+
+fn interval_millis(interval: RateLimitIntervals, interval_num: i64) -> i64 {
+    let millis = match interval {
+        RateLimitIntervals::Second => 1_000,
+        RateLimitIntervals::Minute => 60_000,
+        RateLimitIntervals::Hour => 3_600_000,
+        RateLimitIntervals::Day => 86_400_000,
+    };
+    millis * interval_num.max(1)
+}
+
+fn window_start(now: i64, interval: RateLimitIntervals, interval_num: i64) -> i64 {
+    now - now.rem_euclid(interval_millis(interval, interval_num))
+}
+
+/// Parse a header name like `x-mbx-used-weight-1m` or `x-mbx-order-count-10s`.
+pub(crate) fn parse_header_name(name: &str) -> Option<(&'static str, i64, RateLimitIntervals)> {
+    let name = name.to_ascii_lowercase();
+    let (rate_limit_type, interval) =
+        if let Some(interval) = name.strip_prefix("x-mbx-used-weight-") {
+            ("REQUEST_WEIGHT", interval)
+        } else if let Some(interval) = name.strip_prefix("x-mbx-order-count-") {
+            ("ORDERS", interval)
+        } else {
+            return None;
+        };
+
+    let (num, unit) = interval.split_at(interval.len().checked_sub(1)?);
+    let interval = match unit {
+        "s" => RateLimitIntervals::Second,
+        "m" => RateLimitIntervals::Minute,
+        "h" => RateLimitIntervals::Hour,
+        "d" => RateLimitIntervals::Day,
+        _ => return None,
+    };
+    Some((rate_limit_type, num.parse().ok()?, interval))
+}
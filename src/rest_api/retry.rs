@@ -0,0 +1,133 @@
+use std::time::Duration;
+
+use reqwest::Method;
+
+use super::RestApiError;
+use crate::errors::BinanceErrorCode;
+use rand::Rng;
+
+/// Retry policy for failed requests, with exponential backoff and jitter.
+///
+/// Requests are retried only if the endpoint allows it, see
+/// [`Endpoint::retryable`](super::Endpoint::retryable), and on errors that
+/// depend on the HTTP method:
+///
+/// - `GET` requests are retried on an error for which
+///   [`RestApiError::is_retryable`] or [`RestApiError::is_execution_unknown`]
+///   holds, as reading again has no side effect.
+/// - Other requests, e.g. order placement, are retried only when they were
+///   rejected before being executed: HTTP 429 with a `Retry-After` header,
+///   [`BinanceErrorCode::ServerBusy`], or a connection error before the
+///   request was sent. An HTTP 5xx, a timeout,
+///   [`BinanceErrorCode::UnexpectedResp`] or [`BinanceErrorCode::Timeout`]
+///   leaves the execution status unknown, so the order has to be queried by
+///   its `origClientOrderId` before it is sent again.
+///
+/// On 429 the request is held until the `Retry-After` delay expires instead
+/// of backing off, and only if the delay is within the maximum backoff. A 418
+/// means the IP is banned, for minutes up to days, and is never retried.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RetryPolicy {
+    pub fn new() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(10),
+            jitter: true,
+        }
+    }
+
+    /// A policy that never retries.
+    pub fn disabled() -> Self {
+        Self::new().max_retries(0)
+    }
+
+    /// Default: 3.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Backoff before the first retry, doubled on every retry. Default: 200ms.
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Default: 10s.
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Whether to randomize the backoff between half and all of its value, so
+    /// that clients failing at the same time do not retry at the same time.
+    /// Default: true.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub(crate) fn should_retry(&self, attempt: u32, method: &Method, err: &RestApiError) -> bool {
+        if attempt >= self.max_retries || !self.can_wait_out(err) {
+            return false;
+        }
+        if *method == Method::GET {
+            err.is_retryable() || err.is_execution_unknown() || rejected_before_execution(err)
+        } else {
+            rejected_before_execution(err)
+        }
+    }
+
+    /// Whether a rate limit rejection can be waited out: never for an IP ban
+    /// (418), and for a 429 only if its `Retry-After` is within the maximum
+    /// backoff.
+    fn can_wait_out(&self, err: &RestApiError) -> bool {
+        match err {
+            RestApiError::Binance(res) if res.status == 418 => false,
+            RestApiError::Binance(res) if res.status == 429 => res
+                .retry_after()
+                .is_some_and(|retry_after| retry_after <= self.max_backoff),
+            _ => true,
+        }
+    }
+
+    /// Backoff before the retry following the given attempt, starting at 0.
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_backoff);
+        if self.jitter && !backoff.is_zero() {
+            rand::thread_rng().gen_range(backoff / 2..=backoff)
+        } else {
+            backoff
+        }
+    }
+}
+
+/// Whether the request was rejected before it reached the matching engine,
+/// so that sending it again cannot execute it twice.
+fn rejected_before_execution(err: &RestApiError) -> bool {
+    match err {
+        RestApiError::Reqwest(err) => err.is_connect(),
+        RestApiError::Binance(res) => {
+            (res.status == 429 && res.retry_after().is_some())
+                || err.error_code() == Some(BinanceErrorCode::ServerBusy)
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,137 @@
+//! Request signers.
+//!
+//! Binance accepts three types of API keys, and each of them signs the request
+//! payload differently:
+//!
+//! - [`HmacSigner`] - HMAC-SHA256, the signature is hex encoded.
+//! - [`Ed25519Signer`] - Ed25519, the signature is base64 encoded.
+//! - [`RsaSigner`] - RSA PKCS#1 v1.5 with SHA-256, the signature is base64
+//!   encoded.
+//!
+//! A signer is chosen when a client is built, and the same signer type can be
+//! used with both the REST API client and the Web Socket API client.
+//!
+//! ```no_run
+//! use birdie::{rest_api::RestApiClient, signer::Ed25519Signer};
+//!
+//! let pem = std::fs::read_to_string("private_key.pem").unwrap();
+//! let signer = Ed25519Signer::new(&pem).unwrap();
+//! let client = RestApiClient::with_signer("https://api.binance.com", "api_key", signer).unwrap();
+//! ```
+//!
+//! See [docs](https://developers.binance.com/docs/binance-spot-api-docs/rest-api#signed-trade-and-user_data-endpoint-security)
+//! for more information.
+
+use base64::{engine::general_purpose::STANDARD as b64, Engine};
+use ed25519_dalek::{pkcs8::DecodePrivateKey, SigningKey};
+use rsa::{
+    pkcs1v15::SigningKey as RsaSigningKey,
+    signature::{SignatureEncoding, Signer as _},
+};
+use sha2::Sha256;
+use thiserror::Error;
+
+use crate::{hmac_signature, rsa_private_key};
+
+#[derive(Debug, Error)]
+pub enum SignerError {
+    #[error("hmac error: {0}")]
+    Hmac(#[from] hmac::digest::InvalidLength),
+    #[error("pkcs8 error: {0}")]
+    Pkcs8(#[from] ed25519_dalek::pkcs8::Error),
+    #[error("pkcs1 error: {0}")]
+    Pkcs1(#[from] rsa::pkcs1::Error),
+    #[error("rsa error: {0}")]
+    Rsa(#[from] rsa::Error),
+}
+
+/// Type of an API key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyType {
+    Hmac,
+    Ed25519,
+    Rsa,
+}
+
+/// Signs the payload of a request.
+pub trait Signer: Send + Sync {
+    /// The type of the key the payloads are signed with.
+    fn key_type(&self) -> KeyType;
+
+    /// Returns the signature of the payload, encoded in the form that Binance
+    /// expects for the key type.
+    fn sign(&self, payload: &str) -> Result<String, SignerError>;
+}
+
+/// Signer for HMAC-SHA256 keys.
+pub struct HmacSigner {
+    secret_key: String,
+}
+
+impl HmacSigner {
+    pub fn new(secret_key: &str) -> Self {
+        Self {
+            secret_key: secret_key.to_owned(),
+        }
+    }
+}
+
+impl Signer for HmacSigner {
+    fn key_type(&self) -> KeyType {
+        KeyType::Hmac
+    }
+
+    fn sign(&self, payload: &str) -> Result<String, SignerError> {
+        Ok(hmac_signature(&self.secret_key, payload)?)
+    }
+}
+
+/// Signer for Ed25519 keys. The private key is expected to be a PKCS#8 PEM
+/// string, it is parsed once when the signer is created.
+pub struct Ed25519Signer {
+    key: SigningKey,
+}
+
+impl Ed25519Signer {
+    pub fn new(private_key: &str) -> Result<Self, SignerError> {
+        Ok(Self {
+            key: SigningKey::from_pkcs8_pem(private_key)?,
+        })
+    }
+}
+
+impl Signer for Ed25519Signer {
+    fn key_type(&self) -> KeyType {
+        KeyType::Ed25519
+    }
+
+    fn sign(&self, payload: &str) -> Result<String, SignerError> {
+        let signature = self.key.sign(payload.as_bytes());
+        Ok(b64.encode(signature.to_bytes()))
+    }
+}
+
+/// Signer for RSA keys. The private key is expected to be a PKCS#8 or PKCS#1
+/// PEM string, it is parsed once when the signer is created.
+pub struct RsaSigner {
+    key: RsaSigningKey<Sha256>,
+}
+
+impl RsaSigner {
+    pub fn new(private_key: &str) -> Result<Self, SignerError> {
+        Ok(Self {
+            key: RsaSigningKey::new(rsa_private_key(private_key)?),
+        })
+    }
+}
+
+impl Signer for RsaSigner {
+    fn key_type(&self) -> KeyType {
+        KeyType::Rsa
+    }
+
+    fn sign(&self, payload: &str) -> Result<String, SignerError> {
+        let signature = self.key.sign(payload.as_bytes());
+        Ok(b64.encode(signature.to_bytes()))
+    }
+}
@@ -1,8 +1,9 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint, web_socket_api::web_socket};
+use crate::{
+    decimal::Decimal, enums::SecurityType, rest_api::endpoint, web_socket_api::web_socket,
+};
 
 endpoint!(
     "/api/v3/account",
@@ -10,7 +11,8 @@ endpoint!(
     SecurityType::UserData,
     AccountInformationEndpoint,
     AccountInformationParams,
-    AccountInformationResponse
+    AccountInformationResponse,
+    weight = 20
 );
 
 /// Get current account information.
@@ -34,7 +36,6 @@ pub struct AccountInformationParams {
     omit_zero_balances: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl Default for AccountInformationParams {
@@ -48,7 +49,6 @@ impl AccountInformationParams {
         Self {
             omit_zero_balances: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -104,9 +104,9 @@ pub struct Account {
 #[serde(rename_all = "camelCase")]
 pub struct CommissionRate {
     /// Example: "0.00150000"
-    pub maker: String,
+    pub maker: Decimal,
     /// Example: "0.00150000"
-    pub taker: String,
+    pub taker: Decimal,
     /// Example: "0.00000000"
     pub buyer: String,
     /// Example: "0.00000000"
@@ -119,9 +119,9 @@ pub struct Balance {
     /// Example: `"BTC"`
     pub asset: String,
     /// Example: "4723846.89208129"
-    pub free: String,
+    pub free: Decimal,
     /// Example: "0.00000000"
-    pub locked: String,
+    pub locked: Decimal,
 }
 
 #[derive(Debug, Deserialize)]
@@ -130,14 +130,16 @@ pub struct Discount {
     pub enabled_for_account: bool,
     pub enabled_for_symbol: bool,
     pub discount_asset: Option<String>,
-    pub discount: String,
+    pub discount: Decimal,
 }
 
 web_socket!(
     "account.status",
+    SecurityType::UserData,
     AccountInformationWebSocket,
     AccountInformationParams,
-    AccountInformationResponse
+    AccountInformationResponse,
+    weight = 20
 );
 
 pub struct AccountInformationWebSocket<'w> {
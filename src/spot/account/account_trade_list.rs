@@ -1,8 +1,9 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint, web_socket_api::web_socket};
+use crate::{
+    decimal::Decimal, enums::SecurityType, rest_api::endpoint, web_socket_api::web_socket,
+};
 
 endpoint!(
     "/api/v3/myTrades",
@@ -10,7 +11,8 @@ endpoint!(
     SecurityType::UserData,
     AccountTradeListEndpoint,
     AccountTradeListParams,
-    AccountTradeListResponse
+    AccountTradeListResponse,
+    weight = 20
 );
 
 /// Get trades for a specific account and symbol.
@@ -43,7 +45,6 @@ pub struct AccountTradeListParams {
     limit: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl AccountTradeListParams {
@@ -56,7 +57,6 @@ impl AccountTradeListParams {
             from_id: None,
             limit: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -104,10 +104,10 @@ pub struct MyTrade {
     pub id: i64,
     pub order_id: i64,
     pub order_list_id: i64,
-    pub price: String,
-    pub qty: String,
-    pub quote_qty: String,
-    pub commission: String,
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub quote_qty: Decimal,
+    pub commission: Decimal,
     pub commission_asset: String,
     pub time: i64,
     pub is_buyer: bool,
@@ -117,9 +117,11 @@ pub struct MyTrade {
 
 web_socket!(
     "myTrades",
+    SecurityType::UserData,
     AccountTradeListWebSocket,
     AccountTradeListParams,
-    AccountTradeListResponse
+    AccountTradeListResponse,
+    weight = 20
 );
 
 pub struct AccountTradeListWebSocket<'w> {
@@ -1,8 +1,9 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint, web_socket_api::web_socket};
+use crate::{
+    decimal::Decimal, enums::SecurityType, rest_api::endpoint, web_socket_api::web_socket,
+};
 
 endpoint!(
     "/api/v3/myAllocations",
@@ -10,7 +11,8 @@ endpoint!(
     SecurityType::UserData,
     QueryAllocationsEndpoint,
     QueryAllocationsParams,
-    QueryAllocationsResponse
+    QueryAllocationsResponse,
+    weight = 20
 );
 
 /// Retrieves allocations resulting from SOR order placement.
@@ -43,7 +45,6 @@ pub struct QueryAllocationsParams {
     order_id: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryAllocationsParams {
@@ -56,7 +57,6 @@ impl QueryAllocationsParams {
             limit: None,
             order_id: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -103,10 +103,10 @@ pub struct Allocation {
     pub allocation_type: String,
     pub order_id: i64,
     pub order_list_id: i64,
-    pub price: String,
-    pub qty: String,
-    pub quote_qty: String,
-    pub commission: String,
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub quote_qty: Decimal,
+    pub commission: Decimal,
     pub commission_asset: String,
     pub time: i64,
     pub is_buyer: bool,
@@ -116,9 +116,11 @@ pub struct Allocation {
 
 web_socket!(
     "myAllocations",
+    SecurityType::UserData,
     QueryAllocationsWebSocket,
     QueryAllocationsParams,
-    QueryAllocationsResponse
+    QueryAllocationsResponse,
+    weight = 20
 );
 
 pub struct QueryAllocationsWebSocket<'w> {
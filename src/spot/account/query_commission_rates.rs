@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
@@ -12,7 +11,8 @@ endpoint!(
     SecurityType::UserData,
     QueryCommissionRatesEndpoint,
     QueryCommissionRatesParams,
-    QueryCommissionRatesResponse
+    QueryCommissionRatesResponse,
+    weight = 20
 );
 
 /// Get current account commission rates.
@@ -33,14 +33,12 @@ impl<'r> QueryCommissionRatesEndpoint<'r> {
 #[serde(rename_all = "camelCase")]
 pub struct QueryCommissionRatesParams {
     symbol: String,
-    timestamp: i64,
 }
 
 impl QueryCommissionRatesParams {
     pub fn new(symbol: &str) -> Self {
         Self {
             symbol: symbol.to_owned(),
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 }
@@ -56,9 +54,11 @@ pub struct QueryCommissionRatesResponse {
 
 web_socket!(
     "account.commission",
+    SecurityType::UserData,
     QueryCommissionRatesWebSocket,
     QueryCommissionRatesParams,
-    QueryCommissionRatesResponse
+    QueryCommissionRatesResponse,
+    weight = 20
 );
 
 pub struct QueryCommissionRatesWebSocket<'w> {
@@ -1,8 +1,9 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint, web_socket_api::web_socket};
+use crate::{
+    decimal::Decimal, enums::SecurityType, rest_api::endpoint, web_socket_api::web_socket,
+};
 
 endpoint!(
     "/api/v3/myPreventedMatches",
@@ -10,7 +11,8 @@ endpoint!(
     SecurityType::UserData,
     QueryPreventedMatchesEndpoint,
     QueryPreventedMatchesParams,
-    QueryPreventedMatchesResponse
+    QueryPreventedMatchesResponse,
+    weight = |params| if params.order_id.is_some() { 20 } else { 2 }
 );
 
 /// Displays the list of orders that were expired due to STP.
@@ -44,7 +46,6 @@ pub struct QueryPreventedMatchesParams {
     limit: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryPreventedMatchesParams {
@@ -56,7 +57,6 @@ impl QueryPreventedMatchesParams {
             from_prevented_match_id: None,
             limit: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -100,16 +100,18 @@ pub struct PreventedMatch {
     pub maker_order_id: i64,
     pub trade_group_id: i64,
     pub self_trade_prevention_mode: String,
-    pub price: String,
-    pub maker_prevented_quantity: String,
+    pub price: Decimal,
+    pub maker_prevented_quantity: Decimal,
     pub transact_time: i64,
 }
 
 web_socket!(
     "myPreventedMatches",
+    SecurityType::UserData,
     QueryPreventedMatchesWebSocket,
     QueryPreventedMatchesParams,
-    QueryPreventedMatchesResponse
+    QueryPreventedMatchesResponse,
+    weight = |params| if params.order_id.is_some() { 20 } else { 2 }
 );
 
 pub struct QueryPreventedMatchesWebSocket<'w> {
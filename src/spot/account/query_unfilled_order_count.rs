@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
@@ -14,7 +13,8 @@ endpoint!(
     SecurityType::UserData,
     QueryUnfilledOrderCountEndpoint,
     QueryUnfilledOrderCountParams,
-    QueryUnfilledOrderCountResponse
+    QueryUnfilledOrderCountResponse,
+    weight = 40
 );
 
 /// Displays the user's unfilled order count for all intervals.
@@ -37,7 +37,6 @@ pub struct QueryUnfilledOrderCountParams {
     /// The value cannot be greater than `60000`.
     #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl Default for QueryUnfilledOrderCountParams {
@@ -48,10 +47,7 @@ impl Default for QueryUnfilledOrderCountParams {
 
 impl QueryUnfilledOrderCountParams {
     pub fn new() -> Self {
-        Self {
-            recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
-        }
+        Self { recv_window: None }
     }
 
     /// The value cannot be greater than 60000.
@@ -73,9 +69,11 @@ pub struct UnfilledOrderCount {
 
 web_socket!(
     "account.rateLimits.orders",
+    SecurityType::UserData,
     QueryUnfilledOrderCountWebSocket,
     QueryUnfilledOrderCountParams,
-    QueryUnfilledOrderCountResponse
+    QueryUnfilledOrderCountResponse,
+    weight = 40
 );
 
 pub struct QueryUnfilledOrderCountWebSocket<'w> {
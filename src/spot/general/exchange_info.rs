@@ -2,8 +2,8 @@ use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    enums::{OrderType, RateLimit},
-    filters::ExchangeFilter,
+    enums::{OrderType, RateLimit, SelfTradePreventionMode},
+    filters::{ExchangeFilter, SymbolFilter},
     rest_api::endpoint,
     web_socket_api::web_socket,
 };
@@ -13,7 +13,8 @@ endpoint!(
     Method::GET,
     ExchangeInfoEndpoint,
     ExchangeInfoParams,
-    ExchangeInfoResponse
+    ExchangeInfoResponse,
+    weight = 20
 );
 
 /// Current exchange trading rules and symbol information.
@@ -99,6 +100,7 @@ pub struct Symbol {
     pub base_asset: String,
     pub base_asset_precision: i64,
     pub quote_asset: String,
+    pub quote_precision: i64,
     pub quote_asset_precision: i64,
     pub base_commission_precision: i64,
     pub quote_commission_precision: i64,
@@ -108,15 +110,22 @@ pub struct Symbol {
     pub oto_allowed: bool,
     pub quote_order_qty_market_allowed: bool,
     pub allow_trailing_stop: bool,
+    pub cancel_replace_allowed: bool,
     pub is_spot_trading_allowed: bool,
     pub is_margin_trading_allowed: bool,
+    pub filters: Vec<SymbolFilter>,
+    pub permissions: Vec<String>,
+    pub permission_sets: Vec<Vec<String>>,
+    pub default_self_trade_prevention_mode: SelfTradePreventionMode,
+    pub allowed_self_trade_prevention_modes: Vec<SelfTradePreventionMode>,
 }
 
 web_socket!(
     "exchangeInfo",
     ExchangeInfoWebSocket,
     ExchangeInfoParams,
-    ExchangeInfoResponse
+    ExchangeInfoResponse,
+    weight = 20
 );
 
 pub struct ExchangeInfoWebSocket<'w> {
@@ -1,14 +1,15 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{rest_api::endpoint, web_socket_api::web_socket};
+use crate::{decimal::Decimal, rest_api::endpoint, web_socket_api::web_socket};
 
 endpoint!(
     "/api/v3/aggTrades",
     Method::GET,
     AggregateTradesListEndpoint,
     AggregateTradesListParams,
-    AggregateTradesListResponse
+    AggregateTradesListResponse,
+    weight = 2
 );
 
 /// Get compressed, aggregate trades. Trades that fill at the time, from the
@@ -86,10 +87,10 @@ pub struct AggTrade {
     pub aggregated: i64,
     /// Example: `"0.01633102"`
     #[serde(rename = "p")]
-    pub price: String,
+    pub price: Decimal,
     /// Example: `"4.70443515"`
     #[serde(rename = "q")]
-    pub quantity: String,
+    pub quantity: Decimal,
     /// Example: `27781`
     #[serde(rename = "f")]
     pub first_trade_id: i64,
@@ -109,7 +110,8 @@ web_socket!(
     "trades.aggregate",
     AggregateTradesListWebSocket,
     AggregateTradesListParams,
-    AggregateTradesListResponse
+    AggregateTradesListResponse,
+    weight = 2
 );
 
 pub struct AggregateTradesListWebSocket<'w> {
@@ -1,14 +1,15 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{rest_api::endpoint, web_socket_api::web_socket};
+use crate::{decimal::Decimal, rest_api::endpoint, web_socket_api::web_socket};
 
 endpoint!(
     "/api/v3/avgPrice",
     Method::GET,
     CurrentAveragePriceEndpoint,
     CurrentAveragePriceParams,
-    CurrentAveragePriceResponse
+    CurrentAveragePriceResponse,
+    weight = 2
 );
 
 /// Current average price for a symbol.
@@ -43,7 +44,7 @@ impl CurrentAveragePriceParams {
 #[serde(rename_all = "camelCase")]
 pub struct CurrentAveragePriceResponse {
     pub mins: i64,
-    pub price: String,
+    pub price: Decimal,
     pub close_time: i64,
 }
 
@@ -51,7 +52,8 @@ web_socket!(
     "avgPrice",
     CurrentAveragePriceWebSocket,
     CurrentAveragePriceParams,
-    CurrentAveragePriceResponse
+    CurrentAveragePriceResponse,
+    weight = 2
 );
 
 pub struct CurrentAveragePriceWebSocket<'w> {
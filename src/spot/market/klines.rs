@@ -1,14 +1,17 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::KlineInterval, rest_api::endpoint, web_socket_api::web_socket};
+use crate::{
+    decimal::Decimal, enums::KlineInterval, rest_api::endpoint, web_socket_api::web_socket,
+};
 
 endpoint!(
     "/api/v3/klines",
     Method::GET,
     KlinesEndpoint,
     KlinesParams,
-    KlinesResponse
+    KlinesResponse,
+    weight = 2
 );
 
 /// Kline/candlestick bars for a symbol. Klines are uniquely identified by their
@@ -80,21 +83,27 @@ pub type KlinesResponse = Vec<Kline>;
 
 #[derive(Debug, Deserialize)]
 pub struct Kline(
-    pub i64,    // Open time
-    pub String, // Open price
-    pub String, // High price
-    pub String, // Low price
-    pub String, // Close price
-    pub String, // Volume
-    pub i64,    // Close time
-    pub String, // Quote asset volume
-    pub i64,    // Number of trades
-    pub String, // Taker buy base asset volume
-    pub String, // Taker buy quote asset volume
-    pub String, // Unused field, ignore.
+    pub i64,     // Open time
+    pub Decimal, // Open price
+    pub Decimal, // High price
+    pub Decimal, // Low price
+    pub Decimal, // Close price
+    pub Decimal, // Volume
+    pub i64,     // Close time
+    pub Decimal, // Quote asset volume
+    pub i64,     // Number of trades
+    pub Decimal, // Taker buy base asset volume
+    pub Decimal, // Taker buy quote asset volume
+    pub String,  // Unused field, ignore.
 );
 
-web_socket!("klines", KlinesWebSocket, KlinesParams, KlinesResponse);
+web_socket!(
+    "klines",
+    KlinesWebSocket,
+    KlinesParams,
+    KlinesResponse,
+    weight = 2
+);
 
 pub struct KlinesWebSocket<'w> {
     client: &'w crate::web_socket_api::WebSocketApiClient,
@@ -1,14 +1,15 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{rest_api::endpoint, web_socket_api::web_socket};
+use crate::{decimal::Decimal, rest_api::endpoint, web_socket_api::web_socket};
 
 endpoint!(
     "/api/v3/historicalTrades",
     Method::GET,
     OldTradeLookupEndpoint,
     OldTradeLookupParams,
-    OldTradeLookupResponse
+    OldTradeLookupResponse,
+    weight = 25
 );
 
 /// Get older trades.
@@ -63,9 +64,9 @@ pub type OldTradeLookupResponse = Vec<OldTrade>;
 #[serde(rename_all = "camelCase")]
 pub struct OldTrade {
     pub id: i64,
-    pub price: String,
-    pub qty: String,
-    pub quote_qty: String,
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub quote_qty: Decimal,
     pub time: i64,
     pub is_buyer_maker: bool,
     pub is_best_match: bool,
@@ -75,7 +76,8 @@ web_socket!(
     "trades.historical",
     OldTradeLookupWebSocket,
     OldTradeLookupParams,
-    OldTradeLookupResponse
+    OldTradeLookupResponse,
+    weight = 25
 );
 
 pub struct OldTradeLookupWebSocket<'w> {
@@ -1,14 +1,20 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{rest_api::endpoint, web_socket_api::web_socket};
+use crate::{decimal::Decimal, rest_api::endpoint, web_socket_api::web_socket};
 
 endpoint!(
     "/api/v3/depth",
     Method::GET,
     OrderBookEndpoint,
     OrderBookParams,
-    OrderBookResponse
+    OrderBookResponse,
+    weight = |params| match params.limit.unwrap_or(100) {
+        ..=100 => 5,
+        101..=500 => 25,
+        501..=1000 => 50,
+        _ => 250,
+    }
 );
 
 /// Get order book.
@@ -57,15 +63,21 @@ impl OrderBookParams {
 #[serde(rename_all = "camelCase")]
 pub struct OrderBookResponse {
     pub last_update_id: i64,
-    pub bids: Vec<(String, String)>,
-    pub asks: Vec<(String, String)>,
+    pub bids: Vec<(Decimal, Decimal)>,
+    pub asks: Vec<(Decimal, Decimal)>,
 }
 
 web_socket!(
     "depth",
     OrderBookWebSocket,
     OrderBookParams,
-    OrderBookResponse
+    OrderBookResponse,
+    weight = |params| match params.limit.unwrap_or(100) {
+        ..=100 => 5,
+        101..=500 => 25,
+        501..=1000 => 50,
+        _ => 250,
+    }
 );
 
 pub struct OrderBookWebSocket<'w> {
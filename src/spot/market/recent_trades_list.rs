@@ -1,14 +1,15 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{rest_api::endpoint, web_socket_api::web_socket};
+use crate::{decimal::Decimal, rest_api::endpoint, web_socket_api::web_socket};
 
 endpoint!(
     "/api/v3/trades",
     Method::GET,
     RecentTradesListEndpoint,
     RecentTradesListParams,
-    RecentTradesListResponse
+    RecentTradesListResponse,
+    weight = 25
 );
 
 /// Get recent trades.
@@ -54,9 +55,9 @@ pub type RecentTradesListResponse = Vec<RecentTrade>;
 #[serde(rename_all = "camelCase")]
 pub struct RecentTrade {
     pub id: i64,
-    pub price: String,
-    pub qty: String,
-    pub quote_qty: String,
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub quote_qty: Decimal,
     pub time: i64,
     pub is_buyer_maker: bool,
     pub is_best_match: bool,
@@ -66,7 +67,8 @@ web_socket!(
     "trades.recent",
     RecentTradesListWebSocket,
     RecentTradesListParams,
-    RecentTradesListResponse
+    RecentTradesListResponse,
+    weight = 25
 );
 
 pub struct RecentTradesListWebSocket<'w> {
@@ -10,7 +10,11 @@ endpoint!(
     Method::GET,
     RollingWindowPriceChangeEndpoint,
     RollingWindowPriceChangeParams,
-    RollingWindowPriceChangeResponse
+    RollingWindowPriceChangeResponse,
+    weight = |params| match &params.symbols {
+        Some(symbols) => (4 * symbols.len() as u32).min(200),
+        None => 4,
+    }
 );
 
 /// **Note**: This endpoint is different from the GET /api/v3/ticker/24hr
@@ -28,6 +32,7 @@ endpoint!(
 /// (January 3, 2022, 09:17:00).
 ///
 /// - Weight:
+///     - 4 for each requested symbol, capped at 200 for more than 50 symbols
 /// - Data Source: Memory
 pub struct RollingWindowPriceChangeEndpoint<'r> {
     client: &'r crate::rest_api::RestApiClient,
@@ -112,7 +117,11 @@ web_socket!(
     "ticker",
     RollingWindowPriceChangeWebSocket,
     RollingWindowPriceChangeParams,
-    RollingWindowPriceChangeResponse
+    RollingWindowPriceChangeResponse,
+    weight = |params| match &params.symbols {
+        Some(symbols) => (4 * symbols.len() as u32).min(200),
+        None => 4,
+    }
 );
 
 pub struct RollingWindowPriceChangeWebSocket<'w> {
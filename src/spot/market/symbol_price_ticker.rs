@@ -1,19 +1,22 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{rest_api::endpoint, web_socket_api::web_socket};
+use crate::{decimal::Decimal, rest_api::endpoint, web_socket_api::web_socket};
 
 endpoint!(
     "/api/v3/ticker/price",
     Method::GET,
     SymbolPriceTickerEndpoint,
     SymbolPriceTickerParams,
-    SymbolPriceTickerResponse
+    SymbolPriceTickerResponse,
+    weight = |params| if params.symbol.is_some() { 2 } else { 4 }
 );
 
 /// Latest price for a symbol or symbols.
 ///
 /// - Weight:
+///     - `symbol`: 2
+///     - `symbols` or none: 4
 /// - Data Source: Memory
 pub struct SymbolPriceTickerEndpoint<'r> {
     client: &'r crate::rest_api::RestApiClient,
@@ -70,14 +73,15 @@ pub enum SymbolPriceTickerResponse {
 #[serde(rename_all = "camelCase")]
 pub struct SymbolPriceTicker {
     pub symbol: String,
-    pub price: String,
+    pub price: Decimal,
 }
 
 web_socket!(
     "ticker.price",
     SymbolPriceTickerWebSocket,
     SymbolPriceTickerParams,
-    SymbolPriceTickerResponse
+    SymbolPriceTickerResponse,
+    weight = |params| if params.symbol.is_some() { 2 } else { 4 }
 );
 
 pub struct SymbolPriceTickerWebSocket<'w> {
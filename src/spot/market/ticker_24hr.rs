@@ -10,13 +10,23 @@ endpoint!(
     Method::GET,
     Ticker24hrEndpoint,
     Ticker24hrParams,
-    Ticker24hrResponse
+    Ticker24hrResponse,
+    weight = |params| match (&params.symbol, &params.symbols) {
+        (Some(_), _) => 2,
+        (None, Some(symbols)) if symbols.len() <= 20 => 2,
+        (None, Some(symbols)) if symbols.len() <= 100 => 40,
+        _ => 80,
+    }
 );
 
 /// 24 hour rolling window price change statistics. Careful when accessing this
 /// with no symbol.
 ///
 /// - Weight:
+///     - `symbol`: 2
+///     - `symbols` 1-20: 2
+///     - `symbols` 21-100: 40
+///     - `symbols` 101 or more, or none: 80
 /// - Data Source: Memory
 pub struct Ticker24hrEndpoint<'r> {
     client: &'r crate::rest_api::RestApiClient,
@@ -84,7 +94,13 @@ web_socket!(
     "ticker.24hr",
     Ticker24hrWebSocket,
     Ticker24hrParams,
-    Ticker24hrResponse
+    Ticker24hrResponse,
+    weight = |params| match (&params.symbol, &params.symbols) {
+        (Some(_), _) => 2,
+        (None, Some(symbols)) if symbols.len() <= 20 => 2,
+        (None, Some(symbols)) if symbols.len() <= 100 => 40,
+        _ => 80,
+    }
 );
 
 pub struct Ticker24hrWebSocket<'w> {
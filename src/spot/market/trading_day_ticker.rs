@@ -1,14 +1,18 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::TickerType, rest_api::endpoint, web_socket_api::web_socket};
+use crate::{decimal::Decimal, enums::TickerType, rest_api::endpoint, web_socket_api::web_socket};
 
 endpoint!(
     "/api/v3/ticker/tradingDay",
     Method::GET,
     TradingDayTickerEndpoint,
     TradingDayTickerParams,
-    TradingDayTickerResponse
+    TradingDayTickerResponse,
+    weight = |params| match &params.symbols {
+        Some(symbols) => (4 * symbols.len() as u32).min(200),
+        None => 4,
+    }
 );
 
 /// Price change statistics for a trading day.
@@ -91,21 +95,21 @@ pub enum TradingDayTickerResponse {
 #[serde(rename_all = "camelCase")]
 pub struct TickerFull {
     pub symbol: String,
-    pub price_change: String,
-    pub price_change_percent: String,
-    pub weighted_avg_price: String,
-    pub prev_close_price: String,
-    pub last_price: String,
-    pub last_qty: String,
-    pub bid_price: String,
-    pub bid_qty: String,
-    pub ask_price: String,
-    pub ask_qty: String,
-    pub open_price: String,
-    pub high_price: String,
-    pub low_price: String,
-    pub volume: String,
-    pub quote_volume: String,
+    pub price_change: Decimal,
+    pub price_change_percent: Decimal,
+    pub weighted_avg_price: Decimal,
+    pub prev_close_price: Decimal,
+    pub last_price: Decimal,
+    pub last_qty: Decimal,
+    pub bid_price: Decimal,
+    pub bid_qty: Decimal,
+    pub ask_price: Decimal,
+    pub ask_qty: Decimal,
+    pub open_price: Decimal,
+    pub high_price: Decimal,
+    pub low_price: Decimal,
+    pub volume: Decimal,
+    pub quote_volume: Decimal,
     pub open_time: i64,
     pub close_time: i64,
     pub first_id: i64,
@@ -117,12 +121,12 @@ pub struct TickerFull {
 #[serde(rename_all = "camelCase")]
 pub struct TickerMini {
     pub symbol: String,
-    pub open_price: String,
-    pub high_price: String,
-    pub low_price: String,
-    pub last_price: String,
-    pub volume: String,
-    pub quote_volume: String,
+    pub open_price: Decimal,
+    pub high_price: Decimal,
+    pub low_price: Decimal,
+    pub last_price: Decimal,
+    pub volume: Decimal,
+    pub quote_volume: Decimal,
     pub open_time: i64,
     pub close_time: i64,
     pub first_id: i64,
@@ -134,7 +138,11 @@ web_socket!(
     "ticker.tradingDay",
     TradingDayTickerWebSocket,
     TradingDayTickerParams,
-    TradingDayTickerResponse
+    TradingDayTickerResponse,
+    weight = |params| match &params.symbols {
+        Some(symbols) => (4 * symbols.len() as u32).min(200),
+        None => 4,
+    }
 );
 
 pub struct TradingDayTickerWebSocket<'w> {
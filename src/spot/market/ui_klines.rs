@@ -31,6 +31,10 @@ impl Endpoint for UiKlinesEndpoint<'_> {
     fn security_type(&self) -> SecurityType {
         SecurityType::None
     }
+
+    fn weight(&self, _params: &Self::Params) -> u32 {
+        2
+    }
 }
 
 /// Kline/candlestick bars for a symbol. Klines are uniquely identified by their
@@ -104,7 +108,8 @@ web_socket!(
     "uiKlines",
     UiKlinesWebSocket,
     UiKlinesParams,
-    UiKlinesResponse
+    UiKlinesResponse,
+    weight = 2
 );
 
 pub struct UiKlinesWebSocket<'w> {
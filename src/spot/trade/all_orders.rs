@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::Serialize;
 
@@ -12,7 +11,8 @@ endpoint!(
     SecurityType::UserData,
     AllOrdersEndpoint,
     AllOrdersParams,
-    AllOrdersResponse
+    AllOrdersResponse,
+    weight = 20
 );
 
 /// Get all account orders; active, canceled, or filled.
@@ -43,7 +43,6 @@ pub struct AllOrdersParams {
     limit: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl AllOrdersParams {
@@ -55,7 +54,6 @@ impl AllOrdersParams {
             end_time: None,
             limit: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -1,10 +1,9 @@
-use jiff::Timestamp;
 use reqwest::Method;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{enums::SecurityType, rest_api::endpoint, web_socket_api::web_socket};
 
-use super::CancelOrderResult;
+use super::{CancelOrderResult, OrderListResult};
 
 endpoint!(
     "/api/v3/openOrders",
@@ -35,7 +34,6 @@ pub struct CancelAllOpenOrdersParams {
     symbol: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl CancelAllOpenOrdersParams {
@@ -43,7 +41,6 @@ impl CancelAllOpenOrdersParams {
         Self {
             symbol: symbol.to_owned(),
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -54,10 +51,19 @@ impl CancelAllOpenOrdersParams {
     }
 }
 
-pub type CancelAllOpenOrdersResponse = Vec<CancelOrderResult>;
+pub type CancelAllOpenOrdersResponse = Vec<CanceledOrder>;
+
+/// Orders that are part of an order list are reported with their list.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum CanceledOrder {
+    Order(Box<CancelOrderResult>),
+    OrderList(Box<OrderListResult>),
+}
 
 web_socket!(
     "openOrders.cancelAll",
+    SecurityType::Trade,
     CancelAllOpenOrdersWebSocket,
     CancelAllOpenOrdersParams,
     CancelAllOpenOrdersResponse
@@ -1,8 +1,8 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    decimal::Decimal,
     enums::{
         CancelRestriction, OrderSide, OrderStatus, OrderType, SecurityType,
         SelfTradePreventionMode, TimeInForce,
@@ -50,7 +50,6 @@ pub struct CancelOrderParams {
     cancel_restrictions: Option<CancelRestriction>,
     #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl CancelOrderParams {
@@ -62,7 +61,6 @@ impl CancelOrderParams {
             new_client_order_id: None,
             cancel_restrictions: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -106,10 +104,11 @@ pub struct CancelOrderResult {
     pub order_list_id: i64,
     pub client_order_id: String,
     pub transact_time: i64,
-    pub price: String,
-    pub orig_qty: String,
-    pub executed_qty: String,
-    pub cummulative_quote_qty: String,
+    pub price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub orig_quote_order_qty: Decimal,
+    pub cummulative_quote_qty: Decimal,
     pub status: OrderStatus,
     pub time_in_force: TimeInForce,
     pub r#type: OrderType,
@@ -119,6 +118,7 @@ pub struct CancelOrderResult {
 
 web_socket!(
     "order.cancel",
+    SecurityType::Trade,
     CancelOrderWebSocket,
     CancelOrderParams,
     CancelOrderResponse
@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::Serialize;
 
@@ -67,7 +66,6 @@ pub struct CancelOrderListParams {
     new_client_order_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl CancelOrderListParams {
@@ -78,7 +76,6 @@ impl CancelOrderListParams {
             list_client_order_id: None,
             new_client_order_id: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -108,6 +105,7 @@ pub type CancelOrderListResponse = OrderListResult;
 
 web_socket!(
     "orderList.cancel",
+    SecurityType::Trade,
     CancelOrderListWebSocket,
     CancelOrderListParams,
     CancelOrderListResponse
@@ -1,8 +1,8 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    decimal::Decimal,
     enums::{
         CancelReplaceMode, CancelRestriction, OrderRateLimitExceededMode, OrderSide, OrderType,
         ResponseType, SecurityType, SelfTradePreventionMode, TimeInForce,
@@ -52,11 +52,11 @@ pub struct CancelReplaceOrderParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     time_in_force: Option<TimeInForce>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    quantity: Option<f64>,
+    quantity: Option<Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    quote_order_qty: Option<f64>,
+    quote_order_qty: Option<Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    price: Option<f64>,
+    price: Option<Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
     cancel_new_client_order_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -70,11 +70,11 @@ pub struct CancelReplaceOrderParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     strategy_type: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    stop_price: Option<f64>,
+    stop_price: Option<Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
     trailing_delta: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    iceberg_qty: Option<f64>,
+    iceberg_qty: Option<Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
     new_order_resp_type: Option<ResponseType>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -85,7 +85,6 @@ pub struct CancelReplaceOrderParams {
     order_rate_limit_exceeded_mode: Option<OrderRateLimitExceededMode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl CancelReplaceOrderParams {
@@ -118,7 +117,6 @@ impl CancelReplaceOrderParams {
             cancel_restrictions: None,
             order_rate_limit_exceeded_mode: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -127,18 +125,18 @@ impl CancelReplaceOrderParams {
         self
     }
 
-    pub fn quantity(mut self, quantity: f64) -> Self {
-        self.quantity = Some(quantity);
+    pub fn quantity(mut self, quantity: impl Into<Decimal>) -> Self {
+        self.quantity = Some(quantity.into());
         self
     }
 
-    pub fn quote_order_qty(mut self, quote_order_qty: f64) -> Self {
-        self.quote_order_qty = Some(quote_order_qty);
+    pub fn quote_order_qty(mut self, quote_order_qty: impl Into<Decimal>) -> Self {
+        self.quote_order_qty = Some(quote_order_qty.into());
         self
     }
 
-    pub fn price(mut self, price: f64) -> Self {
-        self.price = Some(price);
+    pub fn price(mut self, price: impl Into<Decimal>) -> Self {
+        self.price = Some(price.into());
         self
     }
 
@@ -172,8 +170,8 @@ impl CancelReplaceOrderParams {
         self
     }
 
-    pub fn stop_price(mut self, stop_price: f64) -> Self {
-        self.stop_price = Some(stop_price);
+    pub fn stop_price(mut self, stop_price: impl Into<Decimal>) -> Self {
+        self.stop_price = Some(stop_price.into());
         self
     }
 
@@ -182,8 +180,8 @@ impl CancelReplaceOrderParams {
         self
     }
 
-    pub fn iceberg_qty(mut self, iceberg_qty: f64) -> Self {
-        self.iceberg_qty = Some(iceberg_qty);
+    pub fn iceberg_qty(mut self, iceberg_qty: impl Into<Decimal>) -> Self {
+        self.iceberg_qty = Some(iceberg_qty.into());
         self
     }
 
@@ -271,7 +269,7 @@ pub struct NotSuccessData {
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 pub enum CancelResult {
-    Success(CancelOrderResult),
+    Success(Box<CancelOrderResult>),
     Failure(BinanceError),
 }
 
@@ -284,6 +282,7 @@ pub enum NewResult {
 
 web_socket!(
     "order.cancelReplace",
+    SecurityType::Trade,
     CancelReplaceOrderWebSocket,
     CancelReplaceOrderParams,
     CancelReplaceOrderResponse
@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::Serialize;
 
@@ -30,6 +29,10 @@ impl Endpoint for CurrentOpenOrdersEndpoint<'_> {
     fn security_type(&self) -> SecurityType {
         SecurityType::UserData
     }
+
+    fn weight(&self, _params: &Self::Params) -> u32 {
+        6
+    }
 }
 
 impl Params for CurrentOpenOrdersParams {}
@@ -56,7 +59,6 @@ pub struct CurrentOpenOrdersParams {
     symbol: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl CurrentOpenOrdersParams {
@@ -64,7 +66,6 @@ impl CurrentOpenOrdersParams {
         Self {
             symbol: symbol.to_owned(),
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -79,9 +80,11 @@ pub type CurrentOpenOrdersResponse = Vec<OrderDetail>;
 
 web_socket!(
     "openOrders.status",
+    SecurityType::UserData,
     CurrentOpenOrdersWebSocket,
     CurrentOpenOrdersParams,
-    CurrentOpenOrdersResponse
+    CurrentOpenOrdersResponse,
+    weight = 6
 );
 
 pub struct CurrentOpenOrdersWebSocket<'w> {
@@ -1,8 +1,8 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    decimal::Decimal,
     enums::{
         OrderSide, OrderStatus, OrderType, ResponseType, SecurityType, SelfTradePreventionMode,
         TimeInForce, WorkingFloor,
@@ -17,7 +17,8 @@ endpoint!(
     SecurityType::Trade,
     NewOrderEndpoint,
     NewOrderParams,
-    NewOrderResponse
+    NewOrderResponse,
+    retry = |params| params.new_client_order_id.is_some()
 );
 
 /// Send in a new order.
@@ -56,11 +57,11 @@ pub struct NewOrderParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     time_in_force: Option<TimeInForce>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    quantity: Option<f64>,
+    quantity: Option<Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    quote_order_qty: Option<f64>,
+    quote_order_qty: Option<Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    price: Option<f64>,
+    price: Option<Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
     new_client_order_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -68,18 +69,17 @@ pub struct NewOrderParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     strategy_type: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    stop_price: Option<f64>,
+    stop_price: Option<Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
     trailing_delta: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    iceberg_qty: Option<f64>,
+    iceberg_qty: Option<Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
     new_order_resp_type: Option<ResponseType>,
     #[serde(skip_serializing_if = "Option::is_none")]
     self_trade_prevention_mode: Option<SelfTradePreventionMode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl NewOrderParams {
@@ -101,7 +101,6 @@ impl NewOrderParams {
             new_order_resp_type: None,
             self_trade_prevention_mode: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -110,24 +109,29 @@ impl NewOrderParams {
         self
     }
 
-    pub fn quantity(mut self, quantity: f64) -> Self {
-        self.quantity = Some(quantity);
+    pub fn quantity(mut self, quantity: impl Into<Decimal>) -> Self {
+        self.quantity = Some(quantity.into());
         self
     }
 
-    pub fn quote_order_qty(mut self, quote_order_qty: f64) -> Self {
-        self.quote_order_qty = Some(quote_order_qty);
+    pub fn quote_order_qty(mut self, quote_order_qty: impl Into<Decimal>) -> Self {
+        self.quote_order_qty = Some(quote_order_qty.into());
         self
     }
 
-    pub fn price(mut self, price: f64) -> Self {
-        self.price = Some(price);
+    pub fn price(mut self, price: impl Into<Decimal>) -> Self {
+        self.price = Some(price.into());
         self
     }
 
     /// A unique id among open orders. Automatically generated if not sent.
     /// Orders with the same `new_client_order_id` can be accepted only when the
     /// previous one is filled, otherwise the order will be rejected.
+    ///
+    /// Setting it allows the request to be retried when it is rejected before
+    /// being executed. When its execution status is unknown, the order has to
+    /// be queried by `origClientOrderId` before it is sent again, see
+    /// [`RetryPolicy`](crate::rest_api::RetryPolicy).
     pub fn new_client_order_id(mut self, new_client_order_id: &str) -> Self {
         self.new_client_order_id = Some(new_client_order_id.to_owned());
         self
@@ -144,8 +148,8 @@ impl NewOrderParams {
         self
     }
 
-    pub fn stop_price(mut self, stop_price: f64) -> Self {
-        self.stop_price = Some(stop_price);
+    pub fn stop_price(mut self, stop_price: impl Into<Decimal>) -> Self {
+        self.stop_price = Some(stop_price.into());
         self
     }
 
@@ -154,8 +158,8 @@ impl NewOrderParams {
         self
     }
 
-    pub fn iceberg_qty(mut self, iceberg_qty: f64) -> Self {
-        self.iceberg_qty = Some(iceberg_qty);
+    pub fn iceberg_qty(mut self, iceberg_qty: impl Into<Decimal>) -> Self {
+        self.iceberg_qty = Some(iceberg_qty.into());
         self
     }
 
@@ -179,12 +183,13 @@ impl NewOrderParams {
     }
 }
 
+/// The variants are tried in order, from the most detailed one.
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 pub enum NewOrderResponse {
-    Ack(Box<NewOrderAck>),
-    Result(Box<NewOrderResult>),
     Full(Box<NewOrderFull>),
+    Result(Box<NewOrderResult>),
+    Ack(Box<NewOrderAck>),
 }
 
 #[derive(Debug, Deserialize)]
@@ -207,15 +212,16 @@ pub struct NewOrderResult {
     pub order_list_id: i64,
     pub client_order_id: String,
     pub transact_time: i64,
-    pub price: String,
-    pub orig_qty: String,
-    pub executed_qty: String,
-    pub cummulative_quote_qty: String,
+    pub price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub orig_quote_order_qty: Decimal,
+    pub cummulative_quote_qty: Decimal,
     pub status: OrderStatus,
     pub time_in_force: TimeInForce,
     pub r#type: OrderType,
     pub side: OrderSide,
-    pub working_type: i64,
+    pub working_time: i64,
     pub self_trade_prevention_mode: SelfTradePreventionMode,
     #[serde(flatten)]
     pub conditional: ConditionalFields,
@@ -229,15 +235,16 @@ pub struct NewOrderFull {
     pub order_list_id: i64,
     pub client_order_id: String,
     pub transact_time: i64,
-    pub price: String,
-    pub orig_qty: String,
-    pub executed_qty: String,
-    pub cummulative_quote_qty: String,
+    pub price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub orig_quote_order_qty: Decimal,
+    pub cummulative_quote_qty: Decimal,
     pub status: OrderStatus,
     pub time_in_force: TimeInForce,
     pub r#type: OrderType,
-    pub side: String,
-    pub working_type: i64,
+    pub side: OrderSide,
+    pub working_time: i64,
     pub self_trade_prevention_mode: SelfTradePreventionMode,
     pub fills: Vec<OrderFill>,
     #[serde(flatten)]
@@ -247,9 +254,9 @@ pub struct NewOrderFull {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderFill {
-    pub price: String,
-    pub qty: String,
-    pub commission: String,
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub commission: Decimal,
     pub commission_asset: String,
     pub trade_id: i64,
 }
@@ -259,16 +266,16 @@ pub struct OrderFill {
 pub struct ConditionalFields {
     /// Quantity for the iceberg order. Appears only if the parameter
     /// `iceberg_qty` was sent in the request.
-    pub iceberg_qty: Option<String>,
+    pub iceberg_qty: Option<Decimal>,
     /// When used in combination with `symbol`, can be used to query a prevented
     /// match. Appears only if the order exired due to STP.
     pub prevented_match_id: Option<i64>,
     /// Order quantity that expired due to STP. Appears only if the order
     /// expired due to STP.
-    pub prevented_quantity: Option<String>,
+    pub prevented_quantity: Option<Decimal>,
     /// Price when the algorithmic order will be triggered. Appears for
     /// `StopLoss`, `TakeProfit`, `StopLossLimit` and `TakeProfitLimit` orders.
-    pub stop_price: Option<String>,
+    pub stop_price: Option<Decimal>,
     /// Can be used to label an order that's part of an order strategy. Appears
     /// if the parameter was populated in the request.
     pub strategy_id: Option<i64>,
@@ -292,6 +299,7 @@ pub struct ConditionalFields {
 
 web_socket!(
     "order.place",
+    SecurityType::Trade,
     NewOrderWebSocket,
     NewOrderParams,
     NewOrderResponse
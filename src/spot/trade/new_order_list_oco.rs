@@ -1,8 +1,8 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    decimal::Decimal,
     enums::{
         ContingencyType, OrderListOrderStatus, OrderListStatus, OrderSide, OrderStatus, OrderType,
         ResponseType, SecurityType, SelfTradePreventionMode, TimeInForce,
@@ -53,16 +53,16 @@ pub struct NewOrderListOcoParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     list_client_order_id: Option<String>,
     side: OrderSide,
-    quantity: Option<f64>,
+    quantity: Option<Decimal>,
     above_type: OrderType,
     #[serde(skip_serializing_if = "Option::is_none")]
     above_client_order_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     above_iceberg_qty: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    above_price: Option<f64>,
+    above_price: Option<Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    above_stop_price: Option<f64>,
+    above_stop_price: Option<Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
     above_trailing_delta: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -77,9 +77,9 @@ pub struct NewOrderListOcoParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     below_iceberg_qty: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    below_price: Option<f64>,
+    below_price: Option<Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    below_stop_price: Option<f64>,
+    below_stop_price: Option<Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
     below_trailing_delta: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -94,7 +94,6 @@ pub struct NewOrderListOcoParams {
     self_trade_prevention_mode: Option<SelfTradePreventionMode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl NewOrderListOcoParams {
@@ -130,7 +129,6 @@ impl NewOrderListOcoParams {
             new_order_resp_type: None,
             self_trade_prevention_mode: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -140,8 +138,8 @@ impl NewOrderListOcoParams {
     }
 
     ///	Quantity for both legs of the order list.
-    pub fn quantity(mut self, quantity: f64) -> Self {
-        self.quantity = Some(quantity);
+    pub fn quantity(mut self, quantity: impl Into<Decimal>) -> Self {
+        self.quantity = Some(quantity.into());
         self
     }
 
@@ -157,13 +155,13 @@ impl NewOrderListOcoParams {
         self
     }
 
-    pub fn above_price(mut self, above_price: f64) -> Self {
-        self.above_price = Some(above_price);
+    pub fn above_price(mut self, above_price: impl Into<Decimal>) -> Self {
+        self.above_price = Some(above_price.into());
         self
     }
 
-    pub fn above_stop_price(mut self, above_stop_price: f64) -> Self {
-        self.above_stop_price = Some(above_stop_price);
+    pub fn above_stop_price(mut self, above_stop_price: impl Into<Decimal>) -> Self {
+        self.above_stop_price = Some(above_stop_price.into());
         self
     }
 
@@ -201,13 +199,13 @@ impl NewOrderListOcoParams {
         self
     }
 
-    pub fn below_price(mut self, below_price: f64) -> Self {
-        self.below_price = Some(below_price);
+    pub fn below_price(mut self, below_price: impl Into<Decimal>) -> Self {
+        self.below_price = Some(below_price.into());
         self
     }
 
-    pub fn below_stop_price(mut self, below_stop_price: f64) -> Self {
-        self.below_stop_price = Some(below_stop_price);
+    pub fn below_stop_price(mut self, below_stop_price: impl Into<Decimal>) -> Self {
+        self.below_stop_price = Some(below_stop_price.into());
         self
     }
 
@@ -290,25 +288,29 @@ pub struct OrderListItem {
 #[serde(rename_all = "camelCase")]
 pub struct OrderListReport {
     pub symbol: String,
-    pub orig_client_order_id: String,
+    pub orig_client_order_id: Option<String>,
     pub order_id: i64,
     pub order_list_id: i64,
     pub client_order_id: String,
-    pub price: String,
-    pub orig_qty: String,
-    pub executed_qty: String,
-    pub cummulative_quote_qty: String,
+    pub transact_time: i64,
+    pub price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub orig_quote_order_qty: Decimal,
+    pub cummulative_quote_qty: Decimal,
     pub status: OrderStatus,
     pub time_in_force: TimeInForce,
     pub r#type: OrderType,
     pub side: OrderSide,
-    pub stop_price: String,
+    pub stop_price: Option<Decimal>,
+    pub iceberg_qty: Option<Decimal>,
+    pub working_time: Option<i64>,
     pub self_trade_prevention_mode: SelfTradePreventionMode,
-    pub transaction_time: i64,
 }
 
 web_socket!(
     "orderList.place.oco",
+    SecurityType::Trade,
     NewOrderListOcoWebSocket,
     NewOrderListOcoParams,
     NewOrderListOcoResponse
@@ -1,8 +1,8 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::Serialize;
 
 use crate::{
+    decimal::Decimal,
     enums::{
         OrderSide, OrderType, ResponseType, SecurityType, SelfTradePreventionMode, TimeInForce,
     },
@@ -82,8 +82,8 @@ pub struct NewOrderListOtoParams {
     working_side: OrderSide,
     #[serde(skip_serializing_if = "Option::is_none")]
     working_client_order_id: Option<String>,
-    working_price: f64,
-    working_quantity: f64,
+    working_price: Decimal,
+    working_quantity: Decimal,
     #[serde(skip_serializing_if = "Option::is_none")]
     working_iceberg_qty: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -96,12 +96,12 @@ pub struct NewOrderListOtoParams {
     pending_side: OrderSide,
     #[serde(skip_serializing_if = "Option::is_none")]
     pending_client_order_id: Option<String>,
-    pending_price: f64,
+    pending_price: Decimal,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pending_stop_price: Option<f64>,
+    pending_stop_price: Option<Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pending_trailing_delta: Option<f64>,
-    pending_quantity: f64,
+    pending_quantity: Decimal,
     #[serde(skip_serializing_if = "Option::is_none")]
     pending_iceberg_qty: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -112,7 +112,6 @@ pub struct NewOrderListOtoParams {
     pending_strategy_type: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl NewOrderListOtoParams {
@@ -120,12 +119,12 @@ impl NewOrderListOtoParams {
         symbol: &str,
         working_type: OrderType,
         working_side: OrderSide,
-        working_price: f64,
-        working_quantity: f64,
+        working_price: impl Into<Decimal>,
+        working_quantity: impl Into<Decimal>,
         pending_type: OrderType,
         pending_side: OrderSide,
-        pending_price: f64,
-        pending_quantity: f64,
+        pending_price: impl Into<Decimal>,
+        pending_quantity: impl Into<Decimal>,
     ) -> Self {
         Self {
             symbol: symbol.to_owned(),
@@ -135,8 +134,8 @@ impl NewOrderListOtoParams {
             working_type,
             working_side,
             working_client_order_id: None,
-            working_price,
-            working_quantity,
+            working_price: working_price.into(),
+            working_quantity: working_quantity.into(),
             working_iceberg_qty: None,
             working_time_in_force: None,
             working_strategy_id: None,
@@ -144,16 +143,15 @@ impl NewOrderListOtoParams {
             pending_type,
             pending_side,
             pending_client_order_id: None,
-            pending_price,
+            pending_price: pending_price.into(),
             pending_stop_price: None,
             pending_trailing_delta: None,
-            pending_quantity,
+            pending_quantity: pending_quantity.into(),
             pending_iceberg_qty: None,
             pending_time_in_force: None,
             pending_strategy_id: None,
             pending_strategy_type: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -205,8 +203,8 @@ impl NewOrderListOtoParams {
         self
     }
 
-    pub fn pending_stop_price(mut self, pending_stop_price: f64) -> Self {
-        self.pending_stop_price = Some(pending_stop_price);
+    pub fn pending_stop_price(mut self, pending_stop_price: impl Into<Decimal>) -> Self {
+        self.pending_stop_price = Some(pending_stop_price.into());
         self
     }
 
@@ -246,6 +244,7 @@ pub type NewOrderListOtoResponse = OrderListResult;
 
 web_socket!(
     "orderList.place.oto",
+    SecurityType::Trade,
     NewOrderListOtoWebSocket,
     NewOrderListOtoParams,
     NewOrderListOtoResponse
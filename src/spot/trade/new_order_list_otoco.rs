@@ -1,8 +1,8 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::Serialize;
 
 use crate::{
+    decimal::Decimal,
     enums::{
         OrderSide, OrderType, ResponseType, SecurityType, SelfTradePreventionMode, TimeInForce,
     },
@@ -78,8 +78,8 @@ pub struct NewOrderListOtocoParams {
     working_side: OrderSide,
     #[serde(skip_serializing_if = "Option::is_none")]
     working_client_order_id: Option<String>,
-    working_price: f64,
-    working_quantity: f64,
+    working_price: Decimal,
+    working_quantity: Decimal,
     #[serde(skip_serializing_if = "Option::is_none")]
     working_iceberg_qty: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -89,14 +89,14 @@ pub struct NewOrderListOtocoParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     working_strategy_type: Option<i64>,
     pending_side: OrderSide,
-    pending_quantity: f64,
+    pending_quantity: Decimal,
     pending_above_type: OrderType,
     #[serde(skip_serializing_if = "Option::is_none")]
     pending_above_client_order_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pending_above_price: Option<f64>,
+    pending_above_price: Option<Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pending_above_stop_price: Option<f64>,
+    pending_above_stop_price: Option<Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pending_above_trailing_delta: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -112,9 +112,9 @@ pub struct NewOrderListOtocoParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pending_below_client_order_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pending_below_price: Option<f64>,
+    pending_below_price: Option<Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pending_below_stop_price: Option<f64>,
+    pending_below_stop_price: Option<Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pending_below_trailing_delta: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -127,7 +127,6 @@ pub struct NewOrderListOtocoParams {
     pending_below_strategy_type: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl NewOrderListOtocoParams {
@@ -135,10 +134,10 @@ impl NewOrderListOtocoParams {
         symbol: &str,
         working_type: OrderType,
         working_side: OrderSide,
-        working_price: f64,
-        working_quantity: f64,
+        working_price: impl Into<Decimal>,
+        working_quantity: impl Into<Decimal>,
         pending_side: OrderSide,
-        pending_quantity: f64,
+        pending_quantity: impl Into<Decimal>,
         pending_above_type: OrderType,
     ) -> Self {
         Self {
@@ -149,14 +148,14 @@ impl NewOrderListOtocoParams {
             working_type,
             working_side,
             working_client_order_id: None,
-            working_price,
-            working_quantity,
+            working_price: working_price.into(),
+            working_quantity: working_quantity.into(),
             working_iceberg_qty: None,
             working_time_in_force: None,
             working_strategy_id: None,
             working_strategy_type: None,
             pending_side,
-            pending_quantity,
+            pending_quantity: pending_quantity.into(),
             pending_above_type,
             pending_above_client_order_id: None,
             pending_above_price: None,
@@ -176,7 +175,6 @@ impl NewOrderListOtocoParams {
             pending_below_strategy_id: None,
             pending_below_strategy_type: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -228,13 +226,16 @@ impl NewOrderListOtocoParams {
         self
     }
 
-    pub fn pending_above_price(mut self, pending_above_price: f64) -> Self {
-        self.pending_above_price = Some(pending_above_price);
+    pub fn pending_above_price(mut self, pending_above_price: impl Into<Decimal>) -> Self {
+        self.pending_above_price = Some(pending_above_price.into());
         self
     }
 
-    pub fn pending_above_stop_price(mut self, pending_above_stop_price: f64) -> Self {
-        self.pending_above_stop_price = Some(pending_above_stop_price);
+    pub fn pending_above_stop_price(
+        mut self,
+        pending_above_stop_price: impl Into<Decimal>,
+    ) -> Self {
+        self.pending_above_stop_price = Some(pending_above_stop_price.into());
         self
     }
 
@@ -273,13 +274,16 @@ impl NewOrderListOtocoParams {
         self
     }
 
-    pub fn pending_below_price(mut self, pending_below_price: f64) -> Self {
-        self.pending_below_price = Some(pending_below_price);
+    pub fn pending_below_price(mut self, pending_below_price: impl Into<Decimal>) -> Self {
+        self.pending_below_price = Some(pending_below_price.into());
         self
     }
 
-    pub fn pending_below_stop_price(mut self, pending_below_stop_price: f64) -> Self {
-        self.pending_below_stop_price = Some(pending_below_stop_price);
+    pub fn pending_below_stop_price(
+        mut self,
+        pending_below_stop_price: impl Into<Decimal>,
+    ) -> Self {
+        self.pending_below_stop_price = Some(pending_below_stop_price.into());
         self
     }
 
@@ -319,6 +323,7 @@ pub type NewOrderListOtocoResponse = OrderListResult;
 
 web_socket!(
     "orderList.place.otoco",
+    SecurityType::Trade,
     NewOrderListOtocoWebSocket,
     NewOrderListOtocoParams,
     NewOrderListOtocoResponse
@@ -1,8 +1,8 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    decimal::Decimal,
     enums::{
         OrderSide, OrderStatus, OrderType, ResponseType, SecurityType, SelfTradePreventionMode,
         TimeInForce, WorkingFloor,
@@ -17,7 +17,8 @@ endpoint!(
     SecurityType::Trade,
     NewOrderUsingSorEndpoint,
     NewOrderUsingSorParams,
-    NewOrderUsingSorResponse
+    NewOrderUsingSorResponse,
+    retry = |params| params.new_client_order_id.is_some()
 );
 
 /// Places an order using smart order routing (SOR).
@@ -43,9 +44,9 @@ pub struct NewOrderUsingSorParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     time_in_force: Option<TimeInForce>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    quantity: Option<f64>,
+    quantity: Option<Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    price: Option<f64>,
+    price: Option<Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
     new_client_order_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -53,14 +54,13 @@ pub struct NewOrderUsingSorParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     strategy_type: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    iceberg_qty: Option<f64>,
+    iceberg_qty: Option<Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
     new_order_resp_type: Option<ResponseType>,
     #[serde(skip_serializing_if = "Option::is_none")]
     self_trade_prevention_mode: Option<SelfTradePreventionMode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl NewOrderUsingSorParams {
@@ -79,7 +79,6 @@ impl NewOrderUsingSorParams {
             new_order_resp_type: None,
             self_trade_prevention_mode: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -88,19 +87,24 @@ impl NewOrderUsingSorParams {
         self
     }
 
-    pub fn quantity(mut self, quantity: f64) -> Self {
-        self.quantity = Some(quantity);
+    pub fn quantity(mut self, quantity: impl Into<Decimal>) -> Self {
+        self.quantity = Some(quantity.into());
         self
     }
 
-    pub fn price(mut self, price: f64) -> Self {
-        self.price = Some(price);
+    pub fn price(mut self, price: impl Into<Decimal>) -> Self {
+        self.price = Some(price.into());
         self
     }
 
     /// A unique id among open orders. Automatically generated if not sent.
     /// Orders with the same `new_client_order_id` can be accepted only when the
     /// previous one is filled, otherwise the order will be rejected.
+    ///
+    /// Setting it allows the request to be retried when it is rejected before
+    /// being executed. When its execution status is unknown, the order has to
+    /// be queried by `origClientOrderId` before it is sent again, see
+    /// [`RetryPolicy`](crate::rest_api::RetryPolicy).
     pub fn new_client_order_id(mut self, new_client_order_id: &str) -> Self {
         self.new_client_order_id = Some(new_client_order_id.to_owned());
         self
@@ -118,8 +122,8 @@ impl NewOrderUsingSorParams {
     }
 
     /// Used with `LIMIT` to create an iceberg order.
-    pub fn iceberg_qty(mut self, iceberg_qty: f64) -> Self {
-        self.iceberg_qty = Some(iceberg_qty);
+    pub fn iceberg_qty(mut self, iceberg_qty: impl Into<Decimal>) -> Self {
+        self.iceberg_qty = Some(iceberg_qty.into());
         self
     }
 
@@ -151,10 +155,11 @@ pub struct NewOrderUsingSorResponse {
     pub order_list_id: i64,
     pub client_order_id: String,
     pub transact_time: i64,
-    pub price: String,
-    pub orig_qty: String,
-    pub executed_qty: String,
-    pub cummulative_quote_qty: String,
+    pub price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub orig_quote_order_qty: Decimal,
+    pub cummulative_quote_qty: Decimal,
     pub status: OrderStatus,
     pub time_in_force: TimeInForce,
     pub r#type: OrderType,
@@ -170,9 +175,9 @@ pub struct NewOrderUsingSorResponse {
 #[serde(rename_all = "camelCase")]
 pub struct SorOrderFill {
     pub match_type: String,
-    pub price: String,
-    pub qty: String,
-    pub commission: String,
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub commission: Decimal,
     pub commission_asset: String,
     pub trade_id: i64,
     pub alloc_id: i64,
@@ -180,6 +185,7 @@ pub struct SorOrderFill {
 
 web_socket!(
     "sor.order.place",
+    SecurityType::Trade,
     NewOrderUsingSorWebSocket,
     NewOrderUsingSorParams,
     NewOrderUsingSorResponse
@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::Serialize;
 
@@ -12,7 +11,8 @@ endpoint!(
     SecurityType::UserData,
     QueryAllOrderListsEndpoint,
     QueryAllOrderListsParams,
-    QueryAllOrderListsResponse
+    QueryAllOrderListsResponse,
+    weight = 20
 );
 
 /// Retrieves all order lists based on provided optional parameters.
@@ -45,7 +45,6 @@ pub struct QueryAllOrderListsParams {
     limit: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryAllOrderListsParams {
@@ -56,7 +55,6 @@ impl QueryAllOrderListsParams {
             end_time: None,
             limit: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
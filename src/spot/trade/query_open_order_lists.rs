@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::Serialize;
 
@@ -30,6 +29,10 @@ impl Endpoint for QueryOpenOrderListsEndpoint<'_> {
     fn security_type(&self) -> SecurityType {
         SecurityType::UserData
     }
+
+    fn weight(&self, _params: &Self::Params) -> u32 {
+        6
+    }
 }
 
 impl Params for QueryOpenOrderListsParams {}
@@ -53,15 +56,11 @@ impl<'r> QueryOpenOrderListsEndpoint<'r> {
 pub struct QueryOpenOrderListsParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryOpenOrderListsParams {
     pub fn new() -> Self {
-        Self {
-            recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
-        }
+        Self { recv_window: None }
     }
 
     /// The value cannot be greater than 60000.
@@ -75,9 +74,11 @@ pub type QueryOpenOrderListsResponse = Vec<OrderListResult>;
 
 web_socket!(
     "openOrderLists.status",
+    SecurityType::UserData,
     QueryOpenOrderListsWebSocket,
     QueryOpenOrderListsParams,
-    QueryOpenOrderListsResponse
+    QueryOpenOrderListsResponse,
+    weight = 6
 );
 
 pub struct QueryOpenOrderListsWebSocket<'w> {
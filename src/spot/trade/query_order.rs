@@ -1,8 +1,8 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    decimal::Decimal,
     enums::{
         OrderSide, OrderStatus, OrderType, SecurityType, SelfTradePreventionMode, TimeInForce,
     },
@@ -16,7 +16,8 @@ endpoint!(
     SecurityType::UserData,
     QueryOrderEndpoint,
     QueryOrderParams,
-    QueryOrderResponse
+    QueryOrderResponse,
+    weight = 4
 );
 
 /// Check an order's status.
@@ -48,7 +49,6 @@ pub struct QueryOrderParams {
     orig_client_order_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryOrderParams {
@@ -58,7 +58,6 @@ impl QueryOrderParams {
             order_id: None,
             orig_client_order_id: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -88,29 +87,31 @@ pub struct OrderDetail {
     pub order_id: i64,
     pub order_list_id: i64,
     pub client_order_id: String,
-    pub price: String,
-    pub orig_qty: String,
-    pub executed_qty: String,
-    pub cummulative_quote_qty: String,
+    pub price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub cummulative_quote_qty: Decimal,
     pub status: OrderStatus,
     pub time_in_force: TimeInForce,
     pub r#type: OrderType,
     pub side: OrderSide,
-    pub stop_price: String,
-    pub iceberg_qty: String,
+    pub stop_price: Decimal,
+    pub iceberg_qty: Decimal,
     pub time: i64,
     pub update_time: i64,
     pub is_working: bool,
     pub working_time: i64,
-    pub orig_quote_order_qty: String,
+    pub orig_quote_order_qty: Decimal,
     pub self_trade_prevention_mode: SelfTradePreventionMode,
 }
 
 web_socket!(
     "order.status",
+    SecurityType::UserData,
     QueryOrderWebSocket,
     QueryOrderParams,
-    QueryOrderResponse
+    QueryOrderResponse,
+    weight = 4
 );
 
 pub struct QueryOrderWebSocket<'w> {
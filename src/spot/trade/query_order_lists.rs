@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::Serialize;
 
@@ -30,6 +29,10 @@ impl Endpoint for QueryOrderListsEndpoint<'_> {
     fn security_type(&self) -> SecurityType {
         SecurityType::UserData
     }
+
+    fn weight(&self, _params: &Self::Params) -> u32 {
+        4
+    }
 }
 
 impl Params for QueryOrderListsParams {}
@@ -64,7 +67,6 @@ pub struct QueryOrderListsParams {
     orig_client_order_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryOrderListsParams {
@@ -74,7 +76,6 @@ impl QueryOrderListsParams {
             order_id: None,
             orig_client_order_id: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -104,9 +105,11 @@ pub type QueryOrderListsResponse = OrderListResult;
 
 web_socket!(
     "orderList.status",
+    SecurityType::UserData,
     QueryOrderListsWebSocket,
     QueryOrderListsParams,
-    QueryOrderListsResponse
+    QueryOrderListsResponse,
+    weight = 4
 );
 
 pub struct QueryOrderListsWebSocket<'w> {
@@ -1,10 +1,10 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::enums::SecurityType;
 use crate::rest_api::endpoint;
 use crate::spot::account::Discount;
 use crate::web_socket_api::web_socket;
+use crate::{decimal::Decimal, enums::SecurityType};
 
 use super::NewOrderParams;
 
@@ -14,7 +14,12 @@ endpoint!(
     SecurityType::Trade,
     TestNewOrderEndpoint,
     TestNewOrderParams,
-    TestNewOrderResponse
+    TestNewOrderResponse,
+    weight = |params| if params.compute_commission_rates == Some(true) {
+        20
+    } else {
+        1
+    }
 );
 
 /// Test new order creation and signature/recvWindow long. Creates and validates
@@ -61,8 +66,8 @@ impl TestNewOrderParams {
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 pub enum TestNewOrderResponse {
-    Empty,
     CommissionRates(CommissionRates),
+    Empty {},
 }
 
 #[derive(Debug, Deserialize)]
@@ -76,15 +81,21 @@ pub struct CommissionRates {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CommissionForOrder {
-    pub maker: i64,
-    pub taker: i64,
+    pub maker: Decimal,
+    pub taker: Decimal,
 }
 
 web_socket!(
     "order.test",
+    SecurityType::Trade,
     TestNewOrderWebSocket,
     TestNewOrderParams,
-    TestNewOrderResponse
+    TestNewOrderResponse,
+    weight = |params| if params.compute_commission_rates == Some(true) {
+        20
+    } else {
+        1
+    }
 );
 
 pub struct TestNewOrderWebSocket<'w> {
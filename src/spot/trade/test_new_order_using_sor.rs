@@ -11,7 +11,12 @@ endpoint!(
     SecurityType::Trade,
     TestNewOrderUsingSorEndpoint,
     TestNewOrderUsingSorParams,
-    TestNewOrderUsingSorResponse
+    TestNewOrderUsingSorResponse,
+    weight = |params| if params.compute_commission_rates == Some(true) {
+        20
+    } else {
+        1
+    }
 );
 
 /// Test new order creation and signature/recvWindow using smart order routing
@@ -56,15 +61,21 @@ impl TestNewOrderUsingSorParams {
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 pub enum TestNewOrderUsingSorResponse {
-    Empty,
     CommissionRates(CommissionRates),
+    Empty {},
 }
 
 web_socket!(
     "sor.order.test",
+    SecurityType::Trade,
     TestNewOrderUsingSorWebSocket,
     TestNewOrderUsingSorParams,
-    TestNewOrderUsingSorResponse
+    TestNewOrderUsingSorResponse,
+    weight = |params| if params.compute_commission_rates == Some(true) {
+        20
+    } else {
+        1
+    }
 );
 
 pub struct TestNewOrderUsingSorWebSocket<'w> {
@@ -9,7 +9,8 @@ endpoint!(
     SecurityType::UserStream,
     CloseUserDataStreamEndpoint,
     CloseUserDataStreamParams,
-    CloseUserDataStreamResponse
+    CloseUserDataStreamResponse,
+    weight = 2
 );
 
 /// Close a user data stream.
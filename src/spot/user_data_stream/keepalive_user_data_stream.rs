@@ -9,7 +9,8 @@ endpoint!(
     SecurityType::UserStream,
     KeepaliveUserDataStreamEndpoint,
     KeepaliveUserDataStreamParams,
-    KeepaliveUserDataStreamResponse
+    KeepaliveUserDataStreamResponse,
+    weight = 2
 );
 
 /// Keepalive a user data stream to prevent a time out. User data streams will
@@ -7,6 +7,7 @@ pub use keepalive_user_data_stream::*;
 pub use start_user_data_stream::*;
 
 use crate::{
+    decimal::Decimal,
     enums::{
         ContingencyType, OrderListOrderStatus, OrderListStatus, OrderSide, OrderType,
         SelfTradePreventionMode, TimeInForce, WorkingFloor,
@@ -39,6 +40,8 @@ pub enum UserDataStreamPayload {
     ExecutionReport(ExecutionReport),
     ListStatus(ListStatus),
     ListenKeyExpired(ListenKeyExpired),
+    /// The end of a subscription of the WebSocket API, e.g. on logout.
+    EventStreamTerminated(EventStreamTerminated),
 }
 
 impl Payload for UserDataStreamPayload {}
@@ -58,9 +61,9 @@ pub struct BalanceData {
     #[serde(rename = "a")]
     pub asset: String,
     #[serde(rename = "f")]
-    pub free: String,
+    pub free: Decimal,
     #[serde(rename = "l")]
-    pub locked: String,
+    pub locked: Decimal,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -70,7 +73,7 @@ pub struct BalanceUpdate {
     #[serde(rename = "a")]
     pub asset: String,
     #[serde(rename = "d")]
-    pub balance_delta: String,
+    pub balance_delta: Decimal,
     #[serde(rename = "T")]
     pub clear_time: i64,
 }
@@ -90,13 +93,13 @@ pub struct ExecutionReport {
     #[serde(rename = "f")]
     pub time_in_force: TimeInForce,
     #[serde(rename = "q")]
-    pub quantity: String,
+    pub quantity: Decimal,
     #[serde(rename = "p")]
-    pub price: String,
+    pub price: Decimal,
     #[serde(rename = "P")]
-    pub stop_price: String,
+    pub stop_price: Decimal,
     #[serde(rename = "F")]
-    pub iceberg_quantity: String,
+    pub iceberg_quantity: Decimal,
     #[serde(rename = "g")]
     pub order_list_id: i64,
     #[serde(rename = "C")]
@@ -110,13 +113,13 @@ pub struct ExecutionReport {
     #[serde(rename = "i")]
     pub order_id: i64,
     #[serde(rename = "l")]
-    pub last_executed_quantity: String,
+    pub last_executed_quantity: Decimal,
     #[serde(rename = "z")]
-    pub cummulative_filled_quantity: String,
+    pub cummulative_filled_quantity: Decimal,
     #[serde(rename = "L")]
-    pub last_executed_price: String,
+    pub last_executed_price: Decimal,
     #[serde(rename = "n")]
-    pub commission_amount: String,
+    pub commission_amount: Decimal,
     #[serde(rename = "T")]
     pub transaction_time: i64,
     #[serde(rename = "t")]
@@ -132,11 +135,11 @@ pub struct ExecutionReport {
     #[serde(rename = "O")]
     pub order_creation_time: i64,
     #[serde(rename = "Z")]
-    pub cumulative_quote_asset_quantity: String,
+    pub cumulative_quote_asset_quantity: Decimal,
     #[serde(rename = "Y")]
-    pub last_quote_asset_quantity: String,
+    pub last_quote_asset_quantity: Decimal,
     #[serde(rename = "Q")]
-    pub quote_order_quantity: String,
+    pub quote_order_quantity: Decimal,
     #[serde(rename = "V")]
     pub self_trade_prevention_mode: SelfTradePreventionMode,
     #[serde(flatten)]
@@ -149,7 +152,7 @@ pub struct ConditionalData {
     pub commission_asset: Option<String>,
     #[serde(rename = "d")]
     pub trailing_delta: Option<i64>,
-    #[serde(rename = "d")]
+    #[serde(rename = "D")]
     pub trailing_time: Option<i64>,
     #[serde(rename = "j")]
     pub strategy_id: Option<i64>,
@@ -158,9 +161,9 @@ pub struct ConditionalData {
     #[serde(rename = "v")]
     pub prevented_match_id: Option<i64>,
     #[serde(rename = "A")]
-    pub prevented_quantity: Option<String>,
+    pub prevented_quantity: Option<Decimal>,
     #[serde(rename = "B")]
-    pub last_prevented_quantity: Option<String>,
+    pub last_prevented_quantity: Option<Decimal>,
     #[serde(rename = "u")]
     pub trade_group_id: Option<i64>,
     #[serde(rename = "U")]
@@ -168,11 +171,11 @@ pub struct ConditionalData {
     #[serde(rename = "Cs")]
     pub counter_symbol: Option<String>,
     #[serde(rename = "pl")]
-    pub prevented_execution_quantity: Option<String>,
+    pub prevented_execution_quantity: Option<Decimal>,
     #[serde(rename = "pL")]
-    pub prevented_execution_price: Option<String>,
+    pub prevented_execution_price: Option<Decimal>,
     #[serde(rename = "pY")]
-    pub prevented_execution_quote_qty: Option<String>,
+    pub prevented_execution_quote_qty: Option<Decimal>,
     #[serde(rename = "W")]
     pub working_time: Option<i64>,
     #[serde(rename = "b")]
@@ -203,8 +206,10 @@ pub struct ListStatus {
     pub list_reject_reason: String,
     #[serde(rename = "C")]
     pub list_client_order_id: String,
-    #[serde(rename = "O")]
+    #[serde(rename = "T")]
     pub transaction_time: i64,
+    #[serde(rename = "O")]
+    pub orders: Vec<ListStatusObject>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -224,3 +229,9 @@ pub struct ListenKeyExpired {
     #[serde(rename = "listenKey")]
     pub listen_key: String,
 }
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct EventStreamTerminated {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+}
@@ -9,7 +9,8 @@ endpoint!(
     SecurityType::UserStream,
     StartUserDataStreamEndpoint,
     StartUserDataStreamParams,
-    StartUserDataStreamResponse
+    StartUserDataStreamResponse,
+    weight = 2
 );
 
 /// Start a new user data stream. The stream will close after 60 minutes unless
@@ -1,15 +1,23 @@
 use serde::Deserialize;
 
-use crate::web_socket_stream::Payload;
+use crate::{
+    decimal::Decimal,
+    web_socket_stream::{CombinedMessage, Payload},
+};
 
+/// Payloads of combined spot streams, routed on the name of their stream.
 #[derive(Clone, Debug)]
 pub enum SpotStreamPayloadload {
     AggregatedTrade(AggregatedTrade),
     Trade(Trade),
     Kline(Kline),
     MiniTicker(MiniTicker),
+    /// The `!miniTicker@arr` stream.
+    MiniTickers(Vec<MiniTicker>),
     Ticker(Ticker),
     RollingWindowTicker(RollingWindowTicker),
+    /// The `!ticker_<window>@arr` streams.
+    RollingWindowTickers(Vec<RollingWindowTicker>),
     BookTicker(BookTicker),
     AvgPrice(AvgPrice),
     PartialBookDepth(PartialBookDepth),
@@ -23,56 +31,35 @@ impl<'de> Deserialize<'de> for SpotStreamPayloadload {
     where
         D: serde::Deserializer<'de>,
     {
-        #[derive(Deserialize)]
-        struct Helper {
-            stream: String,
-            data: serde_json::Value,
-        }
+        let message = CombinedMessage::deserialize(deserializer)?;
+        let stream = message.stream.as_str();
 
-        let helper = Helper::deserialize(deserializer)?;
+        // All market streams: `!<stream>@arr`.
+        if let Some(stream) = stream.strip_prefix('!') {
+            return match stream.strip_suffix("@arr") {
+                Some("miniTicker") => message.data().map(Self::MiniTickers),
+                Some(s) if s.starts_with("ticker_") => {
+                    message.data().map(Self::RollingWindowTickers)
+                }
+                _ => Err(message.unknown_stream()),
+            };
+        }
 
-        if helper.stream.ends_with("@aggTrade") {
-            Ok(SpotStreamPayloadload::AggregatedTrade(
-                AggregatedTrade::deserialize(helper.data).map_err(serde::de::Error::custom)?,
-            ))
-        } else if helper.stream.ends_with("@trade") {
-            Ok(SpotStreamPayloadload::Trade(
-                Trade::deserialize(helper.data).map_err(serde::de::Error::custom)?,
-            ))
-        } else if helper.stream.contains("@kline_") {
-            Ok(SpotStreamPayloadload::Kline(
-                Kline::deserialize(helper.data).map_err(serde::de::Error::custom)?,
-            ))
-        } else if helper.stream.ends_with("@miniTicker") {
-            Ok(SpotStreamPayloadload::MiniTicker(
-                MiniTicker::deserialize(helper.data).map_err(serde::de::Error::custom)?,
-            ))
-        } else if helper.stream.ends_with("@ticker") {
-            Ok(SpotStreamPayloadload::Ticker(
-                Ticker::deserialize(helper.data).map_err(serde::de::Error::custom)?,
-            ))
-        } else if helper.stream.contains("@ticker_") {
-            Ok(SpotStreamPayloadload::RollingWindowTicker(
-                RollingWindowTicker::deserialize(helper.data).map_err(serde::de::Error::custom)?,
-            ))
-        } else if helper.stream.ends_with("@bookTicker") {
-            Ok(SpotStreamPayloadload::BookTicker(
-                BookTicker::deserialize(helper.data).map_err(serde::de::Error::custom)?,
-            ))
-        } else if helper.stream.ends_with("@avgPrice") {
-            Ok(SpotStreamPayloadload::AvgPrice(
-                AvgPrice::deserialize(helper.data).map_err(serde::de::Error::custom)?,
-            ))
-        } else if helper.stream.ends_with("@depth") {
-            Ok(SpotStreamPayloadload::Depth(
-                Depth::deserialize(helper.data).map_err(serde::de::Error::custom)?,
-            ))
-        } else if !helper.stream.ends_with("@depth") && helper.stream.contains("@depth") {
-            Ok(SpotStreamPayloadload::PartialBookDepth(
-                PartialBookDepth::deserialize(helper.data).map_err(serde::de::Error::custom)?,
-            ))
-        } else {
-            Err(serde::de::Error::custom("Unknown stream type"))
+        // Symbol streams: `<symbol>@<stream>[_<param>][@<speed>]`.
+        let name = stream.split_once('@').map_or("", |(_, name)| name);
+        let has_param = name.contains('_');
+        match name.split(['_', '@']).next().unwrap_or_default() {
+            "aggTrade" => message.data().map(Self::AggregatedTrade),
+            "trade" => message.data().map(Self::Trade),
+            "kline" => message.data().map(Self::Kline),
+            "miniTicker" => message.data().map(Self::MiniTicker),
+            "ticker" if has_param => message.data().map(Self::RollingWindowTicker),
+            "ticker" => message.data().map(Self::Ticker),
+            "bookTicker" => message.data().map(Self::BookTicker),
+            "avgPrice" => message.data().map(Self::AvgPrice),
+            "depth" => message.data().map(Self::Depth),
+            "depth5" | "depth10" | "depth20" => message.data().map(Self::PartialBookDepth),
+            _ => Err(message.unknown_stream()),
         }
     }
 }
@@ -88,9 +75,9 @@ pub struct AggregatedTrade {
     #[serde(rename = "a")]
     pub aggregated_trade_id: i64,
     #[serde(rename = "p")]
-    pub price: String,
+    pub price: Decimal,
     #[serde(rename = "q")]
-    pub quantity: String,
+    pub quantity: Decimal,
     #[serde(rename = "f")]
     pub first_trade_id: i64,
     #[serde(rename = "l")]
@@ -116,9 +103,9 @@ pub struct Trade {
     #[serde(rename = "t")]
     pub trade_id: i64,
     #[serde(rename = "p")]
-    pub price: String,
+    pub price: Decimal,
     #[serde(rename = "q")]
-    pub quantity: String,
+    pub quantity: Decimal,
     #[serde(rename = "T")]
     pub trade_time: i64,
     #[serde(rename = "m")]
@@ -158,25 +145,25 @@ pub struct KlineData {
     #[serde(rename = "L")]
     pub last_trade_id: i64,
     #[serde(rename = "o")]
-    pub open_price: String,
+    pub open_price: Decimal,
     #[serde(rename = "c")]
-    pub close_price: String,
+    pub close_price: Decimal,
     #[serde(rename = "h")]
-    pub high_price: String,
+    pub high_price: Decimal,
     #[serde(rename = "l")]
-    pub low_price: String,
+    pub low_price: Decimal,
     #[serde(rename = "v")]
-    pub base_asset_volume: String,
+    pub base_asset_volume: Decimal,
     #[serde(rename = "n")]
     pub number_of_trades: i64,
     #[serde(rename = "x")]
     pub is_closed: bool,
     #[serde(rename = "q")]
-    pub quote_asset_volume: String,
+    pub quote_asset_volume: Decimal,
     #[serde(rename = "V")]
-    pub taker_buy_base_asset_volume: String,
+    pub taker_buy_base_asset_volume: Decimal,
     #[serde(rename = "Q")]
-    pub taker_buy_quote_asset_volume: String,
+    pub taker_buy_quote_asset_volume: Decimal,
     #[serde(rename = "B")]
     pub ignore: String,
 }
@@ -190,17 +177,17 @@ pub struct MiniTicker {
     #[serde(rename = "s")]
     pub symbol: String,
     #[serde(rename = "c")]
-    pub close_price: String,
+    pub close_price: Decimal,
     #[serde(rename = "o")]
-    pub open_price: String,
+    pub open_price: Decimal,
     #[serde(rename = "h")]
-    pub high_price: String,
+    pub high_price: Decimal,
     #[serde(rename = "l")]
-    pub low_price: String,
+    pub low_price: Decimal,
     #[serde(rename = "v")]
-    pub base_asset_volume: String,
+    pub base_asset_volume: Decimal,
     #[serde(rename = "q")]
-    pub quote_asset_volume: String,
+    pub quote_asset_volume: Decimal,
 }
 
 impl Payload for MiniTicker {}
@@ -214,35 +201,35 @@ pub struct Ticker {
     #[serde(rename = "s")]
     pub symbol: String,
     #[serde(rename = "p")]
-    pub price_change: String,
+    pub price_change: Decimal,
     #[serde(rename = "P")]
-    pub price_change_percent: String,
+    pub price_change_percent: Decimal,
     #[serde(rename = "w")]
-    pub weighted_avg_price: String,
+    pub weighted_avg_price: Decimal,
     #[serde(rename = "x")]
-    pub first_trade_price: String,
+    pub first_trade_price: Decimal,
     #[serde(rename = "c")]
-    pub last_price: String,
+    pub last_price: Decimal,
     #[serde(rename = "Q")]
-    pub last_quantity: String,
+    pub last_quantity: Decimal,
     #[serde(rename = "b")]
-    pub best_bid_price: String,
+    pub best_bid_price: Decimal,
     #[serde(rename = "B")]
-    pub best_bid_quantity: String,
+    pub best_bid_quantity: Decimal,
     #[serde(rename = "a")]
-    pub best_ask_price: String,
+    pub best_ask_price: Decimal,
     #[serde(rename = "A")]
-    pub best_ask_quantity: String,
+    pub best_ask_quantity: Decimal,
     #[serde(rename = "o")]
-    pub open_price: String,
+    pub open_price: Decimal,
     #[serde(rename = "h")]
-    pub high_price: String,
+    pub high_price: Decimal,
     #[serde(rename = "l")]
-    pub low_price: String,
+    pub low_price: Decimal,
     #[serde(rename = "v")]
-    pub base_asset_volume: String,
+    pub base_asset_volume: Decimal,
     #[serde(rename = "q")]
-    pub quote_asset_volume: String,
+    pub quote_asset_volume: Decimal,
     #[serde(rename = "O")]
     pub open_time: i64,
     #[serde(rename = "C")]
@@ -266,23 +253,23 @@ pub struct RollingWindowTicker {
     #[serde(rename = "s")]
     pub symbol: String,
     #[serde(rename = "p")]
-    pub price_change: String,
+    pub price_change: Decimal,
     #[serde(rename = "P")]
-    pub price_change_percent: String,
+    pub price_change_percent: Decimal,
     #[serde(rename = "o")]
-    pub open_price: String,
+    pub open_price: Decimal,
     #[serde(rename = "h")]
-    pub high_price: String,
+    pub high_price: Decimal,
     #[serde(rename = "l")]
-    pub low_price: String,
+    pub low_price: Decimal,
     #[serde(rename = "c")]
-    pub close_price: String,
+    pub close_price: Decimal,
     #[serde(rename = "w")]
-    pub weighted_avg_price: String,
+    pub weighted_avg_price: Decimal,
     #[serde(rename = "v")]
-    pub base_asset_volume: String,
+    pub base_asset_volume: Decimal,
     #[serde(rename = "q")]
-    pub quote_asset_volume: String,
+    pub quote_asset_volume: Decimal,
     #[serde(rename = "O")]
     pub open_time: i64,
     #[serde(rename = "C")]
@@ -304,13 +291,13 @@ pub struct BookTicker {
     #[serde(rename = "s")]
     pub symbol: String,
     #[serde(rename = "b")]
-    pub best_bid_price: String,
+    pub best_bid_price: Decimal,
     #[serde(rename = "B")]
-    pub best_bid_quantity: String,
+    pub best_bid_quantity: Decimal,
     #[serde(rename = "a")]
-    pub best_ask_price: String,
+    pub best_ask_price: Decimal,
     #[serde(rename = "A")]
-    pub best_ask_quantity: String,
+    pub best_ask_quantity: Decimal,
 }
 
 impl Payload for BookTicker {}
@@ -326,7 +313,7 @@ pub struct AvgPrice {
     #[serde(rename = "i")]
     pub average_price_interval: String,
     #[serde(rename = "w")]
-    pub average_price: String,
+    pub average_price: Decimal,
     #[serde(rename = "T")]
     pub last_trade_time: i64,
 }
@@ -337,8 +324,8 @@ impl Payload for AvgPrice {}
 #[serde(rename_all = "camelCase")]
 pub struct PartialBookDepth {
     pub last_update_id: i64,
-    pub bids: Vec<(String, String)>,
-    pub asks: Vec<(String, String)>,
+    pub bids: Vec<(Decimal, Decimal)>,
+    pub asks: Vec<(Decimal, Decimal)>,
 }
 
 impl Payload for PartialBookDepth {}
@@ -356,9 +343,9 @@ pub struct Depth {
     #[serde(rename = "u")]
     pub final_update_id: i64,
     #[serde(rename = "b")]
-    pub bids: Vec<(String, String)>,
+    pub bids: Vec<(Decimal, Decimal)>,
     #[serde(rename = "a")]
-    pub asks: Vec<(String, String)>,
+    pub asks: Vec<(Decimal, Decimal)>,
 }
 
 impl Payload for Depth {}
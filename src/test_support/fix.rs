@@ -0,0 +1,205 @@
+use std::sync::{
+    atomic::{AtomicI64, Ordering},
+    Arc,
+};
+
+use jiff::Timestamp;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+use tracing::error;
+
+use super::{verify_ed25519_signature, State, API_KEY};
+use crate::fix_api::{msg_type, tag, FixApiError, FixMessage, SOH};
+
+/// Order ids assigned by the acceptor, shared by all sessions.
+static ORDER_ID: AtomicI64 = AtomicI64::new(1);
+
+pub(super) async fn serve(state: Arc<State>, listener: TcpListener) {
+    loop {
+        match listener.accept().await {
+            Ok((stream, _)) => {
+                tokio::spawn(handle_connection(state.clone(), stream));
+            }
+            Err(err) => error!("mock server accept error: {err}"),
+        }
+    }
+}
+
+/// The acceptor side of a session.
+struct Session {
+    stream: TcpStream,
+    buf: Vec<u8>,
+    sender_comp_id: String,
+    next_seq_num: u64,
+}
+
+impl Session {
+    async fn read(&mut self) -> Result<Option<FixMessage>, FixApiError> {
+        loop {
+            if let Some(len) = crate::fix_api::frame_len(&self.buf)? {
+                let msg = FixMessage::decode(&self.buf[..len]);
+                self.buf.drain(..len);
+                return msg.map(Some);
+            }
+            let mut chunk = [0u8; 4096];
+            let n = self.stream.read(&mut chunk).await?;
+            if n == 0 {
+                return Ok(None);
+            }
+            self.buf.extend_from_slice(&chunk[..n]);
+        }
+    }
+
+    async fn write(&mut self, msg: FixMessage) -> Result<(), FixApiError> {
+        let sending_time = Timestamp::now().strftime("%Y%m%d-%H:%M:%S%.3f");
+        let mut full = FixMessage::new(msg.msg_type())
+            .field(tag::SENDER_COMP_ID, "SPOT")
+            .field(tag::TARGET_COMP_ID, &self.sender_comp_id)
+            .field(tag::MSG_SEQ_NUM, self.next_seq_num)
+            .field(tag::SENDING_TIME, sending_time);
+        for (tag, value) in msg.fields() {
+            full.push(*tag, value);
+        }
+        self.next_seq_num += 1;
+        self.stream.write_all(full.encode().as_bytes()).await?;
+        Ok(())
+    }
+}
+
+async fn handle_connection(state: Arc<State>, stream: TcpStream) {
+    let mut session = Session {
+        stream,
+        buf: Vec::new(),
+        sender_comp_id: String::new(),
+        next_seq_num: 1,
+    };
+    if let Err(err) = run(&state, &mut session).await {
+        error!("mock FIX session error: {err}");
+    }
+}
+
+async fn run(state: &State, session: &mut Session) -> Result<(), FixApiError> {
+    let Some(logon) = session.read().await? else {
+        return Ok(());
+    };
+    session.sender_comp_id = logon
+        .get(tag::SENDER_COMP_ID)
+        .unwrap_or_default()
+        .to_owned();
+    state.fix_messages.lock().unwrap().push(logon.clone());
+    if logon.msg_type() != msg_type::LOGON || !verify_logon(&logon) {
+        let logout = FixMessage::new(msg_type::LOGOUT).field(tag::TEXT, "Logon rejected.");
+        return session.write(logout).await;
+    }
+    let resp = FixMessage::new(msg_type::LOGON)
+        .field(tag::ENCRYPT_METHOD, 0)
+        .field_opt(tag::HEART_BT_INT, logon.get(tag::HEART_BT_INT));
+    session.write(resp).await?;
+
+    let mut pushes = state.fix_pushes.subscribe();
+    loop {
+        let msg = tokio::select! {
+            msg = session.read() => match msg? {
+                Some(msg) => msg,
+                None => return Ok(()),
+            },
+            Ok(msg) = pushes.recv() => {
+                session.write(msg).await?;
+                continue;
+            }
+        };
+
+        state.fix_messages.lock().unwrap().push(msg.clone());
+        match msg.msg_type() {
+            msg_type::HEARTBEAT => continue,
+            msg_type::TEST_REQUEST => {
+                let heartbeat = FixMessage::new(msg_type::HEARTBEAT)
+                    .field_opt(tag::TEST_REQ_ID, msg.get(tag::TEST_REQ_ID));
+                session.write(heartbeat).await?;
+                continue;
+            }
+            _ => {}
+        }
+
+        if msg.msg_type() == msg_type::LOGOUT {
+            return session.write(FixMessage::new(msg_type::LOGOUT)).await;
+        }
+        for resp in state.respond_fix(&msg) {
+            session.write(resp).await?;
+        }
+    }
+}
+
+/// Verify the API key and the signature of a Logon message.
+fn verify_logon(logon: &FixMessage) -> bool {
+    let field = |tag| logon.get(tag).unwrap_or_default();
+    let payload = [
+        msg_type::LOGON,
+        field(tag::SENDER_COMP_ID),
+        field(tag::TARGET_COMP_ID),
+        field(tag::MSG_SEQ_NUM),
+        field(tag::SENDING_TIME),
+    ]
+    .join(&SOH.to_string());
+    field(tag::USERNAME) == API_KEY && verify_ed25519_signature(&payload, field(tag::RAW_DATA))
+}
+
+/// Default responses of the acceptor: orders are accepted and cancellations
+/// succeed, other application messages are rejected.
+pub(super) fn default_response(msg: &FixMessage) -> Vec<FixMessage> {
+    match msg.msg_type() {
+        msg_type::NEW_ORDER_SINGLE => vec![new_order(msg)],
+        msg_type::ORDER_CANCEL_REQUEST => vec![canceled(msg, msg.get(tag::CL_ORD_ID))],
+        msg_type::ORDER_CANCEL_REQUEST_AND_NEW_ORDER_SINGLE => vec![
+            canceled(msg, msg.get(tag::CANCEL_CL_ORD_ID)),
+            new_order(msg),
+        ],
+        msg_type::MARKET_DATA_REQUEST => vec![FixMessage::new(msg_type::MARKET_DATA_SNAPSHOT)
+            .field_opt(tag::MD_REQ_ID, msg.get(tag::MD_REQ_ID))
+            .field_opt(tag::SYMBOL, msg.get(tag::SYMBOL))
+            .field(tag::NO_MD_ENTRIES, 0)],
+        msg_type => vec![FixMessage::new(msg_type::REJECT)
+            .field_opt(tag::REF_SEQ_NUM, msg.get(tag::MSG_SEQ_NUM))
+            .field(tag::TEXT, format!("Unsupported message type {msg_type}."))],
+    }
+}
+
+fn execution_report(msg: &FixMessage, order_id: i64) -> FixMessage {
+    let now = Timestamp::now();
+    FixMessage::new(msg_type::EXECUTION_REPORT)
+        .field(tag::ORDER_ID, order_id)
+        .field(tag::EXEC_ID, now.as_nanosecond())
+        .field_opt(tag::SYMBOL, msg.get(tag::SYMBOL))
+        .field_opt(tag::SIDE, msg.get(tag::SIDE))
+        .field_opt(tag::ORD_TYPE, msg.get(tag::ORD_TYPE))
+        .field_opt(tag::ORDER_QTY, msg.get(tag::ORDER_QTY))
+        .field_opt(tag::PRICE, msg.get(tag::PRICE))
+        .field(tag::TRANSACT_TIME, now.strftime("%Y%m%d-%H:%M:%S%.3f"))
+}
+
+fn new_order(msg: &FixMessage) -> FixMessage {
+    let order_id = ORDER_ID.fetch_add(1, Ordering::Relaxed);
+    execution_report(msg, order_id)
+        .field_opt(tag::CL_ORD_ID, msg.get(tag::CL_ORD_ID))
+        .field(tag::EXEC_TYPE, "0")
+        .field(tag::ORD_STATUS, "0")
+        .field(tag::CUM_QTY, 0)
+        .field_opt(tag::LEAVES_QTY, msg.get(tag::ORDER_QTY))
+        .field(tag::CUM_QUOTE_QTY, 0)
+}
+
+fn canceled(msg: &FixMessage, cl_ord_id: Option<&str>) -> FixMessage {
+    let order_id = msg
+        .parse(tag::ORDER_ID)
+        .ok()
+        .flatten()
+        .unwrap_or_else(|| ORDER_ID.fetch_add(1, Ordering::Relaxed));
+    execution_report(msg, order_id)
+        .field_opt(tag::CL_ORD_ID, cl_ord_id)
+        .field_opt(tag::ORIG_CL_ORD_ID, msg.get(tag::ORIG_CL_ORD_ID))
+        .field(tag::EXEC_TYPE, "4")
+        .field(tag::ORD_STATUS, "4")
+        .field(tag::LEAVES_QTY, 0)
+}
@@ -0,0 +1,111 @@
+use std::{convert::Infallible, sync::Arc};
+
+use bytes::Bytes;
+use http_body_util::{BodyExt, Full};
+use hyper::{body::Incoming, server::conn::http1, service::service_fn, Request, Response};
+use hyper_util::rt::TokioIo;
+use tokio::net::TcpListener;
+use tracing::error;
+use url::form_urlencoded;
+
+use super::{
+    invalid_api_key, invalid_signature, verify_signature, verify_timestamp, MockRequest,
+    MockResponse, State, API_KEY,
+};
+
+pub(super) async fn serve(state: Arc<State>, listener: TcpListener) {
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(err) => {
+                error!("mock server accept error: {err}");
+                continue;
+            }
+        };
+
+        let state = state.clone();
+        tokio::spawn(async move {
+            let service = service_fn(move |req| {
+                let state = state.clone();
+                async move { Ok::<_, Infallible>(handle(&state, req).await) }
+            });
+            if let Err(err) = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .await
+            {
+                error!("mock server connection error: {err}");
+            }
+        });
+    }
+}
+
+async fn handle(state: &State, req: Request<Incoming>) -> Response<Full<Bytes>> {
+    let (parts, body) = req.into_parts();
+    let body = body
+        .collect()
+        .await
+        .map(|b| b.to_bytes())
+        .unwrap_or_default();
+    let query = parts.uri.query().unwrap_or_default();
+
+    // Binance accepts params in both the query string and the body.
+    let mut params: Vec<(String, String)> = form_urlencoded::parse(query.as_bytes())
+        .into_owned()
+        .collect();
+    params.extend(form_urlencoded::parse(&body).into_owned());
+
+    let req = MockRequest {
+        method: Some(parts.method),
+        path: parts.uri.path().to_owned(),
+        params,
+        headers: parts.headers,
+    };
+
+    let resp = match authenticate(&req, query, &body) {
+        Ok(()) => state.respond(req),
+        Err(resp) => {
+            state.requests.lock().unwrap().push(req);
+            resp
+        }
+    };
+
+    tokio::time::sleep(resp.delay).await;
+    let mut builder = Response::builder()
+        .status(resp.status)
+        .header("content-type", "application/json");
+    for (name, value) in &resp.headers {
+        builder = builder.header(name, value);
+    }
+    builder.body(Full::new(Bytes::from(resp.body))).unwrap()
+}
+
+/// Check the API key if one is sent, and the signature of signed requests.
+fn authenticate(req: &MockRequest, query: &str, body: &[u8]) -> Result<(), MockResponse> {
+    if let Some(api_key) = req.headers.get("x-mbx-apikey") {
+        if api_key != API_KEY {
+            return Err(invalid_api_key());
+        }
+    }
+    if !req.has_param("signature") {
+        return Ok(());
+    }
+    if !req.headers.contains_key("x-mbx-apikey") {
+        return Err(invalid_api_key());
+    }
+
+    // The signature is computed over the query string followed by the body,
+    // and it is the last parameter of either.
+    let payload = format!("{query}{}", String::from_utf8_lossy(body));
+    let (payload, signature) = payload
+        .rsplit_once("signature=")
+        .ok_or_else(invalid_signature)?;
+    let payload = payload.strip_suffix('&').unwrap_or(payload);
+    let signature: String = form_urlencoded::parse(format!("s={signature}").as_bytes())
+        .map(|(_, v)| v.into_owned())
+        .next()
+        .unwrap_or_default();
+    if !verify_signature(payload, &signature) {
+        return Err(invalid_signature());
+    }
+    verify_timestamp(req)
+}
@@ -0,0 +1,351 @@
+use std::{sync::Arc, time::Duration};
+
+use futures_util::{SinkExt, StreamExt};
+use jiff::Timestamp;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::mpsc,
+};
+use tokio_tungstenite::{
+    accept_hdr_async,
+    tungstenite::{
+        handshake::server::{Request, Response},
+        Message,
+    },
+};
+use tracing::error;
+
+use super::{
+    invalid_api_key, invalid_signature, verify_ed25519_signature, verify_signature,
+    verify_timestamp, MockRequest, MockResponse, State, API_KEY,
+};
+
+#[derive(Debug, Deserialize)]
+struct RequestFrame {
+    id: Value,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+/// Authentication state of a Web Socket API connection, and subscriptions of
+/// a stream connection.
+#[derive(Debug, Default)]
+struct Session {
+    connected_since: i64,
+    authorized_since: Option<i64>,
+    return_rate_limits: bool,
+    requests: i64,
+    streams: Vec<String>,
+    combined: bool,
+}
+
+pub(super) async fn serve(state: Arc<State>, listener: TcpListener) {
+    loop {
+        match listener.accept().await {
+            Ok((stream, _)) => {
+                tokio::spawn(handle_connection(state.clone(), stream));
+            }
+            Err(err) => error!("mock server accept error: {err}"),
+        }
+    }
+}
+
+async fn handle_connection(state: Arc<State>, stream: TcpStream) {
+    let mut path = String::new();
+    let mut query = String::new();
+    // The error type is defined by tungstenite.
+    #[allow(clippy::result_large_err)]
+    let callback = |req: &Request, resp: Response| {
+        path = req.uri().path().to_owned();
+        query = req.uri().query().unwrap_or_default().to_owned();
+        Ok(resp)
+    };
+    let stream = match accept_hdr_async(stream, callback).await {
+        Ok(stream) => stream,
+        Err(err) => {
+            error!("mock server handshake error: {err}");
+            return;
+        }
+    };
+
+    // Streams are served under `/ws` and `/stream`, the API under `/ws-api`.
+    let is_stream = !path.starts_with("/ws-api");
+    let mut streams = state.streams.subscribe();
+    let mut api_pushes = state.api_pushes.subscribe();
+    let mut disconnects = state.ws_disconnects.subscribe();
+    let subscribed = match path.strip_prefix("/ws/") {
+        Some(streams) => streams,
+        None => query.strip_prefix("streams=").unwrap_or_default(),
+    };
+    let mut session = Session {
+        connected_since: Timestamp::now().as_millisecond(),
+        authorized_since: None,
+        return_rate_limits: !query.split('&').any(|p| p == "returnRateLimits=false"),
+        requests: 0,
+        streams: subscribed
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .map(str::to_owned)
+            .collect(),
+        combined: path.starts_with("/stream"),
+    };
+
+    let (mut write, mut read) = stream.split();
+    // The delayed replies do not hold back the others.
+    let (delayed_tx, mut delayed_rx) = mpsc::unbounded_channel();
+    loop {
+        let reply = tokio::select! {
+            msg = read.next() => match msg {
+                Some(Ok(Message::Text(text))) => match handle_frame(&state, &mut session, &text) {
+                    Some((reply, delay)) if !delay.is_zero() => {
+                        let delayed_tx = delayed_tx.clone();
+                        tokio::spawn(async move {
+                            tokio::time::sleep(delay).await;
+                            let _ = delayed_tx.send(reply);
+                        });
+                        None
+                    }
+                    reply => reply.map(|(reply, _)| reply),
+                },
+                Some(Ok(Message::Close(_))) | None => break,
+                Some(Ok(_)) => continue,
+                Some(Err(err)) => {
+                    error!("mock server read error: {err}");
+                    break;
+                }
+            },
+            Some(reply) = delayed_rx.recv() => Some(reply),
+            Ok(msg) = streams.recv(), if is_stream => Some(msg),
+            Ok(msg) = api_pushes.recv(), if !is_stream => Some(msg),
+            Ok(()) = disconnects.recv() => {
+                let _ = write.send(Message::Close(None)).await;
+                break;
+            }
+        };
+        if let Some(reply) = reply {
+            if let Err(err) = write.send(Message::Text(reply)).await {
+                error!("mock server write error: {err}");
+                break;
+            }
+        }
+    }
+}
+
+/// The reply to a frame, and its delay.
+fn handle_frame(state: &State, session: &mut Session, text: &str) -> Option<(String, Duration)> {
+    let frame: RequestFrame = match serde_json::from_str(text) {
+        Ok(frame) => frame,
+        Err(err) => {
+            error!("mock server received a malformed frame: {err}");
+            return None;
+        }
+    };
+
+    if matches!(
+        frame.method.as_str(),
+        "SUBSCRIBE" | "UNSUBSCRIBE" | "LIST_SUBSCRIPTIONS" | "SET_PROPERTY" | "GET_PROPERTY"
+    ) {
+        state.requests.lock().unwrap().push(MockRequest {
+            method: None,
+            path: frame.method.clone(),
+            params: params(&frame.params),
+            headers: Default::default(),
+        });
+        if state
+            .ignored_stream_requests
+            .lock()
+            .unwrap()
+            .contains(&frame.method)
+        {
+            return None;
+        }
+        let reply = match subscription(session, &frame) {
+            Ok(result) => json!({ "result": result, "id": frame.id }),
+            Err(msg) => json!({ "error": { "code": 2, "msg": msg }, "id": frame.id }),
+        };
+        return Some((reply.to_string(), Duration::ZERO));
+    }
+
+    let req = MockRequest {
+        method: None,
+        path: frame.method,
+        params: params(&frame.params),
+        headers: Default::default(),
+    };
+    let resp = match authenticate(&req, session) {
+        Ok(Some(resp)) => {
+            state.requests.lock().unwrap().push(req);
+            resp
+        }
+        Ok(None) => state.respond(req),
+        Err(resp) => {
+            state.requests.lock().unwrap().push(req);
+            resp
+        }
+    };
+
+    let body = serde_json::from_str(&resp.body).unwrap_or(Value::String(resp.body));
+    let mut reply = if resp.status == 200 {
+        json!({ "id": frame.id, "status": 200, "result": body })
+    } else {
+        json!({ "id": frame.id, "status": resp.status, "error": body })
+    };
+    session.requests += 1;
+    let return_rate_limits = frame.params["returnRateLimits"]
+        .as_bool()
+        .unwrap_or(session.return_rate_limits);
+    if return_rate_limits {
+        reply["rateLimits"] = json!([{
+            "rateLimitType": "REQUEST_WEIGHT",
+            "interval": "MINUTE",
+            "intervalNum": 1,
+            "limit": 6000,
+            "count": session.requests,
+        }]);
+    }
+    Some((reply.to_string(), resp.delay))
+}
+
+/// Authenticate the request, handling the session methods. Returns the
+/// response to session requests, and an error if the request is rejected.
+fn authenticate(
+    req: &MockRequest,
+    session: &mut Session,
+) -> Result<Option<MockResponse>, MockResponse> {
+    match req.path.as_str() {
+        // Binance only accepts Ed25519 keys for the logon.
+        "session.logon" => {
+            verify_signed(req, verify_ed25519_signature)?;
+            let now = Timestamp::now().as_millisecond();
+            session.authorized_since = Some(now);
+            Ok(Some(session_status(session, now)))
+        }
+        "session.logout" => {
+            session.authorized_since = None;
+            let now = Timestamp::now().as_millisecond();
+            Ok(Some(session_status(session, now)))
+        }
+        "session.status" => {
+            let now = Timestamp::now().as_millisecond();
+            Ok(Some(session_status(session, now)))
+        }
+        // The events of the account are pushed with `MockServer::push_api`.
+        "userDataStream.subscribe" | "userDataStream.unsubscribe" => {
+            if session.authorized_since.is_none() {
+                return Err(invalid_api_key());
+            }
+            let result = match req.path.as_str() {
+                "userDataStream.subscribe" => json!({ "subscriptionId": 0 }),
+                _ => json!({}),
+            };
+            Ok(Some(MockResponse::serialize(&result)))
+        }
+        _ if req.has_param("signature") => verify_signed(req, verify_signature).map(|_| None),
+        // Requests with a timestamp need a signature unless the session is
+        // authenticated.
+        _ if req.has_param("timestamp") => {
+            if session.authorized_since.is_none() {
+                return Err(MockResponse::error(
+                    400,
+                    -1102,
+                    "Mandatory parameter 'signature' was not sent, was empty/null, or malformed.",
+                ));
+            }
+            verify_timestamp(req).map(|_| None)
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Verify a request signed over its params sorted by name, with `verify`.
+fn verify_signed(req: &MockRequest, verify: fn(&str, &str) -> bool) -> Result<(), MockResponse> {
+    if req.param("apiKey") != Some(API_KEY) {
+        return Err(invalid_api_key());
+    }
+    let mut params: Vec<_> = req
+        .params
+        .iter()
+        .filter(|(k, _)| k != "signature")
+        .collect();
+    params.sort();
+    let payload = params
+        .iter()
+        .map(|(k, v)| format!("{k}={v}"))
+        .collect::<Vec<_>>()
+        .join("&");
+    if !verify(&payload, req.param("signature").unwrap_or_default()) {
+        return Err(invalid_signature());
+    }
+    verify_timestamp(req)
+}
+
+/// Handle a request of a stream connection, on its subscriptions.
+fn subscription(session: &mut Session, frame: &RequestFrame) -> Result<Value, String> {
+    let invalid = || format!("Invalid request: {}", frame.params);
+    let params = frame.params.as_array().ok_or_else(invalid)?;
+    let streams = || {
+        params
+            .iter()
+            .map(|v| v.as_str().map(str::to_owned).ok_or_else(invalid))
+            .collect::<Result<Vec<_>, _>>()
+    };
+    let property = || match params.first().and_then(Value::as_str) {
+        Some("combined") => Ok(()),
+        _ => Err(invalid()),
+    };
+    match frame.method.as_str() {
+        "SUBSCRIBE" => {
+            for stream in streams()? {
+                if !session.streams.contains(&stream) {
+                    session.streams.push(stream);
+                }
+            }
+            Ok(Value::Null)
+        }
+        "UNSUBSCRIBE" => {
+            let streams = streams()?;
+            session.streams.retain(|s| !streams.contains(s));
+            Ok(Value::Null)
+        }
+        "LIST_SUBSCRIPTIONS" => Ok(json!(session.streams)),
+        "SET_PROPERTY" => {
+            property()?;
+            session.combined = params.get(1).and_then(Value::as_bool).ok_or_else(invalid)?;
+            Ok(Value::Null)
+        }
+        _ => {
+            property()?;
+            Ok(session.combined.into())
+        }
+    }
+}
+
+fn session_status(session: &Session, now: i64) -> MockResponse {
+    MockResponse::serialize(&json!({
+        "apiKey": session.authorized_since.map(|_| API_KEY),
+        "authorizedSince": session.authorized_since,
+        "connectedSince": session.connected_since,
+        "returnRateLimits": session.return_rate_limits,
+        "serverTime": now,
+    }))
+}
+
+/// Flatten the params of a request into name-value pairs.
+fn params(params: &Value) -> Vec<(String, String)> {
+    let Some(params) = params.as_object() else {
+        return Vec::new();
+    };
+    params
+        .iter()
+        .map(|(k, v)| {
+            let v = match v {
+                Value::String(s) => s.clone(),
+                v => v.to_string(),
+            };
+            (k.clone(), v)
+        })
+        .collect()
+}
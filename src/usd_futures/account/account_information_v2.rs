@@ -0,0 +1,146 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    decimal::Decimal,
+    enums::{futures::PositionSide, SecurityType},
+    rest_api::endpoint,
+    web_socket_api::web_socket,
+};
+
+endpoint!(
+    "/fapi/v2/account",
+    Method::GET,
+    SecurityType::UserData,
+    AccountInformationV2Endpoint,
+    AccountInformationV2Params,
+    AccountInformationV2Response,
+    weight = 5
+);
+
+/// Get current account information, with the positions of all symbols.
+///
+/// - Weight: 5
+pub struct AccountInformationV2Endpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> AccountInformationV2Endpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformationV2Params {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl Default for AccountInformationV2Params {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AccountInformationV2Params {
+    pub fn new() -> Self {
+        Self { recv_window: None }
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformationV2Response {
+    /// Account commission tier.
+    pub fee_tier: i64,
+    /// Whether BNB is used to pay the commissions.
+    pub fee_burn: bool,
+    pub can_trade: bool,
+    pub can_deposit: bool,
+    pub can_withdraw: bool,
+    pub update_time: i64,
+    pub multi_assets_margin: bool,
+    pub trade_group_id: i64,
+    /// Total initial margin required with the current mark price, only for
+    /// the USDT assets.
+    pub total_initial_margin: Decimal,
+    pub total_maint_margin: Decimal,
+    pub total_wallet_balance: Decimal,
+    pub total_unrealized_profit: Decimal,
+    pub total_margin_balance: Decimal,
+    pub total_position_initial_margin: Decimal,
+    pub total_open_order_initial_margin: Decimal,
+    pub total_cross_wallet_balance: Decimal,
+    pub total_cross_un_pnl: Decimal,
+    pub available_balance: Decimal,
+    pub max_withdraw_amount: Decimal,
+    pub assets: Vec<AccountAssetV2>,
+    pub positions: Vec<AccountPositionV2>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountAssetV2 {
+    pub asset: String,
+    pub wallet_balance: Decimal,
+    pub unrealized_profit: Decimal,
+    pub margin_balance: Decimal,
+    pub maint_margin: Decimal,
+    pub initial_margin: Decimal,
+    pub position_initial_margin: Decimal,
+    pub open_order_initial_margin: Decimal,
+    pub cross_wallet_balance: Decimal,
+    pub cross_un_pnl: Decimal,
+    pub available_balance: Decimal,
+    pub max_withdraw_amount: Decimal,
+    /// Whether the asset can be used as margin in Multi-Assets mode.
+    pub margin_available: bool,
+    pub update_time: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountPositionV2 {
+    pub symbol: String,
+    pub initial_margin: Decimal,
+    pub maint_margin: Decimal,
+    pub unrealized_profit: Decimal,
+    pub position_initial_margin: Decimal,
+    pub open_order_initial_margin: Decimal,
+    pub leverage: Decimal,
+    pub isolated: bool,
+    pub entry_price: Decimal,
+    pub break_even_price: Decimal,
+    pub max_notional: Decimal,
+    pub bid_notional: Decimal,
+    pub ask_notional: Decimal,
+    pub position_side: PositionSide,
+    pub position_amt: Decimal,
+    pub update_time: i64,
+}
+
+web_socket!(
+    "account.status",
+    SecurityType::UserData,
+    AccountInformationV2WebSocket,
+    AccountInformationV2Params,
+    AccountInformationV2Response,
+    weight = 5
+);
+
+pub struct AccountInformationV2WebSocket<'w> {
+    client: &'w crate::web_socket_api::WebSocketApiClient,
+}
+
+impl<'w> AccountInformationV2WebSocket<'w> {
+    pub fn new(client: &'w crate::web_socket_api::WebSocketApiClient) -> Self {
+        Self { client }
+    }
+}
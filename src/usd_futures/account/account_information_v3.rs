@@ -0,0 +1,110 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    decimal::Decimal,
+    enums::{futures::PositionSide, SecurityType},
+    rest_api::endpoint,
+};
+
+endpoint!(
+    "/fapi/v3/account",
+    Method::GET,
+    SecurityType::UserData,
+    AccountInformationV3Endpoint,
+    AccountInformationV3Params,
+    AccountInformationV3Response,
+    weight = 5
+);
+
+/// Get current account information. Only the symbols with a position or open
+/// orders are returned.
+///
+/// - Weight: 5
+pub struct AccountInformationV3Endpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> AccountInformationV3Endpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformationV3Params {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl Default for AccountInformationV3Params {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AccountInformationV3Params {
+    pub fn new() -> Self {
+        Self { recv_window: None }
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformationV3Response {
+    /// Total initial margin required with the current mark price, only for
+    /// the USDT assets.
+    pub total_initial_margin: Decimal,
+    pub total_maint_margin: Decimal,
+    pub total_wallet_balance: Decimal,
+    pub total_unrealized_profit: Decimal,
+    pub total_margin_balance: Decimal,
+    pub total_position_initial_margin: Decimal,
+    pub total_open_order_initial_margin: Decimal,
+    pub total_cross_wallet_balance: Decimal,
+    pub total_cross_un_pnl: Decimal,
+    pub available_balance: Decimal,
+    pub max_withdraw_amount: Decimal,
+    pub assets: Vec<AccountAssetV3>,
+    pub positions: Vec<AccountPositionV3>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountAssetV3 {
+    pub asset: String,
+    pub wallet_balance: Decimal,
+    pub unrealized_profit: Decimal,
+    pub margin_balance: Decimal,
+    pub maint_margin: Decimal,
+    pub initial_margin: Decimal,
+    pub position_initial_margin: Decimal,
+    pub open_order_initial_margin: Decimal,
+    pub cross_wallet_balance: Decimal,
+    pub cross_un_pnl: Decimal,
+    pub available_balance: Decimal,
+    pub max_withdraw_amount: Decimal,
+    pub update_time: i64,
+}
+
+/// Only the symbols with a position or open orders are returned.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountPositionV3 {
+    pub symbol: String,
+    pub position_side: PositionSide,
+    pub position_amt: Decimal,
+    pub unrealized_profit: Decimal,
+    pub isolated_margin: Decimal,
+    pub notional: Decimal,
+    pub isolated_wallet: Decimal,
+    pub initial_margin: Decimal,
+    pub maint_margin: Decimal,
+    pub update_time: i64,
+}
@@ -0,0 +1,71 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/fapi/v3/balance",
+    Method::GET,
+    SecurityType::UserData,
+    FuturesAccountBalanceV3Endpoint,
+    FuturesAccountBalanceV3Params,
+    FuturesAccountBalanceV3Response,
+    weight = 5
+);
+
+/// Query account balance info.
+///
+/// - Weight: 5
+pub struct FuturesAccountBalanceV3Endpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> FuturesAccountBalanceV3Endpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesAccountBalanceV3Params {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl Default for FuturesAccountBalanceV3Params {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FuturesAccountBalanceV3Params {
+    pub fn new() -> Self {
+        Self { recv_window: None }
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+pub type FuturesAccountBalanceV3Response = Vec<FuturesAccountBalanceV3>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesAccountBalanceV3 {
+    /// Unique account code.
+    pub account_alias: String,
+    pub asset: String,
+    /// Wallet balance.
+    pub balance: Decimal,
+    pub cross_wallet_balance: Decimal,
+    /// Unrealized profit of the crossed positions.
+    pub cross_un_pnl: Decimal,
+    pub available_balance: Decimal,
+    pub max_withdraw_amount: Decimal,
+    /// Whether the asset can be used as margin in Multi-Assets mode.
+    pub margin_available: bool,
+    pub update_time: i64,
+}
@@ -0,0 +1,66 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/fapi/v1/accountConfig",
+    Method::GET,
+    SecurityType::UserData,
+    FuturesAccountConfigurationEndpoint,
+    FuturesAccountConfigurationParams,
+    FuturesAccountConfigurationResponse,
+    weight = 5
+);
+
+/// Query account configuration.
+///
+/// - Weight: 5
+pub struct FuturesAccountConfigurationEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> FuturesAccountConfigurationEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesAccountConfigurationParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl Default for FuturesAccountConfigurationParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FuturesAccountConfigurationParams {
+    pub fn new() -> Self {
+        Self { recv_window: None }
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesAccountConfigurationResponse {
+    /// Account commission tier.
+    pub fee_tier: i64,
+    pub can_trade: bool,
+    pub can_deposit: bool,
+    pub can_withdraw: bool,
+    /// `true` in Hedge Mode, `false` in One-way Mode.
+    pub dual_side_position: bool,
+    pub update_time: i64,
+    pub multi_assets_margin: bool,
+    pub trade_group_id: i64,
+}
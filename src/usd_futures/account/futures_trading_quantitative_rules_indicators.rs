@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/fapi/v1/apiTradingStatus",
+    Method::GET,
+    SecurityType::UserData,
+    FuturesTradingQuantitativeRulesIndicatorsEndpoint,
+    FuturesTradingQuantitativeRulesIndicatorsParams,
+    FuturesTradingQuantitativeRulesIndicatorsResponse,
+    weight = |params| if params.symbol.is_some() { 1 } else { 10 }
+);
+
+/// Futures trading quantitative rules indicators.
+///
+/// - Weight:
+///   - with symbol: 1
+///   - without symbol: 10
+pub struct FuturesTradingQuantitativeRulesIndicatorsEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> FuturesTradingQuantitativeRulesIndicatorsEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesTradingQuantitativeRulesIndicatorsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl Default for FuturesTradingQuantitativeRulesIndicatorsParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FuturesTradingQuantitativeRulesIndicatorsParams {
+    pub fn new() -> Self {
+        Self {
+            symbol: None,
+            recv_window: None,
+        }
+    }
+
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_owned());
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesTradingQuantitativeRulesIndicatorsResponse {
+    /// The indicators of each symbol, and of the account under `ACCOUNT`.
+    pub indicators: HashMap<String, Vec<Indicator>>,
+    pub update_time: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Indicator {
+    pub is_locked: bool,
+    /// Zero when the trading is not locked.
+    pub planned_recover_time: i64,
+    /// e.g. `UFR`, `IFER`, `GCR`, `DR` or `TMV`.
+    pub indicator: String,
+    pub value: Decimal,
+    pub trigger_value: Decimal,
+}
@@ -0,0 +1,58 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/fapi/v1/feeBurn",
+    Method::GET,
+    SecurityType::UserData,
+    GetBnbBurnStatusEndpoint,
+    GetBnbBurnStatusParams,
+    GetBnbBurnStatusResponse,
+    weight = 30
+);
+
+/// Get user's BNB Fee Discount (Fee Discount On or Fee Discount OFF).
+///
+/// - Weight: 30
+pub struct GetBnbBurnStatusEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> GetBnbBurnStatusEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBnbBurnStatusParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl Default for GetBnbBurnStatusParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GetBnbBurnStatusParams {
+    pub fn new() -> Self {
+        Self { recv_window: None }
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBnbBurnStatusResponse {
+    /// Whether BNB is used to pay the commissions.
+    pub fee_burn: bool,
+}
@@ -0,0 +1,59 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/fapi/v1/multiAssetsMargin",
+    Method::GET,
+    SecurityType::UserData,
+    GetCurrentMultiAssetsModeEndpoint,
+    GetCurrentMultiAssetsModeParams,
+    GetCurrentMultiAssetsModeResponse,
+    weight = 30
+);
+
+/// Get user's Multi-Assets mode (Multi-Assets Mode or Single-Asset Mode) on
+/// every symbol.
+///
+/// - Weight: 30
+pub struct GetCurrentMultiAssetsModeEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> GetCurrentMultiAssetsModeEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetCurrentMultiAssetsModeParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl Default for GetCurrentMultiAssetsModeParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GetCurrentMultiAssetsModeParams {
+    pub fn new() -> Self {
+        Self { recv_window: None }
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetCurrentMultiAssetsModeResponse {
+    /// `true` in Multi-Assets Mode, `false` in Single-Asset Mode.
+    pub multi_assets_margin: bool,
+}
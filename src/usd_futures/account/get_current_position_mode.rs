@@ -0,0 +1,58 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/fapi/v1/positionSide/dual",
+    Method::GET,
+    SecurityType::UserData,
+    GetCurrentPositionModeEndpoint,
+    GetCurrentPositionModeParams,
+    GetCurrentPositionModeResponse,
+    weight = 30
+);
+
+/// Get user's position mode (Hedge Mode or One-way Mode) on every symbol.
+///
+/// - Weight: 30
+pub struct GetCurrentPositionModeEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> GetCurrentPositionModeEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetCurrentPositionModeParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl Default for GetCurrentPositionModeParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GetCurrentPositionModeParams {
+    pub fn new() -> Self {
+        Self { recv_window: None }
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetCurrentPositionModeResponse {
+    /// `true` in Hedge Mode, `false` in One-way Mode.
+    pub dual_side_position: bool,
+}
@@ -0,0 +1,60 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/fapi/v1/order/asyn",
+    Method::GET,
+    SecurityType::UserData,
+    GetDownloadIdForFuturesOrderHistoryEndpoint,
+    GetDownloadIdForFuturesOrderHistoryParams,
+    GetDownloadIdForFuturesOrderHistoryResponse,
+    weight = 1000
+);
+
+/// Get download id for futures order history. The time between `start_time`
+/// and `end_time` can not be longer than 1 year.
+///
+/// - Weight: 1000
+pub struct GetDownloadIdForFuturesOrderHistoryEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> GetDownloadIdForFuturesOrderHistoryEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDownloadIdForFuturesOrderHistoryParams {
+    start_time: i64,
+    end_time: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl GetDownloadIdForFuturesOrderHistoryParams {
+    pub fn new(start_time: i64, end_time: i64) -> Self {
+        Self {
+            start_time,
+            end_time,
+            recv_window: None,
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDownloadIdForFuturesOrderHistoryResponse {
+    /// Average time taken for the data download in the past 30 days.
+    pub avg_cost_timestamp_of_last30d: i64,
+    pub download_id: String,
+}
@@ -0,0 +1,65 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/fapi/v1/order/asyn/id",
+    Method::GET,
+    SecurityType::UserData,
+    GetFuturesOrderHistoryDownloadLinkByIdEndpoint,
+    GetFuturesOrderHistoryDownloadLinkByIdParams,
+    GetFuturesOrderHistoryDownloadLinkByIdResponse,
+    weight = 10
+);
+
+/// Get futures order history download link by id. The link expires after 24
+/// hours.
+///
+/// - Weight: 10
+pub struct GetFuturesOrderHistoryDownloadLinkByIdEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> GetFuturesOrderHistoryDownloadLinkByIdEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFuturesOrderHistoryDownloadLinkByIdParams {
+    download_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl GetFuturesOrderHistoryDownloadLinkByIdParams {
+    pub fn new(download_id: &str) -> Self {
+        Self {
+            download_id: download_id.to_owned(),
+            recv_window: None,
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFuturesOrderHistoryDownloadLinkByIdResponse {
+    pub download_id: String,
+    /// `completed` or `processing`.
+    pub status: String,
+    /// Empty while the download is processing.
+    pub url: String,
+    /// Ignore.
+    pub notified: bool,
+    /// The link expires after this timestamp, -1 while processing.
+    pub expiration_timestamp: i64,
+    pub is_expired: Option<bool>,
+}
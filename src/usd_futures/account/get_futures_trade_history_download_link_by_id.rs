@@ -0,0 +1,65 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/fapi/v1/trade/asyn/id",
+    Method::GET,
+    SecurityType::UserData,
+    GetFuturesTradeHistoryDownloadLinkByIdEndpoint,
+    GetFuturesTradeHistoryDownloadLinkByIdParams,
+    GetFuturesTradeHistoryDownloadLinkByIdResponse,
+    weight = 10
+);
+
+/// Get futures trade history download link by id. The link expires after 24
+/// hours.
+///
+/// - Weight: 10
+pub struct GetFuturesTradeHistoryDownloadLinkByIdEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> GetFuturesTradeHistoryDownloadLinkByIdEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFuturesTradeHistoryDownloadLinkByIdParams {
+    download_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl GetFuturesTradeHistoryDownloadLinkByIdParams {
+    pub fn new(download_id: &str) -> Self {
+        Self {
+            download_id: download_id.to_owned(),
+            recv_window: None,
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFuturesTradeHistoryDownloadLinkByIdResponse {
+    pub download_id: String,
+    /// `completed` or `processing`.
+    pub status: String,
+    /// Empty while the download is processing.
+    pub url: String,
+    /// Ignore.
+    pub notified: bool,
+    /// The link expires after this timestamp, -1 while processing.
+    pub expiration_timestamp: i64,
+    pub is_expired: Option<bool>,
+}
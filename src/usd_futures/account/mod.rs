@@ -1,4 +1,51 @@
-use crate::rest_api::{route, RestApiClient};
+mod account_information_v2;
+mod account_information_v3;
+mod futures_account_balance_v2;
+mod futures_account_balance_v3;
+mod futures_account_configuration;
+mod futures_trading_quantitative_rules_indicators;
+mod get_bnb_burn_status;
+mod get_current_multi_assets_mode;
+mod get_current_position_mode;
+mod get_download_id_for_futures_order_history;
+mod get_download_id_for_futures_trade_history;
+mod get_download_id_for_futures_transaction_history;
+mod get_futures_order_history_download_link_by_id;
+mod get_futures_trade_history_download_link_by_id;
+mod get_futures_transaction_history_download_link_by_id;
+mod get_income_history;
+mod notional_and_leverage_brackets;
+mod query_user_rate_limit;
+mod symbol_configuration;
+mod toggle_bnb_burn_on_futures_trade;
+mod user_commission_rate;
+
+pub use account_information_v2::*;
+pub use account_information_v3::*;
+pub use futures_account_balance_v2::*;
+pub use futures_account_balance_v3::*;
+pub use futures_account_configuration::*;
+pub use futures_trading_quantitative_rules_indicators::*;
+pub use get_bnb_burn_status::*;
+pub use get_current_multi_assets_mode::*;
+pub use get_current_position_mode::*;
+pub use get_download_id_for_futures_order_history::*;
+pub use get_download_id_for_futures_trade_history::*;
+pub use get_download_id_for_futures_transaction_history::*;
+pub use get_futures_order_history_download_link_by_id::*;
+pub use get_futures_trade_history_download_link_by_id::*;
+pub use get_futures_transaction_history_download_link_by_id::*;
+pub use get_income_history::*;
+pub use notional_and_leverage_brackets::*;
+pub use query_user_rate_limit::*;
+pub use symbol_configuration::*;
+pub use toggle_bnb_burn_on_futures_trade::*;
+pub use user_commission_rate::*;
+
+use crate::{
+    rest_api::{route, RestApiClient},
+    web_socket_api::{ws_route, WebSocketApiClient},
+};
 
 pub struct RestApiHandler<'r> {
     client: &'r RestApiClient,
@@ -8,4 +55,72 @@ impl<'r> RestApiHandler<'r> {
     pub fn new(client: &'r RestApiClient) -> Self {
         RestApiHandler { client }
     }
+
+    route!(futures_account_balance_v3, FuturesAccountBalanceV3Endpoint);
+    route!(futures_account_balance_v2, FuturesAccountBalanceV2Endpoint);
+    route!(account_information_v3, AccountInformationV3Endpoint);
+    route!(account_information_v2, AccountInformationV2Endpoint);
+    route!(user_commission_rate, UserCommissionRateEndpoint);
+    route!(
+        futures_account_configuration,
+        FuturesAccountConfigurationEndpoint
+    );
+    route!(symbol_configuration, SymbolConfigurationEndpoint);
+    route!(query_user_rate_limit, QueryUserRateLimitEndpoint);
+    route!(
+        notional_and_leverage_brackets,
+        NotionalAndLeverageBracketsEndpoint
+    );
+    route!(
+        get_current_multi_assets_mode,
+        GetCurrentMultiAssetsModeEndpoint
+    );
+    route!(get_current_position_mode, GetCurrentPositionModeEndpoint);
+    route!(get_income_history, GetIncomeHistoryEndpoint);
+    route!(
+        futures_trading_quantitative_rules_indicators,
+        FuturesTradingQuantitativeRulesIndicatorsEndpoint
+    );
+    route!(
+        get_download_id_for_futures_transaction_history,
+        GetDownloadIdForFuturesTransactionHistoryEndpoint
+    );
+    route!(
+        get_futures_transaction_history_download_link_by_id,
+        GetFuturesTransactionHistoryDownloadLinkByIdEndpoint
+    );
+    route!(
+        get_download_id_for_futures_order_history,
+        GetDownloadIdForFuturesOrderHistoryEndpoint
+    );
+    route!(
+        get_futures_order_history_download_link_by_id,
+        GetFuturesOrderHistoryDownloadLinkByIdEndpoint
+    );
+    route!(
+        get_download_id_for_futures_trade_history,
+        GetDownloadIdForFuturesTradeHistoryEndpoint
+    );
+    route!(
+        get_futures_trade_history_download_link_by_id,
+        GetFuturesTradeHistoryDownloadLinkByIdEndpoint
+    );
+    route!(
+        toggle_bnb_burn_on_futures_trade,
+        ToggleBnbBurnOnFuturesTradeEndpoint
+    );
+    route!(get_bnb_burn_status, GetBnbBurnStatusEndpoint);
+}
+
+pub struct WebSocketApiHandler<'w> {
+    client: &'w WebSocketApiClient,
+}
+
+impl<'w> WebSocketApiHandler<'w> {
+    pub fn new(client: &'w WebSocketApiClient) -> Self {
+        WebSocketApiHandler { client }
+    }
+
+    ws_route!(futures_account_balance_v2, FuturesAccountBalanceV2WebSocket);
+    ws_route!(account_information_v2, AccountInformationV2WebSocket);
 }
@@ -0,0 +1,92 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/fapi/v1/leverageBracket",
+    Method::GET,
+    SecurityType::UserData,
+    NotionalAndLeverageBracketsEndpoint,
+    NotionalAndLeverageBracketsParams,
+    NotionalAndLeverageBracketsResponse,
+    weight = 1
+);
+
+/// Query user notional and leverage bracket.
+///
+/// - Weight: 1
+pub struct NotionalAndLeverageBracketsEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> NotionalAndLeverageBracketsEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotionalAndLeverageBracketsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl Default for NotionalAndLeverageBracketsParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NotionalAndLeverageBracketsParams {
+    pub fn new() -> Self {
+        Self {
+            symbol: None,
+            recv_window: None,
+        }
+    }
+
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_owned());
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum NotionalAndLeverageBracketsResponse {
+    Item(SymbolBrackets),
+    Vec(Vec<SymbolBrackets>),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolBrackets {
+    pub symbol: String,
+    /// User symbol bracket multiplier, only sent when it is not 1.
+    pub notional_coef: Option<Decimal>,
+    pub brackets: Vec<Bracket>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bracket {
+    pub bracket: i64,
+    /// Max initial leverage of the bracket.
+    pub initial_leverage: i64,
+    /// Cap notional of the bracket.
+    pub notional_cap: Decimal,
+    /// Notional threshold of the bracket.
+    pub notional_floor: Decimal,
+    pub maint_margin_ratio: Decimal,
+    /// Auxiliary number for the quick calculation of the maintenance margin.
+    pub cum: Decimal,
+}
@@ -0,0 +1,56 @@
+use reqwest::Method;
+use serde::Serialize;
+
+use crate::{
+    enums::{futures::RateLimit, SecurityType},
+    rest_api::endpoint,
+};
+
+endpoint!(
+    "/fapi/v1/rateLimit/order",
+    Method::GET,
+    SecurityType::UserData,
+    QueryUserRateLimitEndpoint,
+    QueryUserRateLimitParams,
+    QueryUserRateLimitResponse,
+    weight = 1
+);
+
+/// Query User Rate Limit.
+///
+/// - Weight: 1
+pub struct QueryUserRateLimitEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> QueryUserRateLimitEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryUserRateLimitParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl Default for QueryUserRateLimitParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl QueryUserRateLimitParams {
+    pub fn new() -> Self {
+        Self { recv_window: None }
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+pub type QueryUserRateLimitResponse = Vec<RateLimit>;
@@ -0,0 +1,75 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/fapi/v1/symbolConfig",
+    Method::GET,
+    SecurityType::UserData,
+    SymbolConfigurationEndpoint,
+    SymbolConfigurationParams,
+    SymbolConfigurationResponse,
+    weight = 5
+);
+
+/// Get current account symbol configuration.
+///
+/// - Weight: 5
+pub struct SymbolConfigurationEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> SymbolConfigurationEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolConfigurationParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl Default for SymbolConfigurationParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SymbolConfigurationParams {
+    pub fn new() -> Self {
+        Self {
+            symbol: None,
+            recv_window: None,
+        }
+    }
+
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_owned());
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+pub type SymbolConfigurationResponse = Vec<SymbolConfiguration>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolConfiguration {
+    pub symbol: String,
+    /// `CROSSED` or `ISOLATED`.
+    pub margin_type: String,
+    /// `true` or `false`, sent as a string.
+    pub is_auto_add_margin: String,
+    pub leverage: i64,
+    pub max_notional_value: Decimal,
+}
@@ -0,0 +1,56 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/fapi/v1/feeBurn",
+    Method::POST,
+    SecurityType::Trade,
+    ToggleBnbBurnOnFuturesTradeEndpoint,
+    ToggleBnbBurnOnFuturesTradeParams,
+    ToggleBnbBurnOnFuturesTradeResponse,
+    weight = 1
+);
+
+/// Change user's BNB Fee Discount (Fee Discount On or Fee Discount OFF) on
+/// every symbol.
+///
+/// - Weight: 1
+pub struct ToggleBnbBurnOnFuturesTradeEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> ToggleBnbBurnOnFuturesTradeEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToggleBnbBurnOnFuturesTradeParams {
+    fee_burn: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recv_window: Option<i64>,
+}
+
+impl ToggleBnbBurnOnFuturesTradeParams {
+    pub fn new(fee_burn: &str) -> Self {
+        Self {
+            fee_burn: fee_burn.to_owned(),
+            recv_window: None,
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ToggleBnbBurnOnFuturesTradeResponse {
+    pub code: i64,
+    pub msg: String,
+}
@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
@@ -10,7 +9,8 @@ endpoint!(
     SecurityType::UserData,
     AcceptQuoteEndpoint,
     AcceptQuoteParams,
-    AcceptQuoteResponse
+    AcceptQuoteResponse,
+    weight = 200
 );
 
 /// Accept the offered quote by quote ID.
@@ -32,7 +32,6 @@ pub struct AcceptQuoteParams {
     quote_id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl AcceptQuoteParams {
@@ -40,7 +39,6 @@ impl AcceptQuoteParams {
         Self {
             quote_id: quote_id.to_owned(),
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
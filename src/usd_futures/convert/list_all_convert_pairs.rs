@@ -1,7 +1,7 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/fapi/v1/convert/exchangeInfo",
@@ -9,7 +9,8 @@ endpoint!(
     SecurityType::UserData,
     ListAllConvertPairsEndpoint,
     ListAllConvertPairsParams,
-    ListAllConvertPairsResponse
+    ListAllConvertPairsResponse,
+    weight = 20
 );
 
 /// Query for all convertible token pairs and the tokens’ respective upper/lower limits
@@ -66,8 +67,8 @@ pub type ListAllConvertPairsResponse = Vec<ConvertPair>;
 pub struct ConvertPair {
     pub from_asset: String,
     pub to_asset: String,
-    pub from_asset_min_amount: String,
-    pub from_asset_max_amount: String,
-    pub to_asset_min_amount: String,
-    pub to_asset_max_amount: String,
+    pub from_asset_min_amount: Decimal,
+    pub from_asset_max_amount: Decimal,
+    pub to_asset_min_amount: Decimal,
+    pub to_asset_max_amount: Decimal,
 }
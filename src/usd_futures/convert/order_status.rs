@@ -1,7 +1,7 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/fapi/v1/convert/orderStatus",
@@ -9,7 +9,8 @@ endpoint!(
     SecurityType::UserData,
     OrderStatusEndpoint,
     OrderStatusParams,
-    OrderStatusResponse
+    OrderStatusResponse,
+    weight = 50
 );
 
 /// Query order status by order ID.
@@ -54,13 +55,13 @@ impl OrderStatusParams {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderStatusResponse {
-    pub order_id: String,
+    pub order_id: i64,
     pub order_status: String,
     pub from_asset: String,
-    pub from_amount: String,
+    pub from_amount: Decimal,
     pub to_asset: String,
-    pub to_amount: String,
-    pub ratio: String,
-    pub inverse_ratio: String,
+    pub to_amount: Decimal,
+    pub ratio: Decimal,
+    pub inverse_ratio: Decimal,
     pub create_time: i64,
 }
@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/fapi/v1/convert/getQuote",
@@ -10,7 +9,8 @@ endpoint!(
     SecurityType::UserData,
     SendQuoteRequestEndpoint,
     SendQuoteRequestParams,
-    SendQuoteRequestResponse
+    SendQuoteRequestResponse,
+    weight = 50
 );
 
 /// Request a quote for the requested token pairs.
@@ -32,14 +32,13 @@ pub struct SendQuoteRequestParams {
     from_asset: String,
     to_asset: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    from_amount: Option<f64>,
+    from_amount: Option<Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    to_amount: Option<f64>,
+    to_amount: Option<Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
     valid_time: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl SendQuoteRequestParams {
@@ -51,17 +50,16 @@ impl SendQuoteRequestParams {
             to_amount: None,
             valid_time: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
-    pub fn from_amount(mut self, from_amount: f64) -> Self {
-        self.from_amount = Some(from_amount);
+    pub fn from_amount(mut self, from_amount: impl Into<Decimal>) -> Self {
+        self.from_amount = Some(from_amount.into());
         self
     }
 
-    pub fn to_amount(mut self, to_amount: f64) -> Self {
-        self.to_amount = Some(to_amount);
+    pub fn to_amount(mut self, to_amount: impl Into<Decimal>) -> Self {
+        self.to_amount = Some(to_amount.into());
         self
     }
 
@@ -80,9 +78,9 @@ impl SendQuoteRequestParams {
 #[serde(rename_all = "camelCase")]
 pub struct SendQuoteRequestResponse {
     pub quote_id: String,
-    pub ratio: String,
-    pub inverse_ratio: String,
+    pub ratio: Decimal,
+    pub inverse_ratio: Decimal,
     pub valid_timestamp: i64,
-    pub to_amount: String,
-    pub from_amount: String,
+    pub to_amount: Decimal,
+    pub from_amount: Decimal,
 }
@@ -1,14 +1,15 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::rest_api::endpoint;
+use crate::{decimal::Decimal, rest_api::endpoint};
 
 endpoint!(
     "/fapi/v1/aggTrades",
     Method::GET,
     AggregateTradesListEndpoint,
     AggregateTradesListParams,
-    AggregateTradesListResponse
+    AggregateTradesListResponse,
+    weight = 20
 );
 
 /// Get compressed, aggregate trades. Trades that fill at the time, from the
@@ -79,9 +80,9 @@ pub struct AggTrade {
     #[serde(rename = "a")]
     pub aggregated: i64,
     #[serde(rename = "p")]
-    pub price: String,
+    pub price: Decimal,
     #[serde(rename = "q")]
-    pub quantity: String,
+    pub quantity: Decimal,
     #[serde(rename = "f")]
     pub first_trade_id: i64,
     #[serde(rename = "l")]
@@ -1,7 +1,7 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::rest_api::endpoint;
+use crate::{decimal::Decimal, rest_api::endpoint};
 
 endpoint!(
     "/fapi/v1/indexInfo",
@@ -55,7 +55,8 @@ pub type CompositeIndexSymbolInformationResponse = Vec<CompositeIndexSymbolInfor
 pub struct CompositeIndexSymbolInformation {
     pub symbol: String,
     pub time: i64,
-    pub component: Vec<BaseAsset>,
+    pub component: String,
+    pub base_asset_list: Vec<BaseAsset>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -63,6 +64,6 @@ pub struct CompositeIndexSymbolInformation {
 pub struct BaseAsset {
     pub base_asset: String,
     pub quote_asset: String,
-    pub weight_in_quantity: String,
-    pub weight_in_percentage: String,
+    pub weight_in_quantity: Decimal,
+    pub weight_in_percentage: Decimal,
 }
@@ -30,6 +30,15 @@ impl Endpoint for ContinuousContractKlinesEndpoint<'_> {
     fn security_type(&self) -> SecurityType {
         SecurityType::None
     }
+
+    fn weight(&self, params: &Self::Params) -> u32 {
+        match params.limit.unwrap_or(500) {
+            ..=99 => 1,
+            100..=499 => 2,
+            500..=999 => 5,
+            _ => 10,
+        }
+    }
 }
 
 /// Kline/candlestick bars for a symbol. ContinuousContractKlines are uniquely identified by their
@@ -2,8 +2,12 @@ use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    enums::{OrderType, RateLimit},
-    filters::ExchangeFilter,
+    decimal::Decimal,
+    enums::{
+        futures::{OrderType, TimeInForce},
+        RateLimit,
+    },
+    filters::{futures::SymbolFilter, ExchangeFilter},
     rest_api::endpoint,
 };
 
@@ -60,26 +64,34 @@ pub struct ExchangeInfoResponse {
 pub struct Asset {
     pub asset: String,
     pub margin_available: bool,
-    pub auto_asset_exchange: i64,
+    pub auto_asset_exchange: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Symbol {
     pub symbol: String,
+    pub pair: String,
+    pub contract_type: String,
+    pub delivery_date: i64,
+    pub onboard_date: i64,
     pub status: String,
+    pub maint_margin_percent: Decimal,
+    pub required_margin_percent: Decimal,
     pub base_asset: String,
-    pub base_asset_precision: i64,
     pub quote_asset: String,
-    pub quote_asset_precision: i64,
-    pub base_commission_precision: i64,
-    pub quote_commission_precision: i64,
+    pub margin_asset: String,
+    pub price_precision: i64,
+    pub quantity_precision: i64,
+    pub base_asset_precision: i64,
+    pub quote_precision: i64,
+    pub underlying_type: String,
+    pub underlying_sub_type: Vec<String>,
+    pub trigger_protect: Decimal,
+    pub liquidation_fee: Decimal,
+    pub market_take_bound: Decimal,
+    pub max_move_order_limit: Option<i64>,
+    pub filters: Vec<SymbolFilter>,
     pub order_types: Vec<OrderType>,
-    pub iceberg_allowed: bool,
-    pub oco_allowed: bool,
-    pub oto_allowed: bool,
-    pub quote_order_qty_market_allowed: bool,
-    pub allow_trailing_stop: bool,
-    pub is_spot_trading_allowed: bool,
-    pub is_margin_trading_allowed: bool,
+    pub time_in_force: Vec<TimeInForce>,
 }
@@ -1,7 +1,7 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::rest_api::endpoint;
+use crate::{decimal::Decimal, rest_api::endpoint};
 
 endpoint!(
     "/fapi/v1/fundingInfo",
@@ -46,8 +46,8 @@ pub type GetFundingInfoResponse = Vec<FundingInfo>;
 #[serde(rename_all = "camelCase")]
 pub struct FundingInfo {
     pub symbol: String,
-    pub adjusted_funding_rate_cap: String,
-    pub adjusted_funding_rate_floor: String,
+    pub adjusted_funding_rate_cap: Decimal,
+    pub adjusted_funding_rate_floor: Decimal,
     pub funding_interval_hours: i64,
     pub disclaimer: bool,
 }
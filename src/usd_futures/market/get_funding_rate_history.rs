@@ -1,14 +1,15 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::rest_api::endpoint;
+use crate::{decimal::Decimal, rest_api::endpoint};
 
 endpoint!(
     "/fapi/v1/fundingInfo",
     Method::GET,
     GetFundingRateHistoryEndpoint,
     GetFundingRateHistoryParams,
-    GetFundingRateHistoryResponse
+    GetFundingRateHistoryResponse,
+    weight = 0
 );
 
 /// Get Funding Rate History.
@@ -76,7 +77,7 @@ pub type GetFundingRateHistoryResponse = Vec<FundingRate>;
 #[serde(rename_all = "camelCase")]
 pub struct FundingRate {
     pub symbol: String,
-    pub funding_rate: String,
+    pub funding_rate: Decimal,
     pub funding_time: i64,
-    pub mark_price: String,
+    pub mark_price: Decimal,
 }
@@ -30,11 +30,24 @@ impl Endpoint for IndexPriceKlinesEndpoint<'_> {
     fn security_type(&self) -> SecurityType {
         SecurityType::None
     }
+
+    fn weight(&self, params: &Self::Params) -> u32 {
+        match params.limit.unwrap_or(500) {
+            ..=99 => 1,
+            100..=499 => 2,
+            500..=999 => 5,
+            _ => 10,
+        }
+    }
 }
 
 /// The BLVT NAV system is based on Binance Futures, so the endpoint is based on fapi
 ///
-/// - Weight: 1
+/// - Weight:
+///   - limit [1,100): 1
+///   - limit [100,500): 2
+///   - limit [500,1000): 5
+///   - limit [1000,): 10
 pub struct IndexPriceKlinesEndpoint<'r> {
     client: &'r crate::rest_api::RestApiClient,
 }
@@ -1,14 +1,20 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::KlineInterval, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::KlineInterval, rest_api::endpoint};
 
 endpoint!(
     "/fapi/v1/klines",
     Method::GET,
     KlinesEndpoint,
     KlinesParams,
-    KlinesResponse
+    KlinesResponse,
+    weight = |params| match params.limit.unwrap_or(500) {
+        ..=99 => 1,
+        100..=499 => 2,
+        500..=999 => 5,
+        _ => 10,
+    }
 );
 
 /// Kline/candlestick bars for a symbol. Klines are uniquely identified by their
@@ -83,16 +89,16 @@ pub type KlinesResponse = Vec<Kline>;
 
 #[derive(Debug, Deserialize)]
 pub struct Kline(
-    pub i64,    // Open time
-    pub String, // Open price
-    pub String, // High price
-    pub String, // Low price
-    pub String, // Close price
-    pub String, // Volume
-    pub i64,    // Close time
-    pub String, // Quote asset volume
-    pub i64,    // Number of trades
-    pub String, // Taker buy base asset volume
-    pub String, // Taker buy quote asset volume
-    pub String, // Unused field, ignore.
+    pub i64,     // Open time
+    pub Decimal, // Open price
+    pub Decimal, // High price
+    pub Decimal, // Low price
+    pub Decimal, // Close price
+    pub Decimal, // Volume
+    pub i64,     // Close time
+    pub Decimal, // Quote asset volume
+    pub i64,     // Number of trades
+    pub Decimal, // Taker buy base asset volume
+    pub Decimal, // Taker buy quote asset volume
+    pub String,  // Unused field, ignore.
 );
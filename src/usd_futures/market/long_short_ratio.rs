@@ -1,14 +1,15 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::rest_api::endpoint;
+use crate::{decimal::Decimal, rest_api::endpoint};
 
 endpoint!(
     "/futures/data/globalLongShortAccountRatio",
     Method::GET,
     LongShortRatioEndpoint,
     LongShortRatioParams,
-    LongShortRatioResponse
+    LongShortRatioResponse,
+    weight = 0
 );
 
 /// Query symbol Long/Short Ratio.
@@ -47,16 +48,18 @@ impl LongShortRatioParams {
 }
 
 #[derive(Debug, Deserialize)]
+#[serde(untagged)]
 pub enum LongShortRatioResponse {
-    Item(Box<LongShortRatio>),
     Vec(Box<Vec<LongShortRatio>>),
+    Item(Box<LongShortRatio>),
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LongShortRatio {
     pub symbol: String,
-    pub long_short_ratio: String,
-    pub long_account: String,
+    pub long_short_ratio: Decimal,
+    pub long_account: Decimal,
+    pub short_account: Decimal,
     pub timestamp: i64,
 }
@@ -1,7 +1,7 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::rest_api::endpoint;
+use crate::{decimal::Decimal, rest_api::endpoint};
 
 endpoint!(
     "/fapi/v1/premiumIndex",
@@ -42,8 +42,9 @@ impl MarkPriceParams {
 }
 
 #[derive(Debug, Deserialize)]
+#[serde(untagged)]
 pub enum MarkPriceResponse {
-    Vec(Box<MarkPriceResult>),
+    Vec(Box<Vec<MarkPriceResult>>),
     Item(Box<MarkPriceResult>),
 }
 
@@ -51,11 +52,11 @@ pub enum MarkPriceResponse {
 #[serde(rename_all = "camelCase")]
 pub struct MarkPriceResult {
     pub symbol: String,
-    pub mark_price: String,
-    pub index_price: String,
-    pub estimated_settle_price: String,
-    pub last_funding_rate: String,
+    pub mark_price: Decimal,
+    pub index_price: Decimal,
+    pub estimated_settle_price: Decimal,
+    pub last_funding_rate: Decimal,
     pub next_funding_time: i64,
-    pub interest_rate: String,
+    pub interest_rate: Decimal,
     pub time: i64,
 }
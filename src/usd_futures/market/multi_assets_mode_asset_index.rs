@@ -1,19 +1,22 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::rest_api::endpoint;
+use crate::{decimal::Decimal, rest_api::endpoint};
 
 endpoint!(
     "/fapi/v1/assetIndex",
     Method::GET,
     MultiAssetsModeAssetIndexEndpoint,
     MultiAssetsModeAssetIndexParams,
-    MultiAssetsModeAssetIndexResponse
+    MultiAssetsModeAssetIndexResponse,
+    weight = |params| if params.symbol.is_some() { 1 } else { 10 }
 );
 
 /// Asset index for Multi-Assets mode.
 ///
 /// - Weight:
+///   - with symbol: 1
+///   - without symbol: 10
 pub struct MultiAssetsModeAssetIndexEndpoint<'r> {
     client: &'r crate::rest_api::RestApiClient,
 }
@@ -42,9 +45,10 @@ impl MultiAssetsModeAssetIndexParams {
 }
 
 #[derive(Debug, Deserialize)]
+#[serde(untagged)]
 pub enum MultiAssetsModeAssetIndexResponse {
-    Item(Box<MultiAssetsModeAssetIndex>),
     Vec(Box<Vec<MultiAssetsModeAssetIndex>>),
+    Item(Box<MultiAssetsModeAssetIndex>),
 }
 
 #[derive(Debug, Deserialize)]
@@ -52,13 +56,13 @@ pub enum MultiAssetsModeAssetIndexResponse {
 pub struct MultiAssetsModeAssetIndex {
     pub symbol: String,
     pub time: i64,
-    pub index: String,
-    pub big_buffer: String,
-    pub ask_buffer: String,
-    pub bid_rate: String,
-    pub ask_rate: String,
-    pub auto_exchange_bid_buffer: String,
-    pub auto_exchange_ask_buffer: String,
-    pub auto_exchange_bid_rate: String,
-    pub auto_exchange_ask_rate: String,
+    pub index: Decimal,
+    pub bid_buffer: Decimal,
+    pub ask_buffer: Decimal,
+    pub bid_rate: Decimal,
+    pub ask_rate: Decimal,
+    pub auto_exchange_bid_buffer: Decimal,
+    pub auto_exchange_ask_buffer: Decimal,
+    pub auto_exchange_bid_rate: Decimal,
+    pub auto_exchange_ask_rate: Decimal,
 }
@@ -1,14 +1,15 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::rest_api::endpoint;
+use crate::{decimal::Decimal, rest_api::endpoint};
 
 endpoint!(
     "/fapi/v1/historicalTrades",
     Method::GET,
     OldTradeLookupEndpoint,
     OldTradeLookupParams,
-    OldTradeLookupResponse
+    OldTradeLookupResponse,
+    weight = 20
 );
 
 /// Get older market historical trades.
@@ -62,9 +63,9 @@ pub type OldTradeLookupResponse = Vec<OldTrade>;
 #[serde(rename_all = "camelCase")]
 pub struct OldTrade {
     pub id: i64,
-    pub price: String,
-    pub qty: String,
-    pub quote_qty: String,
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub quote_qty: Decimal,
     pub time: i64,
     pub is_buyer_maker: bool,
 }
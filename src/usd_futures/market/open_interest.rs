@@ -1,7 +1,7 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::rest_api::endpoint;
+use crate::{decimal::Decimal, rest_api::endpoint};
 
 endpoint!(
     "/fapi/v1/openInterest",
@@ -41,7 +41,7 @@ impl OpenInterestParams {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OpenInterestResponse {
-    pub open_interest: String,
+    pub open_interest: Decimal,
     pub symbol: String,
     pub time: i64,
 }
@@ -1,14 +1,15 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::rest_api::endpoint;
+use crate::{decimal::Decimal, rest_api::endpoint};
 
 endpoint!(
     "/futures/data/openInterestHist",
     Method::GET,
     OpenInterestStatisticsEndpoint,
     OpenInterestStatisticsParams,
-    OpenInterestStatisticsResponse
+    OpenInterestStatisticsResponse,
+    weight = 0
 );
 
 /// Open Interest Statistics.
@@ -67,7 +68,7 @@ pub type OpenInterestStatisticsResponse = Vec<OpenInterestStatistics>;
 #[serde(rename_all = "camelCase")]
 pub struct OpenInterestStatistics {
     pub symbol: String,
-    pub sub_open_interest: String,
-    pub sum_open_interest_value: String,
+    pub sum_open_interest: Decimal,
+    pub sum_open_interest_value: Decimal,
     pub timestamp: i64,
 }
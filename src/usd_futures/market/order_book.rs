@@ -1,14 +1,20 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{rest_api::endpoint, web_socket_api::web_socket};
+use crate::{decimal::Decimal, rest_api::endpoint, web_socket_api::web_socket};
 
 endpoint!(
     "/fapi/v1/depth",
     Method::GET,
     OrderBookEndpoint,
     OrderBookParams,
-    OrderBookResponse
+    OrderBookResponse,
+    weight = |params| match params.limit.unwrap_or(500) {
+        ..=50 => 2,
+        51..=100 => 5,
+        101..=500 => 10,
+        _ => 20,
+    }
 );
 
 /// Query symbol orderbook.
@@ -58,15 +64,21 @@ pub struct OrderBookResponse {
     pub message_output_time: i64,
     #[serde(rename = "T")]
     pub transaction_time: i64,
-    pub bids: Vec<(String, String)>,
-    pub asks: Vec<(String, String)>,
+    pub bids: Vec<(Decimal, Decimal)>,
+    pub asks: Vec<(Decimal, Decimal)>,
 }
 
 web_socket!(
     "depth",
     OrderBookWebSocket,
     OrderBookParams,
-    OrderBookResponse
+    OrderBookResponse,
+    weight = |params| match params.limit.unwrap_or(500) {
+        ..=50 => 2,
+        51..=100 => 5,
+        101..=500 => 10,
+        _ => 20,
+    }
 );
 
 pub struct OrderBookWebSocket<'w> {
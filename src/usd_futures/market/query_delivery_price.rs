@@ -1,14 +1,15 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::rest_api::endpoint;
+use crate::{decimal::Decimal, rest_api::endpoint};
 
 endpoint!(
     "/futures/data/openInterestHist",
     Method::GET,
     QueryDeliveryPriceEndpoint,
     QueryDeliveryPriceParams,
-    QueryDeliveryPriceResponse
+    QueryDeliveryPriceResponse,
+    weight = 0
 );
 
 /// Latest price for a symbol or symbols.
@@ -44,5 +45,5 @@ pub type QueryDeliveryPriceResponse = Vec<DeliveryPrice>;
 #[serde(rename_all = "camelCase")]
 pub struct DeliveryPrice {
     pub delivery_time: i64,
-    pub delivery_price: f64,
+    pub delivery_price: Decimal,
 }
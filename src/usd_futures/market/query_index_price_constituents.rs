@@ -8,7 +8,8 @@ endpoint!(
     Method::GET,
     QueryIndexPriceConstituentsEndpoint,
     QueryIndexPriceConstituentsParams,
-    QueryIndexPriceConstituentsResponse
+    QueryIndexPriceConstituentsResponse,
+    weight = 2
 );
 
 /// Query index price constituents
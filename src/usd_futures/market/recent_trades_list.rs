@@ -1,14 +1,15 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::rest_api::endpoint;
+use crate::{decimal::Decimal, rest_api::endpoint};
 
 endpoint!(
     "/fapi/v1/trades",
     Method::GET,
     RecentTradesListEndpoint,
     RecentTradesListParams,
-    RecentTradesListResponse
+    RecentTradesListResponse,
+    weight = 5
 );
 
 /// Get recent market trades.
@@ -53,9 +54,9 @@ pub type RecentTradesListResponse = Vec<RecentTrade>;
 #[serde(rename_all = "camelCase")]
 pub struct RecentTrade {
     pub id: i64,
-    pub price: String,
-    pub qty: String,
-    pub quote_qty: String,
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub quote_qty: Decimal,
     pub time: i64,
     pub is_buyer_maker: bool,
 }
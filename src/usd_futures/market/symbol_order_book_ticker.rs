@@ -1,19 +1,22 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{rest_api::endpoint, web_socket_api::web_socket};
+use crate::{decimal::Decimal, rest_api::endpoint, web_socket_api::web_socket};
 
 endpoint!(
     "/fapi/v1/ticker/bookTicker",
     Method::GET,
     SymbolOrderBookTickerEndpoint,
     SymbolOrderBookTickerParams,
-    SymbolOrderBookTickerResponse
+    SymbolOrderBookTickerResponse,
+    weight = |params| if params.symbol.is_some() { 2 } else { 5 }
 );
 
 /// Best price/qty on the order book for a symbol or symbols.
 ///
 /// - Weight:
+///   - with symbol: 2
+///   - without symbol: 5
 /// - Data Source: Memory
 pub struct SymbolOrderBookTickerEndpoint<'r> {
     client: &'r crate::rest_api::RestApiClient,
@@ -59,10 +62,10 @@ pub enum SymbolOrderBookTickerResponse {
 #[serde(rename_all = "camelCase")]
 pub struct SymbolOrderBookTicker {
     pub symbol: String,
-    pub bid_price: String,
-    pub bid_qty: String,
-    pub ask_price: String,
-    pub ask_qty: String,
+    pub bid_price: Decimal,
+    pub bid_qty: Decimal,
+    pub ask_price: Decimal,
+    pub ask_qty: Decimal,
     pub time: i64,
 }
 
@@ -70,7 +73,8 @@ web_socket!(
     "ticker.book",
     SymbolOrderBookTickerWebSocket,
     SymbolOrderBookTickerParams,
-    SymbolOrderBookTickerResponse
+    SymbolOrderBookTickerResponse,
+    weight = |params| if params.symbol.is_some() { 2 } else { 5 }
 );
 
 pub struct SymbolOrderBookTickerWebSocket<'w> {
@@ -1,19 +1,22 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::rest_api::endpoint;
+use crate::{decimal::Decimal, rest_api::endpoint};
 
 endpoint!(
     "/fapi/v2/ticker/price",
     Method::GET,
     SymbolOrderBookTickerV2Endpoint,
     SymbolOrderBookTickerV2Params,
-    SymbolOrderBookTickerV2Response
+    SymbolOrderBookTickerV2Response,
+    weight = |params| if params.symbol.is_some() { 2 } else { 5 }
 );
 
 /// Best price/qty on the order book for a symbol or symbols.
 ///
 /// - Weight:
+///   - with symbol: 2
+///   - without symbol: 5
 pub struct SymbolOrderBookTickerV2Endpoint<'r> {
     client: &'r crate::rest_api::RestApiClient,
 }
@@ -58,6 +61,6 @@ pub enum SymbolOrderBookTickerV2Response {
 #[serde(rename_all = "camelCase")]
 pub struct SymbolOrderBookTickerV2 {
     pub symbol: String,
-    pub price: String,
+    pub price: Decimal,
     pub time: i64,
 }
@@ -1,19 +1,22 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{rest_api::endpoint, web_socket_api::web_socket};
+use crate::{decimal::Decimal, rest_api::endpoint, web_socket_api::web_socket};
 
 endpoint!(
     "/fapi/v1/ticker/price",
     Method::GET,
     SymbolPriceTickerEndpoint,
     SymbolPriceTickerParams,
-    SymbolPriceTickerResponse
+    SymbolPriceTickerResponse,
+    weight = |params| if params.symbol.is_some() { 1 } else { 2 }
 );
 
 /// Latest price for a symbol or symbols.
 ///
 /// - Weight:
+///   - with symbol: 1
+///   - without symbol: 2
 pub struct SymbolPriceTickerEndpoint<'r> {
     client: &'r crate::rest_api::RestApiClient,
 }
@@ -59,7 +62,7 @@ pub enum SymbolPriceTickerResponse {
 #[serde(rename_all = "camelCase")]
 pub struct SymbolPriceTicker {
     pub symbol: String,
-    pub price: String,
+    pub price: Decimal,
     pub time: i64,
 }
 
@@ -67,7 +70,8 @@ web_socket!(
     "ticker.price",
     SymbolPriceTickerWebSocket,
     SymbolPriceTickerParams,
-    SymbolPriceTickerResponse
+    SymbolPriceTickerResponse,
+    weight = |params| if params.symbol.is_some() { 1 } else { 2 }
 );
 
 pub struct SymbolPriceTickerWebSocket<'w> {
@@ -1,14 +1,15 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::rest_api::endpoint;
+use crate::{decimal::Decimal, rest_api::endpoint};
 
 endpoint!(
     "/futures/data/takerlongshortRatio",
     Method::GET,
     TakerBuySellVolumeEndpoint,
     TakerBuySellVolumeParams,
-    TakerBuySellVolumeResponse
+    TakerBuySellVolumeResponse,
+    weight = 0
 );
 
 /// Taker Buy/Sell Volume.
@@ -69,8 +70,8 @@ pub type TakerBuySellVolumeResponse = Vec<TakerBuySellVolume>;
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TakerBuySellVolume {
-    pub buy_sell_ratio: String,
-    pub buy_vol: String,
-    pub sell_vol: String,
-    pub timestamp: String,
+    pub buy_sell_ratio: Decimal,
+    pub buy_vol: Decimal,
+    pub sell_vol: Decimal,
+    pub timestamp: i64,
 }
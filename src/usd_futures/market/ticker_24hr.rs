@@ -1,20 +1,23 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::rest_api::endpoint;
+use crate::{decimal::Decimal, rest_api::endpoint};
 
 endpoint!(
     "/fapi/v1/ticker/24hr",
     Method::GET,
     Ticker24hrEndpoint,
     Ticker24hrParams,
-    Ticker24hrResponse
+    Ticker24hrResponse,
+    weight = |params| if params.symbol.is_some() { 1 } else { 40 }
 );
 
 /// 24 hour rolling window price change statistics. Careful when accessing this
 /// with no symbol.
 ///
 /// - Weight:
+///   - with symbol: 1
+///   - without symbol: 40
 /// - Data Source: Memory
 pub struct Ticker24hrEndpoint<'r> {
     client: &'r crate::rest_api::RestApiClient,
@@ -53,7 +56,7 @@ impl Ticker24hrParams {
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 pub enum Ticker24hrResponse {
-    Vec(Box<Ticker>),
+    Vec(Box<Vec<Ticker>>),
     Item(Box<Ticker>),
 }
 
@@ -61,16 +64,16 @@ pub enum Ticker24hrResponse {
 #[serde(rename_all = "camelCase")]
 pub struct Ticker {
     pub symbol: String,
-    pub price_change: String,
-    pub price_change_percent: String,
-    pub weighted_avg_price: String,
-    pub last_price: String,
-    pub last_qty: String,
-    pub open_price: String,
-    pub high_price: String,
-    pub low_price: String,
-    pub volume: String,
-    pub quote_volume: String,
+    pub price_change: Decimal,
+    pub price_change_percent: Decimal,
+    pub weighted_avg_price: Decimal,
+    pub last_price: Decimal,
+    pub last_qty: Decimal,
+    pub open_price: Decimal,
+    pub high_price: Decimal,
+    pub low_price: Decimal,
+    pub volume: Decimal,
+    pub quote_volume: Decimal,
     pub open_time: i64,
     pub close_time: i64,
     pub first_id: i64,
@@ -1,14 +1,15 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::rest_api::endpoint;
+use crate::{decimal::Decimal, rest_api::endpoint};
 
 endpoint!(
     "/futures/data/topLongShortPositionRatio",
     Method::GET,
     TopTraderLongShortPositionRatioEndpoint,
     TopTraderLongShortPositionRatioParams,
-    TopTraderLongShortPositionRatioResponse
+    TopTraderLongShortPositionRatioResponse,
+    weight = 0
 );
 
 /// The proportion of net long and net short positions to total open positions of
@@ -74,8 +75,8 @@ pub type TopTraderLongShortPositionRatioResponse = Vec<TopTraderLongShortPositio
 #[serde(rename_all = "camelCase")]
 pub struct TopTraderLongShortPositionRatio {
     pub symbol: String,
-    pub long_short_ratio: String,
-    pub long_account: String,
-    pub short_account: String,
-    pub timestamp: String,
+    pub long_short_ratio: Decimal,
+    pub long_account: Decimal,
+    pub short_account: Decimal,
+    pub timestamp: i64,
 }
@@ -1,9 +1,11 @@
-// pub mod account;
+pub mod account;
 pub mod convert;
 pub mod market;
 pub mod trade;
+pub mod user_data_stream;
+pub mod web_socket_streams;
 
-use crate::rest_api::RestApiClient;
+use crate::{rest_api::RestApiClient, web_socket_api::WebSocketApiClient};
 
 pub struct RestApiCategory<'r> {
     client: &'r RestApiClient,
@@ -14,9 +16,9 @@ impl<'r> RestApiCategory<'r> {
         RestApiCategory { client }
     }
 
-    // pub fn account(&self) -> account::RestApiHandler {
-    //     account::RestApiHandler::new(self.client)
-    // }
+    pub fn account(&self) -> account::RestApiHandler {
+        account::RestApiHandler::new(self.client)
+    }
 
     pub fn convert(&self) -> convert::RestApiHandler {
         convert::RestApiHandler::new(self.client)
@@ -29,4 +31,32 @@ impl<'r> RestApiCategory<'r> {
     pub fn trade(&self) -> trade::RestApiHandler {
         trade::RestApiHandler::new(self.client)
     }
+
+    pub fn user_data_stream(&self) -> user_data_stream::RestApiHandler {
+        user_data_stream::RestApiHandler::new(self.client)
+    }
+}
+
+/// The USD-M futures methods of the WebSocket API, e.g. on
+/// `wss://ws-fapi.binance.com/ws-fapi/v1`.
+pub struct WebSocketApiCategory<'w> {
+    client: &'w WebSocketApiClient,
+}
+
+impl<'w> WebSocketApiCategory<'w> {
+    pub fn new(client: &'w WebSocketApiClient) -> Self {
+        WebSocketApiCategory { client }
+    }
+
+    pub fn account(&self) -> account::WebSocketApiHandler {
+        account::WebSocketApiHandler::new(self.client)
+    }
+
+    pub fn market(&self) -> market::WebSocketApiHandler {
+        market::WebSocketApiHandler::new(self.client)
+    }
+
+    pub fn trade(&self) -> trade::WebSocketApiHandler {
+        trade::WebSocketApiHandler::new(self.client)
+    }
 }
@@ -1,8 +1,8 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    decimal::Decimal,
     enums::{
         futures::{OrderSide, PositionSide},
         SecurityType,
@@ -16,7 +16,8 @@ endpoint!(
     SecurityType::UserData,
     AccountTradeListEndpoint,
     AccountTradeListParams,
-    AccountTradeListResponse
+    AccountTradeListResponse,
+    weight = 5
 );
 
 /// Get trades for a specific account and symbol.
@@ -42,7 +43,6 @@ pub struct AccountTradeListParams {
     from_id: Option<i64>,
     limit: Option<i64>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl AccountTradeListParams {
@@ -55,7 +55,6 @@ impl AccountTradeListParams {
             from_id: None,
             limit: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -96,15 +95,15 @@ pub type AccountTradeListResponse = Vec<AccountTradeDetail>;
 #[serde(rename_all = "camelCase")]
 pub struct AccountTradeDetail {
     pub buyer: bool,
-    pub commission: String,
+    pub commission: Decimal,
     pub commission_asset: String,
     pub id: i64,
     pub maker: bool,
     pub order_id: i64,
-    pub price: String,
-    pub qty: String,
-    pub quote_qty: String,
-    pub realized_pnl: String,
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub quote_qty: Decimal,
+    pub realized_pnl: Decimal,
     pub side: OrderSide,
     pub position_side: PositionSide,
     pub symbol: String,
@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
@@ -34,7 +33,6 @@ pub struct AutoCancelAllOpenOrdersParams {
     symbol: String,
     countdown_time: i64,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl AutoCancelAllOpenOrdersParams {
@@ -43,7 +41,6 @@ impl AutoCancelAllOpenOrdersParams {
             symbol: symbol.to_owned(),
             countdown_time,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -57,5 +54,5 @@ impl AutoCancelAllOpenOrdersParams {
 #[serde(rename_all = "camelCase")]
 pub struct AutoCancelAllOpenOrdersResponse {
     pub symbol: String,
-    pub countdown_time: i64,
+    pub countdown_time: String,
 }
@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, errors::BinanceError, rest_api::endpoint};
+use crate::{enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/fapi/v1/allOpenOrders",
@@ -31,7 +30,6 @@ impl<'r> CancelAllOpenOrdersEndpoint<'r> {
 pub struct CancelAllOpenOrdersParams {
     symbol: String,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl CancelAllOpenOrdersParams {
@@ -39,7 +37,6 @@ impl CancelAllOpenOrdersParams {
         Self {
             symbol: symbol.to_owned(),
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -50,8 +47,7 @@ impl CancelAllOpenOrdersParams {
 }
 
 #[derive(Debug, Deserialize)]
-#[serde(untagged)]
-pub enum CancelAllOpenOrdersResponse {
-    Success,
-    Failure(BinanceError),
+pub struct CancelAllOpenOrdersResponse {
+    pub code: i64,
+    pub msg: String,
 }
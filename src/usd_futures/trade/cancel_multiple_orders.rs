@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
@@ -35,7 +34,6 @@ pub struct CancelMultipleOrdersParams {
     order_id_list: Option<Vec<i64>>,
     orig_client_order_id_list: Option<Vec<String>>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl CancelMultipleOrdersParams {
@@ -45,7 +43,6 @@ impl CancelMultipleOrdersParams {
             order_id_list: None,
             orig_client_order_id_list: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -65,9 +62,12 @@ impl CancelMultipleOrdersParams {
     }
 }
 
+pub type CancelMultipleOrdersResponse = Vec<CancelMultipleOrdersResult>;
+
+/// The result of each order, in the order of the request.
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
-pub enum CancelMultipleOrdersResponse {
-    Success(Vec<CancelOrderDetail>),
+pub enum CancelMultipleOrdersResult {
+    Success(Box<CancelOrderDetail>),
     Failure(BinanceError),
 }
@@ -1,13 +1,14 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    decimal::Decimal,
     enums::{
         futures::{OrderSide, OrderStatus, OrderType, PositionSide, WorkingType},
         SecurityType, SelfTradePreventionMode, TimeInForce,
     },
     rest_api::endpoint,
+    web_socket_api::web_socket,
 };
 
 endpoint!(
@@ -36,10 +37,12 @@ impl<'r> CancelOrderEndpoint<'r> {
 #[serde(rename_all = "camelCase")]
 pub struct CancelOrderParams {
     symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     order_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     orig_client_order_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl CancelOrderParams {
@@ -49,7 +52,6 @@ impl CancelOrderParams {
             order_id: None,
             orig_client_order_id: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -75,24 +77,24 @@ pub type CancelOrderResponse = CancelOrderDetail;
 #[serde(rename_all = "camelCase")]
 pub struct CancelOrderDetail {
     pub client_order_id: String,
-    pub cum_qty: String,
-    pub cum_quote: String,
-    pub executed_qty: String,
+    pub cum_qty: Decimal,
+    pub cum_quote: Decimal,
+    pub executed_qty: Decimal,
     pub order_id: i64,
-    pub orig_qty: String,
+    pub orig_qty: Decimal,
     pub orig_type: OrderType,
-    pub price: String,
+    pub price: Decimal,
     pub reduce_only: bool,
     pub side: OrderSide,
     pub position_side: PositionSide,
     pub status: OrderStatus,
-    pub stop_price: String,
+    pub stop_price: Decimal,
     pub close_position: bool,
     pub symbol: String,
     pub time_in_force: TimeInForce,
     pub r#type: OrderType,
-    pub activate_price: String,
-    pub price_rate: String,
+    pub activate_price: Option<Decimal>,
+    pub price_rate: Option<Decimal>,
     pub update_time: i64,
     pub working_type: WorkingType,
     pub price_protect: bool,
@@ -100,3 +102,21 @@ pub struct CancelOrderDetail {
     pub self_trade_prevention_mode: SelfTradePreventionMode,
     pub good_till_date: i64,
 }
+
+web_socket!(
+    "order.cancel",
+    SecurityType::Trade,
+    CancelOrderWebSocket,
+    CancelOrderParams,
+    CancelOrderResponse
+);
+
+pub struct CancelOrderWebSocket<'w> {
+    client: &'w crate::web_socket_api::WebSocketApiClient,
+}
+
+impl<'w> CancelOrderWebSocket<'w> {
+    pub fn new(client: &'w crate::web_socket_api::WebSocketApiClient) -> Self {
+        Self { client }
+    }
+}
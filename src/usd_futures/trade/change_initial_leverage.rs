@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/fapi/v1/leverage",
@@ -32,7 +31,6 @@ pub struct ChangeInitialLeverageParams {
     symbol: String,
     leverage: i64,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl ChangeInitialLeverageParams {
@@ -41,7 +39,6 @@ impl ChangeInitialLeverageParams {
             symbol: symbol.to_owned(),
             leverage,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -55,6 +52,6 @@ impl ChangeInitialLeverageParams {
 #[serde(rename_all = "camelCase")]
 pub struct ChangeInitialLeverageResponse {
     pub leverage: i64,
-    pub max_notional_value: String,
+    pub max_notional_value: Decimal,
     pub symbol: String,
 }
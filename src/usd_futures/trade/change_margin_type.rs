@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, errors::BinanceError, rest_api::endpoint};
+use crate::{enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/fapi/v1/marginType",
@@ -32,7 +31,6 @@ pub struct ChangeMarginTypeParams {
     symbol: String,
     margin_type: String,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl ChangeMarginTypeParams {
@@ -41,7 +39,6 @@ impl ChangeMarginTypeParams {
             symbol: symbol.to_owned(),
             margin_type: margin_type.to_owned(),
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -52,8 +49,7 @@ impl ChangeMarginTypeParams {
 }
 
 #[derive(Debug, Deserialize)]
-#[serde(untagged)]
-pub enum ChangeMarginTypeResponse {
-    Success,
-    Failure(BinanceError),
+pub struct ChangeMarginTypeResponse {
+    pub code: i64,
+    pub msg: String,
 }
@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, errors::BinanceError, rest_api::endpoint};
+use crate::{enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/fapi/v1/multiAssetsMargin",
@@ -31,7 +30,6 @@ impl<'r> ChangeMultiAssetsModeEndpoint<'r> {
 pub struct ChangeMultiAssetsModeParams {
     multi_assets_margin: String,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl ChangeMultiAssetsModeParams {
@@ -39,7 +37,6 @@ impl ChangeMultiAssetsModeParams {
         Self {
             multi_assets_margin: multi_assets_margin.to_owned(),
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -50,8 +47,7 @@ impl ChangeMultiAssetsModeParams {
 }
 
 #[derive(Debug, Deserialize)]
-#[serde(untagged)]
-pub enum ChangeMultiAssetsModeResponse {
-    Success,
-    Failure(BinanceError),
+pub struct ChangeMultiAssetsModeResponse {
+    pub code: i64,
+    pub msg: String,
 }
@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, errors::BinanceError, rest_api::endpoint};
+use crate::{enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/fapi/v1/positionSide/dual",
@@ -31,7 +30,6 @@ impl<'r> ChangePositionModeEndpoint<'r> {
 pub struct ChangePositionModeParams {
     dual_side_position: String,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl ChangePositionModeParams {
@@ -39,7 +37,6 @@ impl ChangePositionModeParams {
         Self {
             dual_side_position: dual_side_position.to_owned(),
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -50,8 +47,7 @@ impl ChangePositionModeParams {
 }
 
 #[derive(Debug, Deserialize)]
-#[serde(untagged)]
-pub enum ChangePositionModeResponse {
-    Success,
-    Failure(BinanceError),
+pub struct ChangePositionModeResponse {
+    pub code: i64,
+    pub msg: String,
 }
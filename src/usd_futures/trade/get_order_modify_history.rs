@@ -1,8 +1,7 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::{enums::SecurityType, rest_api::endpoint};
+use crate::{decimal::Decimal, enums::SecurityType, rest_api::endpoint};
 
 endpoint!(
     "/fapi/v1/orderAmendment",
@@ -36,7 +35,6 @@ pub struct GetOrderModifyHistoryParams {
     end_time: Option<i64>,
     limit: Option<i64>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl GetOrderModifyHistoryParams {
@@ -49,7 +47,6 @@ impl GetOrderModifyHistoryParams {
             end_time: None,
             limit: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -95,6 +92,7 @@ pub struct OrderModifyRecord {
     pub order_id: i64,
     pub client_order_id: String,
     pub time: i64,
+    pub amendment: OrderAmendment,
 }
 
 #[derive(Debug, Deserialize)]
@@ -108,13 +106,13 @@ pub struct OrderAmendment {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PriceAmendment {
-    pub before: String,
-    pub after: String,
+    pub before: Decimal,
+    pub after: Decimal,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct QuantityAmendment {
-    pub before: String,
-    pub after: String,
+    pub before: Decimal,
+    pub after: Decimal,
 }
@@ -1,8 +1,8 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    decimal::Decimal,
     enums::{futures::PositionSide, SecurityType},
     rest_api::endpoint,
 };
@@ -38,7 +38,6 @@ pub struct GetPositionMarginChangeHistoryParams {
     end_time: Option<i64>,
     limit: Option<i64>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl GetPositionMarginChangeHistoryParams {
@@ -50,7 +49,6 @@ impl GetPositionMarginChangeHistoryParams {
             end_time: None,
             limit: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -88,7 +86,7 @@ pub struct PositionMarginChangeHistory {
     pub symbol: String,
     pub r#type: i64,
     pub delta_type: String,
-    pub amount: String,
+    pub amount: Decimal,
     pub asset: String,
     pub time: i64,
     pub position_side: PositionSide,
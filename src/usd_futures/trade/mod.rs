@@ -50,7 +50,10 @@ pub use query_force_orders::*;
 pub use query_order::*;
 pub use test_new_order::*;
 
-use crate::rest_api::{route, RestApiClient};
+use crate::{
+    rest_api::{route, RestApiClient},
+    web_socket_api::{ws_route, WebSocketApiClient},
+};
 
 pub struct RestApiHandler<'r> {
     client: &'r RestApiClient,
@@ -99,3 +102,19 @@ impl<'r> RestApiHandler<'r> {
     );
     route!(test_new_order, TestNewOrderEndpoint);
 }
+
+pub struct WebSocketApiHandler<'w> {
+    client: &'w WebSocketApiClient,
+}
+
+impl<'w> WebSocketApiHandler<'w> {
+    pub fn new(client: &'w WebSocketApiClient) -> Self {
+        WebSocketApiHandler { client }
+    }
+
+    ws_route!(new_order, NewOrderWebSocket);
+    ws_route!(modify_order, ModifyOrderWebSocket);
+    ws_route!(cancel_order, CancelOrderWebSocket);
+    ws_route!(query_order, QueryOrderWebSocket);
+    ws_route!(position_information_v2, PositionInformationV2WebSocket);
+}
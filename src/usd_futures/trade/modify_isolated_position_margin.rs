@@ -1,8 +1,8 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    decimal::Decimal,
     enums::{futures::PositionSide, SecurityType},
     rest_api::endpoint,
 };
@@ -34,10 +34,9 @@ impl<'r> ModifyIsolatedPositionMarginEndpoint<'r> {
 pub struct ModifyIsolatedPositionMarginParams {
     symbol: String,
     position_side: Option<PositionSide>,
-    amount: Option<f64>,
+    amount: Option<Decimal>,
     r#type: Option<i64>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl ModifyIsolatedPositionMarginParams {
@@ -48,7 +47,6 @@ impl ModifyIsolatedPositionMarginParams {
             amount: None,
             r#type: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -57,8 +55,8 @@ impl ModifyIsolatedPositionMarginParams {
         self
     }
 
-    pub fn amount(mut self, amount: f64) -> Self {
-        self.amount = Some(amount);
+    pub fn amount(mut self, amount: impl Into<Decimal>) -> Self {
+        self.amount = Some(amount.into());
         self
     }
 
@@ -76,7 +74,7 @@ impl ModifyIsolatedPositionMarginParams {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ModifyIsolatedPositionMarginResponse {
-    pub amount: f64,
+    pub amount: Decimal,
     pub code: i64,
     pub msg: String,
     pub r#type: i64,
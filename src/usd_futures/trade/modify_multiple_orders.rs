@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
@@ -12,13 +11,17 @@ endpoint!(
     SecurityType::Trade,
     ModifyMultipleOrdersEndpoint,
     ModifyMultipleOrdersParams,
-    ModifyMultipleOrdersResponse
+    ModifyMultipleOrdersResponse,
+    weight = 5
 );
 
 /// Order modify function, currently only LIMIT order modification is supported,
 /// modified orders will be reordered in the match queue.
 ///
 /// - Weight:
+///   - 5 on 10s order rate limit (`X-MBX-ORDER-COUNT-10S`)
+///   - 1 on 1min order rate limit (`X-MBX-ORDER-COUNT-1M`)
+///   - 5 on IP rate limit (`X-MBX-USED-WEIGHT-1M`)
 pub struct ModifyMultipleOrdersEndpoint<'r> {
     client: &'r crate::rest_api::RestApiClient,
 }
@@ -35,7 +38,6 @@ pub struct ModifyMultipleOrdersParams {
     #[serde(flatten)]
     input: ModifyOrderInput,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl ModifyMultipleOrdersParams {
@@ -43,7 +45,6 @@ impl ModifyMultipleOrdersParams {
         Self {
             input,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -53,9 +54,12 @@ impl ModifyMultipleOrdersParams {
     }
 }
 
+pub type ModifyMultipleOrdersResponse = Vec<ModifyMultipleOrdersResult>;
+
+/// The result of each order, in the order of the request.
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
-pub enum ModifyMultipleOrdersResponse {
-    Success(ModifyOrderDetail),
+pub enum ModifyMultipleOrdersResult {
+    Success(Box<ModifyOrderDetail>),
     Failure(BinanceError),
 }
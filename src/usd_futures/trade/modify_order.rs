@@ -1,13 +1,14 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    decimal::Decimal,
     enums::{
         futures::{OrderSide, OrderStatus, PositionSide, TimeInForce, WorkingType},
         OrderType, SecurityType, SelfTradePreventionMode,
     },
     rest_api::endpoint,
+    web_socket_api::web_socket,
 };
 
 endpoint!(
@@ -16,13 +17,17 @@ endpoint!(
     SecurityType::Trade,
     ModifyOrderEndpoint,
     ModifyOrderParams,
-    ModifyOrderResponse
+    ModifyOrderResponse,
+    weight = 0
 );
 
 /// Order modify function, currently only LIMIT order modification is supported,
 /// modified orders will be reordered in the match queue.
 ///
 /// - Weight:
+///   - 1 on 10s order rate limit (`X-MBX-ORDER-COUNT-10S`)
+///   - 1 on 1min order rate limit (`X-MBX-ORDER-COUNT-1M`)
+///   - 0 on IP rate limit (`X-MBX-USED-WEIGHT-1M`)
 pub struct ModifyOrderEndpoint<'r> {
     client: &'r crate::rest_api::RestApiClient,
 }
@@ -36,24 +41,32 @@ impl<'r> ModifyOrderEndpoint<'r> {
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ModifyOrderInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
     order_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     orig_client_order_id: Option<String>,
     symbol: String,
     side: OrderSide,
-    quantity: f64,
-    price: f64,
+    quantity: Decimal,
+    price: Decimal,
+    #[serde(skip_serializing_if = "Option::is_none")]
     price_match: Option<String>,
 }
 
 impl ModifyOrderInput {
-    pub fn new(symbol: &str, side: OrderSide, quantity: f64, price: f64) -> Self {
+    pub fn new(
+        symbol: &str,
+        side: OrderSide,
+        quantity: impl Into<Decimal>,
+        price: impl Into<Decimal>,
+    ) -> Self {
         Self {
             order_id: None,
             orig_client_order_id: None,
             symbol: symbol.to_owned(),
             side,
-            quantity,
-            price,
+            quantity: quantity.into(),
+            price: price.into(),
             price_match: None,
         }
     }
@@ -79,8 +92,8 @@ impl ModifyOrderInput {
 pub struct ModifyOrderParams {
     #[serde(flatten)]
     input: ModifyOrderInput,
+    #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl ModifyOrderParams {
@@ -88,7 +101,6 @@ impl ModifyOrderParams {
         Self {
             input,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -105,22 +117,22 @@ pub type ModifyOrderResponse = ModifyOrderDetail;
 pub struct ModifyOrderDetail {
     pub order_id: i64,
     pub symbol: String,
-    pub pair: String,
+    pub pair: Option<String>,
     pub status: OrderStatus,
     pub client_order_id: String,
-    pub price: String,
-    pub avg_price: String,
-    pub orig_qty: String,
-    pub executed_qty: String,
-    pub cum_qty: String,
-    pub cum_base: String,
+    pub price: Decimal,
+    pub avg_price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub cum_qty: Decimal,
+    pub cum_quote: Decimal,
     pub time_in_force: TimeInForce,
     pub r#type: OrderType,
     pub reduce_only: bool,
     pub close_position: bool,
     pub side: OrderSide,
     pub position_side: PositionSide,
-    pub stop_price: String,
+    pub stop_price: Decimal,
     pub working_type: WorkingType,
     pub price_protect: bool,
     pub orig_type: OrderType,
@@ -129,3 +141,22 @@ pub struct ModifyOrderDetail {
     pub good_till_date: i64,
     pub update_time: i64,
 }
+
+web_socket!(
+    "order.modify",
+    SecurityType::Trade,
+    ModifyOrderWebSocket,
+    ModifyOrderParams,
+    ModifyOrderResponse,
+    weight = 0
+);
+
+pub struct ModifyOrderWebSocket<'w> {
+    client: &'w crate::web_socket_api::WebSocketApiClient,
+}
+
+impl<'w> ModifyOrderWebSocket<'w> {
+    pub fn new(client: &'w crate::web_socket_api::WebSocketApiClient) -> Self {
+        Self { client }
+    }
+}
@@ -1,13 +1,14 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    decimal::Decimal,
     enums::{
         futures::{OrderSide, OrderStatus, PositionSide, ResponseType, TimeInForce, WorkingType},
         OrderType, SecurityType, SelfTradePreventionMode,
     },
     rest_api::endpoint,
+    web_socket_api::web_socket,
 };
 
 endpoint!(
@@ -16,12 +17,17 @@ endpoint!(
     SecurityType::Trade,
     NewOrderEndpoint,
     NewOrderParams,
-    NewOrderResponse
+    NewOrderResponse,
+    weight = 0,
+    retry = |params| params.input.new_client_order_id.is_some()
 );
 
 /// Send in a new order.
 ///
 /// - Weight:
+///   - 1 on 10s order rate limit (`X-MBX-ORDER-COUNT-10S`)
+///   - 1 on 1min order rate limit (`X-MBX-ORDER-COUNT-1M`)
+///   - 0 on IP rate limit (`X-MBX-USED-WEIGHT-1M`)
 pub struct NewOrderEndpoint<'r> {
     client: &'r crate::rest_api::RestApiClient,
 }
@@ -37,21 +43,36 @@ impl<'r> NewOrderEndpoint<'r> {
 pub struct NewOrderInput {
     symbol: String,
     side: OrderSide,
+    #[serde(skip_serializing_if = "Option::is_none")]
     position_side: Option<String>,
     r#type: OrderType,
+    #[serde(skip_serializing_if = "Option::is_none")]
     time_in_force: Option<TimeInForce>,
-    quantity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    quantity: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     reduce_only: Option<String>,
-    price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    price: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     new_client_order_id: Option<String>,
-    stop_price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop_price: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     close_position: Option<String>,
-    activation_price: Option<f64>,
-    callback_rate: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    activation_price: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    callback_rate: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     working_type: Option<WorkingType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     price_protect: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     new_order_resp_type: Option<ResponseType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     good_till_date: Option<i64>,
 }
 
@@ -84,8 +105,8 @@ impl NewOrderInput {
         self
     }
 
-    pub fn quantity(mut self, quantity: f64) -> Self {
-        self.quantity = Some(quantity);
+    pub fn quantity(mut self, quantity: impl Into<Decimal>) -> Self {
+        self.quantity = Some(quantity.into());
         self
     }
 
@@ -94,18 +115,22 @@ impl NewOrderInput {
         self
     }
 
-    pub fn price(mut self, price: f64) -> Self {
-        self.price = Some(price);
+    pub fn price(mut self, price: impl Into<Decimal>) -> Self {
+        self.price = Some(price.into());
         self
     }
 
+    /// Setting it allows the request to be retried when it is rejected before
+    /// being executed. When its execution status is unknown, the order has to
+    /// be queried by `origClientOrderId` before it is sent again, see
+    /// [`RetryPolicy`](crate::rest_api::RetryPolicy).
     pub fn new_client_order_id(mut self, new_client_order_id: &str) -> Self {
         self.new_client_order_id = Some(new_client_order_id.to_owned());
         self
     }
 
-    pub fn stop_price(mut self, stop_price: f64) -> Self {
-        self.stop_price = Some(stop_price);
+    pub fn stop_price(mut self, stop_price: impl Into<Decimal>) -> Self {
+        self.stop_price = Some(stop_price.into());
         self
     }
 
@@ -114,13 +139,13 @@ impl NewOrderInput {
         self
     }
 
-    pub fn activation_price(mut self, activation_price: f64) -> Self {
-        self.activation_price = Some(activation_price);
+    pub fn activation_price(mut self, activation_price: impl Into<Decimal>) -> Self {
+        self.activation_price = Some(activation_price.into());
         self
     }
 
-    pub fn callback_rate(mut self, callback_rate: f64) -> Self {
-        self.callback_rate = Some(callback_rate);
+    pub fn callback_rate(mut self, callback_rate: impl Into<Decimal>) -> Self {
+        self.callback_rate = Some(callback_rate.into());
         self
     }
 
@@ -158,8 +183,8 @@ impl NewOrderInput {
 pub struct NewOrderParams {
     #[serde(flatten)]
     input: NewOrderInput,
+    #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl NewOrderParams {
@@ -167,7 +192,6 @@ impl NewOrderParams {
         Self {
             input,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -183,25 +207,25 @@ pub type NewOrderResponse = NewOrderDetail;
 #[serde(rename_all = "camelCase")]
 pub struct NewOrderDetail {
     pub client_order_id: String,
-    pub cum_qty: String,
-    pub cum_quote: String,
-    pub executed_qty: String,
+    pub cum_qty: Decimal,
+    pub cum_quote: Decimal,
+    pub executed_qty: Decimal,
     pub order_id: i64,
-    pub avg_price: String,
-    pub orig_qty: String,
-    pub price: String,
+    pub avg_price: Decimal,
+    pub orig_qty: Decimal,
+    pub price: Decimal,
     pub reduce_only: bool,
     pub side: OrderSide,
     pub position_side: PositionSide,
     pub status: OrderStatus,
-    pub stop_price: String,
+    pub stop_price: Decimal,
     pub close_position: bool,
     pub symbol: String,
     pub time_in_force: TimeInForce,
     pub r#type: OrderType,
     pub orig_type: OrderType,
-    pub activate_price: String,
-    pub price_rate: String,
+    pub activate_price: Option<Decimal>,
+    pub price_rate: Option<Decimal>,
     pub update_time: i64,
     pub working_type: WorkingType,
     pub price_protect: bool,
@@ -209,3 +233,22 @@ pub struct NewOrderDetail {
     pub self_trade_prevention_mode: SelfTradePreventionMode,
     pub good_till_date: i64,
 }
+
+web_socket!(
+    "order.place",
+    SecurityType::Trade,
+    NewOrderWebSocket,
+    NewOrderParams,
+    NewOrderResponse,
+    weight = 0
+);
+
+pub struct NewOrderWebSocket<'w> {
+    client: &'w crate::web_socket_api::WebSocketApiClient,
+}
+
+impl<'w> NewOrderWebSocket<'w> {
+    pub fn new(client: &'w crate::web_socket_api::WebSocketApiClient) -> Self {
+        Self { client }
+    }
+}
@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
@@ -12,12 +11,16 @@ endpoint!(
     SecurityType::Trade,
     PlaceMultipleOrdersEndpoint,
     PlaceMultipleOrdersParams,
-    PlaceMultipleOrdersResponse
+    PlaceMultipleOrdersResponse,
+    weight = 5
 );
 
 /// Send in a new order.
 ///
 /// - Weight:
+///   - 5 on 10s order rate limit (`X-MBX-ORDER-COUNT-10S`)
+///   - 1 on 1min order rate limit (`X-MBX-ORDER-COUNT-1M`)
+///   - 5 on IP rate limit (`X-MBX-USED-WEIGHT-1M`)
 pub struct PlaceMultipleOrdersEndpoint<'r> {
     client: &'r crate::rest_api::RestApiClient,
 }
@@ -34,7 +37,6 @@ pub struct PlaceMultipleOrdersParams {
     #[serde(flatten)]
     input: Vec<NewOrderInput>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl PlaceMultipleOrdersParams {
@@ -42,7 +44,6 @@ impl PlaceMultipleOrdersParams {
         Self {
             input,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -52,9 +53,12 @@ impl PlaceMultipleOrdersParams {
     }
 }
 
+pub type PlaceMultipleOrdersResponse = Vec<PlaceMultipleOrdersResult>;
+
+/// The result of each order, in the order of the request.
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
-pub enum PlaceMultipleOrdersResponse {
-    Success(NewOrderDetail),
+pub enum PlaceMultipleOrdersResult {
+    Success(Box<NewOrderDetail>),
     Failure(BinanceError),
 }
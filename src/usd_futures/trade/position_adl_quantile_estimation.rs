@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
@@ -10,7 +9,8 @@ endpoint!(
     SecurityType::UserData,
     PositionAdlQuantileEstimationEndpoint,
     PositionAdlQuantileEstimationParams,
-    PositionAdlQuantileEstimationResponse
+    PositionAdlQuantileEstimationResponse,
+    weight = 5
 );
 
 /// Position ADL Quantile Estimation.
@@ -31,7 +31,6 @@ impl<'r> PositionAdlQuantileEstimationEndpoint<'r> {
 pub struct PositionAdlQuantileEstimationParams {
     symbol: String,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl PositionAdlQuantileEstimationParams {
@@ -39,7 +38,6 @@ impl PositionAdlQuantileEstimationParams {
         Self {
             symbol: symbol.to_owned(),
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -63,5 +61,6 @@ pub struct PositionAdlQuantileEstimation {
 pub struct AdlQuantile {
     pub long: i64,
     pub short: i64,
-    pub both: i64,
+    pub both: Option<i64>,
+    pub hedge: Option<i64>,
 }
@@ -1,10 +1,11 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    decimal::Decimal,
     enums::{futures::PositionSide, SecurityType},
     rest_api::endpoint,
+    web_socket_api::web_socket,
 };
 
 endpoint!(
@@ -13,7 +14,8 @@ endpoint!(
     SecurityType::UserData,
     PositionInformationV2Endpoint,
     PositionInformationV2Params,
-    PositionInformationV2Response
+    PositionInformationV2Response,
+    weight = 5
 );
 
 /// Get current position information.
@@ -33,8 +35,8 @@ impl<'r> PositionInformationV2Endpoint<'r> {
 #[serde(rename_all = "camelCase")]
 pub struct PositionInformationV2Params {
     symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl PositionInformationV2Params {
@@ -42,7 +44,6 @@ impl PositionInformationV2Params {
         Self {
             symbol: symbol.to_owned(),
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -62,20 +63,39 @@ pub enum PositionInformationV2Response {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PositionInformationV2 {
-    pub entry_price: String,
-    pub break_even_price: String,
+    pub entry_price: Decimal,
+    pub break_even_price: Decimal,
     pub margin_type: String,
     pub is_auto_add_margin: String,
-    pub isolated_margin: String,
-    pub leverage: String,
-    pub liquidation_price: String,
-    pub mark_price: String,
-    pub max_notional_value: String,
-    pub position_amt: String,
-    pub notional: String,
-    pub isolated_wallet: String,
+    pub isolated_margin: Decimal,
+    pub leverage: Decimal,
+    pub liquidation_price: Decimal,
+    pub mark_price: Decimal,
+    pub max_notional_value: Decimal,
+    pub position_amt: Decimal,
+    pub notional: Decimal,
+    pub isolated_wallet: Decimal,
     pub symbol: String,
-    pub un_realized_profit: String,
+    pub un_realized_profit: Decimal,
     pub position_side: PositionSide,
     pub update_time: i64,
 }
+
+web_socket!(
+    "account.position",
+    SecurityType::UserData,
+    PositionInformationV2WebSocket,
+    PositionInformationV2Params,
+    PositionInformationV2Response,
+    weight = 5
+);
+
+pub struct PositionInformationV2WebSocket<'w> {
+    client: &'w crate::web_socket_api::WebSocketApiClient,
+}
+
+impl<'w> PositionInformationV2WebSocket<'w> {
+    pub fn new(client: &'w crate::web_socket_api::WebSocketApiClient) -> Self {
+        Self { client }
+    }
+}
@@ -1,8 +1,8 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    decimal::Decimal,
     enums::{futures::PositionSide, SecurityType},
     rest_api::endpoint,
 };
@@ -13,7 +13,8 @@ endpoint!(
     SecurityType::UserData,
     PositionInformationV3Endpoint,
     PositionInformationV3Params,
-    PositionInformationV3Response
+    PositionInformationV3Response,
+    weight = 5
 );
 
 /// Get current position information(only symbol that has position or open orders
@@ -35,7 +36,6 @@ impl<'r> PositionInformationV3Endpoint<'r> {
 pub struct PositionInformationV3Params {
     symbol: String,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl PositionInformationV3Params {
@@ -43,7 +43,6 @@ impl PositionInformationV3Params {
         Self {
             symbol: symbol.to_owned(),
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -65,22 +64,22 @@ pub enum PositionInformationV3Response {
 pub struct PositionInformationV3 {
     pub symbol: String,
     pub position_side: PositionSide,
-    pub position_amt: String,
-    pub entry_price: String,
-    pub break_even_price: String,
-    pub mark_price: String,
-    pub un_realized_profit: String,
-    pub liquidation_price: String,
-    pub isolated_margin: String,
-    pub notional: String,
+    pub position_amt: Decimal,
+    pub entry_price: Decimal,
+    pub break_even_price: Decimal,
+    pub mark_price: Decimal,
+    pub un_realized_profit: Decimal,
+    pub liquidation_price: Decimal,
+    pub isolated_margin: Decimal,
+    pub notional: Decimal,
     pub margin_asset: String,
-    pub isolated_wallet: String,
-    pub initial_margin: String,
-    pub maint_margin: String,
-    pub position_initial_margin: String,
-    pub open_order_initial_margin: String,
+    pub isolated_wallet: Decimal,
+    pub initial_margin: Decimal,
+    pub maint_margin: Decimal,
+    pub position_initial_margin: Decimal,
+    pub open_order_initial_margin: Decimal,
     pub adl: i64,
-    pub bid_notional: String,
-    pub ask_notional: String,
+    pub bid_notional: Decimal,
+    pub ask_notional: Decimal,
     pub update_time: i64,
 }
@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::Serialize;
 
@@ -12,7 +11,8 @@ endpoint!(
     SecurityType::Trade,
     QueryAllOrdersEndpoint,
     QueryAllOrdersParams,
-    QueryAllOrdersResponse
+    QueryAllOrdersResponse,
+    weight = 5
 );
 
 /// Get all account orders; active, canceled, or filled.
@@ -37,7 +37,6 @@ pub struct QueryAllOrdersParams {
     end_time: Option<i64>,
     limit: Option<i64>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryAllOrdersParams {
@@ -49,7 +48,6 @@ impl QueryAllOrdersParams {
             end_time: None,
             limit: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::Serialize;
 
@@ -36,6 +35,8 @@ impl Params for QueryCurrentAllOpenOrdersParams {}
 /// Get all open orders on a symbol.
 ///
 /// - Weight:
+///   - 1 for a single symbol
+///   - 40 when the symbol parameter is omitted
 pub struct QueryCurrentAllOpenOrdersEndpoint<'r> {
     client: &'r crate::rest_api::RestApiClient,
 }
@@ -51,7 +52,6 @@ impl<'r> QueryCurrentAllOpenOrdersEndpoint<'r> {
 pub struct QueryCurrentAllOpenOrdersParams {
     symbol: String,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryCurrentAllOpenOrdersParams {
@@ -59,7 +59,6 @@ impl QueryCurrentAllOpenOrdersParams {
         Self {
             symbol: symbol.to_owned(),
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
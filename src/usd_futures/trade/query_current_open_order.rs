@@ -1,4 +1,3 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::Serialize;
 
@@ -53,7 +52,6 @@ pub struct QueryCurrentOpenOrderParams {
     order_id: Option<i64>,
     orig_client_order_id: Option<String>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryCurrentOpenOrderParams {
@@ -63,7 +61,6 @@ impl QueryCurrentOpenOrderParams {
             order_id: None,
             orig_client_order_id: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -1,15 +1,16 @@
-use jiff::Timestamp;
 use reqwest::Method;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    enums::SecurityType,
+    decimal::Decimal,
+    enums::{
+        futures::{OrderSide, OrderStatus, OrderType, PositionSide, WorkingType},
+        SecurityType,
+    },
     rest_api::{Endpoint, RestApiClient},
-    Params,
+    Params, Response,
 };
 
-use super::OrderDetail;
-
 impl Endpoint for QueryForceOrdersEndpoint<'_> {
     type Response = QueryForceOrdersResponse;
     type Params = QueryForceOrdersParams;
@@ -29,6 +30,10 @@ impl Endpoint for QueryForceOrdersEndpoint<'_> {
     fn security_type(&self) -> SecurityType {
         SecurityType::UserData
     }
+
+    fn weight(&self, _params: &Self::Params) -> u32 {
+        20
+    }
 }
 
 impl Params for QueryForceOrdersParams {}
@@ -36,6 +41,8 @@ impl Params for QueryForceOrdersParams {}
 /// Query user's Force Orders.
 ///
 /// - Weight:
+///   - 20 with symbol
+///   - 50 without symbol
 pub struct QueryForceOrdersEndpoint<'r> {
     client: &'r crate::rest_api::RestApiClient,
 }
@@ -55,7 +62,6 @@ pub struct QueryForceOrdersParams {
     end_time: Option<i64>,
     limit: Option<i64>,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl QueryForceOrdersParams {
@@ -67,7 +73,6 @@ impl QueryForceOrdersParams {
             end_time: None,
             limit: None,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -97,4 +102,31 @@ impl QueryForceOrdersParams {
     }
 }
 
-pub type QueryForceOrdersResponse = Vec<OrderDetail>;
+pub type QueryForceOrdersResponse = Vec<ForceOrder>;
+
+impl Response for QueryForceOrdersResponse {}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ForceOrder {
+    pub order_id: i64,
+    pub symbol: String,
+    pub status: OrderStatus,
+    pub client_order_id: String,
+    pub price: Decimal,
+    pub avg_price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub cum_quote: Decimal,
+    pub time_in_force: String,
+    pub r#type: OrderType,
+    pub reduce_only: bool,
+    pub close_position: bool,
+    pub side: OrderSide,
+    pub position_side: PositionSide,
+    pub stop_price: Decimal,
+    pub working_type: WorkingType,
+    pub orig_type: OrderType,
+    pub time: i64,
+    pub update_time: i64,
+}
@@ -1,8 +1,8 @@
-use jiff::Timestamp;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    decimal::Decimal,
     enums::{
         futures::{OrderSide, OrderStatus, PositionSide, ResponseType, TimeInForce, WorkingType},
         OrderType, SecurityType, SelfTradePreventionMode,
@@ -16,12 +16,13 @@ endpoint!(
     SecurityType::Trade,
     TestNewOrderEndpoint,
     TestNewOrderParams,
-    TestNewOrderResponse
+    TestNewOrderResponse,
+    weight = 0
 );
 
 /// Testing order request, this order will not be submitted to matching engine.
 ///
-/// - Weight:
+/// - Weight: 0
 pub struct TestNewOrderEndpoint<'r> {
     client: &'r crate::rest_api::RestApiClient,
 }
@@ -40,14 +41,14 @@ pub struct TestNewOrderInput {
     position_side: Option<String>,
     r#type: OrderType,
     time_in_force: Option<TimeInForce>,
-    quantity: Option<f64>,
+    quantity: Option<Decimal>,
     reduce_only: Option<String>,
-    price: Option<f64>,
+    price: Option<Decimal>,
     new_client_order_id: Option<String>,
-    stop_price: Option<f64>,
+    stop_price: Option<Decimal>,
     close_position: Option<String>,
-    activation_price: Option<f64>,
-    callback_rate: Option<f64>,
+    activation_price: Option<Decimal>,
+    callback_rate: Option<Decimal>,
     working_type: Option<WorkingType>,
     price_protect: Option<String>,
     new_order_resp_type: Option<ResponseType>,
@@ -84,8 +85,8 @@ impl TestNewOrderInput {
         self
     }
 
-    pub fn quantity(mut self, quantity: f64) -> Self {
-        self.quantity = Some(quantity);
+    pub fn quantity(mut self, quantity: impl Into<Decimal>) -> Self {
+        self.quantity = Some(quantity.into());
         self
     }
 
@@ -94,8 +95,8 @@ impl TestNewOrderInput {
         self
     }
 
-    pub fn price(mut self, price: f64) -> Self {
-        self.price = Some(price);
+    pub fn price(mut self, price: impl Into<Decimal>) -> Self {
+        self.price = Some(price.into());
         self
     }
 
@@ -104,8 +105,8 @@ impl TestNewOrderInput {
         self
     }
 
-    pub fn stop_price(mut self, stop_price: f64) -> Self {
-        self.stop_price = Some(stop_price);
+    pub fn stop_price(mut self, stop_price: impl Into<Decimal>) -> Self {
+        self.stop_price = Some(stop_price.into());
         self
     }
 
@@ -114,13 +115,13 @@ impl TestNewOrderInput {
         self
     }
 
-    pub fn activation_price(mut self, activation_price: f64) -> Self {
-        self.activation_price = Some(activation_price);
+    pub fn activation_price(mut self, activation_price: impl Into<Decimal>) -> Self {
+        self.activation_price = Some(activation_price.into());
         self
     }
 
-    pub fn callback_rate(mut self, callback_rate: f64) -> Self {
-        self.callback_rate = Some(callback_rate);
+    pub fn callback_rate(mut self, callback_rate: impl Into<Decimal>) -> Self {
+        self.callback_rate = Some(callback_rate.into());
         self
     }
 
@@ -159,7 +160,6 @@ pub struct TestNewOrderParams {
     #[serde(flatten)]
     input: TestNewOrderInput,
     recv_window: Option<i64>,
-    timestamp: i64,
 }
 
 impl TestNewOrderParams {
@@ -167,7 +167,6 @@ impl TestNewOrderParams {
         Self {
             input,
             recv_window: None,
-            timestamp: Timestamp::now().as_millisecond(),
         }
     }
 
@@ -183,25 +182,25 @@ pub type TestNewOrderResponse = TestNewOrderDetail;
 #[serde(rename_all = "camelCase")]
 pub struct TestNewOrderDetail {
     pub client_order_id: String,
-    pub cum_qty: String,
-    pub cum_quote: String,
-    pub executed_qty: String,
+    pub cum_qty: Decimal,
+    pub cum_quote: Decimal,
+    pub executed_qty: Decimal,
     pub order_id: i64,
-    pub avg_price: String,
-    pub orig_qty: String,
-    pub price: String,
+    pub avg_price: Decimal,
+    pub orig_qty: Decimal,
+    pub price: Decimal,
     pub reduce_only: bool,
     pub side: OrderSide,
     pub position_side: PositionSide,
     pub status: OrderStatus,
-    pub stop_price: String,
+    pub stop_price: Decimal,
     pub close_position: bool,
     pub symbol: String,
     pub time_in_force: TimeInForce,
     pub r#type: OrderType,
     pub orig_type: OrderType,
-    pub activate_price: String,
-    pub price_rate: String,
+    pub activate_price: Option<Decimal>,
+    pub price_rate: Option<Decimal>,
     pub update_time: i64,
     pub working_type: WorkingType,
     pub price_protect: bool,
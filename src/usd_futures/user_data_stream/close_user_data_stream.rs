@@ -0,0 +1,46 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/fapi/v1/listenKey",
+    Method::DELETE,
+    SecurityType::UserStream,
+    CloseUserDataStreamEndpoint,
+    CloseUserDataStreamParams,
+    CloseUserDataStreamResponse
+);
+
+/// Close the user data stream of the account.
+///
+/// - Weight: 1
+pub struct CloseUserDataStreamEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> CloseUserDataStreamEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloseUserDataStreamParams {}
+
+impl Default for CloseUserDataStreamParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CloseUserDataStreamParams {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloseUserDataStreamResponse {}
@@ -0,0 +1,50 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/fapi/v1/listenKey",
+    Method::PUT,
+    SecurityType::UserStream,
+    KeepaliveUserDataStreamEndpoint,
+    KeepaliveUserDataStreamParams,
+    KeepaliveUserDataStreamResponse
+);
+
+/// Keepalive the user data stream of the account to prevent a time out. User
+/// data streams will close after 60 minutes. It's recommended to send a ping
+/// about every 60 minutes.
+///
+/// - Weight: 1
+pub struct KeepaliveUserDataStreamEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> KeepaliveUserDataStreamEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeepaliveUserDataStreamParams {}
+
+impl Default for KeepaliveUserDataStreamParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeepaliveUserDataStreamParams {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeepaliveUserDataStreamResponse {
+    pub listen_key: String,
+}
@@ -0,0 +1,50 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{enums::SecurityType, rest_api::endpoint};
+
+endpoint!(
+    "/fapi/v1/listenKey",
+    Method::POST,
+    SecurityType::UserStream,
+    StartUserDataStreamEndpoint,
+    StartUserDataStreamParams,
+    StartUserDataStreamResponse
+);
+
+/// Start a new user data stream. The stream will close after 60 minutes unless
+/// a keepalive is sent. If the account has an active listen key, that key is
+/// returned and its validity is extended for 60 minutes.
+///
+/// - Weight: 1
+pub struct StartUserDataStreamEndpoint<'r> {
+    client: &'r crate::rest_api::RestApiClient,
+}
+
+impl<'r> StartUserDataStreamEndpoint<'r> {
+    pub fn new(client: &'r crate::rest_api::RestApiClient) -> Self {
+        Self { client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartUserDataStreamParams {}
+
+impl Default for StartUserDataStreamParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StartUserDataStreamParams {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartUserDataStreamResponse {
+    pub listen_key: String,
+}
@@ -0,0 +1,364 @@
+use serde::Deserialize;
+
+use crate::{
+    decimal::Decimal,
+    enums::futures::{ContractStats, ContractType, OrderSide, OrderStatus, OrderType, TimeInForce},
+    web_socket_stream::{CombinedMessage, Payload},
+};
+
+/// Payloads of combined USD-M futures streams, routed on the name of their
+/// stream.
+#[derive(Clone, Debug)]
+pub enum UsdFuturesStreamPayload {
+    AggregatedTrade(AggregatedTrade),
+    MarkPrice(MarkPrice),
+    /// The `!markPrice@arr` streams.
+    MarkPrices(Vec<MarkPrice>),
+    ContinuousKline(ContinuousKline),
+    /// The `<symbol>@forceOrder` and `!forceOrder@arr` streams.
+    LiquidationOrder(LiquidationOrder),
+    /// The `<symbol>@bookTicker` and `!bookTicker` streams.
+    BookTicker(BookTicker),
+    /// The partial depth streams have the fields of the diff depth stream.
+    PartialBookDepth(Depth),
+    Depth(Depth),
+    CompositeIndex(CompositeIndex),
+    ContractInfo(ContractInfo),
+    AssetIndex(AssetIndex),
+    /// The `!assetIndex@arr` stream.
+    AssetIndexes(Vec<AssetIndex>),
+}
+
+impl Payload for UsdFuturesStreamPayload {}
+
+impl<'de> Deserialize<'de> for UsdFuturesStreamPayload {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let message = CombinedMessage::deserialize(deserializer)?;
+        let stream = message.stream.as_str();
+
+        // All market streams: `!<stream>[@arr][@<speed>]`.
+        if let Some(stream) = stream.strip_prefix('!') {
+            return match stream.split('@').next().unwrap_or_default() {
+                "markPrice" => message.data().map(Self::MarkPrices),
+                "forceOrder" => message.data().map(Self::LiquidationOrder),
+                "bookTicker" => message.data().map(Self::BookTicker),
+                "contractInfo" => message.data().map(Self::ContractInfo),
+                "assetIndex" => message.data().map(Self::AssetIndexes),
+                _ => Err(message.unknown_stream()),
+            };
+        }
+
+        // Symbol and pair streams: `<symbol>@<stream>[_<param>][@<speed>]`.
+        let name = stream.split_once('@').map_or("", |(_, name)| name);
+        match name.split(['_', '@']).next().unwrap_or_default() {
+            "aggTrade" => message.data().map(Self::AggregatedTrade),
+            "markPrice" => message.data().map(Self::MarkPrice),
+            "continuousKline" => message.data().map(Self::ContinuousKline),
+            "forceOrder" => message.data().map(Self::LiquidationOrder),
+            "bookTicker" => message.data().map(Self::BookTicker),
+            "depth" => message.data().map(Self::Depth),
+            "depth5" | "depth10" | "depth20" => message.data().map(Self::PartialBookDepth),
+            "compositeIndex" => message.data().map(Self::CompositeIndex),
+            "assetIndex" => message.data().map(Self::AssetIndex),
+            _ => Err(message.unknown_stream()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AggregatedTrade {
+    #[serde(rename = "e")]
+    pub event_type: String,
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "a")]
+    pub aggregated_trade_id: i64,
+    #[serde(rename = "p")]
+    pub price: Decimal,
+    #[serde(rename = "q")]
+    pub quantity: Decimal,
+    #[serde(rename = "f")]
+    pub first_trade_id: i64,
+    #[serde(rename = "l")]
+    pub last_trade_id: i64,
+    #[serde(rename = "T")]
+    pub trade_time: i64,
+    #[serde(rename = "m")]
+    pub is_buyer_maker: bool,
+}
+
+impl Payload for AggregatedTrade {}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MarkPrice {
+    #[serde(rename = "e")]
+    pub event_type: String,
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "p")]
+    pub mark_price: Decimal,
+    #[serde(rename = "i")]
+    pub index_price: Decimal,
+    /// Only useful in the last hour before the settlement starts.
+    #[serde(rename = "P")]
+    pub estimated_settle_price: Decimal,
+    #[serde(rename = "r")]
+    pub funding_rate: Decimal,
+    #[serde(rename = "T")]
+    pub next_funding_time: i64,
+}
+
+impl Payload for MarkPrice {}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ContinuousKline {
+    #[serde(rename = "e")]
+    pub event_type: String,
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "ps")]
+    pub pair: String,
+    #[serde(rename = "ct")]
+    pub contract_type: ContractType,
+    #[serde(rename = "k")]
+    pub kline: ContinuousKlineData,
+}
+
+impl Payload for ContinuousKline {}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ContinuousKlineData {
+    #[serde(rename = "t")]
+    pub start_time: i64,
+    #[serde(rename = "T")]
+    pub close_time: i64,
+    #[serde(rename = "i")]
+    pub interval: String,
+    #[serde(rename = "f")]
+    pub first_update_id: i64,
+    #[serde(rename = "L")]
+    pub last_update_id: i64,
+    #[serde(rename = "o")]
+    pub open_price: Decimal,
+    #[serde(rename = "c")]
+    pub close_price: Decimal,
+    #[serde(rename = "h")]
+    pub high_price: Decimal,
+    #[serde(rename = "l")]
+    pub low_price: Decimal,
+    #[serde(rename = "v")]
+    pub volume: Decimal,
+    #[serde(rename = "n")]
+    pub number_of_trades: i64,
+    #[serde(rename = "x")]
+    pub is_closed: bool,
+    #[serde(rename = "q")]
+    pub quote_asset_volume: Decimal,
+    #[serde(rename = "V")]
+    pub taker_buy_volume: Decimal,
+    #[serde(rename = "Q")]
+    pub taker_buy_quote_asset_volume: Decimal,
+    #[serde(rename = "B")]
+    pub ignore: String,
+}
+
+/// The latest liquidation order of a symbol, at most one per second.
+#[derive(Clone, Debug, Deserialize)]
+pub struct LiquidationOrder {
+    #[serde(rename = "e")]
+    pub event_type: String,
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "o")]
+    pub order: LiquidationOrderData,
+}
+
+impl Payload for LiquidationOrder {}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct LiquidationOrderData {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "S")]
+    pub side: OrderSide,
+    #[serde(rename = "o")]
+    pub order_type: OrderType,
+    #[serde(rename = "f")]
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "q")]
+    pub original_quantity: Decimal,
+    #[serde(rename = "p")]
+    pub price: Decimal,
+    #[serde(rename = "ap")]
+    pub average_price: Decimal,
+    #[serde(rename = "X")]
+    pub order_status: OrderStatus,
+    #[serde(rename = "l")]
+    pub last_filled_quantity: Decimal,
+    #[serde(rename = "z")]
+    pub filled_accumulated_quantity: Decimal,
+    #[serde(rename = "T")]
+    pub trade_time: i64,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct BookTicker {
+    #[serde(rename = "e")]
+    pub event_type: String,
+    #[serde(rename = "u")]
+    pub order_book_update_id: i64,
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "T")]
+    pub transaction_time: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "b")]
+    pub best_bid_price: Decimal,
+    #[serde(rename = "B")]
+    pub best_bid_quantity: Decimal,
+    #[serde(rename = "a")]
+    pub best_ask_price: Decimal,
+    #[serde(rename = "A")]
+    pub best_ask_quantity: Decimal,
+}
+
+impl Payload for BookTicker {}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Depth {
+    #[serde(rename = "e")]
+    pub event_type: String,
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "T")]
+    pub transaction_time: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "U")]
+    pub first_update_id: i64,
+    #[serde(rename = "u")]
+    pub final_update_id: i64,
+    /// Final update id of the previous event of the stream.
+    #[serde(rename = "pu")]
+    pub prev_final_update_id: i64,
+    #[serde(rename = "b")]
+    pub bids: Vec<(Decimal, Decimal)>,
+    #[serde(rename = "a")]
+    pub asks: Vec<(Decimal, Decimal)>,
+}
+
+impl Payload for Depth {}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CompositeIndex {
+    #[serde(rename = "e")]
+    pub event_type: String,
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "p")]
+    pub price: Decimal,
+    #[serde(rename = "C")]
+    pub base_asset_type: String,
+    #[serde(rename = "c")]
+    pub composition: Vec<IndexComponent>,
+}
+
+impl Payload for CompositeIndex {}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct IndexComponent {
+    #[serde(rename = "b")]
+    pub base_asset: String,
+    #[serde(rename = "q")]
+    pub quote_asset: String,
+    #[serde(rename = "w")]
+    pub weight_in_quantity: Decimal,
+    #[serde(rename = "W")]
+    pub weight_in_percentage: Decimal,
+    #[serde(rename = "i")]
+    pub index_price: Decimal,
+}
+
+/// A change of a contract. The brackets are only sent when they change.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ContractInfo {
+    #[serde(rename = "e")]
+    pub event_type: String,
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "ps")]
+    pub pair: String,
+    #[serde(rename = "ct")]
+    pub contract_type: ContractType,
+    #[serde(rename = "dt")]
+    pub delivery_date: i64,
+    #[serde(rename = "ot")]
+    pub onboard_date: i64,
+    #[serde(rename = "cs")]
+    pub contract_status: ContractStats,
+    #[serde(rename = "bks", default)]
+    pub brackets: Vec<NotionalBracket>,
+}
+
+impl Payload for ContractInfo {}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct NotionalBracket {
+    #[serde(rename = "bs")]
+    pub bracket: i64,
+    #[serde(rename = "bnf")]
+    pub notional_floor: Decimal,
+    #[serde(rename = "bnc")]
+    pub notional_cap: Decimal,
+    #[serde(rename = "mmr")]
+    pub maintenance_ratio: Decimal,
+    #[serde(rename = "cf")]
+    pub auxiliary_number: Decimal,
+    #[serde(rename = "mi")]
+    pub min_leverage: i64,
+    #[serde(rename = "ma")]
+    pub max_leverage: i64,
+}
+
+/// Index of an asset of the Multi-Assets mode.
+#[derive(Clone, Debug, Deserialize)]
+pub struct AssetIndex {
+    #[serde(rename = "e")]
+    pub event_type: String,
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "i")]
+    pub index_price: Decimal,
+    #[serde(rename = "b")]
+    pub bid_buffer: Decimal,
+    #[serde(rename = "a")]
+    pub ask_buffer: Decimal,
+    #[serde(rename = "B")]
+    pub bid_rate: Decimal,
+    #[serde(rename = "A")]
+    pub ask_rate: Decimal,
+    #[serde(rename = "q")]
+    pub auto_exchange_bid_buffer: Decimal,
+    #[serde(rename = "g")]
+    pub auto_exchange_ask_buffer: Decimal,
+    #[serde(rename = "Q")]
+    pub auto_exchange_bid_rate: Decimal,
+    #[serde(rename = "G")]
+    pub auto_exchange_ask_rate: Decimal,
+}
+
+impl Payload for AssetIndex {}
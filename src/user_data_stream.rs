@@ -0,0 +1,579 @@
+//! Managed user data streams.
+//!
+//! A [`UserDataStream`] creates a listen key, keeps it alive, and yields the
+//! typed payloads of its stream. When the listen key expires, or is no longer
+//! valid, a new one is created and the stream connects to it.
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//!
+//! use birdie::user_data_stream::UserDataStream;
+//! use futures_util::StreamExt;
+//!
+//! # async fn example() {
+//! let client = birdie::rest_api("https://api.binance.com", "api_key", "secret_key").unwrap();
+//! let mut stream = UserDataStream::spot(Arc::new(client), "wss://stream.binance.com:9443");
+//! stream.start().await.unwrap();
+//! while let Some(item) = stream.next().await {
+//!     match item {
+//!         Ok(payload) => println!("{payload:?}"),
+//!         Err(event) => println!("{event:?}"),
+//!     }
+//! }
+//! # }
+//! ```
+use std::{
+    iter,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures_util::{Stream, StreamExt};
+use thiserror::Error;
+use tokio::{sync::mpsc, task::JoinHandle};
+use tracing::{debug, error, warn};
+
+use crate::{
+    coin_futures,
+    errors::BinanceErrorCode,
+    margin::{
+        risk_data_stream::{self, RiskDataStreamPayload},
+        trade_data_stream::{self, TradeUserDataStreamPayload},
+    },
+    rest_api::{Endpoint, RestApiClient, RestApiError},
+    spot::user_data_stream::{self, UserDataStreamPayload},
+    usd_futures,
+    web_socket::{ConnectionStatus, ReconnectPolicy},
+    web_socket_stream::{
+        open_stream_with_policy, Payload, PayloadStream, StreamEvent, WebSocketStreamError,
+    },
+};
+
+const CHANNEL_BUFFER: usize = 2048;
+
+/// Binance closes a listen key after 60 minutes without a keepalive.
+const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+#[derive(Debug, Error)]
+pub enum UserDataStreamError {
+    #[error("rest api error: {0}")]
+    RestApi(#[from] RestApiError),
+    #[error("websocket stream error: {0}")]
+    WebSocketStream(#[from] WebSocketStreamError),
+}
+
+/// The payloads of a user data stream.
+pub trait UserDataPayload: Payload {
+    /// Whether the payload is the `listenKeyExpired` event.
+    fn is_listen_key_expired(&self) -> bool;
+}
+
+impl UserDataPayload for UserDataStreamPayload {
+    fn is_listen_key_expired(&self) -> bool {
+        matches!(self, Self::ListenKeyExpired(_))
+    }
+}
+
+impl UserDataPayload for TradeUserDataStreamPayload {
+    fn is_listen_key_expired(&self) -> bool {
+        matches!(self, Self::ListenKeyExpired(_))
+    }
+}
+
+impl UserDataPayload for RiskDataStreamPayload {
+    fn is_listen_key_expired(&self) -> bool {
+        matches!(self, Self::ListenKeyExpired(_))
+    }
+}
+
+impl UserDataPayload for usd_futures::user_data_stream::UserDataStreamPayload {
+    fn is_listen_key_expired(&self) -> bool {
+        matches!(self, Self::ListenKeyExpired(_))
+    }
+}
+
+impl UserDataPayload for coin_futures::user_data_stream::UserDataStreamPayload {
+    fn is_listen_key_expired(&self) -> bool {
+        matches!(self, Self::ListenKeyExpired(_))
+    }
+}
+
+/// The endpoints managing the listen key of a stream.
+#[derive(Clone, Debug)]
+enum ListenKeyApi {
+    Spot,
+    Margin,
+    IsolatedMargin(String),
+    MarginRisk,
+    /// The listen key is implied by the API key.
+    UsdFutures,
+    /// The listen key is implied by the API key.
+    CoinFutures,
+}
+
+impl ListenKeyApi {
+    async fn start(&self, client: &RestApiClient) -> Result<String, RestApiError> {
+        let listen_key = match self {
+            Self::Spot => {
+                let params = user_data_stream::StartUserDataStreamParams::new();
+                client
+                    .user_data_stream()
+                    .start_user_data_stream()
+                    .request(params)
+                    .await?
+                    .listen_key
+            }
+            Self::Margin => {
+                let params = trade_data_stream::StartMarginUserDataStreamParams::new();
+                client
+                    .margin()
+                    .trade_data_stream()
+                    .start_margin_data_stream()
+                    .request(params)
+                    .await?
+                    .listen_key
+            }
+            Self::IsolatedMargin(symbol) => {
+                let params =
+                    trade_data_stream::StartIsolatedMarginUserDataStreamParams::new(symbol);
+                client
+                    .margin()
+                    .trade_data_stream()
+                    .start_isolated_margin_data_stream()
+                    .request(params)
+                    .await?
+                    .listen_key
+            }
+            Self::MarginRisk => {
+                let params = risk_data_stream::StartUserDataStreamParams::new();
+                client
+                    .margin()
+                    .risk_data_stream()
+                    .start_user_data_stream()
+                    .request(params)
+                    .await?
+                    .listen_key
+            }
+            Self::UsdFutures => {
+                let params = usd_futures::user_data_stream::StartUserDataStreamParams::new();
+                client
+                    .usd_futures()
+                    .user_data_stream()
+                    .start_user_data_stream()
+                    .request(params)
+                    .await?
+                    .listen_key
+            }
+            Self::CoinFutures => {
+                let params = coin_futures::user_data_stream::StartUserDataStreamParams::new();
+                client
+                    .coin_futures()
+                    .user_data_stream()
+                    .start_user_data_stream()
+                    .request(params)
+                    .await?
+                    .listen_key
+            }
+        };
+        Ok(listen_key)
+    }
+
+    async fn keepalive(
+        &self,
+        client: &RestApiClient,
+        listen_key: &str,
+    ) -> Result<(), RestApiError> {
+        let listen_key = listen_key.to_owned();
+        match self {
+            Self::Spot => {
+                let params = user_data_stream::KeepaliveUserDataStreamParams::new(listen_key);
+                client
+                    .user_data_stream()
+                    .keepalive_user_data_stream()
+                    .request(params)
+                    .await?;
+            }
+            Self::Margin => {
+                let params =
+                    trade_data_stream::KeepaliveMarginUserDataStreamParams::new(listen_key);
+                client
+                    .margin()
+                    .trade_data_stream()
+                    .keepalive_margin_data_stream()
+                    .request(params)
+                    .await?;
+            }
+            Self::IsolatedMargin(symbol) => {
+                let params = trade_data_stream::KeepaliveIsolatedMarginUserDataStreamParams::new(
+                    symbol, listen_key,
+                );
+                client
+                    .margin()
+                    .trade_data_stream()
+                    .keepalive_isolated_margin_data_stream()
+                    .request(params)
+                    .await?;
+            }
+            Self::MarginRisk => {
+                let params = risk_data_stream::KeepaliveUserDataStreamParams::new(listen_key);
+                client
+                    .margin()
+                    .risk_data_stream()
+                    .keepalive_user_data_stream()
+                    .request(params)
+                    .await?;
+            }
+            Self::UsdFutures => {
+                let params = usd_futures::user_data_stream::KeepaliveUserDataStreamParams::new();
+                client
+                    .usd_futures()
+                    .user_data_stream()
+                    .keepalive_user_data_stream()
+                    .request(params)
+                    .await?;
+            }
+            Self::CoinFutures => {
+                let params = coin_futures::user_data_stream::KeepaliveUserDataStreamParams::new();
+                client
+                    .coin_futures()
+                    .user_data_stream()
+                    .keepalive_user_data_stream()
+                    .request(params)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn close(&self, client: &RestApiClient, listen_key: &str) -> Result<(), RestApiError> {
+        let listen_key = listen_key.to_owned();
+        match self {
+            Self::Spot => {
+                let params = user_data_stream::CloseUserDataStreamParams::new(listen_key);
+                client
+                    .user_data_stream()
+                    .close_user_data_stream()
+                    .request(params)
+                    .await?;
+            }
+            Self::Margin => {
+                let params = trade_data_stream::CloseMarginUserDataStreamParams::new(listen_key);
+                client
+                    .margin()
+                    .trade_data_stream()
+                    .close_margin_data_stream()
+                    .request(params)
+                    .await?;
+            }
+            Self::IsolatedMargin(symbol) => {
+                let params = trade_data_stream::CloseIsolatedMarginUserDataStreamParams::new(
+                    symbol, listen_key,
+                );
+                client
+                    .margin()
+                    .trade_data_stream()
+                    .close_isolated_margin_data_stream()
+                    .request(params)
+                    .await?;
+            }
+            Self::MarginRisk => {
+                let params = risk_data_stream::CloseUserDataStreamParams::new(listen_key);
+                client
+                    .margin()
+                    .risk_data_stream()
+                    .close_user_data_stream()
+                    .request(params)
+                    .await?;
+            }
+            Self::UsdFutures => {
+                let params = usd_futures::user_data_stream::CloseUserDataStreamParams::new();
+                client
+                    .usd_futures()
+                    .user_data_stream()
+                    .close_user_data_stream()
+                    .request(params)
+                    .await?;
+            }
+            Self::CoinFutures => {
+                let params = coin_futures::user_data_stream::CloseUserDataStreamParams::new();
+                client
+                    .coin_futures()
+                    .user_data_stream()
+                    .close_user_data_stream()
+                    .request(params)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+struct Config {
+    api: ListenKeyApi,
+    rest_client: Arc<RestApiClient>,
+    stream_endpoint: String,
+    keepalive_interval: Duration,
+    reconnect_policy: ReconnectPolicy,
+}
+
+impl Config {
+    /// Create a listen key, and connect to its stream.
+    async fn open<P>(&self) -> Result<(String, PayloadStream<P>), UserDataStreamError>
+    where
+        P: Payload,
+    {
+        let listen_key = self.api.start(&self.rest_client).await?;
+        let endpoint = format!("{}/ws/{listen_key}", self.stream_endpoint);
+        let stream = open_stream_with_policy(&endpoint, self.reconnect_policy.clone()).await?;
+        Ok((listen_key, stream))
+    }
+}
+
+/// A user data stream, with the lifecycle of its listen key managed.
+///
+/// It yields the payloads of the stream, including `listenKeyExpired`, with
+/// the events of the connection in-band, like a [`PayloadStream`]. It ends
+/// once the connection is lost for good, or a new listen key cannot be
+/// created within the attempts of the [`ReconnectPolicy`]. The listen key is
+/// left to expire when it is dropped without [`UserDataStream::close`].
+pub struct UserDataStream<P> {
+    config: Config,
+    listen_key: Arc<Mutex<Option<String>>>,
+    receiver: Option<mpsc::Receiver<Result<P, StreamEvent>>>,
+    task: Option<JoinHandle<()>>,
+}
+
+impl UserDataStream<UserDataStreamPayload> {
+    pub fn spot(rest_client: Arc<RestApiClient>, stream_endpoint: &str) -> Self {
+        Self::new(ListenKeyApi::Spot, rest_client, stream_endpoint)
+    }
+}
+
+impl UserDataStream<TradeUserDataStreamPayload> {
+    /// The stream of the cross margin account.
+    pub fn margin(rest_client: Arc<RestApiClient>, stream_endpoint: &str) -> Self {
+        Self::new(ListenKeyApi::Margin, rest_client, stream_endpoint)
+    }
+
+    /// The stream of the isolated margin account of `symbol`.
+    pub fn isolated_margin(
+        rest_client: Arc<RestApiClient>,
+        stream_endpoint: &str,
+        symbol: &str,
+    ) -> Self {
+        let api = ListenKeyApi::IsolatedMargin(symbol.to_owned());
+        Self::new(api, rest_client, stream_endpoint)
+    }
+}
+
+impl UserDataStream<RiskDataStreamPayload> {
+    /// The stream of the margin calls and liability changes.
+    pub fn margin_risk(rest_client: Arc<RestApiClient>, stream_endpoint: &str) -> Self {
+        Self::new(ListenKeyApi::MarginRisk, rest_client, stream_endpoint)
+    }
+}
+
+impl UserDataStream<usd_futures::user_data_stream::UserDataStreamPayload> {
+    /// The stream of the USD-M futures account, e.g. on
+    /// `wss://fstream.binance.com`.
+    pub fn usd_futures(rest_client: Arc<RestApiClient>, stream_endpoint: &str) -> Self {
+        Self::new(ListenKeyApi::UsdFutures, rest_client, stream_endpoint)
+    }
+}
+
+impl UserDataStream<coin_futures::user_data_stream::UserDataStreamPayload> {
+    /// The stream of the COIN-M futures account, e.g. on
+    /// `wss://dstream.binance.com`.
+    pub fn coin_futures(rest_client: Arc<RestApiClient>, stream_endpoint: &str) -> Self {
+        Self::new(ListenKeyApi::CoinFutures, rest_client, stream_endpoint)
+    }
+}
+
+impl<P> UserDataStream<P>
+where
+    P: UserDataPayload,
+{
+    fn new(api: ListenKeyApi, rest_client: Arc<RestApiClient>, stream_endpoint: &str) -> Self {
+        Self {
+            config: Config {
+                api,
+                rest_client,
+                stream_endpoint: stream_endpoint.to_owned(),
+                keepalive_interval: KEEPALIVE_INTERVAL,
+                reconnect_policy: ReconnectPolicy::default(),
+            },
+            listen_key: Arc::new(Mutex::new(None)),
+            receiver: None,
+            task: None,
+        }
+    }
+
+    /// Default: 30 minutes.
+    pub fn keepalive_interval(mut self, keepalive_interval: Duration) -> Self {
+        self.config.keepalive_interval = keepalive_interval;
+        self
+    }
+
+    /// The policy of the connections, also used to retry creating a listen
+    /// key once the previous one expired.
+    pub fn reconnect_policy(mut self, reconnect_policy: ReconnectPolicy) -> Self {
+        self.config.reconnect_policy = reconnect_policy;
+        self
+    }
+
+    /// The listen key of the stream.
+    pub fn listen_key(&self) -> Option<String> {
+        self.listen_key.lock().unwrap().clone()
+    }
+
+    /// Create a listen key, connect to its stream, and spawn the task that
+    /// keeps it alive and renews it.
+    pub async fn start(&mut self) -> Result<(), UserDataStreamError> {
+        self.stop();
+        let (listen_key, stream) = self.config.open().await?;
+        *self.listen_key.lock().unwrap() = Some(listen_key.clone());
+
+        let (tx, rx) = mpsc::channel(CHANNEL_BUFFER);
+        let task = maintain(
+            self.config.clone(),
+            self.listen_key.clone(),
+            listen_key,
+            stream,
+            tx,
+        );
+        self.receiver = Some(rx);
+        self.task = Some(tokio::spawn(task));
+        Ok(())
+    }
+
+    /// Stop the stream, and close its listen key.
+    pub async fn close(&mut self) -> Result<(), UserDataStreamError> {
+        self.stop();
+        self.receiver = None;
+        let listen_key = self.listen_key.lock().unwrap().take();
+        if let Some(listen_key) = listen_key {
+            let config = &self.config;
+            config.api.close(&config.rest_client, &listen_key).await?;
+        }
+        Ok(())
+    }
+
+    fn stop(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
+}
+
+impl<P> Stream for UserDataStream<P> {
+    type Item = Result<P, StreamEvent>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.get_mut().receiver.as_mut() {
+            Some(receiver) => receiver.poll_recv(cx),
+            None => Poll::Ready(None),
+        }
+    }
+}
+
+impl<P> Drop for UserDataStream<P> {
+    fn drop(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
+}
+
+/// Why the relay of the stream of a listen key stopped.
+enum Relay {
+    Expired,
+    Ended,
+}
+
+/// Relay the payloads, and create a new listen key whenever the previous one
+/// expires, until the stream ends.
+async fn maintain<P>(
+    config: Config,
+    shared_listen_key: Arc<Mutex<Option<String>>>,
+    mut listen_key: String,
+    mut stream: PayloadStream<P>,
+    sender: mpsc::Sender<Result<P, StreamEvent>>,
+) where
+    P: UserDataPayload,
+{
+    loop {
+        if let Relay::Ended = relay(&config, &listen_key, &mut stream, &sender).await {
+            return;
+        }
+        warn!("listen key expired, creating a new one");
+        let renewed = renew(&config).await;
+        let Some((renewed_key, renewed_stream)) = renewed else {
+            let _ = sender
+                .send(Err(StreamEvent::Status(ConnectionStatus::GaveUp)))
+                .await;
+            return;
+        };
+        *shared_listen_key.lock().unwrap() = Some(renewed_key.clone());
+        listen_key = renewed_key;
+        stream = renewed_stream;
+    }
+}
+
+async fn relay<P>(
+    config: &Config,
+    listen_key: &str,
+    stream: &mut PayloadStream<P>,
+    sender: &mpsc::Sender<Result<P, StreamEvent>>,
+) -> Relay
+where
+    P: UserDataPayload,
+{
+    let mut keepalive = tokio::time::interval(config.keepalive_interval);
+    // The first tick completes immediately.
+    keepalive.tick().await;
+
+    loop {
+        tokio::select! {
+            _ = keepalive.tick() => {
+                match config.api.keepalive(&config.rest_client, listen_key).await {
+                    Ok(()) => debug!("listen key kept alive"),
+                    Err(err) if err.error_code() == Some(BinanceErrorCode::InvalidListenKey) => {
+                        return Relay::Expired;
+                    }
+                    Err(err) => error!("listen key keepalive error: {err}"),
+                }
+            }
+            item = stream.next() => {
+                let Some(item) = item else {
+                    return Relay::Ended;
+                };
+                let expired = matches!(&item, Ok(payload) if payload.is_listen_key_expired());
+                if sender.send(item).await.is_err() {
+                    return Relay::Ended;
+                }
+                if expired {
+                    return Relay::Expired;
+                }
+            }
+        }
+    }
+}
+
+/// Create a new listen key and connect to its stream, with the backoff of the
+/// reconnect policy.
+async fn renew<P>(config: &Config) -> Option<(String, PayloadStream<P>)>
+where
+    P: Payload,
+{
+    for backoff in iter::once(Duration::ZERO).chain(config.reconnect_policy.backoffs()) {
+        tokio::time::sleep(backoff).await;
+        match config.open().await {
+            Ok(opened) => return Some(opened),
+            Err(err) => error!("user data stream error: {err}"),
+        }
+    }
+    None
+}
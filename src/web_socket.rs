@@ -1,7 +1,10 @@
+use std::time::Duration;
+
 use futures_util::{SinkExt, StreamExt};
-use tokio::sync::mpsc;
-use tokio_tungstenite::{connect_async, tungstenite};
-use tracing::{debug, error, info};
+use rand::Rng;
+use tokio::{net::TcpStream, sync::mpsc};
+use tokio_tungstenite::{connect_async, tungstenite, MaybeTlsStream, WebSocketStream};
+use tracing::{debug, error, info, warn};
 
 #[derive(Clone, Copy, Debug)]
 pub enum ConnectionStatus {
@@ -9,13 +12,125 @@ pub enum ConnectionStatus {
     PingReceived,
     PoingSent,
     Disconnected,
+    /// An attempt to reconnect is about to be made, after the backoff of the
+    /// [`ReconnectPolicy`].
+    Reconnecting,
+    /// The connection was restored, and the messages of
+    /// [`WebSocketClient::on_reconnect`] were sent.
+    Reconnected,
+    /// The attempts allowed by the [`ReconnectPolicy`] were exhausted. The
+    /// client is stopped.
+    GaveUp,
+}
+
+/// A message that could not be parsed, or that was not expected, with the
+/// reason.
+#[derive(Clone, Debug)]
+pub struct UnparsedMessage {
+    pub message: String,
+    pub error: String,
+}
+
+impl UnparsedMessage {
+    pub(crate) fn new(message: String, error: impl ToString) -> Self {
+        Self {
+            message,
+            error: error.to_string(),
+        }
+    }
+}
+
+/// Reconnection policy of a [`WebSocketClient`], with exponential backoff and
+/// jitter.
+///
+/// Binance closes every connection after 24 hours, and the connection can
+/// drop at any time, so the client reconnects by default. The number of
+/// attempts is reset once a connection is restored.
+#[derive(Clone, Debug)]
+pub struct ReconnectPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
 }
 
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReconnectPolicy {
+    pub fn new() -> Self {
+        Self {
+            max_attempts: u32::MAX,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+        }
+    }
+
+    /// A policy that never reconnects.
+    pub fn disabled() -> Self {
+        Self::new().max_attempts(0)
+    }
+
+    /// Consecutive attempts before giving up. Default: unlimited.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Backoff before the first attempt, doubled on every attempt. Default:
+    /// 500ms.
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Default: 30s.
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Whether to randomize the backoff between half and all of its value.
+    /// Default: true.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// The backoffs before each of the attempts allowed.
+    pub(crate) fn backoffs(&self) -> impl Iterator<Item = Duration> + '_ {
+        (0..self.max_attempts).map(|attempt| self.backoff(attempt))
+    }
+
+    /// Backoff before the given attempt, starting at 0.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_backoff);
+        if self.jitter && !backoff.is_zero() {
+            rand::thread_rng().gen_range(backoff / 2..=backoff)
+        } else {
+            backoff
+        }
+    }
+}
+
+type Stream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+type ReconnectHook = Box<dyn Fn() -> Vec<String> + Send + Sync>;
+
 pub struct WebSocketClient {
     endpoint: String,
     read_channel: mpsc::Receiver<String>,
     write_channel: mpsc::Sender<String>,
     status_channel: mpsc::Sender<ConnectionStatus>,
+    reconnect_policy: ReconnectPolicy,
+    on_reconnect: Option<ReconnectHook>,
 }
 
 impl WebSocketClient {
@@ -30,70 +145,146 @@ impl WebSocketClient {
             read_channel,
             write_channel,
             status_channel,
+            reconnect_policy: ReconnectPolicy::default(),
+            on_reconnect: None,
         }
     }
 
+    pub fn reconnect_policy(mut self, reconnect_policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = reconnect_policy;
+        self
+    }
+
+    /// Messages to send once a connection is restored, before any message
+    /// queued in the meantime, e.g. to restore subscriptions or to log on
+    /// again. `f` is called on every reconnection.
+    pub fn on_reconnect<F>(mut self, f: F) -> Self
+    where
+        F: Fn() -> Vec<String> + Send + Sync + 'static,
+    {
+        self.on_reconnect = Some(Box::new(f));
+        self
+    }
+
+    /// Connect, and spawn a task that relays the messages until the
+    /// connection is lost and cannot be restored, or either channel is
+    /// closed.
     pub async fn connect(mut self) -> Result<(), tungstenite::Error> {
-        let (stream, _) = connect_async(&self.endpoint).await?;
-        let (mut write, mut read) = stream.split();
-        let _ = self.status_channel.send(ConnectionStatus::Connected).await;
+        let (mut stream, _) = connect_async(&self.endpoint).await?;
+        self.status(ConnectionStatus::Connected).await;
 
         tokio::spawn(async move {
             loop {
-                tokio::select! {
-                    Some(msg) = self.read_channel.recv() => {
-                        debug!("sending message to websocket: {msg:?}");
-                        let msg = tungstenite::Message::Text(msg);
-                        write.send(msg).await.unwrap_or_else(|err| {
-                            error!("websocket write error: {err}");
-                        })
+                self.run(stream).await;
+                // Nobody reads or sends messages anymore.
+                if self.write_channel.is_closed() || self.read_channel.is_closed() {
+                    break;
+                }
+                self.status(ConnectionStatus::Disconnected).await;
+                match self.reconnect().await {
+                    Some(reconnected) => stream = reconnected,
+                    None => {
+                        self.status(ConnectionStatus::GaveUp).await;
+                        break;
                     }
-                    Some(msg) = read.next() => {
-                        debug!("received message from websocket: {msg:?}");
-                        let msg = match msg {
-                            Ok(msg) => msg,
-                            Err(err) => {
-                                error!("websocket read error: {err}");
-                                break;
-                            }
-                        };
+                }
+            }
+        });
 
-                        match msg {
-                            tungstenite::Message::Text(msg) => {
-                                self.write_channel.send(msg).await.unwrap_or_else(|err| {
-                                    error!("write channel error: {err}");
-                                });
-                            }
-                            tungstenite::Message::Ping(payload) => {
-                                info!("ping received");
-                                self.status_channel.send(ConnectionStatus::PingReceived).await.unwrap_or_else(|err| {
-                                    error!("status channel error: {err}");
-                                });
-
-                                write.send(tungstenite::Message::Pong(payload)).await.unwrap_or_else(|err| {
-                                    error!("websocket write error: {err}");
-                                });
-                                info!("pong sent");
-
-                                self.status_channel.send(ConnectionStatus::PoingSent).await.unwrap_or_else(|err| {
-                                    error!("status channel error: {err}");
-                                });
-                            }
-                            tungstenite::Message::Close(_) => {
-                                self.status_channel.send(ConnectionStatus::Disconnected).await.unwrap_or_else(|err| {
-                                    error!("status channel error: {err}");
-                                });
-                                break;
-                            }
-                            _ => {
-                                error!("unexpected message: {msg}");
+        Ok(())
+    }
+
+    /// Relay the messages until the connection is lost, or either channel is
+    /// closed.
+    async fn run(&mut self, stream: Stream) {
+        let (mut write, mut read) = stream.split();
+        loop {
+            tokio::select! {
+                msg = self.read_channel.recv() => {
+                    let Some(msg) = msg else {
+                        debug!("read channel closed, closing websocket");
+                        let _ = write.close().await;
+                        return;
+                    };
+                    debug!("sending message to websocket: {msg:?}");
+                    let msg = tungstenite::Message::Text(msg);
+                    write.send(msg).await.unwrap_or_else(|err| {
+                        error!("websocket write error: {err}");
+                    })
+                }
+                msg = read.next() => {
+                    debug!("received message from websocket: {msg:?}");
+                    let msg = match msg {
+                        Some(Ok(msg)) => msg,
+                        Some(Err(err)) => {
+                            error!("websocket read error: {err}");
+                            return;
+                        }
+                        None => return,
+                    };
+
+                    match msg {
+                        tungstenite::Message::Text(msg) => {
+                            if self.write_channel.send(msg).await.is_err() {
+                                debug!("write channel closed, closing websocket");
+                                let _ = write.close().await;
+                                return;
                             }
                         }
+                        tungstenite::Message::Ping(payload) => {
+                            info!("ping received");
+                            self.status(ConnectionStatus::PingReceived).await;
+
+                            write.send(tungstenite::Message::Pong(payload)).await.unwrap_or_else(|err| {
+                                error!("websocket write error: {err}");
+                            });
+                            info!("pong sent");
+
+                            self.status(ConnectionStatus::PoingSent).await;
+                        }
+                        tungstenite::Message::Close(_) => return,
+                        _ => {
+                            error!("unexpected message: {msg}");
+                        }
                     }
                 }
             }
-        });
+        }
+    }
 
-        Ok(())
+    /// Reconnect with the backoff of the policy, and send the messages of the
+    /// reconnect hook. Returns `None` once the attempts are exhausted.
+    async fn reconnect(&self) -> Option<Stream> {
+        for backoff in self.reconnect_policy.backoffs() {
+            self.status(ConnectionStatus::Reconnecting).await;
+            tokio::time::sleep(backoff).await;
+
+            let mut stream = match connect_async(&self.endpoint).await {
+                Ok((stream, _)) => stream,
+                Err(err) => {
+                    warn!("websocket reconnect error: {err}");
+                    continue;
+                }
+            };
+            let msgs = self.on_reconnect.as_ref().map(|f| f()).unwrap_or_default();
+            for msg in msgs {
+                debug!("sending message to websocket: {msg:?}");
+                if let Err(err) = stream.send(tungstenite::Message::Text(msg)).await {
+                    error!("websocket write error: {err}");
+                }
+            }
+            self.status(ConnectionStatus::Reconnected).await;
+            return Some(stream);
+        }
+        None
+    }
+
+    async fn status(&self, status: ConnectionStatus) {
+        self.status_channel
+            .send(status)
+            .await
+            .unwrap_or_else(|err| {
+                error!("status channel error: {err}");
+            });
     }
 }
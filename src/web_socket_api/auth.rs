@@ -1,9 +1,8 @@
 #![allow(dead_code)]
 
-use jiff::Timestamp;
 use serde::{Deserialize, Serialize};
 
-use crate::{ed25519_signature, Params, Response};
+use crate::{signer::Signer, Params, Response};
 
 use super::WebSocketApiError;
 
@@ -26,10 +25,14 @@ impl LogonParams {
         }
     }
 
-    pub(super) fn sign(&mut self, key: &str) -> Result<(), WebSocketApiError> {
-        let timestamp = Timestamp::now().as_millisecond();
+    /// Sign the params with `timestamp`, the current server time.
+    pub(super) fn sign(
+        &mut self,
+        signer: &dyn Signer,
+        timestamp: i64,
+    ) -> Result<(), WebSocketApiError> {
         let data = format!("apiKey={}&timestamp={timestamp}", self.api_key);
-        let signature = ed25519_signature(key, &data)?;
+        let signature = signer.sign(&data)?;
 
         self.timestamp = timestamp;
         self.signature = signature;
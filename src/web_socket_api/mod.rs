@@ -1,57 +1,194 @@
 //! Binance's WebSocket API
 mod auth;
+mod user_data;
 
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
+use jiff::Timestamp;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
-use tokio::sync::{mpsc, oneshot};
-use tracing::error;
+use tokio::{
+    sync::{mpsc, oneshot},
+    task::JoinHandle,
+    time::{self, Instant},
+};
+use tracing::{debug, error, info};
 use uuid::Uuid;
 
 use crate::{
-    enums::SecurityType,
+    enums::{RateLimit, SecurityType},
     errors::BinanceError,
-    spot::{account, general, market, trade},
-    web_socket::{ConnectionStatus, WebSocketClient},
+    rest_api::{RateLimitMode, RateLimitUsage, RateLimiter, RequestCost, ServerClock},
+    signer::{Ed25519Signer, KeyType, Signer, SignerError},
+    spot::{
+        account,
+        general::{self, CheckServerTimeParams, CheckServerTimeResponse},
+        market, trade,
+        user_data_stream::UserDataStreamPayload,
+    },
+    usd_futures,
+    web_socket::{ConnectionStatus, ReconnectPolicy, UnparsedMessage, WebSocketClient},
     Params, Response,
 };
 use auth::*;
+use user_data::*;
 
 const CHANNEL_BUFFER: usize = 2048;
 
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Id of the `session.logon` requests sent on reconnection.
+const RELOGON_ID: &str = "birdie-relogon";
+
+/// Id of the `userDataStream.subscribe` requests sent on reconnection.
+const RESUBSCRIBE_ID: &str = "birdie-resubscribe";
+
+type UserDataChannel = Arc<Mutex<Option<mpsc::Sender<UserDataStreamPayload>>>>;
+
 #[derive(Debug, Error)]
 pub enum WebSocketApiError {
     #[error("websocket error: {0}")]
     WebSocket(#[from] tokio_tungstenite::tungstenite::Error),
     #[error("json parse error: {0}")]
     Json(#[from] serde_json::Error),
-    #[error("pkcs8 error: {0}")]
-    Pkcs8(#[from] ed25519_dalek::pkcs8::Error),
+    #[error("signer error: {0}")]
+    Signer(#[from] SignerError),
     #[error("binance error: {0}")]
     Binance(String, Option<BinanceError>),
     #[error("client error: {0}")]
     Client(String),
+    /// The connection was lost or closed before the response arrived.
+    #[error("disconnected")]
+    Disconnected,
+    #[error("request timed out")]
+    Timeout,
+    /// The request would go over a rate limit, in [`RateLimitMode::Reject`].
+    #[error("rate limit exceeded, retry after {0:?}")]
+    RateLimited(Duration),
 }
 
 pub struct WebSocketApiClient {
     request_sender: Option<mpsc::Sender<RequestEnvelope>>,
     endpoint: String,
     api_key: String,
-    /// Only ed25519 key is supported.
-    secret_key: String,
+    signer: Arc<dyn Signer>,
+    clock: Arc<ServerClock>,
+    time_sync: bool,
+    reconnect_policy: ReconnectPolicy,
+    request_timeout: Duration,
+    return_rate_limits: bool,
+    rate_limiter: Arc<RateLimiter>,
+    unparsed_channel: Option<mpsc::Sender<UnparsedMessage>>,
+    user_data_channel: UserDataChannel,
+    task: Option<JoinHandle<()>>,
 }
 
 impl WebSocketApiClient {
-    pub fn new(endpoint: &str, api_key: &str, secret_key: &str) -> Self {
+    /// Create a client that logs on with an Ed25519 private key (PKCS#8 PEM).
+    pub fn new(endpoint: &str, api_key: &str, secret_key: &str) -> Result<Self, SignerError> {
+        let signer = Ed25519Signer::new(secret_key)?;
+        Ok(Self::with_signer(endpoint, api_key, signer))
+    }
+
+    /// Create a client that signs with the given signer. See
+    /// [`crate::signer`] for the supported key types.
+    ///
+    /// Binance only accepts Ed25519 keys for `session.logon`, so the session
+    /// is logged on with Ed25519 keys only. With the other key types, each
+    /// signed request carries its own `apiKey` and `signature`.
+    pub fn with_signer<S>(endpoint: &str, api_key: &str, signer: S) -> Self
+    where
+        S: Signer + 'static,
+    {
         Self {
             request_sender: None,
             endpoint: endpoint.to_owned(),
             api_key: api_key.to_owned(),
-            secret_key: secret_key.to_owned(),
+            signer: Arc::new(signer),
+            clock: Arc::default(),
+            time_sync: true,
+            reconnect_policy: ReconnectPolicy::default(),
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            return_rate_limits: true,
+            rate_limiter: Arc::default(),
+            unparsed_channel: None,
+            user_data_channel: Arc::new(Mutex::new(None)),
+            task: None,
         }
     }
 
+    /// The session is logged on again once reconnected, and the requests
+    /// pending when the connection was lost fail with
+    /// [`WebSocketApiError::Disconnected`].
+    pub fn reconnect_policy(mut self, reconnect_policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = reconnect_policy;
+        self
+    }
+
+    /// Time to wait for the response to a request before failing it with
+    /// [`WebSocketApiError::Timeout`]. Default: 10s.
+    pub fn request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.request_timeout = request_timeout;
+        self
+    }
+
+    /// Whether to calibrate the clock against the server time when connecting,
+    /// unless it is calibrated already. Enabled by default. When disabled, the
+    /// local clock is used as is.
+    pub fn time_sync(mut self, time_sync: bool) -> Self {
+        self.time_sync = time_sync;
+        self
+    }
+
+    /// Share a clock with other clients of the same server, e.g. a
+    /// [`RestApiClient`](crate::rest_api::RestApiClient) calibrated on its
+    /// signed requests.
+    pub fn with_clock(mut self, clock: Arc<ServerClock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// The clock used to timestamp the logon and the signed requests.
+    pub fn clock(&self) -> &Arc<ServerClock> {
+        &self.clock
+    }
+
+    /// Whether the responses of the session report the `rateLimits` usage,
+    /// which updates the [`RateLimiter`]. Default: true.
+    pub fn return_rate_limits(mut self, return_rate_limits: bool) -> Self {
+        self.return_rate_limits = return_rate_limits;
+        self
+    }
+
+    /// Share a rate limiter with other clients that use the same IP or
+    /// account, e.g. a [`RestApiClient`](crate::rest_api::RestApiClient)
+    /// placing orders too. Its mode tells what to do with requests that would
+    /// go over a rate limit, they wait with the default limiter.
+    pub fn with_rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    pub fn rate_limiter(&self) -> &Arc<RateLimiter> {
+        &self.rate_limiter
+    }
+
+    /// Snapshot of the current rate limit usage.
+    pub fn rate_limit_usage(&self) -> Vec<RateLimitUsage> {
+        self.rate_limiter.usage(self.clock.now())
+    }
+
+    /// Send the messages that cannot be parsed, or that do not answer a
+    /// pending request, to `unparsed_channel`. They are only logged otherwise.
+    pub fn unparsed_channel(mut self, unparsed_channel: mpsc::Sender<UnparsedMessage>) -> Self {
+        self.unparsed_channel = Some(unparsed_channel);
+        self
+    }
+
     pub fn account(&self) -> account::WebSocketApiHandler {
         account::WebSocketApiHandler::new(self)
     }
@@ -68,6 +205,12 @@ impl WebSocketApiClient {
         trade::WebSocketApiHandler::new(self)
     }
 
+    /// The methods of the USD-M futures WebSocket API. The client must be
+    /// connected to its endpoint rather than the spot one.
+    pub fn usd_futures(&self) -> usd_futures::WebSocketApiCategory {
+        usd_futures::WebSocketApiCategory::new(self)
+    }
+
     pub async fn connect(
         &mut self,
         status_sender: mpsc::Sender<ConnectionStatus>,
@@ -79,23 +222,50 @@ impl WebSocketApiClient {
         let (peer_write_channel, mut read_channel) = mpsc::channel(CHANNEL_BUFFER);
         let (status_relay_tx, mut status_relay_rx) = mpsc::channel(CHANNEL_BUFFER);
 
+        let endpoint = if self.return_rate_limits {
+            self.endpoint.clone()
+        } else {
+            let separator = if self.endpoint.contains('?') {
+                '&'
+            } else {
+                '?'
+            };
+            format!("{}{separator}returnRateLimits=false", self.endpoint)
+        };
         let client = WebSocketClient::new(
-            &self.endpoint,
+            &endpoint,
             peer_read_channel,
             peer_write_channel,
             status_relay_tx,
-        );
+        )
+        .reconnect_policy(self.reconnect_policy.clone())
+        .on_reconnect(restore_session(
+            &self.api_key,
+            self.logs_on().then(|| self.signer.clone()),
+            self.clock.clone(),
+            self.user_data_channel.clone(),
+        ));
         client.connect().await?;
 
-        let mut pending_requests = HashMap::new();
+        let mut pending_requests: HashMap<String, (Instant, oneshot::Sender<String>)> =
+            HashMap::new();
+        let request_timeout = self.request_timeout;
+        let mut prune_interval = time::interval(request_timeout);
+        let unparsed_channel = self.unparsed_channel.clone();
+        let user_data_channel = self.user_data_channel.clone();
 
-        tokio::spawn(async move {
+        let task = tokio::spawn(async move {
             loop {
                 tokio::select! {
-                    Some((req, id, channel)) = request_receiver.recv() => {
+                    request = request_receiver.recv() => {
+                        // The client was closed or dropped.
+                        let Some((req, id, channel)) = request else {
+                            break;
+                        };
                         match write_channel.send(req).await {
                             Ok(_) => {
-                                pending_requests.insert(id, channel);
+                                let deadline = Instant::now() + request_timeout;
+                                pending_requests.insert(id, (deadline, channel));
                             }
                             Err(err) => {
                                 error!("write error: {err}");
@@ -104,39 +274,107 @@ impl WebSocketApiClient {
                         }
                     }
                     Some(msg) = read_channel.recv() => {
-                        let res: ResponseFrame<serde_json::Value> = match serde_json::from_str(&msg) {
-                            Ok(res) => res,
-                            Err(err) => {
-                                error!("json parse error: {err}");
-                                continue;
-                            }
+                        // The events of the user data stream have no id.
+                        let Ok(res) = serde_json::from_str::<ResponseFrame<serde_json::Value>>(&msg) else {
+                            relay_event(msg, &user_data_channel, unparsed_channel.as_ref()).await;
+                            continue;
                         };
 
+                        if res.id == RELOGON_ID {
+                            match res.result {
+                                Some(_) => info!("logged on after reconnection"),
+                                None => error!("logon after reconnection failed: {:?}", res.error),
+                            }
+                            continue;
+                        }
+                        if res.id == RESUBSCRIBE_ID {
+                            match res.result {
+                                Some(_) => info!("user data stream subscribed after reconnection"),
+                                None => error!("user data stream subscription after reconnection failed: {:?}", res.error),
+                            }
+                            continue;
+                        }
                         match pending_requests.remove(&res.id) {
-                            Some(channel) => {
+                            Some((_, channel)) => {
                                 let _ = channel.send(msg);
                             }
                             None => {
-                                error!("unexpected message: {msg}");
+                                let unparsed = UnparsedMessage::new(msg, "unexpected message");
+                                report_unparsed(unparsed_channel.as_ref(), unparsed).await;
                             }
                         }
                     }
-                    Some(status) = status_relay_rx.recv() => {
+                    _ = prune_interval.tick() => {
+                        // Forget the requests that timed out, even when no
+                        // other request is sent. The callers that have not
+                        // given up yet are about to.
+                        let now = Instant::now();
+                        pending_requests.retain(|_, (deadline, channel)| {
+                            *deadline > now || !channel.is_closed()
+                        });
+                    }
+                    status = status_relay_rx.recv() => {
+                        // The client is stopped once its status channel is
+                        // closed.
+                        let Some(status) = status else {
+                            break;
+                        };
+                        // The responses to pending requests are lost with the
+                        // connection, fail them.
+                        if matches!(status, ConnectionStatus::Disconnected) {
+                            pending_requests.clear();
+                        }
                         status_sender.send(status).await.unwrap_or_else(|err| {
                             error!("status relay error: {err}");
                         });
                     }
                 }
             }
+            // The pending requests fail, and the connection is closed once
+            // the channels are dropped.
         });
+        if let Some(previous) = self.task.replace(task) {
+            previous.abort();
+        }
 
-        self.logon().await?;
+        if self.time_sync && !self.clock.is_synced() {
+            self.sync_time().await?;
+        }
+        if self.logs_on() {
+            self.logon().await?;
+        }
         Ok(())
     }
 
+    /// Whether the session is logged on, which only Ed25519 keys allow.
+    fn logs_on(&self) -> bool {
+        self.signer.key_type() == KeyType::Ed25519
+    }
+
+    /// Calibrate the clock against the server time, with the `time` method.
+    pub async fn sync_time(&self) -> Result<(), WebSocketApiError> {
+        let sent_at = Timestamp::now().as_millisecond();
+        let res: CheckServerTimeResponse =
+            self.request("time", CheckServerTimeParams::new()).await?;
+        let received_at = Timestamp::now().as_millisecond();
+        self.clock.calibrate(res.server_time, sent_at, received_at);
+        debug!("clock offset: {}ms", self.clock.offset());
+        Ok(())
+    }
+
+    /// Close the connection. The pending requests fail with
+    /// [`WebSocketApiError::Disconnected`]. Dropping the client closes it
+    /// too.
+    pub async fn close(&mut self) {
+        self.request_sender = None;
+        if let Some(task) = self.task.take() {
+            let _ = task.await;
+        }
+    }
+
     pub async fn logon(&self) -> Result<(), WebSocketApiError> {
         let mut params = LogonParams::new(&self.api_key);
-        params.sign(&self.secret_key)?;
+        params.sign(self.signer.as_ref(), self.clock.now())?;
         let _: LogonResponse = self.request("session.logon", params).await?;
         Ok(())
     }
@@ -147,11 +385,93 @@ impl WebSocketApiClient {
         Ok(())
     }
 
+    /// Subscribe to the user data stream of the account, and send its events
+    /// to `channel`. The session must be logged on. The subscription is
+    /// restored on reconnection.
+    pub async fn subscribe_user_data(
+        &self,
+        channel: mpsc::Sender<UserDataStreamPayload>,
+    ) -> Result<(), WebSocketApiError> {
+        // The first events may arrive before the response.
+        *self.user_data_channel.lock().unwrap() = Some(channel);
+        let res: Result<SubscribeResponse, _> = self
+            .request("userDataStream.subscribe", SubscribeParams {})
+            .await;
+        if let Err(err) = res {
+            *self.user_data_channel.lock().unwrap() = None;
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    pub async fn unsubscribe_user_data(&self) -> Result<(), WebSocketApiError> {
+        let _: UnsubscribeResponse = self
+            .request("userDataStream.unsubscribe", UnsubscribeParams {})
+            .await?;
+        *self.user_data_channel.lock().unwrap() = None;
+        Ok(())
+    }
+
     pub async fn request<P, R>(&self, method: &str, params: P) -> Result<R, WebSocketApiError>
     where
         P: Params,
         R: Response,
     {
+        self.send(method, params, 1).await
+    }
+
+    /// Send a request that requires a `timestamp`. With an Ed25519 key, the
+    /// session must be authenticated with [`WebSocketApiClient::logon`]
+    /// beforehand, so the request itself does not need to be signed. With the
+    /// other key types, the request is signed.
+    pub async fn signed_request<P, R>(
+        &self,
+        method: &str,
+        params: P,
+    ) -> Result<R, WebSocketApiError>
+    where
+        P: Params,
+        R: Response,
+    {
+        self.send_signed(method, params, 1).await
+    }
+
+    async fn send_signed<P, R>(
+        &self,
+        method: &str,
+        params: P,
+        weight: u32,
+    ) -> Result<R, WebSocketApiError>
+    where
+        P: Params,
+        R: Response,
+    {
+        let mut params = serde_json::to_value(params)?;
+        if let Some(params) = params.as_object_mut() {
+            params.insert("timestamp".to_owned(), self.clock.now().into());
+            if !self.logs_on() {
+                params.insert("apiKey".to_owned(), self.api_key.clone().into());
+                let signature = self.signer.sign(&signature_payload(params))?;
+                params.insert("signature".to_owned(), signature.into());
+            }
+        }
+        self.send(method, params, weight).await
+    }
+
+    /// Send the request once it fits in the rate limits, and wait for its
+    /// response.
+    async fn send<P, R>(&self, method: &str, params: P, weight: u32) -> Result<R, WebSocketApiError>
+    where
+        P: Serialize,
+        R: Response,
+    {
+        let sender = self
+            .request_sender
+            .as_ref()
+            .ok_or_else(|| WebSocketApiError::Client("not connected".to_owned()))?;
+        self.acquire(RequestCost::web_socket(method, weight))
+            .await?;
+
         let id = Uuid::new_v4().to_string();
         let req = RequestFrame {
             id: &id,
@@ -161,17 +481,20 @@ impl WebSocketApiClient {
 
         let (tx, rx) = oneshot::channel();
         let envelope = (serde_json::to_string(&req)?, id, tx);
-        self.request_sender
-            .as_ref()
-            .ok_or_else(|| WebSocketApiError::Client("not connected".to_owned()))?
+        sender
             .send(envelope)
             .await
-            .map_err(|err| WebSocketApiError::Client(format!("send request error: {err}")))?;
+            .map_err(|_| WebSocketApiError::Disconnected)?;
 
-        let res = rx
+        let res = time::timeout(self.request_timeout, rx)
             .await
-            .map_err(|err| WebSocketApiError::Client(format!("receive response error: {err}")))?;
+            .map_err(|_| WebSocketApiError::Timeout)?
+            .map_err(|_| WebSocketApiError::Disconnected)?;
         let res: ResponseFrame<R> = serde_json::from_str(&res)?;
+        if let Some(rate_limits) = &res.rate_limits {
+            self.rate_limiter
+                .update_rate_limits(rate_limits, self.clock.now());
+        }
 
         if let Some(result) = res.result {
             Ok(result)
@@ -182,6 +505,112 @@ impl WebSocketApiClient {
             ))
         }
     }
+
+    /// Count the request against the rate limits, waiting for the window to
+    /// reset if it does not fit.
+    async fn acquire(&self, cost: RequestCost) -> Result<(), WebSocketApiError> {
+        loop {
+            let wait = match self.rate_limiter.try_acquire(cost, self.clock.now()) {
+                Ok(()) => return Ok(()),
+                Err(wait) => Duration::from_millis(wait as u64),
+            };
+            if self.rate_limiter.mode() == RateLimitMode::Reject {
+                return Err(WebSocketApiError::RateLimited(wait));
+            }
+            info!("rate limit reached, waiting {wait:?}");
+            time::sleep(wait).await;
+        }
+    }
+}
+
+async fn report_unparsed(
+    channel: Option<&mpsc::Sender<UnparsedMessage>>,
+    unparsed: UnparsedMessage,
+) {
+    match channel {
+        Some(channel) => channel.send(unparsed).await.unwrap_or_else(|err| {
+            error!("unparsed channel error: {err}");
+        }),
+        None => error!("{}: {}", unparsed.error, unparsed.message),
+    }
+}
+
+/// Send an event of the user data stream to the subscribed channel.
+async fn relay_event(
+    msg: String,
+    user_data_channel: &UserDataChannel,
+    unparsed_channel: Option<&mpsc::Sender<UnparsedMessage>>,
+) {
+    let event = match serde_json::from_str::<EventFrame>(&msg) {
+        Ok(frame) => frame.event,
+        Err(err) => {
+            report_unparsed(unparsed_channel, UnparsedMessage::new(msg, err)).await;
+            return;
+        }
+    };
+    let channel = user_data_channel.lock().unwrap().clone();
+    match channel {
+        Some(channel) => channel.send(event).await.unwrap_or_else(|err| {
+            error!("user data channel error: {err}");
+        }),
+        None => {
+            let unparsed = UnparsedMessage::new(msg, "unexpected event");
+            report_unparsed(unparsed_channel, unparsed).await;
+        }
+    }
+}
+
+/// The payload signed for a request: its params sorted by name, formatted
+/// like a query string.
+fn signature_payload(params: &serde_json::Map<String, serde_json::Value>) -> String {
+    let mut params: Vec<_> = params.iter().collect();
+    params.sort_by_key(|(k, _)| *k);
+    params
+        .iter()
+        .map(|(k, v)| match v {
+            serde_json::Value::String(v) => format!("{k}={v}"),
+            v => format!("{k}={v}"),
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// A reconnect hook that logs the session on again with `signer`, if the
+/// session is logged on, and subscribes to the user data stream again if it
+/// was.
+fn restore_session(
+    api_key: &str,
+    signer: Option<Arc<dyn Signer>>,
+    clock: Arc<ServerClock>,
+    user_data_channel: UserDataChannel,
+) -> impl Fn() -> Vec<String> + Send + Sync {
+    let api_key = api_key.to_owned();
+    move || {
+        let mut frames = Vec::new();
+        if let Some(signer) = &signer {
+            let mut params = LogonParams::new(&api_key);
+            if let Err(err) = params.sign(signer.as_ref(), clock.now()) {
+                error!("logon after reconnection failed: {err}");
+                return Vec::new();
+            }
+            let logon = RequestFrame {
+                id: RELOGON_ID,
+                method: "session.logon",
+                params,
+            };
+            frames.extend(serde_json::to_string(&logon));
+        }
+
+        if user_data_channel.lock().unwrap().is_some() {
+            let subscribe = RequestFrame {
+                id: RESUBSCRIBE_ID,
+                method: "userDataStream.subscribe",
+                params: SubscribeParams {},
+            };
+            frames.extend(serde_json::to_string(&subscribe));
+        }
+        frames
+    }
 }
 
 /// RequestEnvelope is a tuple of the request string, the request id, and a
@@ -190,7 +619,7 @@ type RequestEnvelope = (String, String, oneshot::Sender<String>);
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct RequestFrame<'a, P: Params> {
+struct RequestFrame<'a, P: Serialize> {
     id: &'a str,
     method: &'a str,
     params: P,
@@ -206,6 +635,8 @@ struct ResponseFrame<R> {
     error: Option<BinanceError>,
     #[serde(skip_serializing_if = "Option::is_none")]
     result: Option<R>,
+    #[serde(default)]
+    rate_limits: Option<Vec<RateLimit>>,
 }
 
 #[async_trait::async_trait]
@@ -217,32 +648,52 @@ pub trait WebSocket {
     fn method(&self) -> &str;
     fn security_type(&self) -> SecurityType;
 
-    async fn request(&self, params: Self::Params) -> Result<Self::Response, WebSocketApiError> {
-        self.client().request(self.method(), params).await
+    /// Request weight of the method for the given params.
+    fn weight(&self, _params: &Self::Params) -> u32 {
+        1
     }
-}
-
-macro_rules! web_socket {
-    ($method:literal, $name:ident, $params:ty, $response:ty) => {
-        #[async_trait::async_trait]
-        impl crate::web_socket_api::WebSocket for $name<'_> {
-            type Params = $params;
-            type Response = $response;
-
-            fn client(&self) -> &crate::web_socket_api::WebSocketApiClient {
-                self.client
-            }
 
-            fn method(&self) -> &str {
-                $method
+    async fn request(&self, params: Self::Params) -> Result<Self::Response, WebSocketApiError> {
+        let weight = self.weight(&params);
+        match self.security_type() {
+            SecurityType::None | SecurityType::UserStream => {
+                self.client().send(self.method(), params, weight).await
             }
-
-            fn security_type(&self) -> $crate::enums::SecurityType {
-                $crate::enums::SecurityType::None
+            _ => {
+                self.client()
+                    .send_signed(self.method(), params, weight)
+                    .await
             }
         }
+    }
+}
+
+/// Implement [`WebSocket`] for a method type. The security type defaults to
+/// [`SecurityType::None`]. The optional `weight` argument overrides
+/// [`WebSocket::weight`], it is either a constant or a closure over the
+/// params, like the one of [`endpoint!`](crate::rest_api::endpoint).
+macro_rules! web_socket {
+    ($method:literal, $name:ident, $params:ty, $response:ty $(, $($opts:tt)+)?) => {
+        $crate::web_socket_api::web_socket!(
+            @impl $method,
+            $crate::enums::SecurityType::None,
+            $name,
+            $params,
+            $response,
+            [$($($opts)+)?]
+        );
+    };
+    ($method:literal, $security:expr, $name:ident, $params:ty, $response:ty $(, $($opts:tt)+)?) => {
+        $crate::web_socket_api::web_socket!(
+            @impl $method,
+            $security,
+            $name,
+            $params,
+            $response,
+            [$($($opts)+)?]
+        );
     };
-    ($method:literal, $security:expr, $name:ident, $params:ty, $response:ty) => {
+    (@impl $method:literal, $security:expr, $name:ident, $params:ty, $response:ty, [$($opts:tt)*]) => {
         #[async_trait::async_trait]
         impl crate::web_socket_api::WebSocket for $name<'_> {
             type Params = $params;
@@ -259,6 +710,19 @@ macro_rules! web_socket {
             fn security_type(&self) -> $crate::enums::SecurityType {
                 $security
             }
+
+            $crate::web_socket_api::web_socket!(@opts $($opts)*);
+        }
+    };
+    (@opts) => {};
+    (@opts weight = |$params:ident| $weight:expr) => {
+        fn weight(&self, $params: &Self::Params) -> u32 {
+            $weight
+        }
+    };
+    (@opts weight = $weight:expr) => {
+        fn weight(&self, _params: &Self::Params) -> u32 {
+            $weight
         }
     };
 }
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{spot::user_data_stream::UserDataStreamPayload, Params, Response};
+
+#[derive(Debug, Serialize)]
+pub(super) struct SubscribeParams {}
+
+impl Params for SubscribeParams {}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct SubscribeResponse {}
+
+impl Response for SubscribeResponse {}
+
+#[derive(Debug, Serialize)]
+pub(super) struct UnsubscribeParams {}
+
+impl Params for UnsubscribeParams {}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct UnsubscribeResponse {}
+
+impl Response for UnsubscribeResponse {}
+
+/// An event of the user data stream, sent without a request id.
+#[derive(Debug, Deserialize)]
+pub(super) struct EventFrame {
+    pub(super) event: UserDataStreamPayload,
+}
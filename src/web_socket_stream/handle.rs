@@ -0,0 +1,228 @@
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::{
+    sync::{mpsc, oneshot},
+    time,
+};
+
+use super::{endpoint_streams, stream_name::check_stream_count, StreamName, WebSocketStreamError};
+use crate::errors::BinanceError;
+
+/// Id of the requests restoring the subscriptions on reconnection, the ids of
+/// the requests of a [`StreamHandle`] start at 1.
+pub(super) const RESTORE_ID: u64 = 0;
+
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// RequestEnvelope is a tuple of the request string, the request id, and a
+/// oneshot sender to send the response back to the caller.
+pub(super) type RequestEnvelope = (String, u64, oneshot::Sender<ResponseFrame>);
+
+#[derive(Debug, Serialize)]
+struct RequestFrame<'a> {
+    method: &'a str,
+    params: Vec<Value>,
+    id: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct ResponseFrame {
+    pub(super) id: u64,
+    #[serde(default)]
+    pub(super) result: Value,
+    pub(super) error: Option<BinanceError>,
+}
+
+/// The subscriptions of a connection, as changed by the requests of a
+/// [`StreamHandle`].
+#[derive(Debug, Default)]
+pub(super) struct Subscriptions {
+    streams: Vec<String>,
+    properties: Vec<(String, bool)>,
+}
+
+impl Subscriptions {
+    pub(super) fn new(streams: Vec<String>) -> Self {
+        Self {
+            streams,
+            properties: Vec::new(),
+        }
+    }
+}
+
+/// Handle to the connection of a stream, to subscribe to or unsubscribe from
+/// streams without reconnecting.
+///
+/// The changes are restored when the connection is restored. Requests pending
+/// when the connection is lost fail.
+#[derive(Clone, Debug)]
+pub struct StreamHandle {
+    request_sender: mpsc::Sender<RequestEnvelope>,
+    next_id: Arc<AtomicU64>,
+    subscriptions: Arc<Mutex<Subscriptions>>,
+    request_timeout: Duration,
+}
+
+impl StreamHandle {
+    pub(super) fn new(
+        request_sender: mpsc::Sender<RequestEnvelope>,
+        subscriptions: Arc<Mutex<Subscriptions>>,
+    ) -> Self {
+        Self {
+            request_sender,
+            next_id: Arc::new(AtomicU64::new(RESTORE_ID + 1)),
+            subscriptions,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+        }
+    }
+
+    /// Time to wait for the response to a request before failing it with
+    /// [`WebSocketStreamError::Timeout`]. Default: 10s.
+    pub fn request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.request_timeout = request_timeout;
+        self
+    }
+
+    /// Subscribe to more streams, within the limit of
+    /// [`MAX_STREAMS`](super::MAX_STREAMS) per connection.
+    pub async fn subscribe(&self, streams: &[StreamName]) -> Result<(), WebSocketStreamError> {
+        {
+            let subscriptions = self.subscriptions.lock().unwrap();
+            let added = streams
+                .iter()
+                .filter(|s| !subscriptions.streams.iter().any(|sub| sub == s.as_str()))
+                .count();
+            check_stream_count(subscriptions.streams.len() + added)?;
+        }
+        self.request("SUBSCRIBE", params(streams)).await?;
+        let mut subscriptions = self.subscriptions.lock().unwrap();
+        for stream in streams {
+            if !subscriptions.streams.iter().any(|s| s == stream.as_str()) {
+                subscriptions.streams.push(stream.to_string());
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn unsubscribe(&self, streams: &[StreamName]) -> Result<(), WebSocketStreamError> {
+        self.request("UNSUBSCRIBE", params(streams)).await?;
+        let mut subscriptions = self.subscriptions.lock().unwrap();
+        subscriptions
+            .streams
+            .retain(|s| !streams.iter().any(|stream| stream.as_str() == s));
+        Ok(())
+    }
+
+    /// The streams the connection is subscribed to, as reported by Binance.
+    pub async fn list_subscriptions(&self) -> Result<Vec<String>, WebSocketStreamError> {
+        let result = self.request("LIST_SUBSCRIPTIONS", Vec::new()).await?;
+        Ok(serde_json::from_value(result)?)
+    }
+
+    /// Set a property of the connection. The only property at the time of
+    /// writing is `combined`: whether payloads are wrapped with the name of
+    /// their stream, as they are on `/stream` connections.
+    pub async fn set_property(
+        &self,
+        property: &str,
+        value: bool,
+    ) -> Result<(), WebSocketStreamError> {
+        self.request("SET_PROPERTY", vec![property.into(), value.into()])
+            .await?;
+        let mut subscriptions = self.subscriptions.lock().unwrap();
+        subscriptions.properties.retain(|(p, _)| p != property);
+        subscriptions.properties.push((property.to_owned(), value));
+        Ok(())
+    }
+
+    pub async fn get_property(&self, property: &str) -> Result<bool, WebSocketStreamError> {
+        let result = self.request("GET_PROPERTY", vec![property.into()]).await?;
+        Ok(serde_json::from_value(result)?)
+    }
+
+    async fn request(
+        &self,
+        method: &str,
+        params: Vec<Value>,
+    ) -> Result<Value, WebSocketStreamError> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let req = serde_json::to_string(&RequestFrame { method, params, id })?;
+
+        let (tx, rx) = oneshot::channel();
+        self.request_sender
+            .send((req, id, tx))
+            .await
+            .map_err(|err| WebSocketStreamError::Client(format!("send request error: {err}")))?;
+        let res = time::timeout(self.request_timeout, rx)
+            .await
+            .map_err(|_| WebSocketStreamError::Timeout)?
+            .map_err(|err| {
+                WebSocketStreamError::Client(format!("receive response error: {err}"))
+            })?;
+
+        match res.error {
+            Some(err) => Err(WebSocketStreamError::Binance(err)),
+            None => Ok(res.result),
+        }
+    }
+}
+
+fn params(streams: &[StreamName]) -> Vec<Value> {
+    streams.iter().map(|s| s.as_str().into()).collect()
+}
+
+/// A reconnect hook that applies the changes made to the subscriptions since
+/// connecting to the endpoint.
+pub(super) fn restore_subscriptions(
+    endpoint: &str,
+    subscriptions: Arc<Mutex<Subscriptions>>,
+) -> impl Fn() -> Vec<String> + Send + Sync {
+    let initial = endpoint_streams(endpoint);
+    move || {
+        let subscriptions = subscriptions.lock().unwrap();
+        let added: Vec<Value> = subscriptions
+            .streams
+            .iter()
+            .filter(|s| !initial.contains(s))
+            .map(|s| s.as_str().into())
+            .collect();
+        let removed: Vec<Value> = initial
+            .iter()
+            .filter(|s| !subscriptions.streams.contains(s))
+            .map(|s| s.as_str().into())
+            .collect();
+
+        let mut frames = Vec::new();
+        if !added.is_empty() {
+            frames.push(("SUBSCRIBE", added));
+        }
+        if !removed.is_empty() {
+            frames.push(("UNSUBSCRIBE", removed));
+        }
+        for (property, value) in &subscriptions.properties {
+            frames.push((
+                "SET_PROPERTY",
+                vec![property.as_str().into(), (*value).into()],
+            ));
+        }
+        frames
+            .into_iter()
+            .filter_map(|(method, params)| {
+                let req = RequestFrame {
+                    method,
+                    params,
+                    id: RESTORE_ID,
+                };
+                serde_json::to_string(&req).ok()
+            })
+            .collect()
+    }
+}
@@ -1,10 +1,59 @@
-use serde::Deserialize;
+//! Binance's Web Socket streams.
+//!
+//! The `connect_*` functions spawn a task that sends the payloads of the
+//! streams to a channel, and the [`StreamEvent`]s of the connection to
+//! another, and return a [`StreamHandle`] to change the
+//! subscriptions of the connection while it is open.
+//!
+//! The `open_*` functions return a [`PayloadStream`] instead, a
+//! [`futures_util::Stream`] of the payloads with the events of the connection
+//! in-band, so that stream combinators apply directly:
+//!
+//! ```no_run
+//! use birdie::{
+//!     spot::web_socket_streams::SpotStreamPayloadload,
+//!     web_socket_stream::{open_combined_streams, StreamName},
+//! };
+//! use futures_util::{future::ready, StreamExt};
+//!
+//! # async fn example() {
+//! let streams = [StreamName::trade("BTCUSDT"), StreamName::trade("ETHUSDT")];
+//! let stream = open_combined_streams::<SpotStreamPayloadload>(
+//!     "wss://stream.binance.com:9443",
+//!     &streams,
+//! )
+//! .await
+//! .unwrap();
+//! let mut trades = stream.filter_map(|item| ready(item.ok())).take(10);
+//! while let Some(trade) = trades.next().await {
+//!     println!("{trade:?}");
+//! }
+//! # }
+//! ```
+mod handle;
+mod payload_stream;
+mod stream_name;
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use serde::{de::DeserializeOwned, Deserialize};
 use thiserror::Error;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, oneshot};
 use tokio_tungstenite::tungstenite;
-use tracing::error;
+use tracing::{error, info};
 
-use crate::web_socket::{ConnectionStatus, WebSocketClient};
+use crate::{
+    errors::BinanceError,
+    web_socket::{ConnectionStatus, ReconnectPolicy, UnparsedMessage, WebSocketClient},
+};
+pub use handle::StreamHandle;
+use handle::{restore_subscriptions, ResponseFrame, Subscriptions, RESTORE_ID};
+pub use payload_stream::{PayloadStream, StreamEvent};
+use stream_name::check_stream_count;
+pub use stream_name::{StreamName, MAX_STREAMS};
 
 const CHANNEL_BUFFER: usize = 2048;
 
@@ -12,43 +61,171 @@ const CHANNEL_BUFFER: usize = 2048;
 pub enum WebSocketStreamError {
     #[error("websocket error: {0}")]
     WebSocket(#[from] tungstenite::Error),
+    #[error("json parse error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("binance error: {}", .0.msg)]
+    Binance(BinanceError),
+    #[error("client error: {0}")]
+    Client(String),
+    #[error("too many streams: {0}, at most {MAX_STREAMS} per connection")]
+    TooManyStreams(usize),
+    #[error("request timed out")]
+    Timeout,
 }
 
 pub async fn connect_combined_streams<P>(
     endpoint: &str,
-    streams: &[&str],
+    streams: &[StreamName],
     data_channel: mpsc::Sender<P>,
-    status_channel: mpsc::Sender<ConnectionStatus>,
-) -> Result<(), WebSocketStreamError>
+    event_channel: mpsc::Sender<StreamEvent>,
+) -> Result<StreamHandle, WebSocketStreamError>
 where
     P: Payload,
 {
-    let endpoint = format!("{endpoint}/stream?streams={}", streams.join("/"));
-    connect_stream(&endpoint, data_channel, status_channel).await
+    let endpoint = combined_endpoint(endpoint, streams)?;
+    connect_stream(&endpoint, data_channel, event_channel).await
 }
 
 pub async fn connect_raw_stream<P>(
     endpoint: &str,
-    stream: &str,
+    stream: &StreamName,
     data_channel: mpsc::Sender<P>,
-    status_channel: mpsc::Sender<ConnectionStatus>,
-) -> Result<(), WebSocketStreamError>
+    event_channel: mpsc::Sender<StreamEvent>,
+) -> Result<StreamHandle, WebSocketStreamError>
 where
     P: Payload,
 {
-    let endpoint = format!("{endpoint}/ws/{}", stream);
-    connect_stream(&endpoint, data_channel, status_channel).await
+    let endpoint = format!("{endpoint}/ws/{stream}");
+    connect_stream(&endpoint, data_channel, event_channel).await
 }
 
+/// Connect to a stream, reconnecting with the default [`ReconnectPolicy`].
+/// The subscriptions are restored on reconnection, including the changes made
+/// with the [`StreamHandle`].
 pub async fn connect_stream<P>(
     endpoint: &str,
     data_channel: mpsc::Sender<P>,
-    status_channel: mpsc::Sender<ConnectionStatus>,
-) -> Result<(), WebSocketStreamError>
+    event_channel: mpsc::Sender<StreamEvent>,
+) -> Result<StreamHandle, WebSocketStreamError>
+where
+    P: Payload,
+{
+    connect_stream_with_policy(
+        endpoint,
+        ReconnectPolicy::default(),
+        data_channel,
+        event_channel,
+    )
+    .await
+}
+
+pub async fn connect_stream_with_policy<P>(
+    endpoint: &str,
+    reconnect_policy: ReconnectPolicy,
+    data_channel: mpsc::Sender<P>,
+    event_channel: mpsc::Sender<StreamEvent>,
+) -> Result<StreamHandle, WebSocketStreamError>
+where
+    P: Payload,
+{
+    let sink = Sink::Channels {
+        data: data_channel,
+        events: event_channel,
+    };
+    spawn_stream(endpoint, reconnect_policy, sink).await
+}
+
+pub async fn open_combined_streams<P>(
+    endpoint: &str,
+    streams: &[StreamName],
+) -> Result<PayloadStream<P>, WebSocketStreamError>
+where
+    P: Payload,
+{
+    let endpoint = combined_endpoint(endpoint, streams)?;
+    open_stream(&endpoint).await
+}
+
+pub async fn open_raw_stream<P>(
+    endpoint: &str,
+    stream: &StreamName,
+) -> Result<PayloadStream<P>, WebSocketStreamError>
+where
+    P: Payload,
+{
+    open_stream(&format!("{endpoint}/ws/{stream}")).await
+}
+
+/// Open a stream, reconnecting with the default [`ReconnectPolicy`]. The
+/// [`PayloadStream`] ends once the connection is lost for good.
+pub async fn open_stream<P>(endpoint: &str) -> Result<PayloadStream<P>, WebSocketStreamError>
+where
+    P: Payload,
+{
+    open_stream_with_policy(endpoint, ReconnectPolicy::default()).await
+}
+
+pub async fn open_stream_with_policy<P>(
+    endpoint: &str,
+    reconnect_policy: ReconnectPolicy,
+) -> Result<PayloadStream<P>, WebSocketStreamError>
 where
     P: Payload,
 {
-    let (_, peer_read_channel) = mpsc::channel(CHANNEL_BUFFER);
+    let (tx, rx) = mpsc::channel(CHANNEL_BUFFER);
+    let handle = spawn_stream(endpoint, reconnect_policy, Sink::Stream(tx)).await?;
+    Ok(PayloadStream::new(rx, handle))
+}
+
+/// Where the relay task sends the payloads and the events.
+enum Sink<P> {
+    Channels {
+        data: mpsc::Sender<P>,
+        events: mpsc::Sender<StreamEvent>,
+    },
+    Stream(mpsc::Sender<Result<P, StreamEvent>>),
+}
+
+impl<P> Sink<P> {
+    /// Send a payload, returns false once the receiver of a stream is gone.
+    async fn payload(&self, payload: P) -> bool {
+        match self {
+            Sink::Channels { data, .. } => {
+                data.send(payload).await.unwrap_or_else(|err| {
+                    error!("data channel error: {err}");
+                });
+                true
+            }
+            Sink::Stream(tx) => tx.send(Ok(payload)).await.is_ok(),
+        }
+    }
+
+    /// Send an event, returns false once the receiver of a stream is gone.
+    async fn event(&self, event: StreamEvent) -> bool {
+        match self {
+            Sink::Channels { events, .. } => {
+                events.send(event).await.unwrap_or_else(|err| {
+                    error!("event channel error: {err}");
+                });
+                true
+            }
+            Sink::Stream(tx) => tx.send(Err(event)).await.is_ok(),
+        }
+    }
+}
+
+/// Connect, and spawn the task relaying the payloads and the events to the
+/// sink.
+async fn spawn_stream<P>(
+    endpoint: &str,
+    reconnect_policy: ReconnectPolicy,
+    sink: Sink<P>,
+) -> Result<StreamHandle, WebSocketStreamError>
+where
+    P: Payload,
+{
+    let subscriptions = Arc::new(Mutex::new(Subscriptions::new(endpoint_streams(endpoint))));
+    let (write_channel, peer_read_channel) = mpsc::channel(CHANNEL_BUFFER);
     let (peer_write_channel, mut read_channel) = mpsc::channel(CHANNEL_BUFFER);
     let (status_relay_tx, mut status_relay_rx) = mpsc::channel(CHANNEL_BUFFER);
 
@@ -57,34 +234,133 @@ where
         peer_read_channel,
         peer_write_channel,
         status_relay_tx,
-    );
+    )
+    .reconnect_policy(reconnect_policy)
+    .on_reconnect(restore_subscriptions(endpoint, subscriptions.clone()));
     client.connect().await?;
 
+    let (request_sender, mut request_receiver) = mpsc::channel(CHANNEL_BUFFER);
+    let mut pending_requests: HashMap<u64, oneshot::Sender<ResponseFrame>> = HashMap::new();
+
     tokio::spawn(async move {
         loop {
             tokio::select! {
+                Some((req, id, channel)) = request_receiver.recv() => {
+                    // Forget the requests that timed out.
+                    pending_requests.retain(|_, channel| !channel.is_closed());
+                    match write_channel.send(req).await {
+                        Ok(_) => {
+                            pending_requests.insert(id, channel);
+                        }
+                        Err(err) => {
+                            error!("write error: {err}");
+                            drop(channel);
+                        }
+                    }
+                }
                 Some(msg) = read_channel.recv() => {
-                    let payload = match serde_json::from_str::<P>(&msg) {
-                        Ok(payload) => payload,
+                    // Responses to requests are the only messages with an id.
+                    if let Ok(res) = serde_json::from_str::<ResponseFrame>(&msg) {
+                        if res.id == RESTORE_ID {
+                            match res.error {
+                                Some(err) => error!("restore subscriptions error: {}", err.msg),
+                                None => info!("subscriptions restored"),
+                            }
+                        } else if let Some(channel) = pending_requests.remove(&res.id) {
+                            let _ = channel.send(res);
+                        } else {
+                            let unparsed = UnparsedMessage::new(msg, "unexpected response");
+                            if !sink.event(StreamEvent::Unparsed(unparsed)).await {
+                                break;
+                            }
+                        }
+                        continue;
+                    }
+
+                    let delivered = match serde_json::from_str::<P>(&msg) {
+                        Ok(payload) => sink.payload(payload).await,
                         Err(err) => {
-                            error!("json parse error: {err}");
-                            continue;
+                            let unparsed = UnparsedMessage::new(msg, err);
+                            sink.event(StreamEvent::Unparsed(unparsed)).await
                         }
                     };
-                    data_channel.send(payload).await.unwrap_or_else(|err| {
-                        error!("data channel error: {err}");
-                    });
+                    if !delivered {
+                        break;
+                    }
                 }
-                Some(status) = status_relay_rx.recv() => {
-                    status_channel.send(status).await.unwrap_or_else(|err| {
-                        error!("status relay error: {err}");
-                    });
+                status = status_relay_rx.recv() => {
+                    // The client is stopped once its status channel is closed.
+                    let Some(status) = status else {
+                        break;
+                    };
+                    // The responses to pending requests are lost with the
+                    // connection, fail them.
+                    if matches!(status, ConnectionStatus::Disconnected) {
+                        pending_requests.clear();
+                    }
+                    if !sink.event(StreamEvent::Status(status)).await {
+                        break;
+                    }
                 }
             }
         }
     });
 
-    Ok(())
+    Ok(StreamHandle::new(request_sender, subscriptions))
+}
+
+// The error is large because of the tungstenite errors.
+#[allow(clippy::result_large_err)]
+fn combined_endpoint(
+    endpoint: &str,
+    streams: &[StreamName],
+) -> Result<String, WebSocketStreamError> {
+    check_stream_count(streams.len())?;
+    let streams: Vec<_> = streams.iter().map(StreamName::as_str).collect();
+    Ok(format!("{endpoint}/stream?streams={}", streams.join("/")))
+}
+
+/// The streams subscribed to by connecting to the endpoint, either
+/// `/ws/<stream>/<stream>` or `/stream?streams=<stream>/<stream>`.
+fn endpoint_streams(endpoint: &str) -> Vec<String> {
+    let streams = match endpoint.split_once("/stream?streams=") {
+        Some((_, streams)) => streams,
+        None => endpoint
+            .split_once("/ws/")
+            .map_or("", |(_, streams)| streams),
+    };
+    streams
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .map(str::to_owned)
+        .collect()
 }
 
 pub trait Payload: for<'de> Deserialize<'de> + Clone + Send + 'static {}
+
+/// A message of combined streams, `{"stream":"<name>","data":<payload>}`,
+/// whose payload type depends on the name of its stream.
+#[derive(Deserialize)]
+pub(crate) struct CombinedMessage {
+    pub(crate) stream: String,
+    data: serde_json::Value,
+}
+
+impl CombinedMessage {
+    /// Deserialize the payload, as the type routed from the stream name.
+    pub(crate) fn data<T, E>(&self) -> Result<T, E>
+    where
+        T: DeserializeOwned,
+        E: serde::de::Error,
+    {
+        T::deserialize(&self.data).map_err(E::custom)
+    }
+
+    /// The error of a stream name that cannot be routed.
+    pub(crate) fn unknown_stream<E>(&self) -> E
+    where
+        E: serde::de::Error,
+    {
+        E::custom(format!("Unknown stream type: {}", self.stream))
+    }
+}
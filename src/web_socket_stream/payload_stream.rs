@@ -0,0 +1,54 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_util::Stream;
+use tokio::sync::mpsc;
+
+use super::StreamHandle;
+use crate::web_socket::{ConnectionStatus, UnparsedMessage};
+
+/// Events of the connection: delivered in-band with the payloads by a
+/// [`PayloadStream`], or to the event channel of the `connect_*` functions.
+#[derive(Clone, Debug)]
+pub enum StreamEvent {
+    Status(ConnectionStatus),
+    /// A message that is not a payload of the stream, e.g. the payload of a
+    /// stream type that is not known to the payload enum.
+    Unparsed(UnparsedMessage),
+}
+
+/// A [`Stream`] of the payloads of a connection, and of its events as errors.
+///
+/// The payloads are buffered up to a bound: once it is reached, the
+/// connection is not read until the stream is polled again. The stream ends
+/// when the connection is lost and cannot be restored. Dropping it closes the
+/// connection.
+#[derive(Debug)]
+pub struct PayloadStream<P> {
+    receiver: mpsc::Receiver<Result<P, StreamEvent>>,
+    handle: StreamHandle,
+}
+
+impl<P> PayloadStream<P> {
+    pub(super) fn new(
+        receiver: mpsc::Receiver<Result<P, StreamEvent>>,
+        handle: StreamHandle,
+    ) -> Self {
+        Self { receiver, handle }
+    }
+
+    /// Handle to change the subscriptions of the connection.
+    pub fn handle(&self) -> &StreamHandle {
+        &self.handle
+    }
+}
+
+impl<P> Stream for PayloadStream<P> {
+    type Item = Result<P, StreamEvent>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().receiver.poll_recv(cx)
+    }
+}
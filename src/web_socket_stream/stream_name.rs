@@ -0,0 +1,307 @@
+use std::fmt;
+
+use super::WebSocketStreamError;
+use crate::enums::{
+    futures::{ContractType, MarkPriceSpeed},
+    DepthSpeed, KlineInterval, Levels, Window,
+};
+
+/// Streams allowed on a single connection.
+pub const MAX_STREAMS: usize = 1024;
+
+/// The name of a stream, e.g. `btcusdt@aggTrade`.
+///
+/// ```
+/// use birdie::{enums::KlineInterval, web_socket_stream::StreamName};
+///
+/// let stream = StreamName::kline("BTCUSDT", KlineInterval::OneMinute);
+/// assert_eq!(stream.as_str(), "btcusdt@kline_1m");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StreamName(String);
+
+impl StreamName {
+    /// A stream that has no constructor, or a listen key. The name is used as
+    /// is.
+    pub fn new(name: &str) -> Self {
+        Self(name.to_owned())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// A stream of a symbol. Symbols are lowercase in stream names.
+    pub(crate) fn of_symbol(symbol: &str, stream: &str) -> Self {
+        Self(format!("{}@{stream}", symbol.to_lowercase()))
+    }
+
+    pub fn agg_trade(symbol: &str) -> Self {
+        Self::of_symbol(symbol, "aggTrade")
+    }
+
+    pub fn trade(symbol: &str) -> Self {
+        Self::of_symbol(symbol, "trade")
+    }
+
+    pub fn kline(symbol: &str, interval: KlineInterval) -> Self {
+        Self::of_symbol(symbol, &format!("kline_{}", interval.as_str()))
+    }
+
+    pub fn mini_ticker(symbol: &str) -> Self {
+        Self::of_symbol(symbol, "miniTicker")
+    }
+
+    /// Mini tickers of the symbols that changed, as an array.
+    pub fn all_market_mini_tickers() -> Self {
+        Self::new("!miniTicker@arr")
+    }
+
+    pub fn ticker(symbol: &str) -> Self {
+        Self::of_symbol(symbol, "ticker")
+    }
+
+    pub fn rolling_ticker(symbol: &str, window: Window) -> Self {
+        Self::of_symbol(symbol, &format!("ticker_{}", window_str(window)))
+    }
+
+    /// Rolling window tickers of the symbols that changed, as an array.
+    pub fn all_market_rolling_tickers(window: Window) -> Self {
+        Self(format!("!ticker_{}@arr", window_str(window)))
+    }
+
+    pub fn book_ticker(symbol: &str) -> Self {
+        Self::of_symbol(symbol, "bookTicker")
+    }
+
+    pub fn avg_price(symbol: &str) -> Self {
+        Self::of_symbol(symbol, "avgPrice")
+    }
+
+    /// Top levels of the order book, every second.
+    pub fn partial_depth(symbol: &str, levels: Levels) -> Self {
+        Self::partial_depth_with_speed(symbol, levels, DepthSpeed::Ms1000)
+    }
+
+    pub fn partial_depth_with_speed(symbol: &str, levels: Levels, speed: DepthSpeed) -> Self {
+        let levels = match levels {
+            Levels::L5 => 5,
+            Levels::L10 => 10,
+            Levels::L20 => 20,
+        };
+        Self::of_symbol(symbol, &format!("depth{levels}{}", speed_suffix(speed)))
+    }
+
+    /// Updates of the order book. The USD-M futures streams without a speed
+    /// update every 250ms.
+    pub fn depth(symbol: &str, speed: DepthSpeed) -> Self {
+        Self::of_symbol(symbol, &format!("depth{}", speed_suffix(speed)))
+    }
+
+    /// Futures mark price and funding rate. On COIN-M futures, the stream of a
+    /// pair sends the mark prices of its symbols as an array.
+    pub fn mark_price(symbol: &str, speed: MarkPriceSpeed) -> Self {
+        Self::of_symbol(symbol, &format!("markPrice{}", mark_price_suffix(speed)))
+    }
+
+    /// USD-M futures mark prices of all the symbols, as an array.
+    pub fn all_market_mark_prices(speed: MarkPriceSpeed) -> Self {
+        Self(format!("!markPrice@arr{}", mark_price_suffix(speed)))
+    }
+
+    /// Futures klines of a contract type of a pair.
+    pub fn continuous_kline(
+        pair: &str,
+        contract_type: ContractType,
+        interval: KlineInterval,
+    ) -> Self {
+        let contract_type = match contract_type {
+            ContractType::Perpetual => "perpetual",
+            ContractType::CurrentMonth => "current_month",
+            ContractType::NextMonth => "next_month",
+            ContractType::CurrentQuarter => "current_quarter",
+            ContractType::NextQuarter => "next_quarter",
+            ContractType::PerpetualDelivering => "perpetual_delivering",
+        };
+        Self::of_symbol(
+            &format!("{pair}_{contract_type}"),
+            &format!("continuousKline_{}", interval.as_str()),
+        )
+    }
+
+    /// Futures liquidation orders.
+    pub fn liquidation_order(symbol: &str) -> Self {
+        Self::of_symbol(symbol, "forceOrder")
+    }
+
+    /// Futures liquidation orders of all the symbols.
+    pub fn all_market_liquidation_orders() -> Self {
+        Self::new("!forceOrder@arr")
+    }
+
+    /// Futures best prices of all the symbols.
+    pub fn all_book_tickers() -> Self {
+        Self::new("!bookTicker")
+    }
+
+    /// COIN-M futures index price of a pair, e.g. `BTCUSD`.
+    pub fn index_price(pair: &str, speed: MarkPriceSpeed) -> Self {
+        Self::of_symbol(pair, &format!("indexPrice{}", mark_price_suffix(speed)))
+    }
+
+    /// COIN-M futures index price klines of a pair.
+    pub fn index_price_kline(pair: &str, interval: KlineInterval) -> Self {
+        Self::of_symbol(pair, &format!("indexPriceKline_{}", interval.as_str()))
+    }
+
+    /// COIN-M futures mark price klines of a symbol.
+    pub fn mark_price_kline(symbol: &str, interval: KlineInterval) -> Self {
+        Self::of_symbol(symbol, &format!("markPriceKline_{}", interval.as_str()))
+    }
+
+    /// USD-M futures composite index.
+    pub fn composite_index(symbol: &str) -> Self {
+        Self::of_symbol(symbol, "compositeIndex")
+    }
+
+    /// Futures contract changes, of all the symbols.
+    pub fn contract_info() -> Self {
+        Self::new("!contractInfo")
+    }
+
+    /// USD-M futures index of an asset of the Multi-Assets mode, e.g.
+    /// `BTCUSD`.
+    pub fn asset_index(symbol: &str) -> Self {
+        Self::of_symbol(symbol, "assetIndex")
+    }
+
+    /// USD-M futures indexes of all the Multi-Assets mode assets, as an
+    /// array.
+    pub fn all_asset_indexes() -> Self {
+        Self::new("!assetIndex@arr")
+    }
+}
+
+impl fmt::Display for StreamName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for StreamName {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+fn window_str(window: Window) -> &'static str {
+    match window {
+        Window::H1 => "1h",
+        Window::H4 => "4h",
+        Window::D1 => "1d",
+    }
+}
+
+fn speed_suffix(speed: DepthSpeed) -> &'static str {
+    match speed {
+        DepthSpeed::Ms100 => "@100ms",
+        DepthSpeed::Ms1000 => "",
+    }
+}
+
+fn mark_price_suffix(speed: MarkPriceSpeed) -> &'static str {
+    match speed {
+        MarkPriceSpeed::Ms1000 => "@1s",
+        MarkPriceSpeed::Ms3000 => "",
+    }
+}
+
+/// Check that `count` streams fit on a single connection.
+// The error is large because of the tungstenite errors.
+#[allow(clippy::result_large_err)]
+pub(super) fn check_stream_count(count: usize) -> Result<(), WebSocketStreamError> {
+    if count > MAX_STREAMS {
+        return Err(WebSocketStreamError::TooManyStreams(count));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stream_names() {
+        let names = [
+            (StreamName::agg_trade("BTCUSDT"), "btcusdt@aggTrade"),
+            (StreamName::trade("ETHBTC"), "ethbtc@trade"),
+            (
+                StreamName::kline("BNBUSDT", KlineInterval::OneMonth),
+                "bnbusdt@kline_1M",
+            ),
+            (StreamName::all_market_mini_tickers(), "!miniTicker@arr"),
+            (
+                StreamName::rolling_ticker("BTCUSDT", Window::H1),
+                "btcusdt@ticker_1h",
+            ),
+            (
+                StreamName::all_market_rolling_tickers(Window::D1),
+                "!ticker_1d@arr",
+            ),
+            (
+                StreamName::partial_depth("BTCUSDT", Levels::L20),
+                "btcusdt@depth20",
+            ),
+            (
+                StreamName::partial_depth_with_speed("BTCUSDT", Levels::L5, DepthSpeed::Ms100),
+                "btcusdt@depth5@100ms",
+            ),
+            (
+                StreamName::depth("BTCUSDT", DepthSpeed::Ms100),
+                "btcusdt@depth@100ms",
+            ),
+            (
+                StreamName::depth("BTCUSDT", DepthSpeed::Ms1000),
+                "btcusdt@depth",
+            ),
+            (
+                StreamName::mark_price("BTCUSDT", MarkPriceSpeed::Ms1000),
+                "btcusdt@markPrice@1s",
+            ),
+            (
+                StreamName::all_market_mark_prices(MarkPriceSpeed::Ms3000),
+                "!markPrice@arr",
+            ),
+            (
+                StreamName::continuous_kline(
+                    "BTCUSDT",
+                    ContractType::CurrentQuarter,
+                    KlineInterval::FiveMinutes,
+                ),
+                "btcusdt_current_quarter@continuousKline_5m",
+            ),
+            (
+                StreamName::index_price("BTCUSD", MarkPriceSpeed::Ms1000),
+                "btcusd@indexPrice@1s",
+            ),
+            (
+                StreamName::index_price_kline("BTCUSD", KlineInterval::OneMinute),
+                "btcusd@indexPriceKline_1m",
+            ),
+            (
+                StreamName::mark_price_kline("BTCUSD_PERP", KlineInterval::OneHour),
+                "btcusd_perp@markPriceKline_1h",
+            ),
+            (
+                StreamName::liquidation_order("BTCUSDT"),
+                "btcusdt@forceOrder",
+            ),
+            (StreamName::all_asset_indexes(), "!assetIndex@arr"),
+        ];
+        for (name, expected) in names {
+            assert_eq!(name.as_str(), expected);
+        }
+        assert!(check_stream_count(MAX_STREAMS).is_ok());
+        assert!(check_stream_count(MAX_STREAMS + 1).is_err());
+    }
+}
@@ -1,23 +1,266 @@
 #![allow(dead_code)]
 
-use std::env;
+use std::{env, fs, path::Path, sync::OnceLock};
 
-use birdie::{rest_api::RestApiClient, web_socket_api::WebSocketApiClient};
+use birdie::{
+    rest_api::RestApiClient,
+    test_support::{
+        MockRequest, MockResponse, MockServer, API_KEY, ED25519_PRIVATE_KEY, SECRET_KEY,
+    },
+    web_socket_api::WebSocketApiClient,
+};
+use reqwest::Method;
+use serde_json::Value;
+
+/// Symbols known to the mock server.
+const SYMBOLS: &[&str] = &["BTCUSDT", "ETHUSDT"];
+
+/// Canned responses of the mock server: the HTTP method and path of the REST
+/// endpoint, the method of the Web Socket API, and the fixture under
+/// `tests/fixtures`.
+#[rustfmt::skip]
+const FIXTURES: &[(Method, &str, Option<&str>, &str)] = &[
+    // spot
+    (Method::GET, "/api/v3/account", Some("account.status"), "spot/account/account_information"),
+    (Method::GET, "/api/v3/myTrades", Some("myTrades"), "spot/account/account_trade_list"),
+    (Method::GET, "/api/v3/rateLimit/order", Some("account.rateLimits.orders"), "spot/account/query_unfilled_order_count"),
+    (Method::GET, "/api/v3/myPreventedMatches", Some("myPreventedMatches"), "spot/account/query_prevented_matches"),
+    (Method::GET, "/api/v3/myAllocations", Some("myAllocations"), "spot/account/query_allocations"),
+    (Method::GET, "/api/v3/account/commission", Some("account.commission"), "spot/account/query_commission_rates"),
+    (Method::GET, "/api/v3/exchangeInfo", Some("exchangeInfo"), "spot/general/exchange_info"),
+    (Method::GET, "/api/v3/depth", Some("depth"), "spot/market/order_book"),
+    (Method::GET, "/api/v3/trades", Some("trades.recent"), "spot/market/recent_trades_list"),
+    (Method::GET, "/api/v3/historicalTrades", Some("trades.historical"), "spot/market/old_trade_lookup"),
+    (Method::GET, "/api/v3/aggTrades", Some("trades.aggregate"), "spot/market/aggregate_trades_list"),
+    (Method::GET, "/api/v3/klines", Some("klines"), "spot/market/klines"),
+    (Method::GET, "/api/v3/uiKlines", Some("uiKlines"), "spot/market/ui_klines"),
+    (Method::GET, "/api/v3/avgPrice", Some("avgPrice"), "spot/market/current_average_price"),
+    (Method::GET, "/api/v3/ticker/24hr", Some("ticker.24hr"), "spot/market/ticker_24hr"),
+    (Method::GET, "/api/v3/ticker/tradingDay", Some("ticker.tradingDay"), "spot/market/trading_day_ticker"),
+    (Method::GET, "/api/v3/ticker/price", Some("ticker.price"), "spot/market/symbol_price_ticker"),
+    (Method::GET, "/api/v3/ticker/bookTicker", Some("ticker.book"), "spot/market/symbol_order_book_ticker"),
+    (Method::GET, "/api/v3/ticker", Some("ticker"), "spot/market/rolling_window_price_change"),
+    (Method::GET, "/api/v3/allOrders", Some("allOrders"), "spot/trade/all_orders"),
+    (Method::POST, "/api/v3/order/test", Some("order.test"), "spot/trade/test_new_order"),
+    // margin
+    (Method::GET, "/sapi/v1/bnbBurn", None, "margin/account/get_bnb_burn_status"),
+    (Method::GET, "/sapi/v1/margin/tradeCoeff", None, "margin/account/get_summary_of_margin_account"),
+    (Method::GET, "/sapi/v1/margin/account", None, "margin/account/query_cross_margin_account_details"),
+    (Method::GET, "/sapi/v1/margin/crossMarginData", None, "margin/account/query_cross_margin_fee_data"),
+    (Method::GET, "/sapi/v1/margin/isolated/accountLimit", None, "margin/account/query_enabled_isolated_margin_account_limit"),
+    (Method::GET, "/sapi/v1/margin/isolated/account", None, "margin/account/query_isolated_margin_account_info"),
+    (Method::GET, "/sapi/v1/margin/isolatedMarginData", None, "margin/account/query_isolated_margin_fee_data"),
+    (Method::GET, "/sapi/v1/margin/capital-flow", None, "margin/account/query_cross_isolated_margin_capital_flow"),
+    (Method::GET, "/sapi/v1/margin/next-hourly-interest-rate", None, "margin/borrow_and_repay/get_future_hourly_interest_rate"),
+    (Method::GET, "/sapi/v1/margin/interestHistory", None, "margin/borrow_and_repay/get_interest_history"),
+    (Method::GET, "/sapi/v1/margin/borrow-repay", None, "margin/borrow_and_repay/query_borrow_repay_records"),
+    (Method::GET, "/sapi/v1/margin/interestRateHistory", None, "margin/borrow_and_repay/query_margin_interest_rate_history"),
+    (Method::GET, "/sapi/v1/margin/maxBorrowable", None, "margin/borrow_and_repay/query_max_borrow"),
+    (Method::GET, "/sapi/v1/margin/crossMarginCollateralRatio", None, "margin/market/cross_margin_collateral_ratio"),
+    (Method::GET, "/sapi/v1/margin/allPairs", None, "margin/market/get_all_cross_margin_pairs"),
+    (Method::GET, "/sapi/v1/margin/isolated/allPairs", None, "margin/market/get_all_isolated_margin_symbol"),
+    (Method::GET, "/sapi/v1/margin/allAssets", None, "margin/market/get_all_margin_assets"),
+    (Method::GET, "/sapi/v1/margin/delist-schedule", None, "margin/market/get_delist_schedule"),
+    (Method::GET, "/sapi/v1/margin/isolatedMarginTier", None, "margin/market/query_isolated_margin_tier_data"),
+    (Method::GET, "/sapi/v1/margin/leverageBracket", None, "margin/market/query_liability_coin_leverage_bracket"),
+    (Method::GET, "/sapi/v1/margin/priceIndex", None, "margin/market/query_margin_price_index"),
+    (Method::GET, "/sapi/v1/margin/available-inventory", None, "margin/market/query_margin_available_inventory"),
+    (Method::GET, "/sapi/v1/margin/forceLiquidationRec", None, "margin/trade/get_force_liquidation_record"),
+    (Method::GET, "/sapi/v1/margin/exchange-small-liability", None, "margin/trade/get_small_liability_exchange_coin_list"),
+    (Method::GET, "/sapi/v1/margin/exchange-small-liability-history", None, "margin/trade/get_small_liability_exchange_history"),
+    (Method::GET, "/sapi/v1/margin/allOrderList", None, "margin/trade/query_all_oco"),
+    (Method::GET, "/sapi/v1/margin/orderList", None, "margin/trade/query_oco"),
+    (Method::GET, "/sapi/v1/margin/openOrderList", None, "margin/trade/query_open_oco"),
+    (Method::GET, "/sapi/v1/margin/allOrders", None, "margin/trade/query_all_orders"),
+    (Method::GET, "/sapi/v1/margin/openOrders", None, "margin/trade/query_open_orders"),
+    (Method::GET, "/sapi/v1/margin/rateLimit/order", None, "margin/trade/query_current_margin_order_count_usage"),
+    (Method::GET, "/sapi/v1/margin/myTrades", None, "margin/trade/query_trade_list"),
+    (Method::GET, "/sapi/v1/margin/transfer", None, "margin/transfer/get_cross_margin_transfer_history"),
+    (Method::GET, "/sapi/v1/margin/maxTransferable", None, "margin/transfer/query_max_transfer_out_amount"),
+    // usd_futures
+    (Method::GET, "/fapi/v1/aggTrades", None, "usd_futures/market/aggregate_trades_list"),
+    (Method::GET, "/fapi/v1/indexInfo", None, "usd_futures/market/composite_index_symbol_information"),
+    (Method::GET, "/fapi/v1/continuousKlines", None, "usd_futures/market/continuous_contract_klines"),
+    (Method::GET, "/fapi/v1/exchangeInfo", None, "usd_futures/market/exchange_info"),
+    (Method::GET, "/fapi/v1/fundingInfo", None, "usd_futures/market/get_funding_info"),
+    (Method::GET, "/fapi/v1/lvtKlines", None, "usd_futures/market/historical_blvt_nav_klines"),
+    (Method::GET, "/fapi/v1/indexPriceKlines", None, "usd_futures/market/index_price_klines"),
+    (Method::GET, "/fapi/v1/klines", None, "usd_futures/market/klines"),
+    (Method::GET, "/futures/data/globalLongShortAccountRatio", None, "usd_futures/market/long_short_ratio"),
+    (Method::GET, "/fapi/v1/premiumIndex", None, "usd_futures/market/mark_price"),
+    (Method::GET, "/fapi/v1/assetIndex", None, "usd_futures/market/multi_assets_mode_asset_index"),
+    (Method::GET, "/fapi/v1/historicalTrades", None, "usd_futures/market/old_trade_lookup"),
+    (Method::GET, "/fapi/v1/openInterest", None, "usd_futures/market/open_interest"),
+    (Method::GET, "/futures/data/openInterestHist", None, "usd_futures/market/open_interest_statistics"),
+    (Method::GET, "/fapi/v1/depth", None, "usd_futures/market/order_book"),
+    (Method::GET, "/fapi/v1/constituent", None, "usd_futures/market/query_index_price_constituents"),
+    (Method::GET, "/fapi/v1/trades", None, "usd_futures/market/recent_trades_list"),
+    (Method::GET, "/fapi/v1/ticker/bookTicker", None, "usd_futures/market/symbol_order_book_ticker"),
+    (Method::GET, "/fapi/v2/ticker/price", None, "usd_futures/market/symbol_order_book_ticker_v2"),
+    (Method::GET, "/fapi/v1/ticker/price", None, "usd_futures/market/symbol_price_ticker"),
+    (Method::GET, "/futures/data/takerlongshortRatio", None, "usd_futures/market/taker_buy_sell_volume"),
+    (Method::GET, "/fapi/v1/ticker/24hr", None, "usd_futures/market/ticker_24hr"),
+    (Method::GET, "/futures/data/topLongShortPositionRatio", None, "usd_futures/market/top_trader_long_short_position_ratio"),
+    (Method::GET, "/fapi/v2/account", None, "usd_futures/account/account_information_v2"),
+    (Method::GET, "/fapi/v3/account", None, "usd_futures/account/account_information_v3"),
+    (Method::GET, "/fapi/v2/balance", None, "usd_futures/account/futures_account_balance_v2"),
+    (Method::GET, "/fapi/v3/balance", None, "usd_futures/account/futures_account_balance_v3"),
+    (Method::GET, "/fapi/v1/accountConfig", None, "usd_futures/account/futures_account_configuration"),
+    (Method::GET, "/fapi/v1/apiTradingStatus", None, "usd_futures/account/futures_trading_quantitative_rules_indicators"),
+    (Method::GET, "/fapi/v1/feeBurn", None, "usd_futures/account/get_bnb_burn_status"),
+    (Method::GET, "/fapi/v1/multiAssetsMargin", None, "usd_futures/account/get_current_multi_assets_mode"),
+    (Method::GET, "/fapi/v1/positionSide/dual", None, "usd_futures/account/get_current_position_mode"),
+    (Method::GET, "/fapi/v1/order/asyn", None, "usd_futures/account/get_download_id_for_futures_order_history"),
+    (Method::GET, "/fapi/v1/trade/asyn", None, "usd_futures/account/get_download_id_for_futures_trade_history"),
+    (Method::GET, "/fapi/v1/income/asyn", None, "usd_futures/account/get_download_id_for_futures_transaction_history"),
+    (Method::GET, "/fapi/v1/order/asyn/id", None, "usd_futures/account/get_futures_order_history_download_link_by_id"),
+    (Method::GET, "/fapi/v1/trade/asyn/id", None, "usd_futures/account/get_futures_trade_history_download_link_by_id"),
+    (Method::GET, "/fapi/v1/income/asyn/id", None, "usd_futures/account/get_futures_transaction_history_download_link_by_id"),
+    (Method::GET, "/fapi/v1/income", None, "usd_futures/account/get_income_history"),
+    (Method::GET, "/fapi/v1/leverageBracket", None, "usd_futures/account/notional_and_leverage_brackets"),
+    (Method::GET, "/fapi/v1/rateLimit/order", None, "usd_futures/account/query_user_rate_limit"),
+    (Method::GET, "/fapi/v1/symbolConfig", None, "usd_futures/account/symbol_configuration"),
+    (Method::GET, "/fapi/v1/commissionRate", None, "usd_futures/account/user_commission_rate"),
+    (Method::GET, "/fapi/v1/userTrades", None, "usd_futures/trade/account_trade_list"),
+    (Method::GET, "/fapi/v1/orderAmendment", None, "usd_futures/trade/get_order_modify_history"),
+    (Method::GET, "/fapi/v1/positionMargin/history", None, "usd_futures/trade/get_position_margin_change_history"),
+    (Method::GET, "/fapi/v1/adlQuantile", None, "usd_futures/trade/position_adl_quantile_estimation"),
+    (Method::GET, "/fapi/v2/positionRisk", None, "usd_futures/trade/position_information_v2"),
+    (Method::GET, "/fapi/v3/positionRisk", None, "usd_futures/trade/position_information_v3"),
+    (Method::GET, "/fapi/v1/allOrders", None, "usd_futures/trade/query_all_orders"),
+    (Method::GET, "/fapi/v1/openOrders", None, "usd_futures/trade/query_current_all_open_orders"),
+    (Method::GET, "/fapi/v1/openOrder", None, "usd_futures/trade/query_current_open_order"),
+    (Method::GET, "/fapi/v1/forceOrders", None, "usd_futures/trade/query_force_orders"),
+    (Method::GET, "/fapi/v1/order", None, "usd_futures/trade/query_order"),
+    (Method::POST, "/fapi/v1/order/test", None, "usd_futures/trade/test_new_order"),
+];
 
 pub fn setup_rest_api_client() -> RestApiClient {
-    let base_url =
-        env::var("BINANCE_REST_API_ENDPOINT").expect("BINANCE_REST_API_ENDPOINT is not set");
+    let Ok(base_url) = env::var("BINANCE_REST_API_ENDPOINT") else {
+        return RestApiClient::new(&mock_server().rest_url(), API_KEY, SECRET_KEY)
+            .expect("Failed to create RestApiClient");
+    };
     let api_key = env::var("BINANCE_API_KEY").expect("BINANCE_API_KEY is not set");
     let secret_key = env::var("BINANCE_SECRET_KEY").expect("BINANCE_SECRET_KEY is not set");
     RestApiClient::new(&base_url, &api_key, &secret_key).expect("Failed to create RestApiClient")
 }
 
 pub fn setup_web_socket_api_client() -> WebSocketApiClient {
-    let endpoint = env::var("BINANCE_WEB_SOCKET_API_ENDPOINT")
-        .expect("BINANCE_WEB_SOCKET_API_ENDPOINT is not set");
+    let Ok(endpoint) = env::var("BINANCE_WEB_SOCKET_API_ENDPOINT") else {
+        return WebSocketApiClient::new(&mock_server().ws_api_url(), API_KEY, ED25519_PRIVATE_KEY)
+            .expect("Failed to create WebSocketApiClient");
+    };
     let api_key =
         env::var("BINANCE_WEB_SOCKET_API_KEY").expect("BINANCE_WEB_SOCKET_API_KEY is not set");
     let secret_key = env::var("BINANCE_WEB_SOCKET_SECRET_KEY")
         .expect("BINANCE_WEB_SOCKET_SECRET_KEY is not set");
     WebSocketApiClient::new(&endpoint, &api_key, &secret_key)
+        .expect("Failed to create WebSocketApiClient")
+}
+
+/// Read a fixture under `tests/fixtures`, e.g. `spot/market/klines`.
+pub fn fixture(name: &str) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(format!("{name}.json"));
+    fs::read_to_string(&path).unwrap_or_else(|err| panic!("{}: {err}", path.display()))
+}
+
+/// The mock server shared by the tests of a test binary, used when the
+/// `BINANCE_*` env vars are not set.
+pub fn mock_server() -> &'static MockServer {
+    static SERVER: OnceLock<MockServer> = OnceLock::new();
+    SERVER.get_or_init(|| {
+        let server = MockServer::spawn();
+        for (method, path, ws_method, name) in FIXTURES {
+            let resp = MockResponse::json(&fixture(name));
+            server.mock(method.clone(), path, resp.clone());
+            if let Some(ws_method) = ws_method {
+                server.mock_ws(ws_method, resp);
+            }
+        }
+
+        let exchange_info: Value =
+            serde_json::from_str(&fixture("spot/general/exchange_info")).unwrap();
+        let filter_symbols = move |req: &MockRequest| {
+            let symbols = requested_symbols(req)?;
+            let mut resp = exchange_info.clone();
+            resp["symbols"]
+                .as_array_mut()
+                .unwrap()
+                .retain(|s| symbols.iter().any(|symbol| s["symbol"] == symbol.as_str()));
+            Some(MockResponse::serialize(&resp))
+        };
+        server.mock_with(Method::GET, "/api/v3/exchangeInfo", filter_symbols.clone());
+        server.mock_ws_with("exchangeInfo", filter_symbols);
+
+        server.intercept(validate);
+        server
+    })
+}
+
+/// The symbols of the `symbol` or `symbols` param.
+fn requested_symbols(req: &MockRequest) -> Option<Vec<String>> {
+    match (req.param("symbol"), req.param("symbols")) {
+        (Some(symbol), _) => Some(vec![symbol.to_owned()]),
+        (None, Some(symbols)) => serde_json::from_str(symbols).ok(),
+        (None, None) => None,
+    }
+}
+
+/// Emulate the validations of Binance that the tests rely on.
+fn validate(req: &MockRequest) -> Option<MockResponse> {
+    let endpoint = match &req.method {
+        Some(method) => format!("{method} {}", req.path),
+        None => req.path.clone(),
+    };
+
+    if req.has_param("symbol") && req.has_param("symbols") {
+        return Some(MockResponse::error(
+            400,
+            -1128,
+            "Combination of optional parameters invalid.",
+        ));
+    }
+    if let Some(symbols) = requested_symbols(req) {
+        if symbols.iter().any(|s| !SYMBOLS.contains(&s.as_str())) {
+            return Some(MockResponse::error(400, -1121, "Invalid symbol."));
+        }
+    }
+
+    match endpoint.as_str() {
+        "POST /api/v3/order/test" | "order.test" => {
+            let price: f64 = req.param("price")?.parse().ok()?;
+            (price < 1.0)
+                .then(|| MockResponse::error(400, -1013, "Filter failure: PERCENT_PRICE_BY_SIDE"))
+        }
+        "GET /api/v3/order" | "order.status" | "GET /sapi/v1/margin/order" => {
+            match req.param("orderId") {
+                None if !req.has_param("origClientOrderId") => Some(MockResponse::error(
+                    400,
+                    -1102,
+                    "Param 'origClientOrderId' or 'orderId' must be sent, but both were empty/null!",
+                )),
+                Some("0") if req.method.is_some() && req.path.starts_with("/sapi/") => {
+                    Some(MockResponse::error(400, -2013, "Order does not exist."))
+                }
+                _ => Some(MockResponse::error(
+                    400,
+                    -2026,
+                    "Order was canceled or expired with no executed qty over 90 days ago and has been archived.",
+                )),
+            }
+        }
+        "DELETE /api/v3/order"
+        | "order.cancel"
+        | "DELETE /api/v3/openOrders"
+        | "openOrders.cancelAll" => Some(MockResponse::error(400, -2011, "Unknown order sent.")),
+        "GET /api/v3/myPreventedMatches" | "myPreventedMatches" => {
+            let valid = req.has_param("preventedMatchId") || req.has_param("orderId");
+            (!valid).then(|| {
+                MockResponse::error(400, -1128, "Combination of optional parameters invalid.")
+            })
+        }
+        _ => None,
+    }
 }
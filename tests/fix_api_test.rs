@@ -0,0 +1,182 @@
+use std::time::Duration;
+
+use birdie::{
+    enums::{CancelReplaceMode, OrderSide, OrderStatus, OrderType, TimeInForce},
+    fix_api::{
+        msg_type, tag, ExecType, ExecutionReport, FixApiClient, FixApiError, FixMessage,
+        MarketDataRequest, MdEntryType, NewOrderSingle, OrderCancelRequest,
+        OrderCancelRequestAndNewOrderSingle, SubscriptionRequestType,
+    },
+    test_support::{MockServer, API_KEY, ED25519_PRIVATE_KEY},
+};
+use tokio::{sync::mpsc, time::timeout};
+
+async fn connect(server: &MockServer) -> (FixApiClient, mpsc::Receiver<FixMessage>) {
+    let mut client = FixApiClient::new(&server.fix_url(), API_KEY, ED25519_PRIVATE_KEY)
+        .unwrap()
+        .sender_comp_id("TEST");
+    let (tx, rx) = mpsc::channel(10);
+    client.connect(tx).await.unwrap();
+    (client, rx)
+}
+
+async fn next(rx: &mut mpsc::Receiver<FixMessage>) -> FixMessage {
+    timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .expect("no message received")
+        .expect("session closed")
+}
+
+async fn next_report(rx: &mut mpsc::Receiver<FixMessage>) -> ExecutionReport {
+    ExecutionReport::try_from(&next(rx).await).unwrap()
+}
+
+#[tokio::test]
+async fn fix_logon() {
+    let server = MockServer::start().await;
+    let _ = connect(&server).await;
+
+    let logon = &server.fix_messages()[0];
+    assert_eq!(logon.msg_type(), msg_type::LOGON);
+    assert_eq!(logon.get(tag::SENDER_COMP_ID), Some("TEST"));
+    assert_eq!(logon.get(tag::TARGET_COMP_ID), Some("SPOT"));
+    assert_eq!(logon.get(tag::MSG_SEQ_NUM), Some("1"));
+    assert_eq!(logon.get(tag::RESET_SEQ_NUM_FLAG), Some("Y"));
+
+    let mut client = FixApiClient::new(&server.fix_url(), "wrong", ED25519_PRIVATE_KEY).unwrap();
+    let (tx, _rx) = mpsc::channel(10);
+    let resp = client.connect(tx).await;
+    assert!(matches!(resp, Err(FixApiError::Session(_))));
+}
+
+#[tokio::test]
+async fn fix_new_order_single() {
+    let server = MockServer::start().await;
+    let (client, mut rx) = connect(&server).await;
+
+    let order = NewOrderSingle::new("order-1", "BTCUSDT", OrderSide::Buy, OrderType::Limit)
+        .order_qty(1e-7)
+        .price("65000.10".parse::<birdie::decimal::Decimal>().unwrap())
+        .time_in_force(TimeInForce::Gtc);
+    let seq_num = client.send(order).await.unwrap();
+    assert_eq!(seq_num, 2);
+
+    let report = next_report(&mut rx).await;
+    assert_eq!(report.cl_ord_id.as_deref(), Some("order-1"));
+    assert_eq!(report.exec_type, ExecType::New);
+    assert!(matches!(report.ord_status, OrderStatus::New));
+    assert_eq!(report.symbol, "BTCUSDT");
+    assert!(report.order_id.is_some());
+
+    let msg = server.fix_messages().pop().unwrap();
+    assert_eq!(msg.msg_type(), msg_type::NEW_ORDER_SINGLE);
+    assert_eq!(msg.get(tag::MSG_SEQ_NUM), Some("2"));
+    assert_eq!(msg.get(tag::SIDE), Some("1"));
+    assert_eq!(msg.get(tag::ORD_TYPE), Some("2"));
+    assert_eq!(msg.get(tag::ORDER_QTY), Some("0.0000001"));
+    assert_eq!(msg.get(tag::PRICE), Some("65000.10"));
+    assert_eq!(msg.get(tag::TIME_IN_FORCE), Some("1"));
+}
+
+#[tokio::test]
+async fn fix_cancel_orders() {
+    let server = MockServer::start().await;
+    let (client, mut rx) = connect(&server).await;
+
+    let cancel = OrderCancelRequest::new("cancel-1", "BTCUSDT").order_id(42);
+    client.send(cancel).await.unwrap();
+    let report = next_report(&mut rx).await;
+    assert_eq!(report.exec_type, ExecType::Canceled);
+    assert_eq!(report.order_id, Some(42));
+
+    let order = NewOrderSingle::new("order-2", "BTCUSDT", OrderSide::Sell, OrderType::LimitMaker)
+        .order_qty(1)
+        .price(70000);
+    let replace = OrderCancelRequestAndNewOrderSingle::new(CancelReplaceMode::StopOnFailure, order)
+        .orig_cl_ord_id("order-1")
+        .cancel_cl_ord_id("cancel-2");
+    client.send(replace).await.unwrap();
+    let canceled = next_report(&mut rx).await;
+    assert_eq!(canceled.cl_ord_id.as_deref(), Some("cancel-2"));
+    assert_eq!(canceled.orig_cl_ord_id.as_deref(), Some("order-1"));
+    let placed = next_report(&mut rx).await;
+    assert_eq!(placed.cl_ord_id.as_deref(), Some("order-2"));
+    assert_eq!(placed.exec_type, ExecType::New);
+
+    let msg = server.fix_messages().pop().unwrap();
+    assert_eq!(
+        msg.get(tag::ORDER_CANCEL_REQUEST_AND_NEW_ORDER_SINGLE_MODE),
+        Some("1")
+    );
+    assert_eq!(msg.get(tag::EXEC_INST), Some("6"));
+}
+
+#[tokio::test]
+async fn fix_market_data_request() {
+    let server = MockServer::start().await;
+    let (client, mut rx) = connect(&server).await;
+
+    let req = MarketDataRequest::new("md-1", SubscriptionRequestType::Subscribe)
+        .symbol("BTCUSDT")
+        .entry_type(MdEntryType::Bid)
+        .entry_type(MdEntryType::Offer)
+        .market_depth(5);
+    client.send(req).await.unwrap();
+    let msg = next(&mut rx).await;
+    assert_eq!(msg.msg_type(), msg_type::MARKET_DATA_SNAPSHOT);
+    assert_eq!(msg.get(tag::MD_REQ_ID), Some("md-1"));
+
+    let req = server.fix_messages().pop().unwrap();
+    let entry_types: Vec<_> = req
+        .fields()
+        .iter()
+        .filter(|(tag, _)| *tag == tag::MD_ENTRY_TYPE)
+        .map(|(_, v)| v.as_str())
+        .collect();
+    assert_eq!(entry_types, ["0", "1"]);
+}
+
+#[tokio::test]
+async fn fix_session_messages() {
+    let server = MockServer::start().await;
+    let (client, mut rx) = connect(&server).await;
+
+    // Test requests are answered by the session, and not forwarded.
+    server.push_fix(FixMessage::new(msg_type::TEST_REQUEST).field(tag::TEST_REQ_ID, "ping"));
+    // Messages of the acceptor are handled in order, so the execution report
+    // is received once the test request was answered.
+    server.push_fix(FixMessage::new(msg_type::EXECUTION_REPORT));
+    let msg = next(&mut rx).await;
+    assert_eq!(msg.msg_type(), msg_type::EXECUTION_REPORT);
+    assert_eq!(msg.seq_num(), Some(3));
+
+    server.mock_fix(msg_type::NEW_ORDER_SINGLE, |msg| {
+        Some(vec![FixMessage::new(msg_type::REJECT)
+            .field_opt(tag::REF_SEQ_NUM, msg.get(tag::MSG_SEQ_NUM))
+            .field(tag::TEXT, "Rejected.")])
+    });
+    let order = NewOrderSingle::new("order-1", "BTCUSDT", OrderSide::Buy, OrderType::Market);
+    let seq_num = client.send(order).await.unwrap();
+    let reject = next(&mut rx).await;
+    assert_eq!(reject.msg_type(), msg_type::REJECT);
+    assert_eq!(
+        reject.get(tag::REF_SEQ_NUM),
+        Some(seq_num.to_string().as_str())
+    );
+    // The acceptor reads in order, so the heartbeat was recorded before the
+    // order was rejected.
+    let heartbeat = server
+        .fix_messages()
+        .into_iter()
+        .find(|msg| msg.msg_type() == msg_type::HEARTBEAT)
+        .unwrap();
+    assert_eq!(heartbeat.get(tag::TEST_REQ_ID), Some("ping"));
+
+    client.logout().await.unwrap();
+    assert_eq!(next(&mut rx).await.msg_type(), msg_type::LOGOUT);
+    assert!(rx.recv().await.is_none());
+    assert!(client
+        .send(FixMessage::new(msg_type::HEARTBEAT))
+        .await
+        .is_err());
+}
@@ -0,0 +1,513 @@
+//! Deserialize the recorded payloads under `tests/fixtures` into their
+//! response and stream payload types.
+//!
+//! Fields of a payload that are not part of its type are reported, and fail
+//! the test when `BIRDIE_STRICT_FIXTURES` is set:
+//!
+//! ```shell
+//! BIRDIE_STRICT_FIXTURES=1 cargo test --test fixtures_test
+//! ```
+//!
+//! serde buffers the content of `#[serde(flatten)]` fields, untagged and
+//! internally tagged enums, so the fields they ignore can't be detected. The
+//! events of a stream payload are also checked against their own type for
+//! that reason.
+use std::env;
+
+use birdie::{coin_futures, margin, spot, usd_futures};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+mod common;
+
+fn strict() -> bool {
+    env::var_os("BIRDIE_STRICT_FIXTURES").is_some_and(|v| v != "0")
+}
+
+/// Deserialize the value, collecting the paths of the fields it ignores.
+fn deserialize<'de, T, D>(name: &str, de: D) -> (T, Vec<String>)
+where
+    T: DeserializeOwned,
+    D: serde::Deserializer<'de>,
+    D::Error: std::fmt::Display,
+{
+    let mut ignored = Vec::new();
+    let value = serde_ignored::deserialize(de, |path| ignored.push(path.to_string()))
+        .unwrap_or_else(|err| panic!("{name}: {err}"));
+    (value, ignored)
+}
+
+fn report(name: &str, ignored: &[String]) {
+    if ignored.is_empty() {
+        return;
+    }
+    let msg = format!("{name}: unknown fields {}", ignored.join(", "));
+    if strict() {
+        panic!("{msg}");
+    }
+    eprintln!("{msg}");
+}
+
+fn check<T: DeserializeOwned>(name: &str) -> T {
+    let json = common::fixture(name);
+    let (value, ignored) = deserialize(name, &mut serde_json::Deserializer::from_str(&json));
+    report(name, &ignored);
+    value
+}
+
+/// Check the event of a stream payload against its own type.
+fn check_event<T: DeserializeOwned>(name: &str) {
+    let mut value: Value = serde_json::from_str(&common::fixture(name)).unwrap();
+    // Combined streams wrap the event in `data`.
+    if let Some(data) = value.get_mut("data") {
+        value = data.take();
+    }
+    let (_, mut ignored): (T, _) = deserialize(name, value);
+    // The event type is the tag of the payload.
+    ignored.retain(|path| path != "e");
+    report(name, &ignored);
+}
+
+/// Define a test per fixture, deserializing it into the type and, for stream
+/// payloads, the event into its own type.
+macro_rules! fixtures {
+    ($($test:ident: $ty:ty $(as $event:ty)? = $name:literal;)*) => {
+        $(
+            #[test]
+            fn $test() {
+                check::<$ty>($name);
+                $(check_event::<$event>($name);)?
+            }
+        )*
+    };
+}
+
+fixtures! {
+// spot
+spot_account_information: spot::account::AccountInformationResponse = "spot/account/account_information";
+spot_account_trade_list: spot::account::AccountTradeListResponse = "spot/account/account_trade_list";
+spot_query_allocations: spot::account::QueryAllocationsResponse = "spot/account/query_allocations";
+spot_query_commission_rates: spot::account::QueryCommissionRatesResponse = "spot/account/query_commission_rates";
+spot_query_prevented_matches: spot::account::QueryPreventedMatchesResponse = "spot/account/query_prevented_matches";
+spot_query_unfilled_order_count: spot::account::QueryUnfilledOrderCountResponse = "spot/account/query_unfilled_order_count";
+spot_check_server_time: spot::general::CheckServerTimeResponse = "spot/general/check_server_time";
+spot_exchange_info: spot::general::ExchangeInfoResponse = "spot/general/exchange_info";
+spot_test_connectivity: spot::general::TestConnectivityResponse = "spot/general/test_connectivity";
+spot_aggregate_trades_list: spot::market::AggregateTradesListResponse = "spot/market/aggregate_trades_list";
+spot_current_average_price: spot::market::CurrentAveragePriceResponse = "spot/market/current_average_price";
+spot_klines: spot::market::KlinesResponse = "spot/market/klines";
+spot_old_trade_lookup: spot::market::OldTradeLookupResponse = "spot/market/old_trade_lookup";
+spot_order_book: spot::market::OrderBookResponse = "spot/market/order_book";
+spot_recent_trades_list: spot::market::RecentTradesListResponse = "spot/market/recent_trades_list";
+spot_rolling_window_price_change: spot::market::RollingWindowPriceChangeResponse = "spot/market/rolling_window_price_change";
+spot_symbol_order_book_ticker: spot::market::SymbolOrderBookTickerResponse = "spot/market/symbol_order_book_ticker";
+spot_symbol_price_ticker: spot::market::SymbolPriceTickerResponse = "spot/market/symbol_price_ticker";
+spot_ticker_24hr: spot::market::Ticker24hrResponse = "spot/market/ticker_24hr";
+spot_trading_day_ticker: spot::market::TradingDayTickerResponse = "spot/market/trading_day_ticker";
+spot_ui_klines: spot::market::UiKlinesResponse = "spot/market/ui_klines";
+spot_all_orders: spot::trade::AllOrdersResponse = "spot/trade/all_orders";
+spot_cancel_all_open_orders: spot::trade::CancelAllOpenOrdersResponse = "spot/trade/cancel_all_open_orders";
+spot_cancel_order: spot::trade::CancelOrderResponse = "spot/trade/cancel_order";
+spot_cancel_order_list: spot::trade::CancelOrderListResponse = "spot/trade/cancel_order_list";
+spot_cancel_replace_order: spot::trade::CancelReplaceOrderResponse = "spot/trade/cancel_replace_order";
+spot_cancel_replace_order_failure: spot::trade::CancelReplaceOrderResponse = "spot/trade/cancel_replace_order_failure";
+spot_current_open_orders: spot::trade::CurrentOpenOrdersResponse = "spot/trade/current_open_orders";
+spot_new_order_ack: spot::trade::NewOrderResponse = "spot/trade/new_order_ack";
+spot_new_order_result: spot::trade::NewOrderResponse = "spot/trade/new_order_result";
+spot_new_order_full: spot::trade::NewOrderResponse = "spot/trade/new_order_full";
+spot_new_order_list_oco: spot::trade::NewOrderListOcoResponse = "spot/trade/new_order_list_oco";
+spot_new_order_list_oto: spot::trade::NewOrderListOtoResponse = "spot/trade/new_order_list_oto";
+spot_new_order_list_otoco: spot::trade::NewOrderListOtocoResponse = "spot/trade/new_order_list_otoco";
+spot_new_order_using_sor: spot::trade::NewOrderUsingSorResponse = "spot/trade/new_order_using_sor";
+spot_query_all_order_lists: spot::trade::QueryAllOrderListsResponse = "spot/trade/query_all_order_lists";
+spot_query_open_order_lists: spot::trade::QueryOpenOrderListsResponse = "spot/trade/query_open_order_lists";
+spot_query_order: spot::trade::QueryOrderResponse = "spot/trade/query_order";
+spot_query_order_lists: spot::trade::QueryOrderListsResponse = "spot/trade/query_order_lists";
+spot_test_new_order: spot::trade::TestNewOrderResponse = "spot/trade/test_new_order";
+spot_test_new_order_commission_rates: spot::trade::TestNewOrderResponse = "spot/trade/test_new_order_commission_rates";
+spot_test_new_order_using_sor: spot::trade::TestNewOrderUsingSorResponse = "spot/trade/test_new_order_using_sor";
+spot_test_new_order_using_sor_commission_rates: spot::trade::TestNewOrderUsingSorResponse = "spot/trade/test_new_order_commission_rates";
+spot_close_user_data_stream: spot::user_data_stream::CloseUserDataStreamResponse = "spot/user_data_stream/close_user_data_stream";
+spot_keepalive_user_data_stream: spot::user_data_stream::KeepaliveUserDataStreamResponse = "spot/user_data_stream/keepalive_user_data_stream";
+spot_start_user_data_stream: spot::user_data_stream::StartUserDataStreamResponse = "spot/user_data_stream/start_user_data_stream";
+
+// spot payloads
+spot_stream_aggregate_trade: spot::web_socket_streams::SpotStreamPayloadload as spot::web_socket_streams::AggregatedTrade = "spot/web_socket_streams/aggregate_trade";
+spot_stream_trade: spot::web_socket_streams::SpotStreamPayloadload as spot::web_socket_streams::Trade = "spot/web_socket_streams/trade";
+spot_stream_kline: spot::web_socket_streams::SpotStreamPayloadload as spot::web_socket_streams::Kline = "spot/web_socket_streams/kline";
+spot_stream_mini_ticker: spot::web_socket_streams::SpotStreamPayloadload as spot::web_socket_streams::MiniTicker = "spot/web_socket_streams/mini_ticker";
+spot_stream_ticker: spot::web_socket_streams::SpotStreamPayloadload as spot::web_socket_streams::Ticker = "spot/web_socket_streams/ticker";
+spot_stream_rolling_window_ticker: spot::web_socket_streams::SpotStreamPayloadload as spot::web_socket_streams::RollingWindowTicker = "spot/web_socket_streams/rolling_window_ticker";
+spot_stream_book_ticker: spot::web_socket_streams::SpotStreamPayloadload as spot::web_socket_streams::BookTicker = "spot/web_socket_streams/book_ticker";
+spot_stream_avg_price: spot::web_socket_streams::SpotStreamPayloadload as spot::web_socket_streams::AvgPrice = "spot/web_socket_streams/avg_price";
+spot_stream_partial_book_depth: spot::web_socket_streams::SpotStreamPayloadload as spot::web_socket_streams::PartialBookDepth = "spot/web_socket_streams/partial_book_depth";
+spot_stream_depth: spot::web_socket_streams::SpotStreamPayloadload as spot::web_socket_streams::Depth = "spot/web_socket_streams/depth";
+spot_user_data_stream_outbound_account_position: spot::user_data_stream::UserDataStreamPayload as spot::user_data_stream::OutboundAccountPosition = "spot/user_data_stream/outbound_account_position";
+spot_user_data_stream_balance_update: spot::user_data_stream::UserDataStreamPayload as spot::user_data_stream::BalanceUpdate = "spot/user_data_stream/balance_update";
+spot_user_data_stream_execution_report: spot::user_data_stream::UserDataStreamPayload as spot::user_data_stream::ExecutionReport = "spot/user_data_stream/execution_report";
+spot_user_data_stream_list_status: spot::user_data_stream::UserDataStreamPayload as spot::user_data_stream::ListStatus = "spot/user_data_stream/list_status";
+spot_user_data_stream_listen_key_expired: spot::user_data_stream::UserDataStreamPayload as spot::user_data_stream::ListenKeyExpired = "spot/user_data_stream/listen_key_expired";
+spot_user_data_stream_event_stream_terminated: spot::user_data_stream::UserDataStreamPayload as spot::user_data_stream::EventStreamTerminated = "spot/user_data_stream/event_stream_terminated";
+
+// margin
+margin_adjust_cross_margin_max_leverage: margin::account::AdjustCrossMarginMaxLeverageResponse = "margin/account/adjust_cross_margin_max_leverage";
+margin_disable_isolated_margin_account: margin::account::DisableIsolatedMarginAccountResponse = "margin/account/disable_isolated_margin_account";
+margin_enable_isolated_margin_account: margin::account::EnableIsolatedMarginAccountResponse = "margin/account/enable_isolated_margin_account";
+margin_get_bnb_burn_status: margin::account::GetBnbBurnStatusResponse = "margin/account/get_bnb_burn_status";
+margin_get_summary_of_margin_account: margin::account::GetSummaryOfMarginAccountResponse = "margin/account/get_summary_of_margin_account";
+margin_query_cross_isolated_margin_capital_flow: margin::account::QueryCrossIsolatedMarginCapitalFlowResponse = "margin/account/query_cross_isolated_margin_capital_flow";
+margin_query_cross_margin_account_details: margin::account::QueryCrossMarginAccountDetailsResponse = "margin/account/query_cross_margin_account_details";
+margin_query_cross_margin_fee_data: margin::account::QueryCrossMarginFeeDataResponse = "margin/account/query_cross_margin_fee_data";
+margin_query_enabled_isolated_margin_account_limit: margin::account::QueryEnabledIsolatedMarginAccountLimitResponse = "margin/account/query_enabled_isolated_margin_account_limit";
+margin_query_isolated_margin_account_info: margin::account::QueryIsolatedMarginAccountInfoResponse = "margin/account/query_isolated_margin_account_info";
+margin_query_isolated_margin_fee_data: margin::account::QueryIsolatedMarginFeeDataResponse = "margin/account/query_isolated_margin_fee_data";
+margin_toggle_bnb_burn_on_spot_trade_and_margin_interest: margin::account::ToggleBnbBurnOnSpotTradeAndMarginInterestResponse = "margin/account/toggle_bnb_burn_on_spot_trade_and_margin_interest";
+margin_get_future_hourly_interest_rate: margin::borrow_and_repay::GetFutureHourlyInterestRateResponse = "margin/borrow_and_repay/get_future_hourly_interest_rate";
+margin_get_interest_history: margin::borrow_and_repay::GetInterestHistoryResponse = "margin/borrow_and_repay/get_interest_history";
+margin_margin_account_borrow_repay: margin::borrow_and_repay::MarginAccountBorrowRepayResponse = "margin/borrow_and_repay/margin_account_borrow_repay";
+margin_query_borrow_repay_records: margin::borrow_and_repay::QueryBorrowRepayRecordsResponse = "margin/borrow_and_repay/query_borrow_repay_records";
+margin_query_margin_interest_rate_history: margin::borrow_and_repay::QueryMarginInterestRateHistoryResponse = "margin/borrow_and_repay/query_margin_interest_rate_history";
+margin_query_max_borrow: margin::borrow_and_repay::QueryMaxBorrowResponse = "margin/borrow_and_repay/query_max_borrow";
+margin_cross_margin_collateral_ratio: margin::market::CrossMarginCollateralRatioResponse = "margin/market/cross_margin_collateral_ratio";
+margin_get_all_cross_margin_pairs: margin::market::GetAllCrossMarginPairsResponse = "margin/market/get_all_cross_margin_pairs";
+margin_get_all_isolated_margin_symbol: margin::market::GetAllIsolatedMarginSymbolResponse = "margin/market/get_all_isolated_margin_symbol";
+margin_get_all_margin_assets: margin::market::GetAllMarginAssetsResponse = "margin/market/get_all_margin_assets";
+margin_get_delist_schedule: margin::market::GetDelistScheduleResponse = "margin/market/get_delist_schedule";
+margin_query_isolated_margin_tier_data: margin::market::QueryIsolatedMarginTierDataResponse = "margin/market/query_isolated_margin_tier_data";
+margin_query_liability_coin_leverage_bracket: margin::market::QueryLiabilityCoinLeverageBracketResponse = "margin/market/query_liability_coin_leverage_bracket";
+margin_query_margin_available_inventory: margin::market::QueryMarginAvailableInventoryResponse = "margin/market/query_margin_available_inventory";
+margin_query_margin_price_index: margin::market::QueryMarginPriceIndexResponse = "margin/market/query_margin_price_index";
+margin_close_user_data_stream: margin::risk_data_stream::CloseUserDataStreamResponse = "margin/risk_data_stream/close_user_data_stream";
+margin_keepalive_user_data_stream: margin::risk_data_stream::KeepaliveUserDataStreamResponse = "margin/risk_data_stream/keepalive_user_data_stream";
+margin_start_user_data_stream: margin::risk_data_stream::StartUserDataStreamResponse = "margin/risk_data_stream/start_user_data_stream";
+margin_cancel_all_open_orders: margin::trade::CancelAllOpenOrdersResponse = "margin/trade/cancel_all_open_orders";
+margin_cancel_oco: margin::trade::CancelOcoResponse = "margin/trade/cancel_oco";
+margin_cancel_order: margin::trade::CancelOrderResponse = "margin/trade/cancel_order";
+margin_get_force_liquidation_record: margin::trade::GetForceLiquidationRecordResponse = "margin/trade/get_force_liquidation_record";
+margin_get_small_liability_exchange_coin_list: margin::trade::GetSmallLiabilityExchangeCoinListResponse = "margin/trade/get_small_liability_exchange_coin_list";
+margin_get_small_liability_exchange_history: margin::trade::GetSmallLiabilityExchangeHistoryResponse = "margin/trade/get_small_liability_exchange_history";
+margin_margin_manual_liquidation: margin::trade::MarginManualLiquidationResponse = "margin/trade/margin_manual_liquidation";
+margin_new_oco: margin::trade::NewOcoResponse = "margin/trade/new_oco";
+margin_new_order_ack: margin::trade::NewOrderResponse = "margin/trade/new_order_ack";
+margin_new_order_result: margin::trade::NewOrderResponse = "margin/trade/new_order_result";
+margin_new_order_full: margin::trade::NewOrderResponse = "margin/trade/new_order_full";
+margin_query_all_oco: margin::trade::QueryAllOcoResponse = "margin/trade/query_all_oco";
+margin_query_all_orders: margin::trade::QueryAllOrdersResponse = "margin/trade/query_all_orders";
+margin_query_current_margin_order_count_usage: margin::trade::QueryCurrentMarginOrderCountUsageResponse = "margin/trade/query_current_margin_order_count_usage";
+margin_query_oco: margin::trade::QueryOcoResponse = "margin/trade/query_oco";
+margin_query_open_oco: margin::trade::QueryOpenOcoResponse = "margin/trade/query_open_oco";
+margin_query_open_orders: margin::trade::QueryOpenOrdersResponse = "margin/trade/query_open_orders";
+margin_query_order: margin::trade::QueryOrderResponse = "margin/trade/query_order";
+margin_query_trade_list: margin::trade::QueryTradeListResponse = "margin/trade/query_trade_list";
+margin_small_liability_exchange: margin::trade::SmallLiabilityExchangeResponse = "margin/trade/small_liability_exchange";
+margin_close_isolated_margin_user_data_stream: margin::trade_data_stream::CloseIsolatedMarginUserDataStreamResponse = "margin/trade_data_stream/close_isolated_margin_user_data_stream";
+margin_close_margin_user_data_stream: margin::trade_data_stream::CloseMarginUserDataStreamResponse = "margin/trade_data_stream/close_margin_user_data_stream";
+margin_keepalive_isolated_margin_user_data_stream: margin::trade_data_stream::KeepaliveIsolatedMarginUserDataStreamResponse = "margin/trade_data_stream/keepalive_isolated_margin_user_data_stream";
+margin_keepalive_margin_user_data_stream: margin::trade_data_stream::KeepaliveMarginUserDataStreamResponse = "margin/trade_data_stream/keepalive_margin_user_data_stream";
+margin_start_isolated_margin_user_data_stream: margin::trade_data_stream::StartIsolatedMarginUserDataStreamResponse = "margin/trade_data_stream/start_isolated_margin_user_data_stream";
+margin_start_margin_user_data_stream: margin::trade_data_stream::StartMarginUserDataStreamResponse = "margin/trade_data_stream/start_margin_user_data_stream";
+margin_get_cross_margin_transfer_history: margin::transfer::GetCrossMarginTransferHistoryResponse = "margin/transfer/get_cross_margin_transfer_history";
+margin_query_max_transfer_out_amount: margin::transfer::QueryMaxTransferOutAmountResponse = "margin/transfer/query_max_transfer_out_amount";
+
+// margin payloads
+margin_trade_data_stream_outbound_account_position: margin::trade_data_stream::TradeUserDataStreamPayload = "spot/user_data_stream/outbound_account_position";
+margin_trade_data_stream_balance_update: margin::trade_data_stream::TradeUserDataStreamPayload = "spot/user_data_stream/balance_update";
+margin_trade_data_stream_execution_report: margin::trade_data_stream::TradeUserDataStreamPayload = "spot/user_data_stream/execution_report";
+margin_trade_data_stream_list_status: margin::trade_data_stream::TradeUserDataStreamPayload = "spot/user_data_stream/list_status";
+margin_trade_data_stream_listen_key_expired: margin::trade_data_stream::TradeUserDataStreamPayload = "spot/user_data_stream/listen_key_expired";
+margin_risk_data_stream_margin_call: margin::risk_data_stream::RiskDataStreamPayload as margin::risk_data_stream::MarginCall = "margin/risk_data_stream/margin_call";
+margin_risk_data_stream_liability_update: margin::risk_data_stream::RiskDataStreamPayload as margin::risk_data_stream::LiabilityUpdate = "margin/risk_data_stream/liability_update";
+
+// usd_futures
+usd_futures_account_information_v2: usd_futures::account::AccountInformationV2Response = "usd_futures/account/account_information_v2";
+usd_futures_account_information_v3: usd_futures::account::AccountInformationV3Response = "usd_futures/account/account_information_v3";
+usd_futures_futures_account_balance_v2: usd_futures::account::FuturesAccountBalanceV2Response = "usd_futures/account/futures_account_balance_v2";
+usd_futures_futures_account_balance_v3: usd_futures::account::FuturesAccountBalanceV3Response = "usd_futures/account/futures_account_balance_v3";
+usd_futures_futures_account_configuration: usd_futures::account::FuturesAccountConfigurationResponse = "usd_futures/account/futures_account_configuration";
+usd_futures_futures_trading_quantitative_rules_indicators: usd_futures::account::FuturesTradingQuantitativeRulesIndicatorsResponse = "usd_futures/account/futures_trading_quantitative_rules_indicators";
+usd_futures_get_bnb_burn_status: usd_futures::account::GetBnbBurnStatusResponse = "usd_futures/account/get_bnb_burn_status";
+usd_futures_get_current_multi_assets_mode: usd_futures::account::GetCurrentMultiAssetsModeResponse = "usd_futures/account/get_current_multi_assets_mode";
+usd_futures_get_current_position_mode: usd_futures::account::GetCurrentPositionModeResponse = "usd_futures/account/get_current_position_mode";
+usd_futures_get_download_id_for_futures_order_history: usd_futures::account::GetDownloadIdForFuturesOrderHistoryResponse = "usd_futures/account/get_download_id_for_futures_order_history";
+usd_futures_get_download_id_for_futures_trade_history: usd_futures::account::GetDownloadIdForFuturesTradeHistoryResponse = "usd_futures/account/get_download_id_for_futures_trade_history";
+usd_futures_get_download_id_for_futures_transaction_history: usd_futures::account::GetDownloadIdForFuturesTransactionHistoryResponse = "usd_futures/account/get_download_id_for_futures_transaction_history";
+usd_futures_get_futures_order_history_download_link_by_id: usd_futures::account::GetFuturesOrderHistoryDownloadLinkByIdResponse = "usd_futures/account/get_futures_order_history_download_link_by_id";
+usd_futures_get_futures_trade_history_download_link_by_id: usd_futures::account::GetFuturesTradeHistoryDownloadLinkByIdResponse = "usd_futures/account/get_futures_trade_history_download_link_by_id";
+usd_futures_get_futures_transaction_history_download_link_by_id: usd_futures::account::GetFuturesTransactionHistoryDownloadLinkByIdResponse = "usd_futures/account/get_futures_transaction_history_download_link_by_id";
+usd_futures_get_income_history: usd_futures::account::GetIncomeHistoryResponse = "usd_futures/account/get_income_history";
+usd_futures_notional_and_leverage_brackets: usd_futures::account::NotionalAndLeverageBracketsResponse = "usd_futures/account/notional_and_leverage_brackets";
+usd_futures_query_user_rate_limit: usd_futures::account::QueryUserRateLimitResponse = "usd_futures/account/query_user_rate_limit";
+usd_futures_symbol_configuration: usd_futures::account::SymbolConfigurationResponse = "usd_futures/account/symbol_configuration";
+usd_futures_toggle_bnb_burn_on_futures_trade: usd_futures::account::ToggleBnbBurnOnFuturesTradeResponse = "usd_futures/account/toggle_bnb_burn_on_futures_trade";
+usd_futures_user_commission_rate: usd_futures::account::UserCommissionRateResponse = "usd_futures/account/user_commission_rate";
+usd_futures_accept_quote: usd_futures::convert::AcceptQuoteResponse = "usd_futures/convert/accept_quote";
+usd_futures_list_all_convert_pairs: usd_futures::convert::ListAllConvertPairsResponse = "usd_futures/convert/list_all_convert_pairs";
+usd_futures_order_status: usd_futures::convert::OrderStatusResponse = "usd_futures/convert/order_status";
+usd_futures_send_quote_request: usd_futures::convert::SendQuoteRequestResponse = "usd_futures/convert/send_quote_request";
+usd_futures_aggregate_trades_list: usd_futures::market::AggregateTradesListResponse = "usd_futures/market/aggregate_trades_list";
+usd_futures_check_server_time: usd_futures::market::CheckServerTimeResponse = "usd_futures/market/check_server_time";
+usd_futures_composite_index_symbol_information: usd_futures::market::CompositeIndexSymbolInformationResponse = "usd_futures/market/composite_index_symbol_information";
+usd_futures_continuous_contract_klines: usd_futures::market::ContinuousContractKlinesResponse = "usd_futures/market/continuous_contract_klines";
+usd_futures_exchange_info: usd_futures::market::ExchangeInfoResponse = "usd_futures/market/exchange_info";
+usd_futures_get_funding_info: usd_futures::market::GetFundingInfoResponse = "usd_futures/market/get_funding_info";
+usd_futures_get_funding_rate_history: usd_futures::market::GetFundingRateHistoryResponse = "usd_futures/market/get_funding_rate_history";
+usd_futures_historical_blvt_nav_klines: usd_futures::market::HistoricalBlvtNavKlinesResponse = "usd_futures/market/historical_blvt_nav_klines";
+usd_futures_index_price_klines: usd_futures::market::IndexPriceKlinesResponse = "usd_futures/market/index_price_klines";
+usd_futures_klines: usd_futures::market::KlinesResponse = "usd_futures/market/klines";
+usd_futures_long_short_ratio: usd_futures::market::LongShortRatioResponse = "usd_futures/market/long_short_ratio";
+usd_futures_mark_price: usd_futures::market::MarkPriceResponse = "usd_futures/market/mark_price";
+usd_futures_mark_price_all: usd_futures::market::MarkPriceResponse = "usd_futures/market/mark_price_all";
+usd_futures_mark_price_klines: usd_futures::market::MarkPriceKlinesResponse = "usd_futures/market/mark_price_klines";
+usd_futures_multi_assets_mode_asset_index: usd_futures::market::MultiAssetsModeAssetIndexResponse = "usd_futures/market/multi_assets_mode_asset_index";
+usd_futures_old_trade_lookup: usd_futures::market::OldTradeLookupResponse = "usd_futures/market/old_trade_lookup";
+usd_futures_open_interest: usd_futures::market::OpenInterestResponse = "usd_futures/market/open_interest";
+usd_futures_open_interest_statistics: usd_futures::market::OpenInterestStatisticsResponse = "usd_futures/market/open_interest_statistics";
+usd_futures_order_book: usd_futures::market::OrderBookResponse = "usd_futures/market/order_book";
+usd_futures_premium_index_klines: usd_futures::market::PremiumIndexKlinesResponse = "usd_futures/market/premium_index_klines";
+usd_futures_query_delivery_price: usd_futures::market::QueryDeliveryPriceResponse = "usd_futures/market/query_delivery_price";
+usd_futures_query_index_price_constituents: usd_futures::market::QueryIndexPriceConstituentsResponse = "usd_futures/market/query_index_price_constituents";
+usd_futures_recent_trades_list: usd_futures::market::RecentTradesListResponse = "usd_futures/market/recent_trades_list";
+usd_futures_symbol_order_book_ticker: usd_futures::market::SymbolOrderBookTickerResponse = "usd_futures/market/symbol_order_book_ticker";
+usd_futures_symbol_order_book_ticker_all: usd_futures::market::SymbolOrderBookTickerResponse = "usd_futures/market/symbol_order_book_ticker_all";
+usd_futures_symbol_order_book_ticker_v2: usd_futures::market::SymbolOrderBookTickerV2Response = "usd_futures/market/symbol_order_book_ticker_v2";
+usd_futures_symbol_price_ticker: usd_futures::market::SymbolPriceTickerResponse = "usd_futures/market/symbol_price_ticker";
+usd_futures_taker_buy_sell_volume: usd_futures::market::TakerBuySellVolumeResponse = "usd_futures/market/taker_buy_sell_volume";
+usd_futures_test_connectivity: usd_futures::market::TestConnectivityResponse = "usd_futures/market/test_connectivity";
+usd_futures_ticker_24hr: usd_futures::market::Ticker24hrResponse = "usd_futures/market/ticker_24hr";
+usd_futures_ticker_24hr_all: usd_futures::market::Ticker24hrResponse = "usd_futures/market/ticker_24hr_all";
+usd_futures_top_trader_long_short_account_ratio: usd_futures::market::TopTraderLongShortAccountRatioResponse = "usd_futures/market/top_trader_long_short_account_ratio";
+usd_futures_top_trader_long_short_position_ratio: usd_futures::market::TopTraderLongShortPositionRatioResponse = "usd_futures/market/top_trader_long_short_position_ratio";
+usd_futures_account_trade_list: usd_futures::trade::AccountTradeListResponse = "usd_futures/trade/account_trade_list";
+usd_futures_auto_cancel_all_open_orders: usd_futures::trade::AutoCancelAllOpenOrdersResponse = "usd_futures/trade/auto_cancel_all_open_orders";
+usd_futures_cancel_all_open_orders: usd_futures::trade::CancelAllOpenOrdersResponse = "usd_futures/trade/cancel_all_open_orders";
+usd_futures_cancel_multiple_orders: usd_futures::trade::CancelMultipleOrdersResponse = "usd_futures/trade/cancel_multiple_orders";
+usd_futures_cancel_order: usd_futures::trade::CancelOrderResponse = "usd_futures/trade/cancel_order";
+usd_futures_change_initial_leverage: usd_futures::trade::ChangeInitialLeverageResponse = "usd_futures/trade/change_initial_leverage";
+usd_futures_change_margin_type: usd_futures::trade::ChangeMarginTypeResponse = "usd_futures/trade/change_margin_type";
+usd_futures_change_multi_assets_mode: usd_futures::trade::ChangeMultiAssetsModeResponse = "usd_futures/trade/change_multi_assets_mode";
+usd_futures_change_position_mode: usd_futures::trade::ChangePositionModeResponse = "usd_futures/trade/change_position_mode";
+usd_futures_get_order_modify_history: usd_futures::trade::GetOrderModifyHistoryResponse = "usd_futures/trade/get_order_modify_history";
+usd_futures_get_position_margin_change_history: usd_futures::trade::GetPositionMarginChangeHistoryResponse = "usd_futures/trade/get_position_margin_change_history";
+usd_futures_modify_isolated_position_margin: usd_futures::trade::ModifyIsolatedPositionMarginResponse = "usd_futures/trade/modify_isolated_position_margin";
+usd_futures_modify_multiple_orders: usd_futures::trade::ModifyMultipleOrdersResponse = "usd_futures/trade/modify_multiple_orders";
+usd_futures_modify_order: usd_futures::trade::ModifyOrderResponse = "usd_futures/trade/modify_order";
+usd_futures_new_order: usd_futures::trade::NewOrderResponse = "usd_futures/trade/new_order";
+usd_futures_place_multiple_orders: usd_futures::trade::PlaceMultipleOrdersResponse = "usd_futures/trade/place_multiple_orders";
+usd_futures_position_adl_quantile_estimation: usd_futures::trade::PositionAdlQuantileEstimationResponse = "usd_futures/trade/position_adl_quantile_estimation";
+usd_futures_position_information_v2: usd_futures::trade::PositionInformationV2Response = "usd_futures/trade/position_information_v2";
+usd_futures_position_information_v3: usd_futures::trade::PositionInformationV3Response = "usd_futures/trade/position_information_v3";
+usd_futures_query_all_orders: usd_futures::trade::QueryAllOrdersResponse = "usd_futures/trade/query_all_orders";
+usd_futures_query_current_all_open_orders: usd_futures::trade::QueryCurrentAllOpenOrdersResponse = "usd_futures/trade/query_current_all_open_orders";
+usd_futures_query_current_open_order: usd_futures::trade::QueryCurrentOpenOrderResponse = "usd_futures/trade/query_current_open_order";
+usd_futures_query_force_orders: usd_futures::trade::QueryForceOrdersResponse = "usd_futures/trade/query_force_orders";
+usd_futures_query_order: usd_futures::trade::QueryOrderResponse = "usd_futures/trade/query_order";
+usd_futures_test_new_order: usd_futures::trade::TestNewOrderResponse = "usd_futures/trade/test_new_order";
+usd_futures_close_user_data_stream: usd_futures::user_data_stream::CloseUserDataStreamResponse = "usd_futures/user_data_stream/close_user_data_stream";
+usd_futures_keepalive_user_data_stream: usd_futures::user_data_stream::KeepaliveUserDataStreamResponse = "usd_futures/user_data_stream/keepalive_user_data_stream";
+usd_futures_start_user_data_stream: usd_futures::user_data_stream::StartUserDataStreamResponse = "usd_futures/user_data_stream/start_user_data_stream";
+
+// usd_futures payloads
+usd_futures_stream_aggregate_trade: usd_futures::web_socket_streams::UsdFuturesStreamPayload as usd_futures::web_socket_streams::AggregatedTrade = "usd_futures/web_socket_streams/aggregate_trade";
+usd_futures_stream_mark_price: usd_futures::web_socket_streams::UsdFuturesStreamPayload as usd_futures::web_socket_streams::MarkPrice = "usd_futures/web_socket_streams/mark_price";
+usd_futures_stream_all_market_mark_prices: usd_futures::web_socket_streams::UsdFuturesStreamPayload = "usd_futures/web_socket_streams/all_market_mark_prices";
+usd_futures_stream_continuous_kline: usd_futures::web_socket_streams::UsdFuturesStreamPayload as usd_futures::web_socket_streams::ContinuousKline = "usd_futures/web_socket_streams/continuous_kline";
+usd_futures_stream_liquidation_order: usd_futures::web_socket_streams::UsdFuturesStreamPayload as usd_futures::web_socket_streams::LiquidationOrder = "usd_futures/web_socket_streams/liquidation_order";
+usd_futures_stream_book_ticker: usd_futures::web_socket_streams::UsdFuturesStreamPayload as usd_futures::web_socket_streams::BookTicker = "usd_futures/web_socket_streams/book_ticker";
+usd_futures_stream_partial_book_depth: usd_futures::web_socket_streams::UsdFuturesStreamPayload as usd_futures::web_socket_streams::Depth = "usd_futures/web_socket_streams/partial_book_depth";
+usd_futures_stream_depth: usd_futures::web_socket_streams::UsdFuturesStreamPayload as usd_futures::web_socket_streams::Depth = "usd_futures/web_socket_streams/depth";
+usd_futures_stream_composite_index: usd_futures::web_socket_streams::UsdFuturesStreamPayload as usd_futures::web_socket_streams::CompositeIndex = "usd_futures/web_socket_streams/composite_index";
+usd_futures_stream_contract_info: usd_futures::web_socket_streams::UsdFuturesStreamPayload as usd_futures::web_socket_streams::ContractInfo = "usd_futures/web_socket_streams/contract_info";
+usd_futures_stream_asset_index: usd_futures::web_socket_streams::UsdFuturesStreamPayload as usd_futures::web_socket_streams::AssetIndex = "usd_futures/web_socket_streams/asset_index";
+usd_futures_user_data_stream_account_update: usd_futures::user_data_stream::UserDataStreamPayload as usd_futures::user_data_stream::AccountUpdate = "usd_futures/user_data_stream/account_update";
+usd_futures_user_data_stream_order_trade_update: usd_futures::user_data_stream::UserDataStreamPayload as usd_futures::user_data_stream::OrderTradeUpdate = "usd_futures/user_data_stream/order_trade_update";
+usd_futures_user_data_stream_account_config_update: usd_futures::user_data_stream::UserDataStreamPayload as usd_futures::user_data_stream::AccountConfigUpdate = "usd_futures/user_data_stream/account_config_update";
+usd_futures_user_data_stream_margin_call: usd_futures::user_data_stream::UserDataStreamPayload as usd_futures::user_data_stream::MarginCall = "usd_futures/user_data_stream/margin_call";
+usd_futures_user_data_stream_trade_lite: usd_futures::user_data_stream::UserDataStreamPayload as usd_futures::user_data_stream::TradeLite = "usd_futures/user_data_stream/trade_lite";
+usd_futures_user_data_stream_strategy_update: usd_futures::user_data_stream::UserDataStreamPayload as usd_futures::user_data_stream::StrategyUpdate = "usd_futures/user_data_stream/strategy_update";
+usd_futures_user_data_stream_listen_key_expired: usd_futures::user_data_stream::UserDataStreamPayload as usd_futures::user_data_stream::ListenKeyExpired = "usd_futures/user_data_stream/listen_key_expired";
+
+// coin_futures
+coin_futures_account_information: coin_futures::account::AccountInformationResponse = "coin_futures/account/account_information";
+coin_futures_futures_account_balance: coin_futures::account::FuturesAccountBalanceResponse = "coin_futures/account/futures_account_balance";
+coin_futures_get_current_position_mode: coin_futures::account::GetCurrentPositionModeResponse = "coin_futures/account/get_current_position_mode";
+coin_futures_get_income_history: coin_futures::account::GetIncomeHistoryResponse = "coin_futures/account/get_income_history";
+coin_futures_notional_bracket_for_pair: coin_futures::account::NotionalBracketForPairResponse = "coin_futures/account/notional_bracket_for_pair";
+coin_futures_user_commission_rate: coin_futures::account::UserCommissionRateResponse = "coin_futures/account/user_commission_rate";
+coin_futures_aggregate_trades_list: coin_futures::market::AggregateTradesListResponse = "coin_futures/market/aggregate_trades_list";
+coin_futures_basis: coin_futures::market::BasisResponse = "coin_futures/market/basis";
+coin_futures_check_server_time: coin_futures::market::CheckServerTimeResponse = "coin_futures/market/check_server_time";
+coin_futures_continuous_contract_klines: coin_futures::market::ContinuousContractKlinesResponse = "coin_futures/market/continuous_contract_klines";
+coin_futures_exchange_info: coin_futures::market::ExchangeInfoResponse = "coin_futures/market/exchange_info";
+coin_futures_get_funding_info: coin_futures::market::GetFundingInfoResponse = "coin_futures/market/get_funding_info";
+coin_futures_get_funding_rate_history: coin_futures::market::GetFundingRateHistoryResponse = "coin_futures/market/get_funding_rate_history";
+coin_futures_index_price_and_mark_price: coin_futures::market::IndexPriceAndMarkPriceResponse = "coin_futures/market/index_price_and_mark_price";
+coin_futures_index_price_klines: coin_futures::market::IndexPriceKlinesResponse = "coin_futures/market/index_price_klines";
+coin_futures_klines: coin_futures::market::KlinesResponse = "coin_futures/market/klines";
+coin_futures_long_short_ratio: coin_futures::market::LongShortRatioResponse = "coin_futures/market/long_short_ratio";
+coin_futures_mark_price_klines: coin_futures::market::MarkPriceKlinesResponse = "coin_futures/market/mark_price_klines";
+coin_futures_old_trade_lookup: coin_futures::market::OldTradeLookupResponse = "coin_futures/market/old_trade_lookup";
+coin_futures_open_interest: coin_futures::market::OpenInterestResponse = "coin_futures/market/open_interest";
+coin_futures_open_interest_statistics: coin_futures::market::OpenInterestStatisticsResponse = "coin_futures/market/open_interest_statistics";
+coin_futures_order_book: coin_futures::market::OrderBookResponse = "coin_futures/market/order_book";
+coin_futures_premium_index_klines: coin_futures::market::PremiumIndexKlinesResponse = "coin_futures/market/premium_index_klines";
+coin_futures_query_index_price_constituents: coin_futures::market::QueryIndexPriceConstituentsResponse = "coin_futures/market/query_index_price_constituents";
+coin_futures_recent_trades_list: coin_futures::market::RecentTradesListResponse = "coin_futures/market/recent_trades_list";
+coin_futures_symbol_order_book_ticker: coin_futures::market::SymbolOrderBookTickerResponse = "coin_futures/market/symbol_order_book_ticker";
+coin_futures_symbol_price_ticker: coin_futures::market::SymbolPriceTickerResponse = "coin_futures/market/symbol_price_ticker";
+coin_futures_taker_buy_sell_volume: coin_futures::market::TakerBuySellVolumeResponse = "coin_futures/market/taker_buy_sell_volume";
+coin_futures_test_connectivity: coin_futures::market::TestConnectivityResponse = "coin_futures/market/test_connectivity";
+coin_futures_ticker_24hr: coin_futures::market::Ticker24hrResponse = "coin_futures/market/ticker_24hr";
+coin_futures_top_trader_long_short_account_ratio: coin_futures::market::TopTraderLongShortAccountRatioResponse = "coin_futures/market/top_trader_long_short_account_ratio";
+coin_futures_top_trader_long_short_position_ratio: coin_futures::market::TopTraderLongShortPositionRatioResponse = "coin_futures/market/top_trader_long_short_position_ratio";
+coin_futures_account_trade_list: coin_futures::trade::AccountTradeListResponse = "coin_futures/trade/account_trade_list";
+coin_futures_auto_cancel_all_open_orders: coin_futures::trade::AutoCancelAllOpenOrdersResponse = "coin_futures/trade/auto_cancel_all_open_orders";
+coin_futures_cancel_all_open_orders: coin_futures::trade::CancelAllOpenOrdersResponse = "coin_futures/trade/cancel_all_open_orders";
+coin_futures_cancel_multiple_orders: coin_futures::trade::CancelMultipleOrdersResponse = "coin_futures/trade/cancel_multiple_orders";
+coin_futures_cancel_order: coin_futures::trade::CancelOrderResponse = "coin_futures/trade/cancel_order";
+coin_futures_change_initial_leverage: coin_futures::trade::ChangeInitialLeverageResponse = "coin_futures/trade/change_initial_leverage";
+coin_futures_change_margin_type: coin_futures::trade::ChangeMarginTypeResponse = "coin_futures/trade/change_margin_type";
+coin_futures_change_position_mode: coin_futures::trade::ChangePositionModeResponse = "coin_futures/trade/change_position_mode";
+coin_futures_get_order_modify_history: coin_futures::trade::GetOrderModifyHistoryResponse = "coin_futures/trade/get_order_modify_history";
+coin_futures_get_position_margin_change_history: coin_futures::trade::GetPositionMarginChangeHistoryResponse = "coin_futures/trade/get_position_margin_change_history";
+coin_futures_modify_isolated_position_margin: coin_futures::trade::ModifyIsolatedPositionMarginResponse = "coin_futures/trade/modify_isolated_position_margin";
+coin_futures_modify_multiple_orders: coin_futures::trade::ModifyMultipleOrdersResponse = "coin_futures/trade/modify_multiple_orders";
+coin_futures_modify_order: coin_futures::trade::ModifyOrderResponse = "coin_futures/trade/modify_order";
+coin_futures_new_order: coin_futures::trade::NewOrderResponse = "coin_futures/trade/new_order";
+coin_futures_place_multiple_orders: coin_futures::trade::PlaceMultipleOrdersResponse = "coin_futures/trade/place_multiple_orders";
+coin_futures_position_adl_quantile_estimation: coin_futures::trade::PositionAdlQuantileEstimationResponse = "coin_futures/trade/position_adl_quantile_estimation";
+coin_futures_position_information: coin_futures::trade::PositionInformationResponse = "coin_futures/trade/position_information";
+coin_futures_query_all_orders: coin_futures::trade::QueryAllOrdersResponse = "coin_futures/trade/query_all_orders";
+coin_futures_query_current_all_open_orders: coin_futures::trade::QueryCurrentAllOpenOrdersResponse = "coin_futures/trade/query_current_all_open_orders";
+coin_futures_query_current_open_order: coin_futures::trade::QueryCurrentOpenOrderResponse = "coin_futures/trade/query_current_open_order";
+coin_futures_query_force_orders: coin_futures::trade::QueryForceOrdersResponse = "coin_futures/trade/query_force_orders";
+coin_futures_query_order: coin_futures::trade::QueryOrderResponse = "coin_futures/trade/query_order";
+coin_futures_close_user_data_stream: coin_futures::user_data_stream::CloseUserDataStreamResponse = "coin_futures/user_data_stream/close_user_data_stream";
+coin_futures_keepalive_user_data_stream: coin_futures::user_data_stream::KeepaliveUserDataStreamResponse = "coin_futures/user_data_stream/keepalive_user_data_stream";
+coin_futures_start_user_data_stream: coin_futures::user_data_stream::StartUserDataStreamResponse = "coin_futures/user_data_stream/start_user_data_stream";
+
+// coin_futures payloads
+coin_futures_stream_aggregate_trade: coin_futures::web_socket_streams::CoinFuturesStreamPayload as coin_futures::web_socket_streams::AggregatedTrade = "coin_futures/web_socket_streams/aggregate_trade";
+coin_futures_stream_index_price: coin_futures::web_socket_streams::CoinFuturesStreamPayload as coin_futures::web_socket_streams::IndexPrice = "coin_futures/web_socket_streams/index_price";
+coin_futures_stream_mark_price: coin_futures::web_socket_streams::CoinFuturesStreamPayload as coin_futures::web_socket_streams::MarkPrice = "coin_futures/web_socket_streams/mark_price";
+coin_futures_stream_pair_mark_prices: coin_futures::web_socket_streams::CoinFuturesStreamPayload = "coin_futures/web_socket_streams/pair_mark_prices";
+coin_futures_stream_kline: coin_futures::web_socket_streams::CoinFuturesStreamPayload as coin_futures::web_socket_streams::Kline = "coin_futures/web_socket_streams/kline";
+coin_futures_stream_continuous_kline: coin_futures::web_socket_streams::CoinFuturesStreamPayload as coin_futures::web_socket_streams::ContinuousKline = "coin_futures/web_socket_streams/continuous_kline";
+coin_futures_stream_index_price_kline: coin_futures::web_socket_streams::CoinFuturesStreamPayload as coin_futures::web_socket_streams::IndexPriceKline = "coin_futures/web_socket_streams/index_price_kline";
+coin_futures_stream_mark_price_kline: coin_futures::web_socket_streams::CoinFuturesStreamPayload as coin_futures::web_socket_streams::MarkPriceKline = "coin_futures/web_socket_streams/mark_price_kline";
+coin_futures_stream_mini_ticker: coin_futures::web_socket_streams::CoinFuturesStreamPayload as coin_futures::web_socket_streams::MiniTicker = "coin_futures/web_socket_streams/mini_ticker";
+coin_futures_stream_all_market_mini_tickers: coin_futures::web_socket_streams::CoinFuturesStreamPayload = "coin_futures/web_socket_streams/all_market_mini_tickers";
+coin_futures_stream_ticker: coin_futures::web_socket_streams::CoinFuturesStreamPayload as coin_futures::web_socket_streams::Ticker = "coin_futures/web_socket_streams/ticker";
+coin_futures_stream_book_ticker: coin_futures::web_socket_streams::CoinFuturesStreamPayload as coin_futures::web_socket_streams::BookTicker = "coin_futures/web_socket_streams/book_ticker";
+coin_futures_stream_liquidation_order: coin_futures::web_socket_streams::CoinFuturesStreamPayload as coin_futures::web_socket_streams::LiquidationOrder = "coin_futures/web_socket_streams/liquidation_order";
+coin_futures_stream_partial_book_depth: coin_futures::web_socket_streams::CoinFuturesStreamPayload as coin_futures::web_socket_streams::Depth = "coin_futures/web_socket_streams/partial_book_depth";
+coin_futures_stream_depth: coin_futures::web_socket_streams::CoinFuturesStreamPayload as coin_futures::web_socket_streams::Depth = "coin_futures/web_socket_streams/depth";
+coin_futures_stream_contract_info: coin_futures::web_socket_streams::CoinFuturesStreamPayload as coin_futures::web_socket_streams::ContractInfo = "coin_futures/web_socket_streams/contract_info";
+coin_futures_user_data_stream_account_update: coin_futures::user_data_stream::UserDataStreamPayload as coin_futures::user_data_stream::AccountUpdate = "coin_futures/user_data_stream/account_update";
+coin_futures_user_data_stream_order_trade_update: coin_futures::user_data_stream::UserDataStreamPayload as coin_futures::user_data_stream::OrderTradeUpdate = "coin_futures/user_data_stream/order_trade_update";
+coin_futures_user_data_stream_account_config_update: coin_futures::user_data_stream::UserDataStreamPayload as coin_futures::user_data_stream::AccountConfigUpdate = "coin_futures/user_data_stream/account_config_update";
+coin_futures_user_data_stream_margin_call: coin_futures::user_data_stream::UserDataStreamPayload as coin_futures::user_data_stream::MarginCall = "coin_futures/user_data_stream/margin_call";
+coin_futures_user_data_stream_listen_key_expired: coin_futures::user_data_stream::UserDataStreamPayload as coin_futures::user_data_stream::ListenKeyExpired = "coin_futures/user_data_stream/listen_key_expired";
+}
+
+#[test]
+fn unknown_filters() {
+    use birdie::filters::{self, ExchangeFilter, SymbolFilter};
+
+    let json = r#"{"filterType":"MAX_NUM_ORDER_AMENDS","maxNumOrderAmends":10}"#;
+    let filter: SymbolFilter = serde_json::from_str(json).unwrap();
+    assert!(matches!(filter, SymbolFilter::Unknown));
+    let filter: filters::futures::SymbolFilter = serde_json::from_str(json).unwrap();
+    assert!(matches!(filter, filters::futures::SymbolFilter::Unknown));
+
+    let json = r#"{"filterType":"EXCHANGE_MAX_NUM_ORDER_LISTS","maxNumOrderLists":20}"#;
+    let filter: ExchangeFilter = serde_json::from_str(json).unwrap();
+    assert!(matches!(filter, ExchangeFilter::Unknown));
+}
+
+#[test]
+fn unknown_income_type() {
+    use birdie::enums::futures::IncomeType;
+
+    let income_type: IncomeType = serde_json::from_str(r#""BFUSD_REWARD""#).unwrap();
+    assert!(matches!(income_type, IncomeType::BfusdReward));
+    let income_type: IncomeType = serde_json::from_str(r#""NEW_INCOME_TYPE""#).unwrap();
+    assert!(matches!(income_type, IncomeType::Unknown));
+}
+
+#[test]
+fn spot_new_order_response_variants() {
+    use spot::trade::NewOrderResponse;
+
+    let resp = check("spot/trade/new_order_ack");
+    assert!(matches!(resp, NewOrderResponse::Ack(_)));
+    let resp = check("spot/trade/new_order_result");
+    assert!(matches!(resp, NewOrderResponse::Result(_)));
+    let resp = check("spot/trade/new_order_full");
+    assert!(matches!(resp, NewOrderResponse::Full(_)));
+}
+
+#[test]
+fn margin_new_order_response_variants() {
+    use margin::trade::NewOrderResponse;
+
+    let resp = check("margin/trade/new_order_ack");
+    assert!(matches!(resp, NewOrderResponse::Ack(_)));
+    let resp = check("margin/trade/new_order_result");
+    assert!(matches!(resp, NewOrderResponse::Result(_)));
+    let resp = check("margin/trade/new_order_full");
+    assert!(matches!(resp, NewOrderResponse::Full(_)));
+}
+
+#[test]
+fn spot_stream_payload_variants() {
+    use spot::web_socket_streams::SpotStreamPayloadload;
+
+    let payload = check("spot/web_socket_streams/depth");
+    assert!(matches!(payload, SpotStreamPayloadload::Depth(_)));
+    let payload = check("spot/web_socket_streams/partial_book_depth");
+    assert!(matches!(
+        payload,
+        SpotStreamPayloadload::PartialBookDepth(_)
+    ));
+}
+
+#[test]
+fn usd_futures_stream_payload_variants() {
+    use usd_futures::web_socket_streams::UsdFuturesStreamPayload;
+
+    let payload = check("usd_futures/web_socket_streams/depth");
+    assert!(matches!(payload, UsdFuturesStreamPayload::Depth(_)));
+    let payload = check("usd_futures/web_socket_streams/partial_book_depth");
+    assert!(matches!(
+        payload,
+        UsdFuturesStreamPayload::PartialBookDepth(_)
+    ));
+    let payload = check("usd_futures/web_socket_streams/mark_price");
+    assert!(matches!(payload, UsdFuturesStreamPayload::MarkPrice(_)));
+    let payload = check("usd_futures/web_socket_streams/all_market_mark_prices");
+    assert!(matches!(payload, UsdFuturesStreamPayload::MarkPrices(prices) if prices.len() == 2));
+}
+
+#[test]
+fn coin_futures_stream_payload_variants() {
+    use coin_futures::web_socket_streams::CoinFuturesStreamPayload;
+
+    let payload = check("coin_futures/web_socket_streams/depth");
+    assert!(matches!(payload, CoinFuturesStreamPayload::Depth(_)));
+    let payload = check("coin_futures/web_socket_streams/partial_book_depth");
+    assert!(matches!(
+        payload,
+        CoinFuturesStreamPayload::PartialBookDepth(_)
+    ));
+    let payload = check("coin_futures/web_socket_streams/mark_price");
+    assert!(matches!(payload, CoinFuturesStreamPayload::MarkPrice(_)));
+    let payload = check("coin_futures/web_socket_streams/pair_mark_prices");
+    assert!(matches!(
+        payload,
+        CoinFuturesStreamPayload::MarkPrices(prices) if prices[1].funding_rate.is_none()
+    ));
+    let payload = check("coin_futures/web_socket_streams/all_market_mini_tickers");
+    assert!(
+        matches!(payload, CoinFuturesStreamPayload::MiniTickers(tickers) if tickers.len() == 2)
+    );
+}
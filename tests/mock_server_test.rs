@@ -0,0 +1,244 @@
+use std::time::Duration;
+
+use birdie::{
+    coin_futures::trade::{NewOrderInput, NewOrderParams as CoinNewOrderParams},
+    enums::{futures, OrderSide, OrderType},
+    errors::BinanceErrorCode,
+    rest_api::{Endpoint, RestApiClient, RestApiError, RetryPolicy},
+    signer::Ed25519Signer,
+    spot::{
+        account::AccountInformationParams, general::CheckServerTimeParams, trade::NewOrderParams,
+    },
+    test_support::{MockResponse, MockServer, API_KEY, ED25519_PRIVATE_KEY, SECRET_KEY},
+    web_socket_api::{WebSocket, WebSocketApiClient, WebSocketApiError},
+};
+use reqwest::Method;
+
+mod common;
+
+fn error_code(resp: Result<impl std::fmt::Debug, RestApiError>) -> Option<BinanceErrorCode> {
+    resp.expect_err("expected an error").error_code()
+}
+
+#[tokio::test]
+async fn mock_signed_request() {
+    let server = MockServer::start().await;
+    server.mock(
+        Method::GET,
+        "/api/v3/account",
+        MockResponse::json(&common::fixture("spot/account/account_information")),
+    );
+    let params = AccountInformationParams::new;
+
+    // HMAC
+    let client = RestApiClient::new(&server.rest_url(), API_KEY, SECRET_KEY).unwrap();
+    let resp = client
+        .account()
+        .account_information()
+        .request(params())
+        .await;
+    assert!(resp.is_ok());
+
+    // Ed25519
+    let signer = Ed25519Signer::new(ED25519_PRIVATE_KEY).unwrap();
+    let client = RestApiClient::with_signer(&server.rest_url(), API_KEY, signer).unwrap();
+    let resp = client
+        .account()
+        .account_information()
+        .request(params())
+        .await;
+    assert!(resp.is_ok());
+
+    // wrong secret key
+    let client = RestApiClient::new(&server.rest_url(), API_KEY, "wrong").unwrap();
+    let resp = client
+        .account()
+        .account_information()
+        .request(params())
+        .await;
+    assert_eq!(error_code(resp), Some(BinanceErrorCode::InvalidSignature));
+
+    // wrong API key
+    let client = RestApiClient::new(&server.rest_url(), "wrong", SECRET_KEY).unwrap();
+    let resp = client
+        .account()
+        .account_information()
+        .request(params())
+        .await;
+    assert_eq!(error_code(resp), Some(BinanceErrorCode::RejectedMbxKey));
+
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.method, Some(Method::GET));
+    assert_eq!(req.path, "/api/v3/account");
+    assert!(req.has_param("timestamp"));
+}
+
+#[tokio::test]
+async fn mock_scripted_responses() {
+    let server = MockServer::start().await;
+    server.script(
+        Method::GET,
+        "/api/v3/time",
+        vec![
+            MockResponse::error(503, -1008, "Server is currently overloaded."),
+            MockResponse::json(r#"{"serverTime":1}"#),
+        ],
+    );
+
+    let policy = RetryPolicy::new().initial_backoff(Duration::ZERO);
+    let client = RestApiClient::builder(&server.rest_url())
+        .retry_policy(policy)
+        .build()
+        .unwrap();
+    let params = CheckServerTimeParams::new;
+
+    // The first response is retried, the second one is returned.
+    let resp = client.general().check_server_time().request(params()).await;
+    assert_eq!(resp.unwrap().server_time, 1);
+    assert_eq!(server.requests().len(), 2);
+
+    // Once the script is used up, the default route is used.
+    let resp = client.general().check_server_time().request(params()).await;
+    assert!(resp.unwrap().server_time > 1);
+}
+
+#[tokio::test]
+async fn mock_order_retries() {
+    let server = MockServer::start().await;
+    let policy = RetryPolicy::new().initial_backoff(Duration::ZERO);
+    let client = RestApiClient::builder(&server.rest_url())
+        .api_key(API_KEY)
+        .secret_key(SECRET_KEY)
+        .retry_policy(policy)
+        .build()
+        .unwrap();
+    let params = || {
+        NewOrderParams::new("BTCUSDT", OrderSide::Buy, OrderType::Market)
+            .quantity(1.0)
+            .new_client_order_id("order-1")
+    };
+    let orders = || {
+        server
+            .requests()
+            .into_iter()
+            .filter(|req| req.path == "/api/v3/order")
+            .count()
+    };
+
+    // The order may have been executed, it is not sent again.
+    server.script(
+        Method::POST,
+        "/api/v3/order",
+        vec![
+            MockResponse::error(
+                503,
+                -1007,
+                "Timeout waiting for response from backend server.",
+            ),
+            MockResponse::json(&common::fixture("spot/trade/new_order_ack")),
+        ],
+    );
+    let resp = client.trade().new_order().request(params()).await;
+    assert_eq!(error_code(resp), Some(BinanceErrorCode::Timeout));
+    assert_eq!(orders(), 1);
+
+    // The order was rejected before being executed, it is sent again.
+    server.script(
+        Method::POST,
+        "/api/v3/order",
+        vec![
+            MockResponse::error(400, -1008, "Server is currently overloaded."),
+            MockResponse::json(&common::fixture("spot/trade/new_order_ack")),
+        ],
+    );
+    let resp = client.trade().new_order().request(params()).await;
+    assert!(resp.is_ok());
+    assert_eq!(orders(), 3);
+}
+
+#[tokio::test]
+async fn mock_coin_futures_order_retries() {
+    let server = MockServer::start().await;
+    let policy = RetryPolicy::new().initial_backoff(Duration::ZERO);
+    let client = RestApiClient::builder(&server.rest_url())
+        .api_key(API_KEY)
+        .secret_key(SECRET_KEY)
+        .retry_policy(policy)
+        .build()
+        .unwrap();
+    let params = || {
+        let input = NewOrderInput::new(
+            "BTCUSD_PERP",
+            futures::OrderSide::Buy,
+            futures::OrderType::Market,
+        )
+        .quantity(1.0)
+        .new_client_order_id("order-1");
+        CoinNewOrderParams::new(input)
+    };
+    let orders = || {
+        server
+            .requests()
+            .into_iter()
+            .filter(|req| req.path == "/dapi/v1/order")
+            .count()
+    };
+
+    // A 5xx leaves the execution status unknown, the order is not sent again.
+    server.script(
+        Method::POST,
+        "/dapi/v1/order",
+        vec![
+            MockResponse::json("Internal Server Error").status(500),
+            MockResponse::json(&common::fixture("coin_futures/trade/new_order")),
+        ],
+    );
+    let resp = client
+        .coin_futures()
+        .trade()
+        .new_order()
+        .request(params())
+        .await;
+    assert!(resp.is_err());
+    assert_eq!(orders(), 1);
+
+    server.script(
+        Method::POST,
+        "/dapi/v1/order",
+        vec![
+            MockResponse::error(400, -1008, "Server is currently overloaded."),
+            MockResponse::json(&common::fixture("coin_futures/trade/new_order")),
+        ],
+    );
+    let resp = client
+        .coin_futures()
+        .trade()
+        .new_order()
+        .request(params())
+        .await;
+    assert!(resp.is_ok());
+    assert_eq!(orders(), 3);
+}
+
+#[tokio::test]
+async fn mock_web_socket_logon() {
+    let server = MockServer::start().await;
+    server.mock_ws(
+        "account.status",
+        MockResponse::json(&common::fixture("spot/account/account_information")),
+    );
+
+    let mut client =
+        WebSocketApiClient::new(&server.ws_api_url(), API_KEY, ED25519_PRIVATE_KEY).unwrap();
+    let (tx, _rx) = tokio::sync::mpsc::channel(10);
+    client.connect(tx).await.unwrap();
+    let params = AccountInformationParams::new();
+    let resp = client.account().account_information().request(params).await;
+    assert!(resp.is_ok());
+
+    let signer = Ed25519Signer::new(ED25519_PRIVATE_KEY).unwrap();
+    let mut client = WebSocketApiClient::with_signer(&server.ws_api_url(), "wrong", signer);
+    let (tx, _rx) = tokio::sync::mpsc::channel(10);
+    let resp = client.connect(tx).await;
+    assert!(matches!(resp, Err(WebSocketApiError::Binance(s, _)) if s == "401"));
+}
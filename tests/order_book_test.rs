@@ -0,0 +1,164 @@
+use std::{sync::Arc, time::Duration};
+
+use birdie::{
+    decimal::Decimal,
+    order_book::{BookSide, LocalOrderBook, OrderBookEvent},
+    rest_api::RestApiClient,
+    test_support::{MockResponse, MockServer, API_KEY, SECRET_KEY},
+};
+use reqwest::Method;
+use serde_json::json;
+use tokio::{sync::broadcast, time::timeout};
+
+fn dec(s: &str) -> Decimal {
+    s.parse().unwrap()
+}
+
+fn rest_client(server: &MockServer) -> Arc<RestApiClient> {
+    Arc::new(RestApiClient::new(&server.rest_url(), API_KEY, SECRET_KEY).unwrap())
+}
+
+fn snapshot(last_update_id: i64) -> MockResponse {
+    let snapshot = json!({
+        "lastUpdateId": last_update_id,
+        "E": 1700000000000i64,
+        "T": 1700000000000i64,
+        "bids": [["10.0", "1"], ["9.0", "2"]],
+        "asks": [["11.0", "1"], ["12.0", "3"]],
+    });
+    MockResponse::json(&snapshot.to_string())
+}
+
+fn depth(first: i64, last: i64, prev: i64, bids: &[(&str, &str)], asks: &[(&str, &str)]) -> String {
+    json!({
+        "e": "depthUpdate",
+        "E": 1700000000000i64,
+        "T": 1700000000000i64,
+        "s": "BTCUSDT",
+        "U": first,
+        "u": last,
+        "pu": prev,
+        "b": bids,
+        "a": asks,
+    })
+    .to_string()
+}
+
+async fn next_event(rx: &mut broadcast::Receiver<OrderBookEvent>) -> OrderBookEvent {
+    timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .expect("no event received")
+        .expect("event channel closed")
+}
+
+/// Push an update until the book is synced, as the server may not be
+/// subscribed to the pushes yet when the stream is connected.
+async fn sync(server: &MockServer, rx: &mut broadcast::Receiver<OrderBookEvent>, update: &str) {
+    for _ in 0..50 {
+        server.push(update);
+        while let Ok(Ok(event)) = timeout(Duration::from_millis(100), rx.recv()).await {
+            if let OrderBookEvent::Synced(_) = event {
+                return;
+            }
+        }
+    }
+    panic!("order book not synced");
+}
+
+#[tokio::test]
+async fn spot_order_book() {
+    let server = MockServer::start().await;
+    server.mock(Method::GET, "/api/v3/depth", snapshot(100));
+    let mut book = LocalOrderBook::spot(rest_client(&server), &server.ws_stream_url(), "BTCUSDT");
+    let mut events = book.subscribe();
+    book.start().await.unwrap();
+    assert_eq!(book.best_bid(), None);
+
+    sync(
+        &server,
+        &mut events,
+        &depth(95, 101, 0, &[("9.5", "4")], &[]),
+    )
+    .await;
+    assert_eq!(book.last_update_id(), Some(101));
+    assert_eq!(book.best_bid(), Some((dec("10"), dec("1"))));
+    assert_eq!(book.depth_at(BookSide::Bid, dec("9.5")), Some(dec("4")));
+
+    server.push(&depth(102, 103, 0, &[("10.0", "0")], &[("11.0", "1.5")]));
+    assert!(matches!(
+        next_event(&mut events).await,
+        OrderBookEvent::Updated(103)
+    ));
+    assert_eq!(book.best_bid(), Some((dec("9.5"), dec("4"))));
+    assert_eq!(book.best_ask(), Some((dec("11"), dec("1.5"))));
+    assert_eq!(book.vwap(BookSide::Ask, dec("2")), Some(dec("11.25")));
+    assert_eq!(book.vwap(BookSide::Ask, dec("5")), None);
+
+    // A gap in the updates, the book is synced again from a new snapshot.
+    server.mock(Method::GET, "/api/v3/depth", snapshot(200));
+    server.push(&depth(150, 201, 0, &[], &[("11.0", "0")]));
+    assert!(matches!(
+        next_event(&mut events).await,
+        OrderBookEvent::Resyncing
+    ));
+    assert!(matches!(
+        next_event(&mut events).await,
+        OrderBookEvent::Synced(201)
+    ));
+    assert_eq!(book.best_bid(), Some((dec("10"), dec("1"))));
+    assert_eq!(book.best_ask(), Some((dec("12"), dec("3"))));
+}
+
+#[tokio::test]
+async fn usd_futures_order_book() {
+    let server = MockServer::start().await;
+    server.mock(Method::GET, "/fapi/v1/depth", snapshot(100));
+    let mut book =
+        LocalOrderBook::usd_futures(rest_client(&server), &server.ws_stream_url(), "BTCUSDT");
+    let mut events = book.subscribe();
+    book.start().await.unwrap();
+
+    sync(&server, &mut events, &depth(90, 105, 89, &[], &[])).await;
+    assert_eq!(book.last_update_id(), Some(105));
+
+    server.push(&depth(110, 120, 105, &[("10.5", "2")], &[]));
+    assert!(matches!(
+        next_event(&mut events).await,
+        OrderBookEvent::Updated(120)
+    ));
+    assert_eq!(book.best_bid(), Some((dec("10.5"), dec("2"))));
+
+    server.push(&depth(130, 140, 125, &[], &[]));
+    assert!(matches!(
+        next_event(&mut events).await,
+        OrderBookEvent::Resyncing
+    ));
+    assert_eq!(book.best_bid(), None);
+}
+
+#[tokio::test]
+async fn order_book_snapshot_retry() {
+    let server = MockServer::start().await;
+    server.script(
+        Method::GET,
+        "/api/v3/depth",
+        vec![
+            MockResponse::error(400, -1121, "Invalid symbol."),
+            snapshot(100),
+        ],
+    );
+    let mut book = LocalOrderBook::spot(rest_client(&server), &server.ws_stream_url(), "BTCUSDT");
+    let mut events = book.subscribe();
+    book.start().await.unwrap();
+
+    // The updates pushed while the snapshot fails are buffered, and applied
+    // once it succeeds.
+    sync(&server, &mut events, &depth(95, 101, 0, &[], &[])).await;
+    assert_eq!(book.last_update_id(), Some(101));
+    let snapshots = server
+        .requests()
+        .into_iter()
+        .filter(|req| req.path == "/api/v3/depth")
+        .count();
+    assert_eq!(snapshots, 2);
+}
@@ -56,10 +56,13 @@ async fn rest_query_prevented_matches() {
         .request(params)
         .await;
     assert!(resp.is_err());
-    let RestApiError::Binance(s, Some(BinanceError { code, .. })) = resp.err().unwrap() else {
+    let RestApiError::Binance(err) = resp.err().unwrap() else {
         panic!()
     };
-    assert_eq!(s, "400 Bad Request");
+    let Some(BinanceError { code, .. }) = err.error else {
+        panic!()
+    };
+    assert_eq!(err.status, 400);
     assert!(matches!(code, BinanceErrorCode::OptionalParamsBadCombo));
 }
 
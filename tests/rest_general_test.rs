@@ -40,10 +40,13 @@ async fn rest_exchange_info() {
     let params = ExchangeInfoParams::new().symbol("NONEXIST");
     let resp = client.general().exchange_info().request(params).await;
     assert!(resp.is_err());
-    let RestApiError::Binance(s, Some(BinanceError { code, msg })) = resp.err().unwrap() else {
+    let RestApiError::Binance(err) = resp.err().unwrap() else {
         panic!()
     };
-    assert_eq!(s, "400 Bad Request");
+    let Some(BinanceError { code, msg }) = err.error else {
+        panic!()
+    };
+    assert_eq!(err.status, 400);
     assert_eq!(msg, "Invalid symbol.".to_owned());
     assert!(matches!(code, BinanceErrorCode::BadSymbol));
 
@@ -58,10 +61,13 @@ async fn rest_exchange_info() {
     let params = ExchangeInfoParams::new().symbols(&["BTCUSDT", "NONEXIST"]);
     let resp = client.general().exchange_info().request(params).await;
     assert!(resp.is_err());
-    let RestApiError::Binance(s, Some(BinanceError { code, .. })) = resp.err().unwrap() else {
+    let RestApiError::Binance(err) = resp.err().unwrap() else {
+        panic!()
+    };
+    let Some(BinanceError { code, .. }) = err.error else {
         panic!()
     };
-    assert_eq!(s, "400 Bad Request");
+    assert_eq!(err.status, 400);
     assert!(matches!(code, BinanceErrorCode::BadSymbol));
 
     // both symbol and symbols param
@@ -70,9 +76,12 @@ async fn rest_exchange_info() {
         .symbols(&["ETHUSDT"]);
     let resp = client.general().exchange_info().request(params).await;
     assert!(resp.is_err());
-    let RestApiError::Binance(s, Some(BinanceError { code, .. })) = resp.err().unwrap() else {
+    let RestApiError::Binance(err) = resp.err().unwrap() else {
+        panic!()
+    };
+    let Some(BinanceError { code, .. }) = err.error else {
         panic!()
     };
-    assert_eq!(s, "400 Bad Request");
+    assert_eq!(err.status, 400);
     assert!(matches!(code, BinanceErrorCode::OptionalParamsBadCombo));
 }
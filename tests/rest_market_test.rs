@@ -51,7 +51,7 @@ async fn rest_aggregate_trades_list() {
 async fn rest_kline_data() {
     let client = common::setup_rest_api_client();
     let params = KlinesParams::new("BTCUSDT", KlineInterval::OneHour).limit(10);
-    let resp = client.market().kline_data().request(params).await;
+    let resp = client.market().klines().request(params).await;
     assert!(resp.is_ok());
 }
 
@@ -22,10 +22,13 @@ async fn rest_test_new_order() {
     let params = TestNewOrderParams::new(new_order_params);
     let resp = client.trade().test_new_order().request(params).await;
     assert!(resp.is_err());
-    let RestApiError::Binance(s, Some(BinanceError { code, msg })) = resp.err().unwrap() else {
+    let RestApiError::Binance(err) = resp.err().unwrap() else {
         panic!()
     };
-    assert_eq!(s, "400 Bad Request");
+    let Some(BinanceError { code, msg }) = err.error else {
+        panic!()
+    };
+    assert_eq!(err.status, 400);
     assert_eq!(msg, "Filter failure: PERCENT_PRICE_BY_SIDE");
     assert!(matches!(code, BinanceErrorCode::FilterFailure));
 
@@ -37,10 +40,13 @@ async fn rest_test_new_order() {
     let params = TestNewOrderParams::new(new_order_params);
     let resp = client.trade().test_new_order().request(params).await;
     assert!(resp.is_err());
-    let RestApiError::Binance(s, Some(BinanceError { code, msg })) = resp.err().unwrap() else {
+    let RestApiError::Binance(err) = resp.err().unwrap() else {
+        panic!()
+    };
+    let Some(BinanceError { code, msg }) = err.error else {
         panic!()
     };
-    assert_eq!(s, "400 Bad Request");
+    assert_eq!(err.status, 400);
     assert_eq!(msg, "Invalid symbol.");
     assert!(matches!(code, BinanceErrorCode::BadSymbol));
 }
@@ -52,10 +58,13 @@ async fn rest_query_order() {
     let params = QueryOrderParams::new("BTCUSDT");
     let resp = client.trade().query_order().request(params).await;
     assert!(resp.is_err());
-    let RestApiError::Binance(s, Some(BinanceError { code, .. })) = resp.err().unwrap() else {
+    let RestApiError::Binance(err) = resp.err().unwrap() else {
+        panic!()
+    };
+    let Some(BinanceError { code, .. }) = err.error else {
         panic!()
     };
-    assert_eq!(s, "400 Bad Request");
+    assert_eq!(err.status, 400);
     assert!(matches!(
         code,
         BinanceErrorCode::MandatoryParamEmptyOrMalformed
@@ -64,10 +73,13 @@ async fn rest_query_order() {
     let params = QueryOrderParams::new("BTCUSDT").order_id(0);
     let resp = client.trade().query_order().request(params).await;
     assert!(resp.is_err());
-    let RestApiError::Binance(s, Some(BinanceError { code, .. })) = resp.err().unwrap() else {
+    let RestApiError::Binance(err) = resp.err().unwrap() else {
         panic!()
     };
-    assert_eq!(s, "400 Bad Request");
+    let Some(BinanceError { code, .. }) = err.error else {
+        panic!()
+    };
+    assert_eq!(err.status, 400);
     assert!(matches!(code, BinanceErrorCode::OrderArchived));
 }
 
@@ -78,10 +90,13 @@ async fn rest_cancel_order() {
     let params = CancelOrderParams::new("BTCUSDT").order_id(1);
     let resp = client.trade().cancel_order().request(params).await;
     assert!(resp.is_err());
-    let RestApiError::Binance(s, Some(BinanceError { code, .. })) = resp.err().unwrap() else {
+    let RestApiError::Binance(err) = resp.err().unwrap() else {
+        panic!()
+    };
+    let Some(BinanceError { code, .. }) = err.error else {
         panic!()
     };
-    assert_eq!(s, "400 Bad Request");
+    assert_eq!(err.status, 400);
     assert!(matches!(code, BinanceErrorCode::CancelRejected));
 }
 
@@ -96,10 +111,13 @@ async fn rest_cancel_all_open_orders() {
         .request(params)
         .await;
     assert!(resp.is_err());
-    let RestApiError::Binance(s, Some(BinanceError { code, .. })) = resp.err().unwrap() else {
+    let RestApiError::Binance(err) = resp.err().unwrap() else {
+        panic!()
+    };
+    let Some(BinanceError { code, .. }) = err.error else {
         panic!()
     };
-    assert_eq!(s, "400 Bad Request");
+    assert_eq!(err.status, 400);
     assert!(matches!(code, BinanceErrorCode::CancelRejected));
 }
 
@@ -0,0 +1,63 @@
+use birdie::{
+    rest_api::Endpoint,
+    usd_futures::{
+        account::{
+            AccountInformationV2Params, FuturesAccountBalanceV2Params, GetIncomeHistoryParams,
+        },
+        trade::QueryAllOrdersParams,
+    },
+};
+
+mod common;
+
+#[tokio::test]
+async fn rest_usd_futures_account_information_v2() {
+    let client = common::setup_rest_api_client();
+    let params = AccountInformationV2Params::new();
+    let resp = client
+        .usd_futures()
+        .account()
+        .account_information_v2()
+        .request(params)
+        .await;
+    assert!(resp.is_ok());
+}
+
+#[tokio::test]
+async fn rest_usd_futures_futures_account_balance_v2() {
+    let client = common::setup_rest_api_client();
+    let params = FuturesAccountBalanceV2Params::new();
+    let resp = client
+        .usd_futures()
+        .account()
+        .futures_account_balance_v2()
+        .request(params)
+        .await;
+    assert!(resp.is_ok());
+}
+
+#[tokio::test]
+async fn rest_usd_futures_get_income_history() {
+    let client = common::setup_rest_api_client();
+    let params = GetIncomeHistoryParams::new();
+    let resp = client
+        .usd_futures()
+        .account()
+        .get_income_history()
+        .request(params)
+        .await;
+    assert!(resp.is_ok());
+}
+
+#[tokio::test]
+async fn rest_usd_futures_query_all_orders() {
+    let client = common::setup_rest_api_client();
+    let params = QueryAllOrdersParams::new("BTCUSDT");
+    let resp = client
+        .usd_futures()
+        .trade()
+        .query_all_orders()
+        .request(params)
+        .await;
+    assert!(resp.is_ok());
+}
@@ -0,0 +1,64 @@
+use birdie::{
+    enums::KlineInterval,
+    rest_api::Endpoint,
+    usd_futures::market::{ExchangeInfoParams, KlinesParams, OrderBookParams, Ticker24hrParams},
+};
+
+mod common;
+
+#[tokio::test]
+async fn rest_usd_futures_exchange_info() {
+    let client = common::setup_rest_api_client();
+    let params = ExchangeInfoParams::new();
+    let resp = client
+        .usd_futures()
+        .market()
+        .exchange_info()
+        .request(params)
+        .await;
+    assert!(resp.is_ok());
+}
+
+#[tokio::test]
+async fn rest_usd_futures_order_book() {
+    let client = common::setup_rest_api_client();
+    let params = OrderBookParams::new("BTCUSDT").limit(5);
+    let resp = client
+        .usd_futures()
+        .market()
+        .order_book()
+        .request(params)
+        .await;
+    assert!(resp.is_ok());
+}
+
+#[tokio::test]
+async fn rest_usd_futures_klines() {
+    let client = common::setup_rest_api_client();
+    let params = KlinesParams::new("BTCUSDT", KlineInterval::OneHour).limit(10);
+    let resp = client.usd_futures().market().klines().request(params).await;
+    assert!(resp.is_ok());
+}
+
+#[tokio::test]
+async fn rest_usd_futures_ticker_24hr() {
+    let client = common::setup_rest_api_client();
+    let params = Ticker24hrParams::new().symbol("BTCUSDT");
+    let resp = client
+        .usd_futures()
+        .market()
+        .ticker_24hr()
+        .request(params)
+        .await;
+    assert!(resp.is_ok());
+
+    // Unknown symbols are rejected.
+    let params = Ticker24hrParams::new().symbol("NONEXIST");
+    let resp = client
+        .usd_futures()
+        .market()
+        .ticker_24hr()
+        .request(params)
+        .await;
+    assert!(resp.is_err());
+}
@@ -0,0 +1,217 @@
+use std::{sync::Arc, time::Duration};
+
+use birdie::{
+    coin_futures,
+    rest_api::RestApiClient,
+    spot::user_data_stream::UserDataStreamPayload,
+    test_support::{MockResponse, MockServer, API_KEY, SECRET_KEY},
+    usd_futures,
+    user_data_stream::{UserDataPayload, UserDataStream},
+    web_socket_stream::StreamEvent,
+};
+use futures_util::{Stream, StreamExt};
+use reqwest::Method;
+use tokio::time::{sleep, timeout};
+
+mod common;
+
+fn rest_client(server: &MockServer) -> Arc<RestApiClient> {
+    Arc::new(RestApiClient::new(&server.rest_url(), API_KEY, SECRET_KEY).unwrap())
+}
+
+fn listen_key(key: &str) -> MockResponse {
+    MockResponse::json(&format!(r#"{{"listenKey":"{key}"}}"#))
+}
+
+/// The next payload matching `f`, skipping the others.
+async fn next_matching<S, P, F>(stream: &mut S, f: &F, wait: Duration) -> Option<P>
+where
+    S: Stream<Item = Result<P, StreamEvent>> + Unpin,
+    F: Fn(&P) -> bool,
+{
+    while let Ok(Some(item)) = timeout(wait, stream.next()).await {
+        match item {
+            Ok(payload) if f(&payload) => return Some(payload),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Push `msg` until a payload matching `f` is received, as the server may not
+/// be subscribed to the pushes yet when the stream is connected.
+async fn receive<S, P, F>(server: &MockServer, stream: &mut S, msg: &str, f: F) -> P
+where
+    S: Stream<Item = Result<P, StreamEvent>> + Unpin,
+    F: Fn(&P) -> bool,
+{
+    for _ in 0..50 {
+        server.push(msg);
+        if let Some(payload) = next_matching(stream, &f, Duration::from_millis(100)).await {
+            return payload;
+        }
+    }
+    panic!("no payload received");
+}
+
+async fn wait_for<F>(f: F)
+where
+    F: Fn() -> bool,
+{
+    for _ in 0..50 {
+        if f() {
+            return;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+    panic!("condition not met");
+}
+
+#[tokio::test]
+async fn spot_user_data_stream() {
+    let server = MockServer::start().await;
+    let path = "/api/v3/userDataStream";
+    server.script(
+        Method::POST,
+        path,
+        vec![listen_key("key1"), listen_key("key2")],
+    );
+    server.mock(Method::PUT, path, MockResponse::json("{}"));
+    server.mock(Method::DELETE, path, MockResponse::json("{}"));
+
+    let mut stream = UserDataStream::spot(rest_client(&server), &server.ws_stream_url())
+        .keepalive_interval(Duration::from_millis(100));
+    stream.start().await.unwrap();
+    assert_eq!(stream.listen_key().as_deref(), Some("key1"));
+
+    let report = common::fixture("spot/user_data_stream/execution_report");
+    receive(&server, &mut stream, &report, |payload| {
+        matches!(payload, UserDataStreamPayload::ExecutionReport(_))
+    })
+    .await;
+
+    wait_for(|| {
+        server.requests().iter().any(|req| {
+            req.method == Some(Method::PUT)
+                && req.path == path
+                && req.param("listenKey") == Some("key1")
+        })
+    })
+    .await;
+
+    // The stream connects to a new listen key once the previous one expires.
+    server.push(&common::fixture("spot/user_data_stream/listen_key_expired"));
+    let expired = |payload: &UserDataStreamPayload| {
+        matches!(payload, UserDataStreamPayload::ListenKeyExpired(_))
+    };
+    next_matching(&mut stream, &expired, Duration::from_secs(5))
+        .await
+        .expect("no listenKeyExpired received");
+    wait_for(|| stream.listen_key().as_deref() == Some("key2")).await;
+    receive(&server, &mut stream, &report, |payload| {
+        matches!(payload, UserDataStreamPayload::ExecutionReport(_))
+    })
+    .await;
+
+    stream.close().await.unwrap();
+    assert!(server.requests().iter().any(|req| {
+        req.method == Some(Method::DELETE) && req.param("listenKey") == Some("key2")
+    }));
+    assert!(stream.next().await.is_none());
+}
+
+/// Start a futures user data stream, receive `fixture` from it, then check
+/// that its listen key is kept alive and closed with the stream.
+async fn assert_listen_key_lifecycle<P, C, F>(path: &str, ctor: C, fixture: &str, matcher: F)
+where
+    P: UserDataPayload,
+    C: FnOnce(Arc<RestApiClient>, &str) -> UserDataStream<P>,
+    F: Fn(&P) -> bool,
+{
+    let server = MockServer::start().await;
+    server.mock(Method::POST, path, listen_key("key1"));
+    server.mock(Method::PUT, path, listen_key("key1"));
+    server.mock(Method::DELETE, path, MockResponse::json("{}"));
+
+    let mut stream = ctor(rest_client(&server), &server.ws_stream_url())
+        .keepalive_interval(Duration::from_millis(100));
+    stream.start().await.unwrap();
+    assert_eq!(stream.listen_key().as_deref(), Some("key1"));
+
+    receive(&server, &mut stream, &common::fixture(fixture), matcher).await;
+
+    wait_for(|| {
+        server
+            .requests()
+            .iter()
+            .any(|req| req.method == Some(Method::PUT) && req.path == path)
+    })
+    .await;
+
+    stream.close().await.unwrap();
+    assert!(server
+        .requests()
+        .iter()
+        .any(|req| req.method == Some(Method::DELETE) && req.path == path));
+    assert!(stream.next().await.is_none());
+}
+
+#[tokio::test]
+async fn usd_futures_user_data_stream() {
+    use usd_futures::user_data_stream::UserDataStreamPayload;
+
+    assert_listen_key_lifecycle(
+        "/fapi/v1/listenKey",
+        UserDataStream::usd_futures,
+        "usd_futures/user_data_stream/order_trade_update",
+        |payload| matches!(payload, UserDataStreamPayload::OrderTradeUpdate(_)),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn coin_futures_user_data_stream() {
+    use coin_futures::user_data_stream::UserDataStreamPayload;
+
+    assert_listen_key_lifecycle(
+        "/dapi/v1/listenKey",
+        UserDataStream::coin_futures,
+        "coin_futures/user_data_stream/account_update",
+        |payload| matches!(payload, UserDataStreamPayload::AccountUpdate(_)),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn isolated_margin_user_data_stream_renews_invalid_listen_key() {
+    let server = MockServer::start().await;
+    let path = "/sapi/v1/userDataStream/isolated";
+    server.script(
+        Method::POST,
+        path,
+        vec![listen_key("key1"), listen_key("key2")],
+    );
+    server.mock(
+        Method::PUT,
+        path,
+        MockResponse::error(400, -1125, "This listenKey does not exist."),
+    );
+
+    let mut stream =
+        UserDataStream::isolated_margin(rest_client(&server), &server.ws_stream_url(), "BTCUSDT")
+            .keepalive_interval(Duration::from_millis(100));
+    stream.start().await.unwrap();
+
+    wait_for(|| stream.listen_key().as_deref() == Some("key2")).await;
+    let requests = server.requests();
+    let keepalive = requests
+        .iter()
+        .find(|req| req.method == Some(Method::PUT))
+        .unwrap();
+    assert_eq!(keepalive.param("symbol"), Some("BTCUSDT"));
+    assert_eq!(keepalive.param("listenKey"), Some("key1"));
+    assert!(requests
+        .iter()
+        .filter(|req| req.method == Some(Method::POST))
+        .all(|req| req.param("symbol") == Some("BTCUSDT")));
+}
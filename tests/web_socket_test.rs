@@ -0,0 +1,844 @@
+use std::{sync::Arc, time::Duration};
+
+use birdie::{
+    coin_futures::web_socket_streams::CoinFuturesStreamPayload,
+    enums::{
+        futures::{MarkPriceSpeed, OrderSide},
+        OrderType, RateLimit, RateLimitIntervals,
+    },
+    rest_api::{RateLimitMode, RateLimiter, RestApiClient},
+    signer::HmacSigner,
+    spot::{
+        account::AccountInformationParams,
+        general::TestConnectivityParams,
+        user_data_stream::UserDataStreamPayload,
+        web_socket_streams::{SpotStreamPayloadload as Payload, Trade},
+    },
+    test_support::{MockResponse, MockServer, API_KEY, ED25519_PRIVATE_KEY, SECRET_KEY},
+    usd_futures::{
+        account::{AccountInformationV2Params, FuturesAccountBalanceV2Params},
+        trade::{NewOrderInput, NewOrderParams, QueryOrderParams},
+        web_socket_streams::UsdFuturesStreamPayload,
+    },
+    web_socket::{ConnectionStatus, ReconnectPolicy},
+    web_socket_api::{WebSocket, WebSocketApiClient, WebSocketApiError},
+    web_socket_stream::{
+        connect_combined_streams, connect_stream_with_policy, open_stream_with_policy, StreamEvent,
+        StreamName, WebSocketStreamError, MAX_STREAMS,
+    },
+};
+use futures_util::StreamExt;
+use tokio::{sync::mpsc, time::timeout};
+
+mod common;
+
+fn reconnect_policy() -> ReconnectPolicy {
+    ReconnectPolicy::new()
+        .initial_backoff(Duration::from_millis(50))
+        .jitter(false)
+}
+
+async fn next_status(rx: &mut mpsc::Receiver<ConnectionStatus>) -> ConnectionStatus {
+    timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .expect("no status received")
+        .expect("status channel closed")
+}
+
+async fn next_stream_status(rx: &mut mpsc::Receiver<StreamEvent>) -> ConnectionStatus {
+    let event = timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .expect("no event received")
+        .expect("event channel closed");
+    match event {
+        StreamEvent::Status(status) => status,
+        event => panic!("unexpected event: {event:?}"),
+    }
+}
+
+/// Push a trade until it is received, as the server may not be subscribed to
+/// the pushes yet when the client is connected.
+async fn receive_trade(server: &MockServer, rx: &mut mpsc::Receiver<Trade>) -> Trade {
+    let fixture: serde_json::Value =
+        serde_json::from_str(&common::fixture("spot/web_socket_streams/trade")).unwrap();
+    let trade = fixture["data"].to_string();
+    for _ in 0..50 {
+        server.push(&trade);
+        if let Ok(Some(trade)) = timeout(Duration::from_millis(100), rx.recv()).await {
+            return trade;
+        }
+    }
+    panic!("no trade received");
+}
+
+/// Push `fixture` until a payload is received, as the server may not be
+/// subscribed to the pushes yet when the streams are connected.
+async fn push_until_received<T>(
+    server: &MockServer,
+    rx: &mut mpsc::Receiver<T>,
+    fixture: &str,
+) -> T {
+    let msg = common::fixture(fixture);
+    for _ in 0..50 {
+        server.push(&msg);
+        if let Ok(Some(payload)) = timeout(Duration::from_millis(100), rx.recv()).await {
+            return payload;
+        }
+    }
+    panic!("no payload received");
+}
+
+#[tokio::test]
+async fn stream_reconnect() {
+    let server = MockServer::start().await;
+    let endpoint = format!("{}/ws/btcusdt@trade", server.ws_stream_url());
+    let (event_tx, mut event_rx) = mpsc::channel(10);
+    let (data_tx, mut data_rx) = mpsc::channel(10);
+    connect_stream_with_policy(&endpoint, reconnect_policy(), data_tx, event_tx)
+        .await
+        .unwrap();
+    assert!(matches!(
+        next_stream_status(&mut event_rx).await,
+        ConnectionStatus::Connected
+    ));
+    receive_trade(&server, &mut data_rx).await;
+
+    server.disconnect_ws();
+    assert!(matches!(
+        next_stream_status(&mut event_rx).await,
+        ConnectionStatus::Disconnected
+    ));
+    assert!(matches!(
+        next_stream_status(&mut event_rx).await,
+        ConnectionStatus::Reconnecting
+    ));
+    assert!(matches!(
+        next_stream_status(&mut event_rx).await,
+        ConnectionStatus::Reconnected
+    ));
+    let trade = receive_trade(&server, &mut data_rx).await;
+    assert_eq!(trade.symbol, "BTCUSDT");
+}
+
+#[tokio::test]
+async fn stream_reconnect_gives_up() {
+    let server = MockServer::start().await;
+    let endpoint = format!("{}/ws/btcusdt@trade", server.ws_stream_url());
+    let (event_tx, mut event_rx) = mpsc::channel(10);
+    let (data_tx, _data_rx) = mpsc::channel::<Trade>(10);
+    let policy = reconnect_policy().max_attempts(2);
+    connect_stream_with_policy(&endpoint, policy, data_tx, event_tx)
+        .await
+        .unwrap();
+    assert!(matches!(
+        next_stream_status(&mut event_rx).await,
+        ConnectionStatus::Connected
+    ));
+
+    server.disconnect_ws();
+    drop(server);
+    assert!(matches!(
+        next_stream_status(&mut event_rx).await,
+        ConnectionStatus::Disconnected
+    ));
+    for _ in 0..2 {
+        assert!(matches!(
+            next_stream_status(&mut event_rx).await,
+            ConnectionStatus::Reconnecting
+        ));
+    }
+    assert!(matches!(
+        next_stream_status(&mut event_rx).await,
+        ConnectionStatus::GaveUp
+    ));
+    assert!(event_rx.recv().await.is_none());
+}
+
+#[tokio::test]
+async fn ws_api_reconnect_logs_on() {
+    let server = MockServer::start().await;
+    server.mock_ws(
+        "account.status",
+        MockResponse::json(&common::fixture("spot/account/account_information")),
+    );
+    let mut client = WebSocketApiClient::new(&server.ws_api_url(), API_KEY, ED25519_PRIVATE_KEY)
+        .unwrap()
+        .reconnect_policy(reconnect_policy());
+    let (tx, mut rx) = mpsc::channel(10);
+    client.connect(tx).await.unwrap();
+    assert!(matches!(
+        next_status(&mut rx).await,
+        ConnectionStatus::Connected
+    ));
+
+    server.disconnect_ws();
+    assert!(matches!(
+        next_status(&mut rx).await,
+        ConnectionStatus::Disconnected
+    ));
+    assert!(matches!(
+        next_status(&mut rx).await,
+        ConnectionStatus::Reconnecting
+    ));
+    assert!(matches!(
+        next_status(&mut rx).await,
+        ConnectionStatus::Reconnected
+    ));
+
+    // Signed requests require the session to be logged on.
+    let params = AccountInformationParams::new();
+    let resp = client.account().account_information().request(params).await;
+    assert!(resp.is_ok());
+
+    let logons = server
+        .requests()
+        .into_iter()
+        .filter(|req| req.path == "session.logon")
+        .count();
+    assert_eq!(logons, 2);
+}
+
+#[tokio::test]
+async fn stream_subscriptions() {
+    let server = MockServer::start().await;
+    let (event_tx, mut event_rx) = mpsc::channel(10);
+    let (data_tx, _data_rx) = mpsc::channel::<Payload>(10);
+    let handle = connect_combined_streams(
+        &server.ws_stream_url(),
+        &[StreamName::trade("BTCUSDT")],
+        data_tx,
+        event_tx,
+    )
+    .await
+    .unwrap();
+    assert!(matches!(
+        next_stream_status(&mut event_rx).await,
+        ConnectionStatus::Connected
+    ));
+
+    handle
+        .subscribe(&[StreamName::trade("ETHUSDT")])
+        .await
+        .unwrap();
+    handle
+        .unsubscribe(&[StreamName::trade("BTCUSDT")])
+        .await
+        .unwrap();
+    let streams = handle.list_subscriptions().await.unwrap();
+    assert_eq!(streams, ["ethusdt@trade"]);
+    assert!(handle.get_property("combined").await.unwrap());
+    let resp = handle.set_property("unknown", true).await;
+    assert!(matches!(resp, Err(WebSocketStreamError::Binance(_))));
+    let streams: Vec<_> = (0..MAX_STREAMS)
+        .map(|i| StreamName::trade(&format!("SYM{i}")))
+        .collect();
+    let resp = handle.subscribe(&streams).await;
+    assert!(matches!(
+        resp,
+        Err(WebSocketStreamError::TooManyStreams(1025))
+    ));
+
+    // The subscriptions are restored on reconnection.
+    server.disconnect_ws();
+    loop {
+        if let ConnectionStatus::Reconnected = next_stream_status(&mut event_rx).await {
+            break;
+        }
+    }
+    let streams = handle.list_subscriptions().await.unwrap();
+    assert_eq!(streams, ["ethusdt@trade"]);
+}
+
+#[tokio::test]
+async fn stream_request_timeout() {
+    let server = MockServer::start().await;
+    server.ignore_stream_requests("SUBSCRIBE");
+    let (event_tx, _event_rx) = mpsc::channel(10);
+    let (data_tx, _data_rx) = mpsc::channel::<Payload>(10);
+    let handle = connect_combined_streams(
+        &server.ws_stream_url(),
+        &[StreamName::trade("BTCUSDT")],
+        data_tx,
+        event_tx,
+    )
+    .await
+    .unwrap()
+    .request_timeout(Duration::from_millis(100));
+
+    let resp = handle.subscribe(&[StreamName::trade("ETHUSDT")]).await;
+    assert!(matches!(resp, Err(WebSocketStreamError::Timeout)));
+    assert!(server.requests().iter().any(|req| req.path == "SUBSCRIBE"));
+
+    // The other requests are still answered.
+    let streams = handle.list_subscriptions().await.unwrap();
+    assert_eq!(streams, ["btcusdt@trade"]);
+}
+
+#[test]
+fn spot_stream_payload_routing() {
+    let payload = |name: &str| -> Payload {
+        serde_json::from_str(&common::fixture(&format!("spot/web_socket_streams/{name}"))).unwrap()
+    };
+    assert!(matches!(
+        payload("aggregate_trade"),
+        Payload::AggregatedTrade(_)
+    ));
+    assert!(matches!(payload("trade"), Payload::Trade(_)));
+    assert!(matches!(payload("kline"), Payload::Kline(_)));
+    assert!(matches!(payload("mini_ticker"), Payload::MiniTicker(_)));
+    assert!(matches!(payload("ticker"), Payload::Ticker(_)));
+    assert!(matches!(
+        payload("rolling_window_ticker"),
+        Payload::RollingWindowTicker(_)
+    ));
+    assert!(matches!(payload("book_ticker"), Payload::BookTicker(_)));
+    assert!(matches!(payload("avg_price"), Payload::AvgPrice(_)));
+    assert!(matches!(
+        payload("partial_book_depth"),
+        Payload::PartialBookDepth(_)
+    ));
+    assert!(matches!(payload("depth"), Payload::Depth(_)));
+
+    let mut value: serde_json::Value =
+        serde_json::from_str(&common::fixture("spot/web_socket_streams/mini_ticker")).unwrap();
+    value["stream"] = StreamName::all_market_mini_tickers().as_str().into();
+    value["data"] = serde_json::json!([value["data"].take()]);
+    let payload: Payload = serde_json::from_value(value).unwrap();
+    assert!(matches!(payload, Payload::MiniTickers(tickers) if tickers.len() == 1));
+}
+
+#[tokio::test]
+async fn payload_stream() {
+    let server = MockServer::start().await;
+    let endpoint = format!("{}/ws/btcusdt@trade", server.ws_stream_url());
+    let mut stream = open_stream_with_policy::<Trade>(&endpoint, ReconnectPolicy::disabled())
+        .await
+        .unwrap();
+    let next = |stream| async move {
+        timeout(Duration::from_secs(5), StreamExt::next(stream))
+            .await
+            .expect("no item received")
+    };
+    assert!(matches!(
+        next(&mut stream).await,
+        Some(Err(StreamEvent::Status(ConnectionStatus::Connected)))
+    ));
+
+    let fixture: serde_json::Value =
+        serde_json::from_str(&common::fixture("spot/web_socket_streams/trade")).unwrap();
+    let trade = fixture["data"].to_string();
+    let ((), received) = tokio::join!(
+        async {
+            for _ in 0..50 {
+                server.push(&trade);
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+        },
+        stream
+            .by_ref()
+            .filter_map(|item| async move { item.ok() })
+            .take(2)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(received.len(), 2);
+    assert_eq!(received[0].symbol, "BTCUSDT");
+
+    // The stream ends once the connection is lost for good.
+    server.disconnect_ws();
+    let events: Vec<_> = timeout(Duration::from_secs(5), stream.collect::<Vec<_>>())
+        .await
+        .unwrap();
+    assert!(matches!(
+        events[..],
+        [
+            ..,
+            Err(StreamEvent::Status(ConnectionStatus::Disconnected)),
+            Err(StreamEvent::Status(ConnectionStatus::GaveUp)),
+        ]
+    ));
+}
+
+#[tokio::test]
+async fn unparsed_messages() {
+    let server = MockServer::start().await;
+    let (event_tx, mut event_rx) = mpsc::channel(10);
+    let (data_tx, _data_rx) = mpsc::channel::<Payload>(10);
+    connect_combined_streams(
+        &server.ws_stream_url(),
+        &[StreamName::trade("BTCUSDT")],
+        data_tx,
+        event_tx,
+    )
+    .await
+    .unwrap();
+    assert!(matches!(
+        next_stream_status(&mut event_rx).await,
+        ConnectionStatus::Connected
+    ));
+
+    // Unknown stream types are reported like any other parse failure.
+    let msg = r#"{"stream":"btcusdt@unknown","data":{}}"#;
+    let unparsed = loop {
+        server.push(msg);
+        if let Ok(Some(StreamEvent::Unparsed(unparsed))) =
+            timeout(Duration::from_millis(100), event_rx.recv()).await
+        {
+            break unparsed;
+        }
+    };
+    assert_eq!(unparsed.message, msg);
+    assert!(unparsed.error.contains("Unknown stream type"));
+
+    // Messages of the Web Socket API that answer no request.
+    let (tx, mut rx) = mpsc::channel(10);
+    let mut client = WebSocketApiClient::new(&server.ws_api_url(), API_KEY, ED25519_PRIVATE_KEY)
+        .unwrap()
+        .unparsed_channel(tx);
+    let (status_tx, _status_rx) = mpsc::channel(10);
+    client.connect(status_tx).await.unwrap();
+    server.push_api("not json");
+    let unparsed = timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(unparsed.message, "not json");
+    let msg = r#"{"id":"unknown","status":200,"result":{}}"#;
+    server.push_api(msg);
+    let unparsed = timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(unparsed.message, msg);
+    assert_eq!(unparsed.error, "unexpected message");
+}
+
+#[tokio::test]
+async fn ws_api_user_data_stream() {
+    let server = MockServer::start().await;
+    let (unparsed_tx, mut unparsed_rx) = mpsc::channel(10);
+    let mut client = WebSocketApiClient::new(&server.ws_api_url(), API_KEY, ED25519_PRIVATE_KEY)
+        .unwrap()
+        .reconnect_policy(reconnect_policy())
+        .unparsed_channel(unparsed_tx);
+    let (status_tx, mut status_rx) = mpsc::channel(10);
+    client.connect(status_tx).await.unwrap();
+
+    let (tx, mut rx) = mpsc::channel(10);
+    client.subscribe_user_data(tx).await.unwrap();
+    let report: serde_json::Value =
+        serde_json::from_str(&common::fixture("spot/user_data_stream/execution_report")).unwrap();
+    let event = serde_json::json!({ "subscriptionId": 0, "event": report }).to_string();
+    server.push_api(&event);
+    let payload = timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(matches!(payload, UserDataStreamPayload::ExecutionReport(_)));
+
+    // The subscription is restored on reconnection.
+    server.disconnect_ws();
+    loop {
+        if let ConnectionStatus::Reconnected = next_status(&mut status_rx).await {
+            break;
+        }
+    }
+    server.push_api(&event);
+    let payload = timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(matches!(payload, UserDataStreamPayload::ExecutionReport(_)));
+    let subscriptions = server
+        .requests()
+        .into_iter()
+        .filter(|req| req.path == "userDataStream.subscribe")
+        .count();
+    assert_eq!(subscriptions, 2);
+
+    client.unsubscribe_user_data().await.unwrap();
+    server.push_api(&event);
+    let unparsed = timeout(Duration::from_secs(5), unparsed_rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(unparsed.error, "unexpected event");
+}
+
+#[tokio::test]
+async fn ws_api_request_timeout() {
+    let server = MockServer::start().await;
+    server.mock_ws(
+        "ping",
+        MockResponse::json("{}").delay(Duration::from_millis(500)),
+    );
+    let mut client = WebSocketApiClient::new(&server.ws_api_url(), API_KEY, ED25519_PRIVATE_KEY)
+        .unwrap()
+        .request_timeout(Duration::from_millis(100));
+    let (tx, _rx) = mpsc::channel(10);
+    client.connect(tx).await.unwrap();
+
+    let params = TestConnectivityParams::new();
+    let resp = client.general().test_connectivity().request(params).await;
+    assert!(matches!(resp, Err(WebSocketApiError::Timeout)));
+
+    // The late response is discarded.
+    server.mock_ws("ping", MockResponse::json("{}"));
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    let params = TestConnectivityParams::new();
+    let resp = client.general().test_connectivity().request(params).await;
+    assert!(resp.is_ok());
+
+    client.close().await;
+    let params = TestConnectivityParams::new();
+    let resp = client.general().test_connectivity().request(params).await;
+    assert!(resp.is_err());
+}
+
+#[tokio::test]
+async fn ws_api_timed_out_requests_are_forgotten() {
+    let server = MockServer::start().await;
+    server.mock_ws(
+        "ping",
+        MockResponse::json("{}").delay(Duration::from_millis(500)),
+    );
+    let (unparsed_tx, mut unparsed_rx) = mpsc::channel(10);
+    let mut client = WebSocketApiClient::new(&server.ws_api_url(), API_KEY, ED25519_PRIVATE_KEY)
+        .unwrap()
+        .request_timeout(Duration::from_millis(100))
+        .unparsed_channel(unparsed_tx);
+    let (tx, _rx) = mpsc::channel(10);
+    client.connect(tx).await.unwrap();
+
+    let params = TestConnectivityParams::new();
+    let resp = client.general().test_connectivity().request(params).await;
+    assert!(matches!(resp, Err(WebSocketApiError::Timeout)));
+
+    // The request is forgotten while the session is idle, its late response
+    // is unexpected.
+    let unparsed = timeout(Duration::from_secs(5), unparsed_rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(unparsed.error, "unexpected message");
+}
+
+#[tokio::test]
+async fn ws_api_pending_requests_fail_on_disconnect() {
+    let server = MockServer::start().await;
+    server.mock_ws(
+        "ping",
+        MockResponse::json("{}").delay(Duration::from_secs(5)),
+    );
+    let mut client = WebSocketApiClient::new(&server.ws_api_url(), API_KEY, ED25519_PRIVATE_KEY)
+        .unwrap()
+        .reconnect_policy(ReconnectPolicy::disabled());
+    let (tx, mut rx) = mpsc::channel(10);
+    client.connect(tx).await.unwrap();
+
+    let general = client.general();
+    let ping = general.test_connectivity();
+    let (resp, _) = tokio::join!(ping.request(TestConnectivityParams::new()), async {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        server.disconnect_ws();
+    });
+    assert!(matches!(resp, Err(WebSocketApiError::Disconnected)));
+    loop {
+        if let ConnectionStatus::GaveUp = next_status(&mut rx).await {
+            break;
+        }
+    }
+
+    let params = TestConnectivityParams::new();
+    let resp = client.general().test_connectivity().request(params).await;
+    assert!(matches!(resp, Err(WebSocketApiError::Disconnected)));
+}
+
+#[tokio::test]
+async fn ws_api_time_sync() {
+    let server = MockServer::start().await;
+    let mut client =
+        WebSocketApiClient::new(&server.ws_api_url(), API_KEY, ED25519_PRIVATE_KEY).unwrap();
+    let (tx, _rx) = mpsc::channel(10);
+    client.connect(tx).await.unwrap();
+    assert!(client.clock().is_synced());
+
+    // The logon is timestamped with the calibrated clock.
+    let paths: Vec<_> = server.requests().into_iter().map(|req| req.path).collect();
+    assert_eq!(paths, ["time", "session.logon"]);
+
+    // A clock shared with a client that calibrated it is not calibrated again.
+    let mut client = WebSocketApiClient::new(&server.ws_api_url(), API_KEY, ED25519_PRIVATE_KEY)
+        .unwrap()
+        .with_clock(client.clock().clone());
+    let (tx, _rx) = mpsc::channel(10);
+    client.connect(tx).await.unwrap();
+    let syncs = server
+        .requests()
+        .into_iter()
+        .filter(|req| req.path == "time")
+        .count();
+    assert_eq!(syncs, 1);
+}
+
+#[tokio::test]
+async fn ws_api_hmac_signer() {
+    let server = MockServer::start().await;
+    server.mock_ws(
+        "account.status",
+        MockResponse::json(&common::fixture("spot/account/account_information")),
+    );
+    let signer = HmacSigner::new(SECRET_KEY);
+    let mut client = WebSocketApiClient::with_signer(&server.ws_api_url(), API_KEY, signer);
+    let (tx, _rx) = mpsc::channel(10);
+    client.connect(tx).await.unwrap();
+
+    // The session is not logged on, signed requests carry their signature.
+    let resp = client
+        .account()
+        .account_information()
+        .request(AccountInformationParams::new().omit_zero_balances(true))
+        .await;
+    assert!(resp.is_ok());
+    let requests = server.requests();
+    assert!(requests.iter().all(|req| req.path != "session.logon"));
+    let req = requests
+        .iter()
+        .find(|req| req.path == "account.status")
+        .unwrap();
+    assert_eq!(req.param("apiKey"), Some(API_KEY));
+    assert!(req.has_param("signature"));
+
+    // Binance only accepts Ed25519 keys for the logon.
+    assert!(client.logon().await.is_err());
+}
+
+#[tokio::test]
+async fn ws_api_rate_limits() {
+    let server = MockServer::start().await;
+    let rest_client = RestApiClient::new(&server.rest_url(), API_KEY, SECRET_KEY).unwrap();
+    let mut client = WebSocketApiClient::new(&server.ws_api_url(), API_KEY, ED25519_PRIVATE_KEY)
+        .unwrap()
+        .with_rate_limiter(rest_client.rate_limiter().clone());
+    let (tx, _rx) = mpsc::channel(10);
+    client.connect(tx).await.unwrap();
+
+    // The clock is calibrated and the session logged on before the first
+    // request.
+    let params = TestConnectivityParams::new();
+    client
+        .general()
+        .test_connectivity()
+        .request(params)
+        .await
+        .unwrap();
+    let usage = rest_client.rate_limit_usage();
+    assert_eq!(usage.len(), 1);
+    assert_eq!(usage[0].rate_limit_type, "REQUEST_WEIGHT");
+    assert_eq!(usage[0].interval, RateLimitIntervals::Minute);
+    assert_eq!(usage[0].used, 3);
+    assert_eq!(usage[0].limit, Some(6000));
+
+    let mut client = WebSocketApiClient::new(&server.ws_api_url(), API_KEY, ED25519_PRIVATE_KEY)
+        .unwrap()
+        .return_rate_limits(false);
+    let (tx, _rx) = mpsc::channel(10);
+    client.connect(tx).await.unwrap();
+    let params = TestConnectivityParams::new();
+    client
+        .general()
+        .test_connectivity()
+        .request(params)
+        .await
+        .unwrap();
+    assert!(client.rate_limit_usage().is_empty());
+}
+
+#[tokio::test]
+async fn ws_api_rate_limits_before_send() {
+    let server = MockServer::start().await;
+    server.mock_ws(
+        "account.status",
+        MockResponse::json(&common::fixture("spot/account/account_information")),
+    );
+    let mut client = WebSocketApiClient::new(&server.ws_api_url(), API_KEY, ED25519_PRIVATE_KEY)
+        .unwrap()
+        .with_rate_limiter(Arc::new(RateLimiter::new(RateLimitMode::Reject)));
+    let (tx, _rx) = mpsc::channel(10);
+    client.connect(tx).await.unwrap();
+    client.rate_limiter().set_rate_limits(&[RateLimit {
+        rate_limit_type: "REQUEST_WEIGHT".to_owned(),
+        interval: RateLimitIntervals::Day,
+        interval_num: 1,
+        limit: 30,
+        count: None,
+    }]);
+
+    // The account status weighs 20, the second request is rejected before
+    // being sent.
+    let resp = client
+        .account()
+        .account_information()
+        .request(AccountInformationParams::new())
+        .await;
+    assert!(resp.is_ok());
+    let resp = client
+        .account()
+        .account_information()
+        .request(AccountInformationParams::new())
+        .await;
+    assert!(matches!(resp, Err(WebSocketApiError::RateLimited(_))));
+    let sent = server
+        .requests()
+        .into_iter()
+        .filter(|req| req.path == "account.status")
+        .count();
+    assert_eq!(sent, 1);
+}
+
+#[tokio::test]
+async fn usd_futures_combined_streams() {
+    let server = MockServer::start().await;
+    let (event_tx, _event_rx) = mpsc::channel(10);
+    let (data_tx, mut data_rx) = mpsc::channel(10);
+    connect_combined_streams::<UsdFuturesStreamPayload>(
+        &server.ws_stream_url(),
+        &[
+            StreamName::mark_price("BTCUSDT", MarkPriceSpeed::Ms1000),
+            StreamName::liquidation_order("BTCUSDT"),
+        ],
+        data_tx,
+        event_tx,
+    )
+    .await
+    .unwrap();
+
+    let payload = push_until_received(
+        &server,
+        &mut data_rx,
+        "usd_futures/web_socket_streams/mark_price",
+    )
+    .await;
+    let UsdFuturesStreamPayload::MarkPrice(mark_price) = payload else {
+        panic!("unexpected payload: {payload:?}");
+    };
+    assert_eq!(mark_price.symbol, "BTCUSDT");
+
+    server.push(&common::fixture(
+        "usd_futures/web_socket_streams/liquidation_order",
+    ));
+    let payload = timeout(Duration::from_secs(5), data_rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(matches!(
+        payload,
+        UsdFuturesStreamPayload::LiquidationOrder(_)
+    ));
+}
+
+#[tokio::test]
+async fn coin_futures_combined_streams() {
+    let server = MockServer::start().await;
+    let (event_tx, _event_rx) = mpsc::channel(10);
+    let (data_tx, mut data_rx) = mpsc::channel(10);
+    connect_combined_streams::<CoinFuturesStreamPayload>(
+        &server.ws_stream_url(),
+        &[
+            StreamName::mark_price("BTCUSD_PERP", MarkPriceSpeed::Ms1000),
+            StreamName::mark_price("BTCUSD", MarkPriceSpeed::Ms3000),
+        ],
+        data_tx,
+        event_tx,
+    )
+    .await
+    .unwrap();
+
+    let payload = push_until_received(
+        &server,
+        &mut data_rx,
+        "coin_futures/web_socket_streams/mark_price",
+    )
+    .await;
+    let CoinFuturesStreamPayload::MarkPrice(mark_price) = payload else {
+        panic!("unexpected payload: {payload:?}");
+    };
+    assert_eq!(mark_price.symbol, "BTCUSD_PERP");
+
+    // The stream of a pair has the mark prices of all its symbols.
+    server.push(&common::fixture(
+        "coin_futures/web_socket_streams/pair_mark_prices",
+    ));
+    let payload = timeout(Duration::from_secs(5), data_rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(matches!(
+        payload,
+        CoinFuturesStreamPayload::MarkPrices(prices) if prices.len() == 2
+    ));
+}
+
+#[tokio::test]
+async fn usd_futures_ws_api() {
+    let server = MockServer::start().await;
+    let fixture = |name: &str| MockResponse::json(&common::fixture(name));
+    server.mock_ws("order.place", fixture("usd_futures/trade/new_order"));
+    server.mock_ws("order.status", fixture("usd_futures/trade/query_order"));
+    server.mock_ws(
+        "account.balance",
+        fixture("usd_futures/account/futures_account_balance_v2"),
+    );
+    server.mock_ws(
+        "account.status",
+        fixture("usd_futures/account/account_information_v2"),
+    );
+    let mut client =
+        WebSocketApiClient::new(&server.ws_api_url(), API_KEY, ED25519_PRIVATE_KEY).unwrap();
+    let (tx, _rx) = mpsc::channel(10);
+    client.connect(tx).await.unwrap();
+    let futures = client.usd_futures();
+
+    let input = NewOrderInput::new(
+        "BTCUSDT",
+        OrderSide::Buy,
+        "BOTH".to_owned(),
+        OrderType::Market,
+    )
+    .quantity(1);
+    futures
+        .trade()
+        .new_order()
+        .request(NewOrderParams::new(input))
+        .await
+        .unwrap();
+    let params = QueryOrderParams::new("BTCUSDT").order_id(1);
+    futures.trade().query_order().request(params).await.unwrap();
+    let params = FuturesAccountBalanceV2Params::new();
+    let balances = futures
+        .account()
+        .futures_account_balance_v2()
+        .request(params)
+        .await
+        .unwrap();
+    assert_eq!(balances[0].asset, "USDT");
+    let params = AccountInformationV2Params::new();
+    futures
+        .account()
+        .account_information_v2()
+        .request(params)
+        .await
+        .unwrap();
+
+    // The signed requests rely on the logon of the session, and leave the
+    // unset options out.
+    let requests = server.requests();
+    let order = requests
+        .iter()
+        .find(|req| req.path == "order.place")
+        .unwrap();
+    assert_eq!(order.param("type"), Some("MARKET"));
+    assert!(order.has_param("timestamp"));
+    assert!(!order.has_param("signature"));
+    assert!(!order.has_param("price"));
+    assert!(!order.has_param("recvWindow"));
+}
@@ -77,7 +77,7 @@ async fn ws_kline_data() {
     assert!(matches!(rx.recv().await, Some(ConnectionStatus::Connected)));
 
     let params = KlinesParams::new("BTCUSDT", KlineInterval::OneHour).limit(10);
-    let resp = client.market().kline_data().request(params).await;
+    let resp = client.market().klines().request(params).await;
     assert!(resp.is_ok());
 }
 